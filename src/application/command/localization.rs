@@ -0,0 +1,580 @@
+use super::{Command, CommandOption, CommandOptionChoice, CommandType};
+use crate::util::Locale;
+use std::collections::HashMap;
+
+/// Maximum length of command and option names.
+const NAME_LENGTH_MAX: usize = 32;
+
+/// Maximum length of descriptions and choice names.
+const TEXT_LENGTH_MAX: usize = 100;
+
+/// Localized field of a command, option or choice.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LocalizationField {
+    /// Description of a command or option.
+    Description,
+    /// Name of a command, option or choice.
+    Name,
+}
+
+/// Localization of a command, option or choice that is missing or invalid.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LocalizationIssue {
+    /// Field that is localized.
+    pub field: LocalizationField,
+    /// Type of issue with the localization.
+    pub kind: LocalizationIssueType,
+    /// Locale of the localization.
+    pub locale: Locale,
+    /// Default names of the command, options and choice leading to the
+    /// localized field, starting with the command's name.
+    pub path: Vec<String>,
+}
+
+/// Type of [`LocalizationIssue`] that occurred.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LocalizationIssueType {
+    /// Localized name contains characters other than letters, numbers,
+    /// hyphens and underscores.
+    InvalidCharacters,
+    /// Localized value is empty or longer than allowed.
+    InvalidLength {
+        /// Number of characters in the localized value.
+        len: usize,
+        /// Maximum number of characters allowed.
+        max: usize,
+    },
+    /// Locale is not supported by Discord.
+    LocaleUnsupported,
+    /// No localization is provided for the locale.
+    Missing,
+    /// Localized name of a chat input command or option contains uppercase
+    /// letters.
+    Uppercase,
+}
+
+/// Missing and invalid localizations of a command.
+///
+/// Created by [`Command::check_localizations`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LocalizationReport {
+    issues: Vec<LocalizationIssue>,
+}
+
+impl LocalizationReport {
+    /// Whether all checked localizations are provided and valid.
+    pub const fn is_complete(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Issues with the command's localizations, in the order of the command
+    /// tree.
+    pub fn issues(&self) -> &[LocalizationIssue] {
+        &self.issues
+    }
+
+    /// Consume the report, returning its issues.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_issues(self) -> Vec<LocalizationIssue> {
+        self.issues
+    }
+
+    /// Issues with the localizations of a locale.
+    pub fn locale<'a>(
+        &'a self,
+        locale: &'a Locale,
+    ) -> impl Iterator<Item = &'a LocalizationIssue> + 'a {
+        self.issues
+            .iter()
+            .filter(move |issue| issue.locale == *locale)
+    }
+}
+
+/// Command as seen by a user of a locale.
+///
+/// Created by [`Command::localized`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalizedCommand<'a> {
+    /// Command that is localized.
+    pub command: &'a Command,
+    /// Localized description.
+    pub description: &'a str,
+    /// Localized name.
+    pub name: &'a str,
+    /// Localized options.
+    pub options: Vec<LocalizedCommandOption<'a>>,
+}
+
+/// Command option as seen by a user of a locale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalizedCommandOption<'a> {
+    /// Localized choices.
+    pub choices: Vec<LocalizedCommandOptionChoice<'a>>,
+    /// Localized description.
+    pub description: &'a str,
+    /// Localized name.
+    pub name: &'a str,
+    /// Option that is localized.
+    pub option: &'a CommandOption,
+    /// Localized options of a subcommand or subcommand group.
+    pub options: Vec<LocalizedCommandOption<'a>>,
+}
+
+/// Command option choice as seen by a user of a locale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalizedCommandOptionChoice<'a> {
+    /// Choice that is localized.
+    pub choice: &'a CommandOptionChoice,
+    /// Localized name.
+    pub name: &'a str,
+}
+
+impl Command {
+    /// Check the localizations of the command and its options and choices.
+    ///
+    /// Each of the provided locales is checked for missing localizations, and
+    /// all provided localizations are checked against Discord's length and
+    /// naming rules and for unsupported locales. Descriptions of user and
+    /// message commands, which must be empty, aren't checked.
+    ///
+    /// # Examples
+    ///
+    /// Check that a command is localized in every locale supported by
+    /// Discord:
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use twilight_model::{
+    ///     application::command::{Command, CommandType, LocalizationIssueType},
+    ///     id::Id,
+    ///     util::Locale,
+    /// };
+    ///
+    /// let command = Command {
+    ///     application_id: None,
+    ///     default_member_permissions: None,
+    ///     dm_permission: None,
+    ///     description: "Ping the bot".to_owned(),
+    ///     description_localizations: None,
+    ///     guild_id: None,
+    ///     id: None,
+    ///     kind: CommandType::ChatInput,
+    ///     name: "ping".to_owned(),
+    ///     name_localizations: Some(HashMap::from([(Locale::German, "Klingeln".to_owned())])),
+    ///     nsfw: None,
+    ///     options: Vec::new(),
+    ///     version: Id::new(1),
+    /// };
+    ///
+    /// let report = command.check_localizations(Locale::ALL);
+    /// let german = report.locale(&Locale::German).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(2, german.len());
+    /// assert_eq!(LocalizationIssueType::Uppercase, german[0].kind);
+    /// assert_eq!(LocalizationIssueType::Missing, german[1].kind);
+    /// ```
+    pub fn check_localizations(&self, locales: &[Locale]) -> LocalizationReport {
+        let mut checker = Checker {
+            chat_input: self.kind == CommandType::ChatInput,
+            issues: Vec::new(),
+            locales,
+            path: vec![self.name.clone()],
+        };
+
+        checker.check(
+            LocalizationField::Name,
+            Rule::CommandName,
+            self.name_localizations.as_ref(),
+        );
+
+        if checker.chat_input {
+            checker.check(
+                LocalizationField::Description,
+                Rule::Text,
+                self.description_localizations.as_ref(),
+            );
+        }
+
+        for option in &self.options {
+            checker.option(option);
+        }
+
+        LocalizationReport {
+            issues: checker.issues,
+        }
+    }
+
+    /// View of the command as seen by a user of a locale.
+    ///
+    /// Names and descriptions are [resolved] through the locale's chain of
+    /// fallbacks, using the default name or description if none of the
+    /// locales are localized.
+    ///
+    /// [resolved]: Locale::resolve
+    pub fn localized(&self, locale: &Locale) -> LocalizedCommand<'_> {
+        LocalizedCommand {
+            command: self,
+            description: localize(
+                locale,
+                &self.description,
+                self.description_localizations.as_ref(),
+            ),
+            name: localize(locale, &self.name, self.name_localizations.as_ref()),
+            options: self
+                .options
+                .iter()
+                .map(|option| localize_option(locale, option))
+                .collect(),
+        }
+    }
+}
+
+/// Rule that localized values must follow.
+#[derive(Clone, Copy)]
+enum Rule {
+    /// Name of a command or option.
+    CommandName,
+    /// Description or choice name.
+    Text,
+}
+
+/// Walker of a command tree collecting localization issues.
+struct Checker<'a> {
+    chat_input: bool,
+    issues: Vec<LocalizationIssue>,
+    locales: &'a [Locale],
+    path: Vec<String>,
+}
+
+impl Checker<'_> {
+    /// Check the localizations of a field.
+    fn check(
+        &mut self,
+        field: LocalizationField,
+        rule: Rule,
+        localizations: Option<&HashMap<Locale, String>>,
+    ) {
+        for locale in self.locales {
+            if !matches!(localizations, Some(localizations) if localizations.contains_key(locale)) {
+                self.push(field, LocalizationIssueType::Missing, locale);
+            }
+        }
+
+        let Some(localizations) = localizations else {
+            return;
+        };
+
+        // Sort localizations to report issues in a stable order.
+        let mut localizations = localizations.iter().collect::<Vec<_>>();
+        localizations.sort_unstable_by(|(a, _), (b, _)| a.code().cmp(b.code()));
+
+        for (locale, value) in localizations {
+            if !locale.is_known() {
+                self.push(field, LocalizationIssueType::LocaleUnsupported, locale);
+            }
+
+            if let Some(kind) = self.validate(rule, value) {
+                self.push(field, kind, locale);
+            }
+        }
+    }
+
+    /// Check the localizations of an option and its choices and options.
+    fn option(&mut self, option: &CommandOption) {
+        self.path.push(option.name.clone());
+
+        self.check(
+            LocalizationField::Name,
+            Rule::CommandName,
+            option.name_localizations.as_ref(),
+        );
+        self.check(
+            LocalizationField::Description,
+            Rule::Text,
+            option.description_localizations.as_ref(),
+        );
+
+        for choice in option.choices.iter().flatten() {
+            self.path.push(choice.name.clone());
+            self.check(
+                LocalizationField::Name,
+                Rule::Text,
+                choice.name_localizations.as_ref(),
+            );
+            self.path.pop();
+        }
+
+        for option in option.options.iter().flatten() {
+            self.option(option);
+        }
+
+        self.path.pop();
+    }
+
+    /// Record an issue with the field at the current path.
+    fn push(&mut self, field: LocalizationField, kind: LocalizationIssueType, locale: &Locale) {
+        self.issues.push(LocalizationIssue {
+            field,
+            kind,
+            locale: locale.clone(),
+            path: self.path.clone(),
+        });
+    }
+
+    /// Validate a localized value against a rule.
+    fn validate(&self, rule: Rule, value: &str) -> Option<LocalizationIssueType> {
+        let max = match rule {
+            Rule::CommandName => NAME_LENGTH_MAX,
+            Rule::Text => TEXT_LENGTH_MAX,
+        };
+        let len = value.chars().count();
+
+        if !(1..=max).contains(&len) {
+            return Some(LocalizationIssueType::InvalidLength { len, max });
+        }
+
+        // Names of user and message commands may contain any characters.
+        if !matches!(rule, Rule::CommandName) || !self.chat_input {
+            return None;
+        }
+
+        if !value.chars().all(is_name_character) {
+            return Some(LocalizationIssueType::InvalidCharacters);
+        }
+
+        if value.chars().any(char::is_uppercase) {
+            return Some(LocalizationIssueType::Uppercase);
+        }
+
+        None
+    }
+}
+
+/// Whether a character is allowed in chat input command and option names.
+///
+/// Names may contain letters, numbers, hyphens, underscores and characters of
+/// the Devanagari and Thai scripts.
+fn is_name_character(character: char) -> bool {
+    character.is_alphanumeric()
+        || matches!(character, '-' | '_' | '\u{0900}'..='\u{097F}' | '\u{0E00}'..='\u{0E7F}')
+}
+
+/// Resolve a localized value, falling back to the default value.
+fn localize<'a>(
+    locale: &Locale,
+    default: &'a str,
+    localizations: Option<&'a HashMap<Locale, String>>,
+) -> &'a str {
+    localizations
+        .and_then(|localizations| locale.resolve(localizations))
+        .map_or(default, String::as_str)
+}
+
+/// Localize an option and its choices and options.
+fn localize_option<'a>(locale: &Locale, option: &'a CommandOption) -> LocalizedCommandOption<'a> {
+    LocalizedCommandOption {
+        choices: option
+            .choices
+            .iter()
+            .flatten()
+            .map(|choice| LocalizedCommandOptionChoice {
+                choice,
+                name: localize(locale, &choice.name, choice.name_localizations.as_ref()),
+            })
+            .collect(),
+        description: localize(
+            locale,
+            &option.description,
+            option.description_localizations.as_ref(),
+        ),
+        name: localize(locale, &option.name, option.name_localizations.as_ref()),
+        option,
+        options: option
+            .options
+            .iter()
+            .flatten()
+            .map(|option| localize_option(locale, option))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        LocalizationField, LocalizationIssue, LocalizationIssueType, LocalizationReport,
+        LocalizedCommand,
+    };
+    use crate::{
+        application::command::{
+            Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue,
+            CommandOptionType, CommandType,
+        },
+        id::Id,
+        util::Locale,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashMap, fmt::Debug};
+
+    assert_impl_all!(LocalizationIssue: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(LocalizationReport: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(LocalizedCommand<'_>: Clone, Debug, PartialEq, Send, Sync);
+
+    fn localizations(values: &[(Locale, &str)]) -> HashMap<Locale, String> {
+        values
+            .iter()
+            .map(|(locale, value)| (locale.clone(), (*value).to_owned()))
+            .collect()
+    }
+
+    fn command() -> Command {
+        let option = CommandOption {
+            autocomplete: None,
+            channel_types: None,
+            choices: Some(vec![CommandOptionChoice {
+                name: "Red".to_owned(),
+                name_localizations: Some(localizations(&[
+                    (Locale::German, "Rot"),
+                    (Locale::French, "Rouge"),
+                ])),
+                value: CommandOptionChoiceValue::String("red".to_owned()),
+            }]),
+            description: "Color to paint".to_owned(),
+            description_localizations: Some(localizations(&[
+                (Locale::German, "Farbe zum Malen"),
+                (Locale::French, ""),
+            ])),
+            kind: CommandOptionType::String,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: "color".to_owned(),
+            name_localizations: Some(localizations(&[
+                (Locale::German, "farbe"),
+                (Locale::French, "la couleur"),
+                (Locale::Unknown("fr-CA".to_owned()), "couleur"),
+            ])),
+            options: None,
+            required: None,
+        };
+
+        Command {
+            application_id: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: "Paint something".to_owned(),
+            description_localizations: Some(localizations(&[(Locale::German, "Etwas malen")])),
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: "paint".to_owned(),
+            name_localizations: Some(localizations(&[
+                (Locale::German, "malen"),
+                (Locale::French, "Peindre"),
+            ])),
+            nsfw: None,
+            options: vec![option],
+            version: Id::new(1),
+        }
+    }
+
+    #[test]
+    fn check() {
+        let command = command();
+
+        // Invalid localizations are reported even if their locale isn't
+        // checked for completeness.
+        let german = command.check_localizations(&[Locale::German]);
+        assert!(!german.is_complete());
+        assert_eq!(0, german.locale(&Locale::German).count());
+        assert_eq!(4, german.issues().len());
+
+        let report = command.check_localizations(&[Locale::German, Locale::French]);
+        let issues = report
+            .issues()
+            .iter()
+            .map(|issue| {
+                (
+                    issue.path.join("."),
+                    issue.field,
+                    issue.locale.code(),
+                    issue.kind.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            [
+                (
+                    "paint".to_owned(),
+                    LocalizationField::Name,
+                    "fr",
+                    LocalizationIssueType::Uppercase
+                ),
+                (
+                    "paint".to_owned(),
+                    LocalizationField::Description,
+                    "fr",
+                    LocalizationIssueType::Missing
+                ),
+                (
+                    "paint.color".to_owned(),
+                    LocalizationField::Name,
+                    "fr",
+                    LocalizationIssueType::InvalidCharacters
+                ),
+                (
+                    "paint.color".to_owned(),
+                    LocalizationField::Name,
+                    "fr-CA",
+                    LocalizationIssueType::LocaleUnsupported
+                ),
+                (
+                    "paint.color".to_owned(),
+                    LocalizationField::Description,
+                    "fr",
+                    LocalizationIssueType::InvalidLength { len: 0, max: 100 }
+                ),
+            ],
+            issues.as_slice()
+        );
+        assert_eq!(0, report.locale(&Locale::German).count());
+    }
+
+    #[test]
+    fn user_command_names() {
+        let command = Command {
+            description: String::new(),
+            description_localizations: None,
+            kind: CommandType::User,
+            name: "Send Hug".to_owned(),
+            name_localizations: Some(localizations(&[(Locale::German, "Umarmung senden")])),
+            options: Vec::new(),
+            ..command()
+        };
+
+        assert!(command.check_localizations(&[Locale::German]).is_complete());
+    }
+
+    #[test]
+    fn localized() {
+        let command = command();
+
+        let german = command.localized(&Locale::German);
+        assert_eq!("malen", german.name);
+        assert_eq!("Etwas malen", german.description);
+        assert_eq!("farbe", german.options[0].name);
+        assert_eq!("Rot", german.options[0].choices[0].name);
+
+        let canadian = command.localized(&Locale::Unknown("fr-CA".to_owned()));
+        assert_eq!("Peindre", canadian.name);
+        assert_eq!("Paint something", canadian.description);
+        assert_eq!("couleur", canadian.options[0].name);
+
+        let japanese = command.localized(&Locale::Japanese);
+        assert_eq!("paint", japanese.name);
+        assert_eq!("Red", japanese.options[0].choices[0].name);
+    }
+}
@@ -10,10 +10,15 @@
 pub mod permissions;
 
 mod command_type;
+mod localization;
 mod option;
 
 pub use self::{
     command_type::CommandType,
+    localization::{
+        LocalizationField, LocalizationIssue, LocalizationIssueType, LocalizationReport,
+        LocalizedCommand, LocalizedCommandOption, LocalizedCommandOptionChoice,
+    },
     option::{
         CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType,
         CommandOptionValue,
@@ -26,6 +31,7 @@ use crate::{
         marker::{ApplicationMarker, CommandMarker, CommandVersionMarker, GuildMarker},
         Id,
     },
+    util::Locale,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -38,6 +44,7 @@ use std::collections::HashMap;
 ///
 /// [Discord Docs/Application Command Object]: https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Command {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
@@ -65,7 +72,7 @@ pub struct Command {
     ///
     /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description_localizations: Option<HashMap<String, String>>,
+    pub description_localizations: Option<HashMap<Locale, String>>,
     /// Guild ID of the command, if not global.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
@@ -82,7 +89,7 @@ pub struct Command {
     /// [Discord Docs/Locales]: https://discord.com/developers/docs/reference#locales
     /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name_localizations: Option<HashMap<String, String>>,
+    pub name_localizations: Option<HashMap<Locale, String>>,
     /// Whether the command is age-restricted.
     ///
     /// Defaults to false.
@@ -235,7 +242,7 @@ mod tests {
                             choices: None,
                             description: "mentionable desc".to_owned(),
                             description_localizations: Some(HashMap::from([(
-                                "en-GB".to_owned(),
+                                "en-GB".into(),
                                 "mentionable desc (but british)".to_owned(),
                             )])),
                             kind: CommandOptionType::Mentionable,
@@ -254,7 +261,7 @@ mod tests {
                             choices: Some(Vec::from([CommandOptionChoice {
                                 name: "number choice".to_owned(),
                                 name_localizations: Some(HashMap::from([(
-                                    "en-US".to_owned(),
+                                    "en-US".into(),
                                     "number choice (but american)".to_owned(),
                                 )])),
                                 value: CommandOptionChoiceValue::Number(10.0),
@@ -284,7 +291,7 @@ mod tests {
                             min_value: None,
                             name: "role name".to_owned(),
                             name_localizations: Some(HashMap::from([(
-                                "de-DE".to_owned(),
+                                "de-DE".into(),
                                 "role name (but german)".to_owned(),
                             )])),
                             options: None,
@@ -323,7 +330,9 @@ mod tests {
                 },
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::Str("default_member_permissions"),
                 Token::Some,
@@ -341,11 +350,15 @@ mod tests {
                 Token::MapEnd,
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("300"),
                 Token::Str("id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("200"),
                 Token::Str("type"),
                 Token::U8(CommandType::ChatInput.into()),
@@ -555,7 +568,9 @@ mod tests {
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::Str("version"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
@@ -1,4 +1,4 @@
-use crate::channel::ChannelType;
+use crate::{channel::ChannelType, util::Locale};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::{cmp::Eq, collections::HashMap};
@@ -17,6 +17,7 @@ use std::{cmp::Eq, collections::HashMap};
 /// [`Command`]: super::Command
 /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CommandOption {
     /// Whether the command supports autocomplete.
     ///
@@ -65,7 +66,7 @@ pub struct CommandOption {
     ///
     /// [`description`]: Self::description
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description_localizations: Option<HashMap<String, String>>,
+    pub description_localizations: Option<HashMap<Locale, String>>,
     /// Type of option.
     #[serde(rename = "type")]
     pub kind: CommandOptionType,
@@ -121,7 +122,7 @@ pub struct CommandOption {
     ///
     /// [`name`]: Self::name
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name_localizations: Option<HashMap<String, String>>,
+    pub name_localizations: Option<HashMap<Locale, String>>,
     /// Nested options.
     ///
     /// Applicable for options of type [`SubCommand`] and [`SubCommandGroup`].
@@ -153,6 +154,7 @@ pub struct CommandOption {
 
 /// A predetermined choice users can select.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CommandOptionChoice {
     /// Name of the choice. Must be 100 characters or less.
     pub name: String,
@@ -166,7 +168,7 @@ pub struct CommandOptionChoice {
     ///
     /// [`name`]: Self::name
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name_localizations: Option<HashMap<String, String>>,
+    pub name_localizations: Option<HashMap<Locale, String>>,
     /// Value of the choice.
     pub value: CommandOptionChoiceValue,
 }
@@ -176,6 +178,7 @@ pub struct CommandOptionChoice {
 /// Note that the right variant must be selected based on the
 /// [`CommandOption`]'s [`CommandOptionType`].
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum CommandOptionChoiceValue {
     /// String choice. Must be 100 characters or less.
@@ -191,6 +194,7 @@ pub enum CommandOptionChoiceValue {
 /// Note that the right variant must be selected based on the
 /// [`CommandOption`]'s [`CommandOptionType`].
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum CommandOptionValue {
     /// Integer type.
@@ -201,6 +205,7 @@ pub enum CommandOptionValue {
 
 /// Type of a [`CommandOption`].
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum CommandOptionType {
@@ -12,6 +12,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// List of [`CommandPermission`]s for a command in a guild.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildCommandPermissions {
     /// ID of the application the command belongs to.
     pub application_id: Id<ApplicationMarker>,
@@ -70,6 +71,7 @@ impl CommandPermissionType {
 }
 
 #[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 struct CommandPermissionData {
     /// Affected resource.
     id: Id<GenericMarker>,
@@ -81,6 +83,7 @@ struct CommandPermissionData {
 }
 
 #[derive(Clone, Debug, Deserialize_repr, Eq, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 enum CommandPermissionDataType {
@@ -118,6 +121,17 @@ impl Serialize for CommandPermission {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CommandPermission {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "CommandPermission".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <CommandPermissionData as schemars::JsonSchema>::json_schema(generator)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -142,7 +156,9 @@ mod tests {
                     len: 3,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::Str("type"),
                 Token::U8(CommandPermissionDataType::Role as u8),
@@ -179,13 +195,19 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("application_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("permissions"),
                 Token::Seq { len: Some(2) },
@@ -194,7 +216,9 @@ mod tests {
                     len: 3,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("50"),
                 Token::Str("type"),
                 Token::U8(CommandPermissionDataType::Channel as u8),
@@ -206,7 +230,9 @@ mod tests {
                     len: 3,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("200"),
                 Token::Str("type"),
                 Token::U8(CommandPermissionDataType::User as u8),
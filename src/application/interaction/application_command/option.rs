@@ -70,6 +70,31 @@ impl Serialize for CommandDataOption {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CommandDataOption {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "CommandDataOption".into()
+    }
+
+    // IDs and focused values are strings.
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "properties": {
+                "focused": { "type": "boolean" },
+                "name": { "type": "string" },
+                "options": {
+                    "type": "array",
+                    "items": generator.subschema_for::<Self>(),
+                },
+                "type": generator.subschema_for::<CommandOptionType>(),
+                "value": { "type": ["boolean", "number", "string"] },
+            },
+            "required": ["name", "type"],
+        })
+    }
+}
+
 impl<'de> Deserialize<'de> for CommandDataOption {
     #[allow(clippy::too_many_lines)]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -412,10 +437,14 @@ mod tests {
                 },
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("permissions"),
@@ -450,10 +479,14 @@ mod tests {
                 },
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("permissions"),
@@ -511,10 +544,14 @@ mod tests {
                 },
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("permissions"),
@@ -576,7 +613,9 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("photo"),
@@ -18,6 +18,7 @@ use std::collections::hash_map::HashMap;
 /// [`ApplicationCommand`]: crate::application::interaction::InteractionType::ApplicationCommand
 /// [Discord Docs/Resolved Data Structure]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-resolved-data-structure
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CommandInteractionDataResolved {
     /// Map of resolved attachments.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
@@ -45,6 +46,7 @@ pub struct CommandInteractionDataResolved {
 ///
 /// [`Interaction`]: crate::application::interaction::Interaction
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InteractionChannel {
     /// ID of the channel.
     pub id: Id<ChannelMarker>,
@@ -69,6 +71,7 @@ pub struct InteractionChannel {
 ///
 /// [`Interaction`]: crate::application::interaction::Interaction
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InteractionMember {
     /// Member's guild avatar.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -222,7 +225,9 @@ mod tests {
                     mention_everyone: false,
                     mention_roles: Vec::new(),
                     mentions: Vec::new(),
+                    message_snapshots: Vec::new(),
                     pinned: false,
+                    poll: None,
                     reactions: Vec::new(),
                     reference: None,
                     role_subscription_data: None,
@@ -270,7 +275,7 @@ mod tests {
                     flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
                     global_name: Some("test".to_owned()),
                     id: Id::new(300),
-                    locale: Some("en-us".to_owned()),
+                    locale: Some("en-us".into()),
                     mfa_enabled: Some(true),
                     name: "test".to_owned(),
                     premium_type: Some(PremiumType::Nitro),
@@ -293,7 +298,9 @@ mod tests {
                 },
                 Token::Str("attachments"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("400"),
                 Token::Struct {
                     name: "Attachment",
@@ -310,7 +317,9 @@ mod tests {
                 Token::Some,
                 Token::U64(2674),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("400"),
                 Token::Str("proxy_url"),
                 Token::Str("https://proxy.example.com/rainbow_dash.png"),
@@ -325,14 +334,18 @@ mod tests {
                 Token::MapEnd,
                 Token::Str("channels"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::Struct {
                     name: "InteractionChannel",
                     len: 4,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -344,7 +357,9 @@ mod tests {
                 Token::MapEnd,
                 Token::Str("members"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("300"),
                 Token::Struct {
                     name: "InteractionMember",
@@ -369,7 +384,9 @@ mod tests {
                 Token::MapEnd,
                 Token::Str("messages"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Struct {
                     name: "Message",
@@ -400,13 +417,17 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("test"),
                 Token::StructEnd,
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("content"),
                 Token::Str("ping"),
@@ -420,10 +441,14 @@ mod tests {
                 Token::U64(0),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -471,7 +496,9 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(1),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("sticker name"),
@@ -485,7 +512,9 @@ mod tests {
                 Token::MapEnd,
                 Token::Str("roles"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("400"),
                 Token::Struct {
                     name: "Role",
@@ -496,7 +525,9 @@ mod tests {
                 Token::Str("hoist"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("400"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -514,7 +545,9 @@ mod tests {
                 Token::MapEnd,
                 Token::Str("users"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("300"),
                 Token::Struct {
                     name: "User",
@@ -543,7 +576,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("300"),
                 Token::Str("locale"),
                 Token::Some,
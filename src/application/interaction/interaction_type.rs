@@ -10,6 +10,7 @@ use std::{
 ///
 /// [Discord Docs/Interaction Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum InteractionType {
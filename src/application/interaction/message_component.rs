@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 /// [`MessageComponent`]: crate::application::interaction::InteractionType::MessageComponent
 /// [Discord Docs/Message Component Data Structure]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-message-component-data-structure
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MessageComponentInteractionData {
     /// User defined identifier for the component.
     ///
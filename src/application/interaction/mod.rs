@@ -17,6 +17,7 @@ use self::{
     modal::ModalInteractionData,
 };
 use crate::{
+    application::monetization::Entitlement,
     channel::{Channel, Message},
     guild::{PartialMember, Permissions},
     id::{
@@ -24,6 +25,7 @@ use crate::{
         Id,
     },
     user::User,
+    util::Locale,
 };
 use serde::{
     de::{Error as DeError, IgnoredAny, MapAccess, Visitor},
@@ -38,6 +40,7 @@ use std::fmt::{Formatter, Result as FmtResult};
 ///
 /// [Discord Docs/Interaction Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-structure
 #[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Interaction {
     /// App's permissions in the channel the interaction was sent from.
     ///
@@ -75,6 +78,12 @@ pub struct Interaction {
     /// [`ModalSubmit`]: InteractionType::ModalSubmit
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<InteractionData>,
+    /// Entitlements of the invoking user or guild to the application's SKUs.
+    ///
+    /// Contains the entitlements of the guild when the interaction is invoked
+    /// in a guild with an active guild subscription.
+    #[serde(default)]
+    pub entitlements: Vec<Entitlement>,
     /// ID of the guild the interaction was invoked in.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
@@ -82,7 +91,7 @@ pub struct Interaction {
     ///
     /// Present when the interaction is invoked in a guild.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guild_locale: Option<String>,
+    pub guild_locale: Option<Locale>,
     /// ID of the interaction.
     pub id: Id<InteractionMarker>,
     /// Type of interaction.
@@ -94,7 +103,7 @@ pub struct Interaction {
     ///
     /// [`Ping`]: InteractionType::Ping
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// Member that invoked the interaction.
     ///
     /// Present when the interaction is invoked in a guild.
@@ -173,6 +182,7 @@ enum InteractionField {
     Channel,
     ChannelId,
     Data,
+    Entitlements,
     GuildId,
     GuildLocale,
     Id,
@@ -201,11 +211,12 @@ impl<'de> Visitor<'de> for InteractionVisitor {
         let mut channel: Option<Channel> = None;
         let mut channel_id: Option<Id<ChannelMarker>> = None;
         let mut data: Option<Value> = None;
+        let mut entitlements: Option<Vec<Entitlement>> = None;
         let mut guild_id: Option<Id<GuildMarker>> = None;
-        let mut guild_locale: Option<String> = None;
+        let mut guild_locale: Option<Locale> = None;
         let mut id: Option<Id<InteractionMarker>> = None;
         let mut kind: Option<InteractionType> = None;
-        let mut locale: Option<String> = None;
+        let mut locale: Option<Locale> = None;
         let mut member: Option<PartialMember> = None;
         let mut message: Option<Message> = None;
         let mut token: Option<String> = None;
@@ -258,6 +269,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     data = map.next_value()?;
                 }
+                InteractionField::Entitlements => {
+                    if entitlements.is_some() {
+                        return Err(DeError::duplicate_field("entitlements"));
+                    }
+
+                    entitlements = Some(map.next_value()?);
+                }
                 InteractionField::GuildId => {
                     if guild_id.is_some() {
                         return Err(DeError::duplicate_field("guild_id"));
@@ -376,6 +394,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
             channel,
             channel_id,
             data,
+            entitlements: entitlements.unwrap_or_default(),
             guild_id,
             guild_locale,
             id,
@@ -391,6 +410,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
 /// Additional [`Interaction`] data, such as the invoking user.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(untagged)]
 pub enum InteractionData {
@@ -533,11 +553,12 @@ mod tests {
                 }),
                 target_id: None,
             }))),
+            entitlements: Vec::new(),
             guild_id: Some(Id::new(400)),
-            guild_locale: Some("de".to_owned()),
+            guild_locale: Some("de".into()),
             id: Id::new(500),
             kind: InteractionType::ApplicationCommand,
-            locale: Some("en-GB".to_owned()),
+            locale: Some("en-GB".into()),
             member: Some(PartialMember {
                 avatar: None,
                 communication_disabled_until: None,
@@ -580,13 +601,15 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Interaction",
-                    len: 12,
+                    len: 13,
                 },
                 Token::Str("app_permissions"),
                 Token::Some,
                 Token::Str("2048"),
                 Token::Str("application_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::Str("channel"),
                 Token::Some,
@@ -595,14 +618,18 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("400"),
                 Token::Str("type"),
                 Token::U8(0),
                 Token::StructEnd,
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("200"),
                 Token::Str("data"),
                 Token::Some,
@@ -611,7 +638,9 @@ mod tests {
                     len: 5,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("300"),
                 Token::Str("name"),
                 Token::Str("command name"),
@@ -628,7 +657,9 @@ mod tests {
                 Token::Str("type"),
                 Token::U8(CommandOptionType::User as u8),
                 Token::Str("value"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("600"),
                 Token::StructEnd,
                 Token::SeqEnd,
@@ -640,7 +671,9 @@ mod tests {
                 },
                 Token::Str("members"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("600"),
                 Token::Struct {
                     name: "InteractionMember",
@@ -666,7 +699,9 @@ mod tests {
                 Token::MapEnd,
                 Token::Str("users"),
                 Token::Map { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("600"),
                 Token::Struct {
                     name: "User",
@@ -689,7 +724,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("600"),
                 Token::Str("username"),
                 Token::Str("username"),
@@ -697,15 +734,22 @@ mod tests {
                 Token::MapEnd,
                 Token::StructEnd,
                 Token::StructEnd,
+                Token::Str("entitlements"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("400"),
                 Token::Str("guild_locale"),
                 Token::Some,
                 Token::String("de"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("500"),
                 Token::Str("type"),
                 Token::U8(InteractionType::ApplicationCommand as u8),
@@ -760,7 +804,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("600"),
                 Token::Str("username"),
                 Token::Str("username"),
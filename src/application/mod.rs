@@ -1,2 +1,4 @@
 pub mod command;
 pub mod interaction;
+pub mod monetization;
+pub mod role_connection;
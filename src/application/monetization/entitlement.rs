@@ -0,0 +1,224 @@
+use super::EntitlementType;
+use crate::{
+    id::{
+        marker::{ApplicationMarker, EntitlementMarker, GuildMarker, SkuMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+use serde::{Deserialize, Serialize};
+
+/// Access of a user or guild to a premium offering of an application.
+///
+/// See [Discord Docs/Entitlement Object].
+///
+/// [Discord Docs/Entitlement Object]: https://discord.com/developers/docs/monetization/entitlements#entitlement-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Entitlement {
+    /// ID of the parent application.
+    pub application_id: Id<ApplicationMarker>,
+    /// Whether the entitlement has been consumed.
+    ///
+    /// Only applies to consumable SKUs.
+    #[serde(default)]
+    pub consumed: bool,
+    /// Whether the entitlement was deleted.
+    pub deleted: bool,
+    /// When the entitlement stops granting access.
+    ///
+    /// Not present for test entitlements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<Timestamp>,
+    /// ID of the guild that is granted access to the SKU.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the entitlement.
+    pub id: Id<EntitlementMarker>,
+    /// Type of the entitlement.
+    #[serde(rename = "type")]
+    pub kind: EntitlementType,
+    /// ID of the SKU the entitlement grants access to.
+    pub sku_id: Id<SkuMarker>,
+    /// When the entitlement starts granting access.
+    ///
+    /// Not present for test entitlements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<Timestamp>,
+    /// ID of the user that is granted access to the SKU.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<Id<UserMarker>>,
+}
+
+impl Entitlement {
+    /// Whether the entitlement grants access to its SKU at a point in time.
+    ///
+    /// Deleted and consumed entitlements never grant access. Entitlements
+    /// without a start or end date, such as test entitlements, are unbounded
+    /// on that side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     application::monetization::{Entitlement, EntitlementType},
+    ///     id::Id,
+    ///     util::Timestamp,
+    /// };
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let entitlement = Entitlement {
+    ///     application_id: Id::new(1),
+    ///     consumed: false,
+    ///     deleted: false,
+    ///     ends_at: Some(Timestamp::parse("2024-02-01T00:00:00.000000+00:00")?),
+    ///     guild_id: None,
+    ///     id: Id::new(2),
+    ///     kind: EntitlementType::ApplicationSubscription,
+    ///     sku_id: Id::new(3),
+    ///     starts_at: Some(Timestamp::parse("2024-01-01T00:00:00.000000+00:00")?),
+    ///     user_id: Some(Id::new(4)),
+    /// };
+    ///
+    /// assert!(entitlement.is_active(Timestamp::parse("2024-01-15T00:00:00.000000+00:00")?));
+    /// assert!(!entitlement.is_active(Timestamp::parse("2024-02-01T00:00:00.000000+00:00")?));
+    /// # Ok(()) }
+    /// ```
+    pub const fn is_active(&self, now: Timestamp) -> bool {
+        if self.deleted || self.consumed {
+            return false;
+        }
+
+        if let Some(starts_at) = self.starts_at {
+            if now.as_micros() < starts_at.as_micros() {
+                return false;
+            }
+        }
+
+        if let Some(ends_at) = self.ends_at {
+            if now.as_micros() >= ends_at.as_micros() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Entitlement;
+    use crate::{
+        application::monetization::EntitlementType,
+        id::Id,
+        util::{datetime::TimestampParseError, Timestamp},
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        Entitlement: application_id,
+        consumed,
+        deleted,
+        ends_at,
+        guild_id,
+        id,
+        kind,
+        sku_id,
+        starts_at,
+        user_id
+    );
+    assert_impl_all!(
+        Entitlement: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn entitlement() -> Result<(), TimestampParseError> {
+        let starts_at = Timestamp::parse("2024-01-01T00:00:00.000000+00:00")?;
+        let ends_at = Timestamp::parse("2024-02-01T00:00:00.000000+00:00")?;
+
+        let value = Entitlement {
+            application_id: Id::new(1),
+            consumed: false,
+            deleted: false,
+            ends_at: Some(ends_at),
+            guild_id: Some(Id::new(2)),
+            id: Id::new(3),
+            kind: EntitlementType::ApplicationSubscription,
+            sku_id: Id::new(4),
+            starts_at: Some(starts_at),
+            user_id: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Entitlement",
+                    len: 9,
+                },
+                Token::Str("application_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::Str("consumed"),
+                Token::Bool(false),
+                Token::Str("deleted"),
+                Token::Bool(false),
+                Token::Str("ends_at"),
+                Token::Some,
+                Token::Str("2024-02-01T00:00:00.000000+00:00"),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("2"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("3"),
+                Token::Str("type"),
+                Token::U8(8),
+                Token::Str("sku_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("4"),
+                Token::Str("starts_at"),
+                Token::Some,
+                Token::Str("2024-01-01T00:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert!(value.is_active(starts_at));
+        assert!(!value.is_active(ends_at));
+        assert!(!value.is_active(Timestamp::parse("2023-12-31T23:59:59.000000+00:00")?));
+        assert!(!Entitlement {
+            deleted: true,
+            ..value.clone()
+        }
+        .is_active(starts_at));
+        assert!(Entitlement {
+            ends_at: None,
+            starts_at: None,
+            ..value
+        }
+        .is_active(ends_at));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+/// Type of an [`Entitlement`].
+///
+/// See [Discord Docs/Entitlement Types].
+///
+/// [`Entitlement`]: super::Entitlement
+/// [Discord Docs/Entitlement Types]: https://discord.com/developers/docs/monetization/entitlements#entitlement-object-entitlement-types
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum EntitlementType {
+    /// Entitlement was purchased by a user.
+    Purchase,
+    /// Entitlement is granted by a Discord Nitro subscription.
+    PremiumSubscription,
+    /// Entitlement was gifted by the application's developer.
+    DeveloperGift,
+    /// Entitlement was purchased by a developer in application test mode.
+    TestModePurchase,
+    /// Entitlement was granted when the SKU was free.
+    FreePurchase,
+    /// Entitlement was gifted by another user.
+    UserGift,
+    /// Entitlement was claimed for free by a Discord Nitro subscriber.
+    PremiumPurchase,
+    /// Entitlement was purchased as an application subscription.
+    ApplicationSubscription,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for EntitlementType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Purchase,
+            2 => Self::PremiumSubscription,
+            3 => Self::DeveloperGift,
+            4 => Self::TestModePurchase,
+            5 => Self::FreePurchase,
+            6 => Self::UserGift,
+            7 => Self::PremiumPurchase,
+            8 => Self::ApplicationSubscription,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<EntitlementType> for u8 {
+    fn from(value: EntitlementType) -> Self {
+        match value {
+            EntitlementType::Purchase => 1,
+            EntitlementType::PremiumSubscription => 2,
+            EntitlementType::DeveloperGift => 3,
+            EntitlementType::TestModePurchase => 4,
+            EntitlementType::FreePurchase => 5,
+            EntitlementType::UserGift => 6,
+            EntitlementType::PremiumPurchase => 7,
+            EntitlementType::ApplicationSubscription => 8,
+            EntitlementType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntitlementType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        EntitlementType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        const MAP: &[(EntitlementType, u8)] = &[
+            (EntitlementType::Purchase, 1),
+            (EntitlementType::PremiumSubscription, 2),
+            (EntitlementType::DeveloperGift, 3),
+            (EntitlementType::TestModePurchase, 4),
+            (EntitlementType::FreePurchase, 5),
+            (EntitlementType::UserGift, 6),
+            (EntitlementType::PremiumPurchase, 7),
+            (EntitlementType::ApplicationSubscription, 8),
+            (EntitlementType::Unknown(99), 99),
+        ];
+
+        for (kind, number) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*number)]);
+            assert_eq!(*kind, EntitlementType::from(*number));
+            assert_eq!(*number, u8::from(*kind));
+        }
+    }
+}
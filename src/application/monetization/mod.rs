@@ -0,0 +1,16 @@
+//! Types for application monetization, such as premium subscriptions.
+//!
+//! See [Discord Docs/Monetization].
+//!
+//! [Discord Docs/Monetization]: https://discord.com/developers/docs/monetization/overview
+
+mod entitlement;
+mod entitlement_type;
+mod sku;
+mod sku_flags;
+mod sku_type;
+
+pub use self::{
+    entitlement::Entitlement, entitlement_type::EntitlementType, sku::Sku, sku_flags::SkuFlags,
+    sku_type::SkuType,
+};
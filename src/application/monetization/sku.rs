@@ -0,0 +1,106 @@
+use super::{SkuFlags, SkuType};
+use crate::id::{
+    marker::{ApplicationMarker, SkuMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// Premium offering that can be made available to an application's users or
+/// guilds.
+///
+/// See [Discord Docs/SKU Object].
+///
+/// [Discord Docs/SKU Object]: https://discord.com/developers/docs/monetization/skus#sku-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Sku {
+    /// ID of the parent application.
+    pub application_id: Id<ApplicationMarker>,
+    /// Flags of the SKU.
+    pub flags: SkuFlags,
+    /// ID of the SKU.
+    pub id: Id<SkuMarker>,
+    /// Type of the SKU.
+    #[serde(rename = "type")]
+    pub kind: SkuType,
+    /// Customer-facing name of the premium offering.
+    pub name: String,
+    /// System-generated URL slug based on the name.
+    pub slug: String,
+}
+
+impl Sku {
+    /// Whether the SKU is available for purchase.
+    pub const fn is_available(&self) -> bool {
+        self.flags.contains(SkuFlags::AVAILABLE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sku;
+    use crate::{
+        application::monetization::{SkuFlags, SkuType},
+        id::Id,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(Sku: application_id, flags, id, kind, name, slug);
+    assert_impl_all!(
+        Sku: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn sku() {
+        let value = Sku {
+            application_id: Id::new(1),
+            flags: SkuFlags::AVAILABLE | SkuFlags::USER_SUBSCRIPTION,
+            id: Id::new(2),
+            kind: SkuType::Subscription,
+            name: "Premium".to_owned(),
+            slug: "premium".to_owned(),
+        };
+
+        assert!(value.is_available());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Sku",
+                    len: 6,
+                },
+                Token::Str("application_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::Str("flags"),
+                Token::U64(260),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(5),
+                Token::Str("name"),
+                Token::Str("Premium"),
+                Token::Str("slug"),
+                Token::Str("premium"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use bitflags::bitflags;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+bitflags! {
+    /// Flags of a [`Sku`] represented as a bitfield.
+    ///
+    /// [`Sku`]: super::Sku
+    pub struct SkuFlags: u64 {
+        /// SKU is available for purchase.
+        const AVAILABLE = 1 << 2;
+        /// Recurring SKU that can be purchased by a user and applied to a
+        /// single guild, granting access to every member of that guild.
+        const GUILD_SUBSCRIPTION = 1 << 7;
+        /// Recurring SKU purchased by a user for themselves, granting access
+        /// to that user in every guild the application is in.
+        const USER_SUBSCRIPTION = 1 << 8;
+    }
+}
+
+impl<'de> Deserialize<'de> for SkuFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from_bits_truncate(u64::deserialize(deserializer)?))
+    }
+}
+
+impl Serialize for SkuFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SkuFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "SkuFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SkuFlags;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_impl_all, const_assert_eq};
+    use std::{
+        fmt::{Binary, Debug, LowerHex, Octal, UpperHex},
+        hash::Hash,
+        iter::FromIterator,
+        ops::{
+            BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+        },
+    };
+
+    assert_impl_all!(
+        SkuFlags: Binary,
+        BitAnd,
+        BitAndAssign,
+        BitOr,
+        BitOrAssign,
+        BitXor,
+        BitXorAssign,
+        Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Extend<SkuFlags>,
+        FromIterator<SkuFlags>,
+        Hash,
+        LowerHex,
+        Not,
+        Octal,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        Send,
+        Serialize,
+        Sub,
+        SubAssign,
+        UpperHex,
+    );
+
+    const_assert_eq!(1 << 2, SkuFlags::AVAILABLE.bits());
+    const_assert_eq!(1 << 7, SkuFlags::GUILD_SUBSCRIPTION.bits());
+    const_assert_eq!(1 << 8, SkuFlags::USER_SUBSCRIPTION.bits());
+
+    #[test]
+    fn serde() {
+        serde_test::assert_tokens(
+            &SkuFlags::AVAILABLE,
+            &[Token::U64(SkuFlags::AVAILABLE.bits())],
+        );
+        // Deserialization truncates unknown bits.
+        serde_test::assert_de_tokens(&SkuFlags::USER_SUBSCRIPTION, &[Token::U64(1 << 8 | 1)]);
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+/// Type of a [`Sku`].
+///
+/// See [Discord Docs/SKU Types].
+///
+/// [`Sku`]: super::Sku
+/// [Discord Docs/SKU Types]: https://discord.com/developers/docs/monetization/skus#sku-object-sku-types
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum SkuType {
+    /// Durable one-time purchase.
+    Durable,
+    /// Consumable one-time purchase.
+    Consumable,
+    /// Recurring subscription.
+    Subscription,
+    /// System-generated group for each [`Subscription`] SKU.
+    ///
+    /// [`Subscription`]: Self::Subscription
+    SubscriptionGroup,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for SkuType {
+    fn from(value: u8) -> Self {
+        match value {
+            2 => Self::Durable,
+            3 => Self::Consumable,
+            5 => Self::Subscription,
+            6 => Self::SubscriptionGroup,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<SkuType> for u8 {
+    fn from(value: SkuType) -> Self {
+        match value {
+            SkuType::Durable => 2,
+            SkuType::Consumable => 3,
+            SkuType::Subscription => 5,
+            SkuType::SubscriptionGroup => 6,
+            SkuType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SkuType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        SkuType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        const MAP: &[(SkuType, u8)] = &[
+            (SkuType::Durable, 2),
+            (SkuType::Consumable, 3),
+            (SkuType::Subscription, 5),
+            (SkuType::SubscriptionGroup, 6),
+            (SkuType::Unknown(99), 99),
+        ];
+
+        for (kind, number) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*number)]);
+            assert_eq!(*kind, SkuType::from(*number));
+            assert_eq!(*number, u8::from(*kind));
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use super::ApplicationRoleConnectionMetadataType;
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when a role connection is invalid.
+#[derive(Debug)]
+pub struct ApplicationRoleConnectionError {
+    kind: ApplicationRoleConnectionErrorType,
+}
+
+impl ApplicationRoleConnectionError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ApplicationRoleConnectionErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ApplicationRoleConnectionErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ApplicationRoleConnectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ApplicationRoleConnectionErrorType::MetadataValue { key } => {
+                f.write_str("value of metadata key `")?;
+                f.write_str(key)?;
+                f.write_str("` is longer than ")?;
+                Display::fmt(&ApplicationRoleConnection::METADATA_VALUE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            ApplicationRoleConnectionErrorType::PlatformName => {
+                f.write_str("platform name is longer than ")?;
+                Display::fmt(&ApplicationRoleConnection::PLATFORM_NAME_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            ApplicationRoleConnectionErrorType::PlatformUsername => {
+                f.write_str("platform username is longer than ")?;
+                Display::fmt(&ApplicationRoleConnection::PLATFORM_USERNAME_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+        }
+    }
+}
+
+impl Error for ApplicationRoleConnectionError {}
+
+/// Type of [`ApplicationRoleConnectionError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ApplicationRoleConnectionErrorType {
+    /// Metadata value is too long.
+    MetadataValue {
+        /// Key of the invalid value.
+        key: String,
+    },
+    /// Platform name is too long.
+    PlatformName,
+    /// Platform username is too long.
+    PlatformUsername,
+}
+
+/// Connection of a user to an application, used by linked roles.
+///
+/// See [Discord Docs/Application Role Connection Object].
+///
+/// [Discord Docs/Application Role Connection Object]: https://discord.com/developers/docs/resources/user#application-role-connection-object
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ApplicationRoleConnection {
+    /// Stringified values of the user's metadata, keyed by
+    /// [`ApplicationRoleConnectionMetadata::key`].
+    ///
+    /// [`ApplicationRoleConnectionMetadata::key`]: super::ApplicationRoleConnectionMetadata::key
+    pub metadata: HashMap<String, String>,
+    /// Vanity name of the platform the application is bound to.
+    pub platform_name: Option<String>,
+    /// Username of the user on the platform.
+    pub platform_username: Option<String>,
+}
+
+impl ApplicationRoleConnection {
+    /// Maximum number of characters of a metadata value.
+    pub const METADATA_VALUE_LENGTH_MAX: usize = 100;
+
+    /// Maximum number of characters of the platform name.
+    pub const PLATFORM_NAME_LENGTH_MAX: usize = 50;
+
+    /// Maximum number of characters of the platform username.
+    pub const PLATFORM_USERNAME_LENGTH_MAX: usize = 100;
+
+    /// Whether the user's metadata satisfies all of a linked role's
+    /// requirements.
+    ///
+    /// A requirement is not satisfied if the user has no value for its key or
+    /// the value can't be evaluated. See
+    /// [`ApplicationRoleConnectionMetadataType::evaluate`] for how values are
+    /// compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use twilight_model::{
+    ///     application::role_connection::{
+    ///         ApplicationRoleConnection, ApplicationRoleConnectionMetadata,
+    ///         ApplicationRoleConnectionMetadataType,
+    ///     },
+    ///     util::Timestamp,
+    /// };
+    ///
+    /// let metadata = ApplicationRoleConnectionMetadata::new(
+    ///     ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual,
+    ///     "cookies_eaten",
+    ///     "Cookies Eaten",
+    ///     "Cookies eaten by the user",
+    /// )?;
+    /// let connection = ApplicationRoleConnection {
+    ///     metadata: HashMap::from([("cookies_eaten".to_owned(), "12".to_owned())]),
+    ///     platform_name: None,
+    ///     platform_username: None,
+    /// };
+    /// let now = Timestamp::from_secs(1_700_000_000)?;
+    ///
+    /// assert!(connection.satisfies(&[metadata.requirement(10)], now));
+    /// assert!(!connection.satisfies(&[metadata.requirement(20)], now));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn satisfies(
+        &self,
+        requirements: &[ApplicationRoleConnectionRequirement],
+        now: Timestamp,
+    ) -> bool {
+        requirements.iter().all(|requirement| {
+            matches!(
+                self.metadata.get(&requirement.key),
+                Some(value) if requirement.kind.evaluate(value, requirement.value, now) == Some(true)
+            )
+        })
+    }
+
+    /// Validate the connection against Discord's limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`PlatformName`] if the platform name is
+    /// longer than [`PLATFORM_NAME_LENGTH_MAX`] characters.
+    ///
+    /// Returns an error of type [`PlatformUsername`] if the platform username
+    /// is longer than [`PLATFORM_USERNAME_LENGTH_MAX`] characters.
+    ///
+    /// Returns an error of type [`MetadataValue`] if a metadata value is
+    /// longer than [`METADATA_VALUE_LENGTH_MAX`] characters.
+    ///
+    /// [`METADATA_VALUE_LENGTH_MAX`]: Self::METADATA_VALUE_LENGTH_MAX
+    /// [`MetadataValue`]: ApplicationRoleConnectionErrorType::MetadataValue
+    /// [`PLATFORM_NAME_LENGTH_MAX`]: Self::PLATFORM_NAME_LENGTH_MAX
+    /// [`PLATFORM_USERNAME_LENGTH_MAX`]: Self::PLATFORM_USERNAME_LENGTH_MAX
+    /// [`PlatformName`]: ApplicationRoleConnectionErrorType::PlatformName
+    /// [`PlatformUsername`]: ApplicationRoleConnectionErrorType::PlatformUsername
+    pub fn validate(&self) -> Result<(), ApplicationRoleConnectionError> {
+        if exceeds(
+            self.platform_name.as_deref(),
+            Self::PLATFORM_NAME_LENGTH_MAX,
+        ) {
+            return Err(ApplicationRoleConnectionError {
+                kind: ApplicationRoleConnectionErrorType::PlatformName,
+            });
+        }
+
+        if exceeds(
+            self.platform_username.as_deref(),
+            Self::PLATFORM_USERNAME_LENGTH_MAX,
+        ) {
+            return Err(ApplicationRoleConnectionError {
+                kind: ApplicationRoleConnectionErrorType::PlatformUsername,
+            });
+        }
+
+        // Check keys in order so the same key is reported for the same metadata.
+        if let Some(key) = self
+            .metadata
+            .iter()
+            .filter(|(_, value)| exceeds(Some(value), Self::METADATA_VALUE_LENGTH_MAX))
+            .map(|(key, _)| key)
+            .min()
+        {
+            return Err(ApplicationRoleConnectionError {
+                kind: ApplicationRoleConnectionErrorType::MetadataValue { key: key.clone() },
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Requirement of a guild's linked role on a metadata field.
+///
+/// Created with [`ApplicationRoleConnectionMetadata::requirement`].
+///
+/// [`ApplicationRoleConnectionMetadata::requirement`]: super::ApplicationRoleConnectionMetadata::requirement
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ApplicationRoleConnectionRequirement {
+    /// Key of the metadata field.
+    pub key: String,
+    /// Type of the metadata field.
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// Value configured by the guild.
+    pub value: i64,
+}
+
+/// Whether text is longer than a number of characters.
+fn exceeds(text: Option<&str>, max: usize) -> bool {
+    matches!(text, Some(text) if text.chars().count() > max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ApplicationRoleConnection, ApplicationRoleConnectionErrorType,
+        ApplicationRoleConnectionRequirement,
+    };
+    use crate::{
+        application::role_connection::ApplicationRoleConnectionMetadataType, util::Timestamp,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashMap, error::Error, fmt::Debug};
+
+    assert_fields!(
+        ApplicationRoleConnection: metadata,
+        platform_name,
+        platform_username
+    );
+    assert_impl_all!(
+        ApplicationRoleConnection: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    fn connection() -> ApplicationRoleConnection {
+        ApplicationRoleConnection {
+            metadata: HashMap::from([
+                ("cookies_eaten".to_owned(), "12".to_owned()),
+                (
+                    "first_cookie".to_owned(),
+                    "2024-01-01T00:00:00+00:00".to_owned(),
+                ),
+                ("likes_nuts".to_owned(), "0".to_owned()),
+            ]),
+            platform_name: Some("Cookie Clicker".to_owned()),
+            platform_username: Some("baker".to_owned()),
+        }
+    }
+
+    fn requirement(
+        key: &str,
+        kind: ApplicationRoleConnectionMetadataType,
+        value: i64,
+    ) -> ApplicationRoleConnectionRequirement {
+        ApplicationRoleConnectionRequirement {
+            key: key.to_owned(),
+            kind,
+            value,
+        }
+    }
+
+    #[test]
+    fn role_connection() {
+        let value = ApplicationRoleConnection {
+            metadata: HashMap::from([("cookies_eaten".to_owned(), "12".to_owned())]),
+            platform_name: None,
+            platform_username: Some("baker".to_owned()),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnection",
+                    len: 3,
+                },
+                Token::Str("metadata"),
+                Token::Map { len: Some(1) },
+                Token::Str("cookies_eaten"),
+                Token::Str("12"),
+                Token::MapEnd,
+                Token::Str("platform_name"),
+                Token::None,
+                Token::Str("platform_username"),
+                Token::Some,
+                Token::Str("baker"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn satisfies() -> Result<(), Box<dyn Error>> {
+        use ApplicationRoleConnectionMetadataType as Kind;
+
+        let connection = connection();
+        let now = Timestamp::parse("2024-02-01T00:00:00+00:00")?;
+
+        assert!(connection.satisfies(&[], now));
+        assert!(connection.satisfies(
+            &[
+                requirement("cookies_eaten", Kind::IntegerGreaterThanOrEqual, 10),
+                requirement("first_cookie", Kind::DatetimeGreaterThanOrEqual, 31),
+                requirement("likes_nuts", Kind::BooleanNotEqual, 1),
+            ],
+            now
+        ));
+        assert!(!connection.satisfies(
+            &[
+                requirement("cookies_eaten", Kind::IntegerGreaterThanOrEqual, 10),
+                requirement("first_cookie", Kind::DatetimeGreaterThanOrEqual, 32),
+            ],
+            now
+        ));
+        assert!(!connection.satisfies(&[requirement("missing", Kind::IntegerNotEqual, 1)], now));
+        assert!(!connection.satisfies(&[requirement("likes_nuts", Kind::Unknown(99), 0)], now));
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let mut connection = connection();
+        assert!(connection.validate().is_ok());
+
+        connection
+            .metadata
+            .insert("long".to_owned(), "1".repeat(101));
+        assert!(matches!(
+            connection.validate().unwrap_err().kind(),
+            ApplicationRoleConnectionErrorType::MetadataValue { key } if key == "long"
+        ));
+
+        connection
+            .metadata
+            .insert("a_long".to_owned(), "1".repeat(101));
+        assert!(matches!(
+            connection.validate().unwrap_err().kind(),
+            ApplicationRoleConnectionErrorType::MetadataValue { key } if key == "a_long"
+        ));
+
+        connection.platform_username = Some("a".repeat(101));
+        assert!(matches!(
+            connection.validate().unwrap_err().kind(),
+            ApplicationRoleConnectionErrorType::PlatformUsername
+        ));
+
+        connection.platform_name = Some("a".repeat(51));
+        assert!(matches!(
+            connection.validate().unwrap_err().kind(),
+            ApplicationRoleConnectionErrorType::PlatformName
+        ));
+    }
+}
@@ -0,0 +1,466 @@
+use super::{ApplicationRoleConnectionMetadataType, ApplicationRoleConnectionRequirement};
+use crate::util::Locale;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when role connection metadata is invalid.
+#[derive(Debug)]
+pub struct ApplicationRoleConnectionMetadataError {
+    kind: ApplicationRoleConnectionMetadataErrorType,
+}
+
+impl ApplicationRoleConnectionMetadataError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ApplicationRoleConnectionMetadataErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ApplicationRoleConnectionMetadataErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ApplicationRoleConnectionMetadataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ApplicationRoleConnectionMetadataErrorType::Count { count } => {
+                Display::fmt(count, f)?;
+                f.write_str(" metadata records were provided when at most ")?;
+                Display::fmt(&ApplicationRoleConnectionMetadata::RECORDS_MAX, f)?;
+
+                f.write_str(" are allowed")
+            }
+            ApplicationRoleConnectionMetadataErrorType::Description { locale } => {
+                f.write_str("description")?;
+
+                if let Some(locale) = locale {
+                    f.write_str(" localized to ")?;
+                    f.write_str(locale.code())?;
+                }
+
+                f.write_str(" must be between 1 and ")?;
+                Display::fmt(
+                    &ApplicationRoleConnectionMetadata::DESCRIPTION_LENGTH_MAX,
+                    f,
+                )?;
+
+                f.write_str(" characters")
+            }
+            ApplicationRoleConnectionMetadataErrorType::DuplicateKey { key } => {
+                f.write_str("key `")?;
+                f.write_str(key)?;
+
+                f.write_str("` is used by more than one metadata record")
+            }
+            ApplicationRoleConnectionMetadataErrorType::Key => {
+                f.write_str("key must be between 1 and ")?;
+                Display::fmt(&ApplicationRoleConnectionMetadata::KEY_LENGTH_MAX, f)?;
+
+                f.write_str(" characters of a-z, 0-9 or _")
+            }
+            ApplicationRoleConnectionMetadataErrorType::Name { locale } => {
+                f.write_str("name")?;
+
+                if let Some(locale) = locale {
+                    f.write_str(" localized to ")?;
+                    f.write_str(locale.code())?;
+                }
+
+                f.write_str(" must be between 1 and ")?;
+                Display::fmt(&ApplicationRoleConnectionMetadata::NAME_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+        }
+    }
+}
+
+impl Error for ApplicationRoleConnectionMetadataError {}
+
+/// Type of [`ApplicationRoleConnectionMetadataError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ApplicationRoleConnectionMetadataErrorType {
+    /// Too many metadata records were provided.
+    Count {
+        /// Number of provided records.
+        count: usize,
+    },
+    /// Description is empty or too long.
+    Description {
+        /// Locale of the invalid localized description, or [`None`] if the
+        /// default description is invalid.
+        locale: Option<Locale>,
+    },
+    /// Key is used by more than one metadata record.
+    DuplicateKey {
+        /// Duplicated key.
+        key: String,
+    },
+    /// Key is empty, too long or contains invalid characters.
+    Key,
+    /// Name is empty or too long.
+    Name {
+        /// Locale of the invalid localized name, or [`None`] if the default
+        /// name is invalid.
+        locale: Option<Locale>,
+    },
+}
+
+/// Metadata an application uses to define linked role requirements.
+///
+/// See [Discord Docs/Application Role Connection Metadata Object].
+///
+/// [Discord Docs/Application Role Connection Metadata Object]: https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ApplicationRoleConnectionMetadata {
+    /// Description of the metadata field.
+    pub description: String,
+    /// Localization dictionary for the `description` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<Locale, String>>,
+    /// Dictionary key of the metadata field.
+    pub key: String,
+    /// Type of the metadata field.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// Name of the metadata field.
+    pub name: String,
+    /// Localization dictionary for the `name` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<Locale, String>>,
+}
+
+impl ApplicationRoleConnectionMetadata {
+    /// Maximum number of characters of a description.
+    pub const DESCRIPTION_LENGTH_MAX: usize = 200;
+
+    /// Maximum number of characters of a key.
+    pub const KEY_LENGTH_MAX: usize = 50;
+
+    /// Maximum number of characters of a name.
+    pub const NAME_LENGTH_MAX: usize = 100;
+
+    /// Maximum number of metadata records of an application.
+    pub const RECORDS_MAX: usize = 5;
+
+    /// Create validated metadata without localizations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata is invalid. See [`validate`] for the
+    /// possible errors.
+    ///
+    /// [`validate`]: Self::validate
+    pub fn new(
+        kind: ApplicationRoleConnectionMetadataType,
+        key: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Result<Self, ApplicationRoleConnectionMetadataError> {
+        let metadata = Self {
+            description: description.into(),
+            description_localizations: None,
+            key: key.into(),
+            kind,
+            name: name.into(),
+            name_localizations: None,
+        };
+
+        metadata.validate()?;
+
+        Ok(metadata)
+    }
+
+    /// Requirement of a guild's linked role on this metadata field.
+    ///
+    /// The meaning of the configured value depends on the [`kind`] of the
+    /// metadata.
+    ///
+    /// [`kind`]: Self::kind
+    pub fn requirement(&self, value: i64) -> ApplicationRoleConnectionRequirement {
+        ApplicationRoleConnectionRequirement {
+            key: self.key.clone(),
+            kind: self.kind,
+            value,
+        }
+    }
+
+    /// Validate the metadata against Discord's limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Key`] if the key is empty, longer than
+    /// [`KEY_LENGTH_MAX`] characters or contains characters other than `a-z`,
+    /// `0-9` and `_`.
+    ///
+    /// Returns an error of type [`Name`] if the name or one of its
+    /// localizations is empty or longer than [`NAME_LENGTH_MAX`] characters.
+    ///
+    /// Returns an error of type [`Description`] if the description or one of
+    /// its localizations is empty or longer than [`DESCRIPTION_LENGTH_MAX`]
+    /// characters.
+    ///
+    /// [`Description`]: ApplicationRoleConnectionMetadataErrorType::Description
+    /// [`DESCRIPTION_LENGTH_MAX`]: Self::DESCRIPTION_LENGTH_MAX
+    /// [`Key`]: ApplicationRoleConnectionMetadataErrorType::Key
+    /// [`KEY_LENGTH_MAX`]: Self::KEY_LENGTH_MAX
+    /// [`Name`]: ApplicationRoleConnectionMetadataErrorType::Name
+    /// [`NAME_LENGTH_MAX`]: Self::NAME_LENGTH_MAX
+    pub fn validate(&self) -> Result<(), ApplicationRoleConnectionMetadataError> {
+        let key_valid = !self.key.is_empty()
+            && self.key.len() <= Self::KEY_LENGTH_MAX
+            && self
+                .key
+                .bytes()
+                .all(|byte| matches!(byte, b'a'..=b'z' | b'0'..=b'9' | b'_'));
+
+        if !key_valid {
+            return Err(ApplicationRoleConnectionMetadataError {
+                kind: ApplicationRoleConnectionMetadataErrorType::Key,
+            });
+        }
+
+        if let Some(locale) = invalid_text(
+            &self.name,
+            self.name_localizations.as_ref(),
+            Self::NAME_LENGTH_MAX,
+        ) {
+            return Err(ApplicationRoleConnectionMetadataError {
+                kind: ApplicationRoleConnectionMetadataErrorType::Name { locale },
+            });
+        }
+
+        if let Some(locale) = invalid_text(
+            &self.description,
+            self.description_localizations.as_ref(),
+            Self::DESCRIPTION_LENGTH_MAX,
+        ) {
+            return Err(ApplicationRoleConnectionMetadataError {
+                kind: ApplicationRoleConnectionMetadataErrorType::Description { locale },
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validate all metadata records of an application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Count`] if more than [`RECORDS_MAX`]
+    /// records are provided.
+    ///
+    /// Returns an error of type [`DuplicateKey`] if more than one record has
+    /// the same key.
+    ///
+    /// Returns an error if a record is invalid. See [`validate`] for the
+    /// possible errors.
+    ///
+    /// [`Count`]: ApplicationRoleConnectionMetadataErrorType::Count
+    /// [`DuplicateKey`]: ApplicationRoleConnectionMetadataErrorType::DuplicateKey
+    /// [`RECORDS_MAX`]: Self::RECORDS_MAX
+    /// [`validate`]: Self::validate
+    pub fn validate_all(records: &[Self]) -> Result<(), ApplicationRoleConnectionMetadataError> {
+        if records.len() > Self::RECORDS_MAX {
+            return Err(ApplicationRoleConnectionMetadataError {
+                kind: ApplicationRoleConnectionMetadataErrorType::Count {
+                    count: records.len(),
+                },
+            });
+        }
+
+        for (index, record) in records.iter().enumerate() {
+            record.validate()?;
+
+            if records[..index]
+                .iter()
+                .any(|previous| previous.key == record.key)
+            {
+                return Err(ApplicationRoleConnectionMetadataError {
+                    kind: ApplicationRoleConnectionMetadataErrorType::DuplicateKey {
+                        key: record.key.clone(),
+                    },
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Find invalid text among a default value and its localizations.
+///
+/// Returns `Some(None)` if the default value is invalid and
+/// `Some(Some(locale))` if a localization is invalid. Localizations are
+/// checked in order of their locale code, so the same locale is reported
+/// regardless of the map's iteration order.
+#[allow(clippy::option_option)]
+fn invalid_text(
+    text: &str,
+    localizations: Option<&HashMap<Locale, String>>,
+    max: usize,
+) -> Option<Option<Locale>> {
+    let valid = |text: &str| !text.is_empty() && text.chars().count() <= max;
+
+    if !valid(text) {
+        return Some(None);
+    }
+
+    localizations?
+        .iter()
+        .filter(|(_, text)| !valid(text))
+        .map(|(locale, _)| locale)
+        .min_by(|a, b| a.code().cmp(b.code()))
+        .map(|locale| Some(locale.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApplicationRoleConnectionMetadata, ApplicationRoleConnectionMetadataErrorType};
+    use crate::{
+        application::role_connection::ApplicationRoleConnectionMetadataType, util::Locale,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashMap, error::Error, fmt::Debug};
+
+    assert_fields!(
+        ApplicationRoleConnectionMetadata: description,
+        description_localizations,
+        key,
+        kind,
+        name,
+        name_localizations
+    );
+    assert_impl_all!(
+        ApplicationRoleConnectionMetadata: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn metadata() -> Result<(), Box<dyn Error>> {
+        let value = ApplicationRoleConnectionMetadata::new(
+            ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual,
+            "cookies_eaten",
+            "Cookies Eaten",
+            "Cookies eaten by the user",
+        )?;
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnectionMetadata",
+                    len: 4,
+                },
+                Token::Str("description"),
+                Token::Str("Cookies eaten by the user"),
+                Token::Str("key"),
+                Token::Str("cookies_eaten"),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("name"),
+                Token::Str("Cookies Eaten"),
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() -> Result<(), Box<dyn Error>> {
+        let kind = ApplicationRoleConnectionMetadataType::BooleanEqual;
+
+        for key in ["", "Upper", "with space", "dash-ed", &"a".repeat(51)] {
+            assert!(matches!(
+                ApplicationRoleConnectionMetadata::new(kind, key, "Name", "Description")
+                    .unwrap_err()
+                    .kind(),
+                ApplicationRoleConnectionMetadataErrorType::Key
+            ));
+        }
+
+        assert!(matches!(
+            ApplicationRoleConnectionMetadata::new(kind, "key", "a".repeat(101), "Description")
+                .unwrap_err()
+                .kind(),
+            ApplicationRoleConnectionMetadataErrorType::Name { locale: None }
+        ));
+        assert!(matches!(
+            ApplicationRoleConnectionMetadata::new(kind, "key", "Name", "")
+                .unwrap_err()
+                .kind(),
+            ApplicationRoleConnectionMetadataErrorType::Description { locale: None }
+        ));
+
+        let mut metadata =
+            ApplicationRoleConnectionMetadata::new(kind, "is_verified_2", "Name", "a".repeat(200))?;
+        metadata.name_localizations = Some(HashMap::from([(Locale::French, String::new())]));
+        assert!(matches!(
+            metadata.validate().unwrap_err().kind(),
+            ApplicationRoleConnectionMetadataErrorType::Name {
+                locale: Some(Locale::French)
+            }
+        ));
+
+        metadata.name_localizations = Some(HashMap::from([
+            (Locale::SpanishSpain, String::new()),
+            (Locale::French, "a".repeat(101)),
+            (Locale::German, String::new()),
+            (Locale::Japanese, "Nom".to_owned()),
+        ]));
+        assert!(matches!(
+            metadata.validate().unwrap_err().kind(),
+            ApplicationRoleConnectionMetadataErrorType::Name {
+                locale: Some(Locale::German)
+            }
+        ));
+
+        metadata.name_localizations = None;
+        assert!(ApplicationRoleConnectionMetadata::validate_all(&[metadata.clone()]).is_ok());
+        assert!(matches!(
+            ApplicationRoleConnectionMetadata::validate_all(&[metadata.clone(), metadata.clone()])
+                .unwrap_err()
+                .kind(),
+            ApplicationRoleConnectionMetadataErrorType::DuplicateKey { key }
+                if key == "is_verified_2"
+        ));
+        assert!(matches!(
+            ApplicationRoleConnectionMetadata::validate_all(&vec![metadata; 6])
+                .unwrap_err()
+                .kind(),
+            ApplicationRoleConnectionMetadataErrorType::Count { count: 6 }
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Number of seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Type of an [`ApplicationRoleConnectionMetadata`], determining how a user's
+/// metadata value is compared to a guild's configured value.
+///
+/// See [Discord Docs/Application Role Connection Metadata Type].
+///
+/// [`ApplicationRoleConnectionMetadata`]: super::ApplicationRoleConnectionMetadata
+/// [Discord Docs/Application Role Connection Metadata Type]: https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object-application-role-connection-metadata-type
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum ApplicationRoleConnectionMetadataType {
+    /// Integer value is less than or equal to the configured value.
+    IntegerLessThanOrEqual,
+    /// Integer value is greater than or equal to the configured value.
+    IntegerGreaterThanOrEqual,
+    /// Integer value is equal to the configured value.
+    IntegerEqual,
+    /// Integer value is not equal to the configured value.
+    IntegerNotEqual,
+    /// Number of days since the datetime value is less than or equal to the
+    /// configured number of days.
+    DatetimeLessThanOrEqual,
+    /// Number of days since the datetime value is greater than or equal to
+    /// the configured number of days.
+    DatetimeGreaterThanOrEqual,
+    /// Boolean value is equal to the configured value.
+    BooleanEqual,
+    /// Boolean value is not equal to the configured value.
+    BooleanNotEqual,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl ApplicationRoleConnectionMetadataType {
+    /// Whether a user's metadata value satisfies a guild's configured value.
+    ///
+    /// Integer values are compared as is. Datetime values are ISO 8601
+    /// timestamps whose number of whole days elapsed before `now` is
+    /// compared to the configured number of days. Boolean values are `1` for
+    /// true and `0` for false.
+    ///
+    /// Returns [`None`] if the value can't be parsed for the type or the type
+    /// is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     application::role_connection::ApplicationRoleConnectionMetadataType,
+    ///     util::Timestamp,
+    /// };
+    ///
+    /// let now = Timestamp::parse("2024-01-31T00:00:00+00:00")?;
+    /// let kind = ApplicationRoleConnectionMetadataType::DatetimeGreaterThanOrEqual;
+    ///
+    /// assert_eq!(Some(true), kind.evaluate("2024-01-01T00:00:00+00:00", 30, now));
+    /// assert_eq!(Some(false), kind.evaluate("2024-01-02T00:00:00+00:00", 30, now));
+    /// assert_eq!(None, kind.evaluate("yesterday", 30, now));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn evaluate(self, value: &str, configured: i64, now: Timestamp) -> Option<bool> {
+        Some(match self {
+            Self::IntegerLessThanOrEqual => value.parse::<i64>().ok()? <= configured,
+            Self::IntegerGreaterThanOrEqual => value.parse::<i64>().ok()? >= configured,
+            Self::IntegerEqual => value.parse::<i64>().ok()? == configured,
+            Self::IntegerNotEqual => value.parse::<i64>().ok()? != configured,
+            Self::DatetimeLessThanOrEqual => days_since(value, now)? <= configured,
+            Self::DatetimeGreaterThanOrEqual => days_since(value, now)? >= configured,
+            Self::BooleanEqual => parse_bool(value)? == (configured != 0),
+            Self::BooleanNotEqual => parse_bool(value)? != (configured != 0),
+            Self::Unknown(_) => return None,
+        })
+    }
+}
+
+impl From<u8> for ApplicationRoleConnectionMetadataType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::IntegerLessThanOrEqual,
+            2 => Self::IntegerGreaterThanOrEqual,
+            3 => Self::IntegerEqual,
+            4 => Self::IntegerNotEqual,
+            5 => Self::DatetimeLessThanOrEqual,
+            6 => Self::DatetimeGreaterThanOrEqual,
+            7 => Self::BooleanEqual,
+            8 => Self::BooleanNotEqual,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ApplicationRoleConnectionMetadataType> for u8 {
+    fn from(value: ApplicationRoleConnectionMetadataType) -> Self {
+        match value {
+            ApplicationRoleConnectionMetadataType::IntegerLessThanOrEqual => 1,
+            ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual => 2,
+            ApplicationRoleConnectionMetadataType::IntegerEqual => 3,
+            ApplicationRoleConnectionMetadataType::IntegerNotEqual => 4,
+            ApplicationRoleConnectionMetadataType::DatetimeLessThanOrEqual => 5,
+            ApplicationRoleConnectionMetadataType::DatetimeGreaterThanOrEqual => 6,
+            ApplicationRoleConnectionMetadataType::BooleanEqual => 7,
+            ApplicationRoleConnectionMetadataType::BooleanNotEqual => 8,
+            ApplicationRoleConnectionMetadataType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Number of whole days elapsed between an ISO 8601 timestamp and `now`.
+fn days_since(value: &str, now: Timestamp) -> Option<i64> {
+    let timestamp = Timestamp::parse(value).ok()?;
+
+    Some((now.as_secs() - timestamp.as_secs()).div_euclid(SECONDS_PER_DAY))
+}
+
+/// Parse a stringified boolean, which Discord represents as `1` or `0`.
+fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "1" | "true" => Some(true),
+        "0" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationRoleConnectionMetadataType;
+    use crate::util::Timestamp;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        ApplicationRoleConnectionMetadataType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        const MAP: &[(ApplicationRoleConnectionMetadataType, u8)] = &[
+            (
+                ApplicationRoleConnectionMetadataType::IntegerLessThanOrEqual,
+                1,
+            ),
+            (
+                ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual,
+                2,
+            ),
+            (ApplicationRoleConnectionMetadataType::IntegerEqual, 3),
+            (ApplicationRoleConnectionMetadataType::IntegerNotEqual, 4),
+            (
+                ApplicationRoleConnectionMetadataType::DatetimeLessThanOrEqual,
+                5,
+            ),
+            (
+                ApplicationRoleConnectionMetadataType::DatetimeGreaterThanOrEqual,
+                6,
+            ),
+            (ApplicationRoleConnectionMetadataType::BooleanEqual, 7),
+            (ApplicationRoleConnectionMetadataType::BooleanNotEqual, 8),
+            (ApplicationRoleConnectionMetadataType::Unknown(99), 99),
+        ];
+
+        for (kind, number) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*number)]);
+            assert_eq!(*kind, ApplicationRoleConnectionMetadataType::from(*number));
+            assert_eq!(*number, u8::from(*kind));
+        }
+    }
+
+    #[test]
+    fn evaluate() -> Result<(), Box<dyn Error>> {
+        use ApplicationRoleConnectionMetadataType as Kind;
+
+        let now = Timestamp::parse("2024-01-31T12:00:00+00:00")?;
+
+        assert_eq!(
+            Some(true),
+            Kind::IntegerLessThanOrEqual.evaluate("5", 5, now)
+        );
+        assert_eq!(
+            Some(false),
+            Kind::IntegerLessThanOrEqual.evaluate("6", 5, now)
+        );
+        assert_eq!(
+            Some(true),
+            Kind::IntegerGreaterThanOrEqual.evaluate("6", 5, now)
+        );
+        assert_eq!(Some(true), Kind::IntegerEqual.evaluate("-3", -3, now));
+        assert_eq!(Some(false), Kind::IntegerNotEqual.evaluate("3", 3, now));
+        assert_eq!(None, Kind::IntegerEqual.evaluate("three", 3, now));
+
+        let created = "2024-01-21T13:00:00+00:00";
+        assert_eq!(
+            Some(true),
+            Kind::DatetimeLessThanOrEqual.evaluate(created, 9, now)
+        );
+        assert_eq!(
+            Some(false),
+            Kind::DatetimeLessThanOrEqual.evaluate(created, 8, now)
+        );
+        assert_eq!(
+            Some(true),
+            Kind::DatetimeGreaterThanOrEqual.evaluate(created, 9, now)
+        );
+        assert_eq!(
+            Some(false),
+            Kind::DatetimeGreaterThanOrEqual.evaluate(created, 10, now)
+        );
+
+        assert_eq!(Some(true), Kind::BooleanEqual.evaluate("1", 1, now));
+        assert_eq!(Some(false), Kind::BooleanEqual.evaluate("0", 1, now));
+        assert_eq!(Some(true), Kind::BooleanNotEqual.evaluate("0", 1, now));
+        assert_eq!(None, Kind::BooleanEqual.evaluate("maybe", 1, now));
+        assert_eq!(None, Kind::Unknown(99).evaluate("1", 1, now));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+//! Types for linked roles, which require users to have a connection to an
+//! application with metadata satisfying a guild's requirements.
+//!
+//! See [Discord Docs/Application Role Connection Metadata].
+//!
+//! [Discord Docs/Application Role Connection Metadata]: https://discord.com/developers/docs/resources/application-role-connection-metadata
+
+mod connection;
+mod metadata;
+mod metadata_type;
+
+pub use self::{
+    connection::{
+        ApplicationRoleConnection, ApplicationRoleConnectionError,
+        ApplicationRoleConnectionErrorType, ApplicationRoleConnectionRequirement,
+    },
+    metadata::{
+        ApplicationRoleConnectionMetadata, ApplicationRoleConnectionMetadataError,
+        ApplicationRoleConnectionMetadataErrorType,
+    },
+    metadata_type::ApplicationRoleConnectionMetadataType,
+};
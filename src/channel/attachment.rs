@@ -6,6 +6,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Attachment {
     /// Attachment's [media type].
     ///
@@ -112,7 +113,9 @@ mod tests {
                 Token::Some,
                 Token::U64(184),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("700000000000000000"),
                 Token::Str("proxy_url"),
                 Token::Str("https://cdn.example.com/1.png"),
@@ -26,6 +26,17 @@ impl Serialize for AttachmentFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for AttachmentFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "AttachmentFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::AttachmentFlags;
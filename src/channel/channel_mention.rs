@@ -8,6 +8,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ChannelMention {
     pub guild_id: Id<GuildMarker>,
     pub id: Id<ChannelMarker>,
@@ -39,10 +40,14 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(0),
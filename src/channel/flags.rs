@@ -28,6 +28,17 @@ impl Serialize for ChannelFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ChannelFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ChannelFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ChannelFlags;
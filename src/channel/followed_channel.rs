@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 /// and the [`Id<WebhookMarker>`] that was created in the
 /// target channel.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FollowedChannel {
     pub channel_id: Id<ChannelMarker>,
     pub webhook_id: Id<WebhookMarker>,
@@ -37,10 +38,14 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("webhook_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::StructEnd,
             ],
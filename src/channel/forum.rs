@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Exactly one of `emoji_id` and `emoji_name` must be set.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DefaultReaction {
     /// ID of custom guild emoji.
     ///
@@ -24,6 +25,7 @@ pub struct DefaultReaction {
 /// [channel]: super::Channel
 /// [forum]: super::ChannelType::GuildForum
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum ForumLayout {
@@ -75,6 +77,7 @@ impl From<ForumLayout> for u8 {
 /// [channel]: super::Channel
 /// [forum]: super::ChannelType::GuildForum
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum ForumSortOrder {
@@ -123,6 +126,7 @@ impl From<ForumSortOrder> for u8 {
 /// [`Channel`]: super::Channel
 /// [`GuildForum`]: super::ChannelType::GuildForum
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ForumTag {
     /// ID of custom guild emoji.
     ///
@@ -131,6 +135,7 @@ pub struct ForumTag {
     ///
     /// Conflicts with `emoji_name`.
     #[serde(with = "crate::visitor::zeroable_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Id<EmojiMarker>>"))]
     pub emoji_id: Option<Id<EmojiMarker>>,
     /// Unicode emoji character.
     ///
@@ -270,7 +275,9 @@ mod tests {
                 Token::Some,
                 Token::Str("emoji"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("moderated"),
                 Token::Bool(true),
@@ -300,12 +307,16 @@ mod tests {
                 },
                 Token::Str("emoji_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("emoji_name"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("moderated"),
                 Token::Bool(false),
@@ -342,7 +353,9 @@ mod tests {
                 Token::Str("emoji_name"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("moderated"),
                 Token::Bool(true),
@@ -364,7 +377,9 @@ mod tests {
                 Token::Str("emoji_name"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("moderated"),
                 Token::Bool(true),
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// Activity associated with a message.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MessageActivity {
     /// [`MessageActivityType`]
     #[serde(rename = "type")]
@@ -13,6 +14,7 @@ pub struct MessageActivity {
 
 /// Activity of this message.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum MessageActivityType {
@@ -20,6 +20,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Message Formatting]: https://discord.com/developers/docs/reference#message-formatting
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AllowedMentions {
     /// List of allowed mention types.
     ///
@@ -45,6 +46,7 @@ pub struct AllowedMentions {
 
 /// Allowed mention type.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum MentionType {
@@ -113,12 +115,16 @@ mod tests {
                 Token::Bool(true),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("200"),
                 Token::SeqEnd,
                 Token::Str("users"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::SeqEnd,
                 Token::StructEnd,
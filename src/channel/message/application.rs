@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Application`]: crate::oauth::Application
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MessageApplication {
     /// Default rich presence invite cover image.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,7 +55,9 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::ICON_INPUT),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("application"),
@@ -32,6 +32,7 @@ pub struct Button {
 /// Style of a [`Button`].
 // Keep in sync with `twilight-validate::component`!
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum ButtonStyle {
@@ -5,6 +5,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 ///
 /// [`Component`]: super::Component
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum ComponentType {
@@ -634,6 +634,75 @@ impl Serialize for Component {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Component {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Component".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let component = generator.subschema_for::<Self>();
+        let reaction_type = generator.subschema_for::<ReactionType>();
+
+        schemars::json_schema!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": 1 },
+                        "components": { "type": "array", "items": component },
+                    },
+                    "required": ["type", "components"],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": 2 },
+                        "custom_id": { "type": "string" },
+                        "disabled": { "type": "boolean" },
+                        "emoji": reaction_type,
+                        "label": { "type": "string" },
+                        "style": generator.subschema_for::<ButtonStyle>(),
+                        "url": { "type": "string" },
+                    },
+                    "required": ["type", "style"],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": 3 },
+                        "custom_id": { "type": "string" },
+                        "disabled": { "type": "boolean" },
+                        "max_values": generator.subschema_for::<u8>(),
+                        "min_values": generator.subschema_for::<u8>(),
+                        "options": {
+                            "type": "array",
+                            "items": generator.subschema_for::<SelectMenuOption>(),
+                        },
+                        "placeholder": { "type": "string" },
+                    },
+                    "required": ["type", "custom_id", "options"],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": 4 },
+                        "custom_id": { "type": "string" },
+                        "label": { "type": "string" },
+                        "max_length": generator.subschema_for::<u16>(),
+                        "min_length": generator.subschema_for::<u16>(),
+                        "placeholder": { "type": "string" },
+                        "required": { "type": "boolean" },
+                        "style": generator.subschema_for::<TextInputStyle>(),
+                        "value": { "type": "string" },
+                    },
+                    "required": ["type", "custom_id", "label", "style"],
+                },
+            ],
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Required due to the use of a unicode emoji in a constant.
@@ -24,6 +24,7 @@ pub struct SelectMenu {
 
 /// Dropdown options that are part of [`SelectMenu`].
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SelectMenuOption {
     /// Whether the option will be selected by default.
     #[serde(default)]
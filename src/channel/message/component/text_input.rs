@@ -29,6 +29,7 @@ pub struct TextInput {
 
 /// Style of an [`TextInput`].
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum TextInputStyle {
@@ -35,6 +35,8 @@ bitflags! {
         const SUPPRESS_NOTIFICATIONS = 1 << 12;
         /// This message is a voice message.
         const IS_VOICE_MESSAGE = 1 << 13;
+        /// This message has a snapshot of a forwarded message.
+        const HAS_SNAPSHOT = 1 << 14;
     }
 }
 
@@ -53,6 +55,17 @@ impl Serialize for MessageFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MessageFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "MessageFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::MessageFlags;
@@ -110,6 +123,8 @@ mod tests {
         1 << 8
     );
     const_assert_eq!(MessageFlags::SUPPRESS_NOTIFICATIONS.bits(), 1 << 12);
+    const_assert_eq!(MessageFlags::IS_VOICE_MESSAGE.bits(), 1 << 13);
+    const_assert_eq!(MessageFlags::HAS_SNAPSHOT.bits(), 1 << 14);
 
     #[test]
     fn serde() {
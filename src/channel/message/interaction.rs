@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 /// Associated interaction metadata.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MessageInteraction {
     /// ID of the interaction.
     pub id: Id<InteractionMarker>,
@@ -71,7 +72,7 @@ mod tests {
                 flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
                 global_name: Some("test".to_owned()),
                 id: Id::new(3),
-                locale: Some("en-us".to_owned()),
+                locale: Some("en-us".into()),
                 mfa_enabled: Some(true),
                 name: "test".to_owned(),
                 premium_type: Some(PremiumType::Nitro),
@@ -91,7 +92,9 @@ mod tests {
                     len: 5,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(InteractionType::ApplicationCommand as u8),
@@ -116,7 +119,9 @@ mod tests {
                 Token::Str("a nickname"),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::SeqEnd,
                 Token::Str("user"),
@@ -153,7 +158,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("locale"),
                 Token::Some,
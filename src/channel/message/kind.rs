@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// [`Message`]: super::Message
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum MessageType {
@@ -70,6 +71,8 @@ pub enum MessageType {
     StageTopic,
     /// System message denoting a guild application premium subscription.
     GuildApplicationPremiumSubscription,
+    /// System message denoting the results of an ended poll.
+    PollResult,
     /// Variant value is unknown to the library.
     Unknown(u8),
 }
@@ -111,6 +114,7 @@ impl MessageType {
                 | Self::StageEnd
                 | Self::StageSpeaker
                 | Self::StageTopic
+                | Self::PollResult
         )
     }
 
@@ -173,6 +177,7 @@ impl From<u8> for MessageType {
             29 => Self::StageSpeaker,
             31 => Self::StageTopic,
             32 => Self::GuildApplicationPremiumSubscription,
+            46 => Self::PollResult,
             unknown => Self::Unknown(unknown),
         }
     }
@@ -212,6 +217,7 @@ impl From<MessageType> for u8 {
             MessageType::StageSpeaker => 29,
             MessageType::StageTopic => 31,
             MessageType::GuildApplicationPremiumSubscription => 32,
+            MessageType::PollResult => 46,
             MessageType::Unknown(unknown) => unknown,
         }
     }
@@ -281,6 +287,7 @@ mod tests {
             (MessageType::StageSpeaker, 29, true),
             (MessageType::StageTopic, 31, true),
             (MessageType::GuildApplicationPremiumSubscription, 32, false),
+            (MessageType::PollResult, 46, true),
         ];
 
         for (message_type, number, deletable) in MAP {
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 /// Mention of a user in a message.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Mention {
     /// Hash of the user's avatar, if any.
     pub avatar: Option<ImageHash>,
@@ -22,6 +23,10 @@ pub struct Mention {
     /// integer. The field will always serialize into a string due to that being
     /// the type Discord's API uses.
     #[serde(with = "user::discriminator")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "user::discriminator::schema")
+    )]
     pub discriminator: u16,
     /// Unique ID of the user.
     pub id: Id<UserMarker>,
@@ -81,7 +86,9 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("username"),
                 Token::Str("foo"),
@@ -133,7 +140,9 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0001"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("member"),
                 Token::Some,
@@ -14,7 +14,9 @@ mod kind;
 mod mention;
 mod reaction;
 mod reference;
+mod reference_type;
 mod role_subscription_data;
+mod snapshot;
 
 pub use self::{
     activity::{MessageActivity, MessageActivityType},
@@ -28,7 +30,9 @@ pub use self::{
     mention::Mention,
     reaction::{Reaction, ReactionType},
     reference::MessageReference,
+    reference_type::MessageReferenceType,
     role_subscription_data::RoleSubscriptionData,
+    snapshot::{MessageSnapshot, MessageSnapshotFields},
     sticker::Sticker,
 };
 
@@ -42,6 +46,7 @@ use crate::{
         },
         Id,
     },
+    poll::Poll,
     user::User,
     util::Timestamp,
 };
@@ -49,6 +54,7 @@ use serde::{Deserialize, Serialize};
 
 /// Text message sent in a [`Channel`].
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Message {
     /// Present with Rich Presence-related chat embeds.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -157,8 +163,18 @@ pub struct Message {
     pub mention_roles: Vec<Id<RoleMarker>>,
     /// Users mentioned in the message.
     pub mentions: Vec<Mention>,
+    /// Copies of the forwarded message, if the message is a forward.
+    ///
+    /// See [`is_forward`].
+    ///
+    /// [`is_forward`]: Self::is_forward
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub message_snapshots: Vec<MessageSnapshot>,
     /// Whether the message is pinned.
     pub pinned: bool,
+    /// Poll attached to the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Poll>,
     /// List of reactions to the message.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub reactions: Vec<Reaction>,
@@ -193,12 +209,63 @@ pub struct Message {
     pub webhook_id: Option<Id<WebhookMarker>>,
 }
 
+impl Message {
+    /// Whether the message is a crosspost of a message published in a
+    /// followed announcement channel.
+    ///
+    /// The [`reference`] points to the original message.
+    ///
+    /// [`reference`]: Self::reference
+    pub const fn is_crosspost(&self) -> bool {
+        matches!(self.flags, Some(flags) if flags.contains(MessageFlags::IS_CROSSPOST))
+    }
+
+    /// Whether the message is a forward of another message.
+    ///
+    /// The forwarded message is available in [`message_snapshots`], and the
+    /// [`reference`] points to the original message.
+    ///
+    /// [`message_snapshots`]: Self::message_snapshots
+    /// [`reference`]: Self::reference
+    pub fn is_forward(&self) -> bool {
+        matches!(
+            &self.reference,
+            Some(reference) if reference.kind == MessageReferenceType::Forward
+        )
+    }
+
+    /// Whether the message is a system message denoting that a message was
+    /// pinned.
+    ///
+    /// The [`reference`] points to the pinned message.
+    ///
+    /// [`reference`]: Self::reference
+    pub fn is_pin_notification(&self) -> bool {
+        self.kind == MessageType::ChannelMessagePinned
+    }
+
+    /// Whether the message is a reply to another message.
+    ///
+    /// The [`reference`] points to the replied to message, which is available
+    /// in [`referenced_message`] unless it was deleted.
+    ///
+    /// [`reference`]: Self::reference
+    /// [`referenced_message`]: Self::referenced_message
+    pub fn is_reply(&self) -> bool {
+        self.kind == MessageType::Reply
+            && matches!(
+                &self.reference,
+                Some(reference) if reference.kind == MessageReferenceType::Default
+            )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         sticker::{MessageSticker, StickerFormatType},
         Message, MessageActivity, MessageActivityType, MessageApplication, MessageFlags,
-        MessageReference, MessageType, Reaction, ReactionType,
+        MessageReference, MessageReferenceType, MessageType, Reaction, ReactionType,
     };
     use crate::{
         channel::{ChannelMention, ChannelType},
@@ -269,7 +336,9 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            message_snapshots: Vec::new(),
             pinned: false,
+            poll: None,
             reactions: Vec::new(),
             reference: None,
             role_subscription_data: None,
@@ -317,13 +386,17 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("test"),
                 Token::StructEnd,
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("content"),
                 Token::Str("ping"),
@@ -337,10 +410,14 @@ mod tests {
                 Token::U64(0),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -388,7 +465,9 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(1),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("sticker name"),
@@ -476,7 +555,9 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            message_snapshots: Vec::new(),
             pinned: false,
+            poll: None,
             reactions: vec![Reaction {
                 count: 7,
                 emoji: ReactionType::Unicode {
@@ -487,6 +568,7 @@ mod tests {
             reference: Some(MessageReference {
                 channel_id: Some(Id::new(1)),
                 guild_id: None,
+                kind: MessageReferenceType::Default,
                 message_id: None,
                 fail_if_not_exists: None,
             }),
@@ -534,14 +616,18 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::ICON_INPUT),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("application"),
                 Token::StructEnd,
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("attachments"),
                 Token::Seq { len: Some(0) },
@@ -568,13 +654,17 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("username"),
                 Token::Str("test"),
                 Token::StructEnd,
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("content"),
                 Token::Str("ping"),
@@ -589,10 +679,14 @@ mod tests {
                 Token::U64(0),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -628,10 +722,14 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -677,7 +775,9 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::StructEnd,
                 Token::Str("sticker_items"),
@@ -689,7 +789,9 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(1),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("sticker name"),
@@ -701,7 +803,9 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("webhook_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
@@ -709,4 +813,84 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn reference_helpers() {
+        let value: Message = serde_json::from_str(
+            r#"{
+                "attachments": [],
+                "author": {
+                    "avatar": null,
+                    "discriminator": "0",
+                    "id": "1",
+                    "username": "forwarder"
+                },
+                "channel_id": "2",
+                "content": "",
+                "edited_timestamp": null,
+                "embeds": [],
+                "flags": 16384,
+                "id": "3",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "message_reference": {
+                    "channel_id": "4",
+                    "message_id": "5",
+                    "type": 1
+                },
+                "message_snapshots": [
+                    {
+                        "message": {
+                            "attachments": [],
+                            "content": "original",
+                            "edited_timestamp": null,
+                            "embeds": [],
+                            "flags": 0,
+                            "mention_roles": [],
+                            "mentions": [],
+                            "timestamp": "2024-01-01T00:00:00.000000+00:00",
+                            "type": 0
+                        }
+                    }
+                ],
+                "pinned": false,
+                "timestamp": "2024-01-02T00:00:00.000000+00:00",
+                "tts": false,
+                "type": 0
+            }"#,
+        )
+        .unwrap();
+
+        assert!(value.is_forward());
+        assert!(!value.is_reply());
+        assert!(!value.is_crosspost());
+        assert!(!value.is_pin_notification());
+        assert_eq!(Some(MessageFlags::HAS_SNAPSHOT), value.flags);
+        assert_eq!("original", value.message_snapshots[0].message.content);
+
+        let mut reply = value.clone();
+        reply.kind = MessageType::Reply;
+        reply.reference = Some(MessageReference {
+            channel_id: Some(Id::new(4)),
+            guild_id: None,
+            kind: MessageReferenceType::Default,
+            message_id: Some(Id::new(5)),
+            fail_if_not_exists: None,
+        });
+        reply.message_snapshots.clear();
+        assert!(reply.is_reply());
+        assert!(!reply.is_forward());
+
+        let mut crosspost = reply.clone();
+        crosspost.kind = MessageType::Regular;
+        crosspost.flags = Some(MessageFlags::IS_CROSSPOST);
+        assert!(crosspost.is_crosspost());
+        assert!(!crosspost.is_reply());
+
+        let mut pin = reply;
+        pin.kind = MessageType::ChannelMessagePinned;
+        assert!(pin.is_pin_notification());
+        assert!(!pin.is_reply());
+    }
 }
@@ -1,11 +1,16 @@
-use crate::id::{
-    marker::{ChannelMarker, GuildMarker, MessageMarker},
-    Id,
+use super::MessageReferenceType;
+use crate::{
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker},
+        Id,
+    },
+    util::is_default,
 };
 use serde::{Deserialize, Serialize};
 
 /// Message reference struct.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MessageReference {
     /// Originating message's channel ID.
     ///
@@ -16,6 +21,11 @@ pub struct MessageReference {
     /// Originating message's guild ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
+    /// Type of reference.
+    ///
+    /// Defaults to [`MessageReferenceType::Default`].
+    #[serde(default, rename = "type", skip_serializing_if = "is_default")]
+    pub kind: MessageReferenceType,
     /// Originating message's ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<Id<MessageMarker>>,
@@ -30,7 +40,7 @@ pub struct MessageReference {
 #[cfg(test)]
 mod tests {
     use super::MessageReference;
-    use crate::id::Id;
+    use crate::{channel::message::MessageReferenceType, id::Id};
     use serde_test::Token;
 
     #[test]
@@ -38,6 +48,7 @@ mod tests {
         let value = MessageReference {
             channel_id: Some(Id::new(1)),
             guild_id: None,
+            kind: MessageReferenceType::Default,
             message_id: None,
             fail_if_not_exists: None,
         };
@@ -51,7 +62,9 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
@@ -63,6 +76,7 @@ mod tests {
         let value = MessageReference {
             channel_id: Some(Id::new(1)),
             guild_id: Some(Id::new(2)),
+            kind: MessageReferenceType::Default,
             message_id: Some(Id::new(3)),
             fail_if_not_exists: Some(false),
         };
@@ -76,15 +90,66 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("3"),
+                Token::Str("fail_if_not_exists"),
+                Token::Some,
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let value = MessageReference {
+            channel_id: Some(Id::new(1)),
+            guild_id: Some(Id::new(2)),
+            kind: MessageReferenceType::Forward,
+            message_id: Some(Id::new(3)),
+            fail_if_not_exists: Some(false),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessageReference",
+                    len: 5,
+                },
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("message_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("fail_if_not_exists"),
                 Token::Some,
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+/// Type of a [`MessageReference`].
+///
+/// See [Discord Docs/Message Reference Types].
+///
+/// [`MessageReference`]: super::MessageReference
+/// [Discord Docs/Message Reference Types]: https://discord.com/developers/docs/resources/message#message-reference-types
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum MessageReferenceType {
+    /// Standard reference used by replies and system messages.
+    #[default]
+    Default,
+    /// Reference used to point to a message at a point in time, used by
+    /// forwards.
+    Forward,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for MessageReferenceType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Default,
+            1 => Self::Forward,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<MessageReferenceType> for u8 {
+    fn from(value: MessageReferenceType) -> Self {
+        match value {
+            MessageReferenceType::Default => 0,
+            MessageReferenceType::Forward => 1,
+            MessageReferenceType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageReferenceType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        MessageReferenceType: Clone,
+        Copy,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        const MAP: &[(MessageReferenceType, u8)] = &[
+            (MessageReferenceType::Default, 0),
+            (MessageReferenceType::Forward, 1),
+            (MessageReferenceType::Unknown(99), 99),
+        ];
+
+        for (kind, number) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*number)]);
+            assert_eq!(*kind, MessageReferenceType::from(*number));
+            assert_eq!(*number, u8::from(*kind));
+        }
+    }
+}
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Message`]: super::Message
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RoleSubscriptionData {
     /// Whether this notification is for a renewal rather than a new purchase.
     pub is_renewal: bool,
@@ -62,7 +63,9 @@ mod tests {
                 Token::Str("is_renewal"),
                 Token::Bool(true),
                 Token::Str("role_subscription_listing_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("tier_name"),
                 Token::Str("sparkle"),
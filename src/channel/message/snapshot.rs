@@ -0,0 +1,155 @@
+use super::{sticker::MessageSticker, Component, Embed, Mention, MessageFlags, MessageType};
+use crate::{
+    channel::Attachment,
+    id::{marker::RoleMarker, Id},
+    util::Timestamp,
+};
+use serde::{Deserialize, Serialize};
+
+/// Copy of a message at the time it was forwarded.
+///
+/// See [Discord Docs/Message Snapshot Object].
+///
+/// [Discord Docs/Message Snapshot Object]: https://discord.com/developers/docs/resources/message#message-snapshot-object
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MessageSnapshot {
+    /// Subset of the forwarded message's fields.
+    pub message: MessageSnapshotFields,
+}
+
+/// Fields of a forwarded message included in a [`MessageSnapshot`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MessageSnapshotFields {
+    /// List of attachments.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// List of provided components, such as buttons.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    /// Content of the message.
+    pub content: String,
+    /// When the message was last edited.
+    pub edited_timestamp: Option<Timestamp>,
+    /// List of embeds.
+    #[serde(default)]
+    pub embeds: Vec<Embed>,
+    /// Flags of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    /// Type of message.
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// [`Role`]s mentioned in the message.
+    ///
+    /// [`Role`]: crate::guild::Role
+    #[serde(default)]
+    pub mention_roles: Vec<Id<RoleMarker>>,
+    /// Users mentioned in the message.
+    #[serde(default)]
+    pub mentions: Vec<Mention>,
+    /// Stickers within the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sticker_items: Vec<MessageSticker>,
+    /// Timestamp of when the message was created.
+    pub timestamp: Timestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageSnapshot, MessageSnapshotFields};
+    use crate::{
+        channel::message::{MessageFlags, MessageType},
+        util::Timestamp,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(MessageSnapshot: message);
+    assert_fields!(
+        MessageSnapshotFields: attachments,
+        components,
+        content,
+        edited_timestamp,
+        embeds,
+        flags,
+        kind,
+        mention_roles,
+        mentions,
+        sticker_items,
+        timestamp
+    );
+    assert_impl_all!(
+        MessageSnapshot: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn message_snapshot() -> Result<(), Box<dyn Error>> {
+        let value = MessageSnapshot {
+            message: MessageSnapshotFields {
+                attachments: Vec::new(),
+                components: Vec::new(),
+                content: "forwarded".to_owned(),
+                edited_timestamp: None,
+                embeds: Vec::new(),
+                flags: Some(MessageFlags::empty()),
+                kind: MessageType::Regular,
+                mention_roles: Vec::new(),
+                mentions: Vec::new(),
+                sticker_items: Vec::new(),
+                timestamp: Timestamp::parse("2024-01-01T00:00:00.000000+00:00")?,
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessageSnapshot",
+                    len: 1,
+                },
+                Token::Str("message"),
+                Token::Struct {
+                    name: "MessageSnapshotFields",
+                    len: 9,
+                },
+                Token::Str("attachments"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("content"),
+                Token::Str("forwarded"),
+                Token::Str("edited_timestamp"),
+                Token::None,
+                Token::Str("embeds"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("flags"),
+                Token::Some,
+                Token::U64(0),
+                Token::Str("type"),
+                Token::U8(0),
+                Token::Str("mention_roles"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("mentions"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("timestamp"),
+                Token::Str("2024-01-01T00:00:00.000000+00:00"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Sticker`]: super::Sticker
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MessageSticker {
     /// Format type.
     pub format_type: StickerFormatType,
@@ -56,7 +57,9 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(StickerFormatType::Lottie.into()),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("sticker"),
@@ -25,6 +25,7 @@ use serde::{Deserialize, Serialize};
 
 /// Message sticker.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Sticker {
     /// Whether the sticker is available.
     #[serde(default, skip_serializing_if = "is_false")]
@@ -124,7 +125,9 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(StickerFormatType::Png.into()),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(1),
@@ -162,7 +165,7 @@ mod tests {
                 flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
                 global_name: Some("test".to_owned()),
                 id: Id::new(1),
-                locale: Some("en-us".to_owned()),
+                locale: Some("en-us".into()),
                 mfa_enabled: Some(true),
                 name: "test".to_owned(),
                 premium_type: Some(PremiumType::Nitro),
@@ -190,10 +193,14 @@ mod tests {
                 Token::U8(StickerFormatType::Png.into()),
                 Token::Str("guild_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(2),
@@ -201,7 +208,9 @@ mod tests {
                 Token::Str("stick"),
                 Token::Str("pack_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("sort_value"),
                 Token::Some,
@@ -237,7 +246,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("locale"),
                 Token::Some,
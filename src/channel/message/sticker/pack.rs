@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Standard`]: super::StickerType::Standard
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StickerPack {
     /// ID of the sticker pack's banner image.
     pub banner_asset_id: Option<Id<StickerBannerAssetMarker>>,
@@ -93,21 +94,29 @@ mod tests {
                 },
                 Token::Str("banner_asset_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("761773777976819732"),
                 Token::Str("cover_sticker_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("749053689419006003"),
                 Token::Str("description"),
                 Token::Str("Say hello to Wumpus!"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("847199849233514549"),
                 Token::Str("name"),
                 Token::Str("Wumpus Beyond"),
                 Token::Str("sku_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("847199849233514547"),
                 Token::Str("stickers"),
                 Token::Seq { len: Some(1) },
@@ -123,7 +132,9 @@ mod tests {
                 Token::Str("format_type"),
                 Token::U8(3),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("749054660769218631"),
                 Token::Str("type"),
                 Token::U8(1),
@@ -131,7 +142,9 @@ mod tests {
                 Token::Str("Wave"),
                 Token::Str("pack_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("847199849233514549"),
                 Token::Str("sort_value"),
                 Token::Some,
@@ -56,6 +56,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Channel]: https://discord.com/developers/docs/resources/channel
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Channel {
     /// ID of the channel.
     pub id: Id<ChannelMarker>,
@@ -7,6 +7,7 @@ use strum::EnumString;
 
 /// Permission overwrite data for a role or member.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PermissionOverwrite {
     pub allow: Permissions,
     pub deny: Permissions,
@@ -18,6 +19,7 @@ pub struct PermissionOverwrite {
 /// Type of a permission overwrite target.
 // Keep in sync with `twilight_util::permission_calculator::PermissionCalculator`!
 #[derive(Clone, Copy, Debug, Serialize, Eq, Hash, PartialEq, Deserialize, EnumString)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(try_from = "&str", into = "u8", rename_all = "snake_case")]
 pub enum PermissionOverwriteType {
@@ -102,7 +104,9 @@ mod tests {
                 Token::Str("deny"),
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("12345678"),
                 Token::Str("type"),
                 Token::U8(PermissionOverwriteType::Member.into()),
@@ -144,7 +148,9 @@ mod tests {
                 Token::Str("deny"),
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(1),
@@ -1,6 +1,7 @@
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum PrivacyLevel {
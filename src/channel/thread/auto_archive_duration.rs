@@ -67,6 +67,17 @@ impl Serialize for AutoArchiveDuration {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for AutoArchiveDuration {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "AutoArchiveDuration".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <u16 as schemars::JsonSchema>::json_schema(generator)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::AutoArchiveDuration;
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 /// Response body returned in thread listing methods.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ThreadsListing {
     /// Whether there are potentially more threads that could be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
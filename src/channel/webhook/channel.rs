@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 /// Partial channel object that a webhook is following.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WebhookChannel {
     pub id: Id<ChannelMarker>,
     pub name: String,
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// Partial guild object that a webhook is following.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WebhookGuild {
     pub icon: Option<ImageHash>,
     pub id: Id<GuildMarker>,
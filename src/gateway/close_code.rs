@@ -11,6 +11,7 @@ use std::{
 ///
 /// [Discord Docs/Gateway Close Event Codes]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#gateway-gateway-close-event-codes
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u16)]
 pub enum CloseCode {
@@ -104,6 +105,55 @@ impl CloseCode {
                 | Self::SessionTimedOut
         )
     }
+
+    /// Whether the session may be resumed after receiving the close code.
+    ///
+    /// Reconnectable close codes that invalidate the session, such as
+    /// [`InvalidSequence`] and [`SessionTimedOut`], require a new session to be
+    /// identified instead.
+    ///
+    /// [`InvalidSequence`]: Self::InvalidSequence
+    /// [`SessionTimedOut`]: Self::SessionTimedOut
+    pub const fn can_resume(self) -> bool {
+        matches!(
+            self,
+            Self::UnknownError
+                | Self::UnknownOpcode
+                | Self::DecodeError
+                | Self::AlreadyAuthenticated
+                | Self::RateLimited
+        )
+    }
+
+    /// Description of the configuration error causing a fatal close code.
+    ///
+    /// Returns [`None`] if the close code [allows reconnecting].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::gateway::CloseCode;
+    ///
+    /// assert!(CloseCode::DisallowedIntents.fatal_reason().is_some());
+    /// assert!(CloseCode::RateLimited.fatal_reason().is_none());
+    /// ```
+    ///
+    /// [allows reconnecting]: Self::can_reconnect
+    pub const fn fatal_reason(self) -> Option<&'static str> {
+        Some(match self {
+            Self::AuthenticationFailed => "the bot token is invalid",
+            Self::InvalidShard => "the shard ID is invalid for the total number of shards",
+            Self::ShardingRequired => {
+                "the bot is in too many guilds for its number of shards, increase the total"
+            }
+            Self::InvalidApiVersion => "the gateway API version is invalid or deprecated",
+            Self::InvalidIntents => "the intents are not a valid bitfield",
+            Self::DisallowedIntents => {
+                "privileged intents were requested that are not enabled or approved for the bot"
+            }
+            _ => return None,
+        })
+    }
 }
 
 impl Display for CloseCode {
@@ -133,7 +183,7 @@ pub struct CloseCodeConversionError {
 }
 
 impl CloseCodeConversionError {
-    const fn new(code: u16) -> Self {
+    pub(crate) const fn new(code: u16) -> Self {
         Self { code }
     }
 
@@ -200,30 +250,32 @@ mod tests {
     );
     assert_impl_all!(CloseCodeConversionError: Debug, Eq, PartialEq, Send, Sync);
 
-    const MAP: &[(CloseCode, u16, bool)] = &[
-        (CloseCode::UnknownError, 4000, true),
-        (CloseCode::UnknownOpcode, 4001, true),
-        (CloseCode::DecodeError, 4002, true),
-        (CloseCode::NotAuthenticated, 4003, true),
-        (CloseCode::AuthenticationFailed, 4004, false),
-        (CloseCode::AlreadyAuthenticated, 4005, true),
-        (CloseCode::InvalidSequence, 4007, true),
-        (CloseCode::RateLimited, 4008, true),
-        (CloseCode::SessionTimedOut, 4009, true),
-        (CloseCode::InvalidShard, 4010, false),
-        (CloseCode::ShardingRequired, 4011, false),
-        (CloseCode::InvalidApiVersion, 4012, false),
-        (CloseCode::InvalidIntents, 4013, false),
-        (CloseCode::DisallowedIntents, 4014, false),
+    const MAP: &[(CloseCode, u16, bool, bool)] = &[
+        (CloseCode::UnknownError, 4000, true, true),
+        (CloseCode::UnknownOpcode, 4001, true, true),
+        (CloseCode::DecodeError, 4002, true, true),
+        (CloseCode::NotAuthenticated, 4003, true, false),
+        (CloseCode::AuthenticationFailed, 4004, false, false),
+        (CloseCode::AlreadyAuthenticated, 4005, true, true),
+        (CloseCode::InvalidSequence, 4007, true, false),
+        (CloseCode::RateLimited, 4008, true, true),
+        (CloseCode::SessionTimedOut, 4009, true, false),
+        (CloseCode::InvalidShard, 4010, false, false),
+        (CloseCode::ShardingRequired, 4011, false, false),
+        (CloseCode::InvalidApiVersion, 4012, false, false),
+        (CloseCode::InvalidIntents, 4013, false, false),
+        (CloseCode::DisallowedIntents, 4014, false, false),
     ];
 
     #[test]
     fn variants() {
-        for (kind, num, can_reconnect) in MAP {
+        for (kind, num, can_reconnect, can_resume) in MAP {
             serde_test::assert_tokens(kind, &[Token::U16(*num)]);
             assert_eq!(*kind, CloseCode::try_from(*num).unwrap());
             assert_eq!(*num, *kind as u16);
-            assert!(kind.can_reconnect() == *can_reconnect)
+            assert!(kind.can_reconnect() == *can_reconnect);
+            assert!(kind.can_resume() == *can_resume);
+            assert!(kind.fatal_reason().is_some() != *can_reconnect);
         }
     }
 
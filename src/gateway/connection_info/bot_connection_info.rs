@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// Gateway information containing the recommended shard count and session
 /// availability.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BotConnectionInfo {
     /// Current session availability and session connection concurrency limits.
     pub session_start_limit: SessionStartLimit,
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// Gateway information containing the URL to connect to.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ConnectionInfo {
     /// URL to the gateway.
     pub url: String,
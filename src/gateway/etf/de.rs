@@ -0,0 +1,572 @@
+use super::{
+    EtfError, EtfErrorType, ATOM_EXT, ATOM_UTF8_EXT, BINARY_EXT, FLOAT_EXT, INTEGER_EXT,
+    LARGE_BIG_EXT, LARGE_TUPLE_EXT, LIST_EXT, MAP_EXT, NEW_FLOAT_EXT, NIL_EXT, SMALL_ATOM_EXT,
+    SMALL_ATOM_UTF8_EXT, SMALL_BIG_EXT, SMALL_INTEGER_EXT, SMALL_TUPLE_EXT, STRING_EXT, VERSION,
+};
+use crate::util::{ImageHash, Timestamp};
+use serde::de::{
+    value::{CowStrDeserializer, SeqDeserializer},
+    Deserialize, DeserializeSeed, Deserializer as DeserializerTrait, EnumAccess, Error as DeError,
+    MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use std::{
+    borrow::Cow,
+    fmt::{Formatter, Result as FmtResult},
+    str,
+};
+
+/// Maximum depth of nested lists, tuples and maps.
+const RECURSION_LIMIT: u8 = 128;
+
+/// Deserializer of an ETF payload.
+///
+/// Atoms and binaries are visited as strings, with the `nil`, `true` and
+/// `false` atoms visited as units and booleans. Latin-1 atoms are transcoded
+/// to UTF-8. Lists and tuples are visited as sequences and maps as maps.
+/// Ignored terms are skipped without being decoded.
+///
+/// When deserializing the [storage] format, tuples are the timestamps and
+/// image hashes written compactly by the storage [`Serializer`] and are
+/// visited as their strings.
+///
+/// # Errors
+///
+/// Deserialization returns an error of type [`EtfErrorType::Eof`] if the input
+/// ends before a term was completely read.
+///
+/// Returns an error of type [`EtfErrorType::IntegerOverflow`] if a big integer
+/// doesn't fit within a 64-bit integer.
+///
+/// Returns an error of type [`EtfErrorType::RecursionLimitExceeded`] if lists,
+/// tuples and maps are nested more than 128 levels deep.
+///
+/// Returns an error of type [`EtfErrorType::UnsupportedTag`] if a term isn't
+/// one that Discord sends.
+///
+/// [`Serializer`]: super::Serializer
+/// [storage]: crate::util::storage
+#[derive(Debug)]
+pub struct Deserializer<'de> {
+    input: &'de [u8],
+    /// Number of further levels of nesting allowed.
+    remaining_depth: u8,
+    /// Whether tuples are visited as compactly written strings.
+    storage: bool,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Create a deserializer over a payload, checking its version prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`EtfErrorType::UnsupportedVersion`] if the
+    /// payload isn't prefixed by the supported format version.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn from_slice(input: &'de [u8]) -> Result<Self, EtfError> {
+        match input.split_first() {
+            Some((&VERSION, input)) => Ok(Self {
+                input,
+                remaining_depth: RECURSION_LIMIT,
+                storage: false,
+            }),
+            Some((&version, _)) => Err(EtfError::new(EtfErrorType::UnsupportedVersion { version })),
+            None => Err(EtfError::new(EtfErrorType::Eof)),
+        }
+    }
+
+    /// Create a deserializer over a payload in the [storage] format, visiting
+    /// tuples as the timestamps and image hashes they were written from.
+    ///
+    /// [storage]: crate::util::storage
+    pub(crate) fn storage(input: &'de [u8]) -> Result<Self, EtfError> {
+        Ok(Self {
+            storage: true,
+            ..Self::from_slice(input)?
+        })
+    }
+
+    /// Ensure that the whole input has been consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`EtfErrorType::TrailingBytes`] if input
+    /// remains.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn end(&self) -> Result<(), EtfError> {
+        if self.input.is_empty() {
+            Ok(())
+        } else {
+            Err(EtfError::new(EtfErrorType::TrailingBytes))
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn take(&mut self, len: usize) -> Result<&'de [u8], EtfError> {
+        if self.input.len() < len {
+            return Err(EtfError::new(EtfErrorType::Eof));
+        }
+
+        let (taken, rest) = self.input.split_at(len);
+        self.input = rest;
+
+        Ok(taken)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], EtfError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8, EtfError> {
+        self.take_array::<1>().map(|[byte]| byte)
+    }
+
+    fn u16(&mut self) -> Result<u16, EtfError> {
+        self.take_array().map(u16::from_be_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, EtfError> {
+        self.take_array().map(u32::from_be_bytes)
+    }
+
+    fn len(&mut self, tag: u8) -> Result<usize, EtfError> {
+        let len = match tag {
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT | SMALL_BIG_EXT | SMALL_TUPLE_EXT => {
+                u32::from(self.u8()?)
+            }
+            ATOM_EXT | ATOM_UTF8_EXT | STRING_EXT => u32::from(self.u16()?),
+            _ => self.u32()?,
+        };
+
+        usize::try_from(len).map_err(|_| EtfError::new(EtfErrorType::LengthOverflow))
+    }
+
+    fn peek_tag(&self) -> Result<u8, EtfError> {
+        self.input
+            .first()
+            .copied()
+            .ok_or_else(|| EtfError::new(EtfErrorType::Eof))
+    }
+
+    /// Read a big integer, whose digits are stored in little endian order.
+    fn big<V: Visitor<'de>>(&mut self, tag: u8, visitor: V) -> Result<V::Value, EtfError> {
+        let len = self.len(tag)?;
+        let negative = self.u8()? != 0;
+        let digits = self.take(len)?;
+
+        if digits.iter().skip(8).any(|digit| *digit != 0) {
+            return Err(EtfError::new(EtfErrorType::IntegerOverflow));
+        }
+
+        let magnitude = digits
+            .iter()
+            .take(8)
+            .rev()
+            .fold(0_u64, |value, digit| (value << 8) | u64::from(*digit));
+
+        if !negative {
+            return visitor.visit_u64(magnitude);
+        }
+
+        let value = 0_i64
+            .checked_sub_unsigned(magnitude)
+            .ok_or_else(|| EtfError::new(EtfErrorType::IntegerOverflow))?;
+
+        visitor.visit_i64(value)
+    }
+
+    fn atom<V: Visitor<'de>>(&mut self, tag: u8, visitor: V) -> Result<V::Value, EtfError> {
+        let len = self.len(tag)?;
+        let bytes = self.take(len)?;
+
+        match bytes {
+            b"nil" => visitor.visit_unit(),
+            b"true" => visitor.visit_bool(true),
+            b"false" => visitor.visit_bool(false),
+            // Atoms without the UTF-8 tags are Latin-1, which is only the same
+            // as UTF-8 for ASCII.
+            bytes if matches!(tag, ATOM_EXT | SMALL_ATOM_EXT) && !bytes.is_ascii() => {
+                visitor.visit_string(bytes.iter().copied().map(char::from).collect())
+            }
+            bytes => Self::visit_bytes_as_str(bytes, visitor),
+        }
+    }
+
+    /// Read a timestamp written as `{Micros}` or an image hash written as
+    /// `{Bytes, Animated}` by the storage serializer, visiting its string.
+    fn compacted_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value, EtfError> {
+        match self.len(SMALL_TUPLE_EXT)? {
+            1 => {
+                let micros = i64::deserialize(&mut *self)?;
+                let timestamp = Timestamp::from_micros(micros).map_err(EtfError::custom)?;
+
+                visitor.visit_string(timestamp.iso_8601().to_string())
+            }
+            2 => {
+                let bytes = <&[u8]>::deserialize(&mut *self)?;
+                let bytes = bytes
+                    .try_into()
+                    .map_err(|_| EtfError::invalid_length(bytes.len(), &"16 image hash bytes"))?;
+                let animated = bool::deserialize(&mut *self)?;
+
+                visitor.visit_string(ImageHash::new(bytes, animated).to_string())
+            }
+            len => Err(EtfError::invalid_length(
+                len,
+                &"a compacted timestamp or image hash",
+            )),
+        }
+    }
+
+    fn visit_bytes_as_str<V: Visitor<'de>>(
+        bytes: &'de [u8],
+        visitor: V,
+    ) -> Result<V::Value, EtfError> {
+        match str::from_utf8(bytes) {
+            Ok(value) => visitor.visit_borrowed_str(value),
+            Err(_) => visitor.visit_borrowed_bytes(bytes),
+        }
+    }
+
+    /// Skip the next term without decoding it.
+    ///
+    /// Nested terms are counted rather than recursed into, so skipping isn't
+    /// subject to the recursion limit.
+    fn skip(&mut self) -> Result<(), EtfError> {
+        let mut remaining = 1_usize;
+
+        while remaining > 0 {
+            remaining -= 1;
+
+            let tag = self.u8()?;
+            let nested = match tag {
+                SMALL_INTEGER_EXT => self.take(1).map(|_| 0)?,
+                INTEGER_EXT => self.take(4).map(|_| 0)?,
+                NEW_FLOAT_EXT => self.take(8).map(|_| 0)?,
+                FLOAT_EXT => self.take(31).map(|_| 0)?,
+                SMALL_BIG_EXT | LARGE_BIG_EXT => {
+                    let len = self.len(tag)?;
+
+                    // The sign precedes the digits.
+                    self.take(1)?;
+                    self.take(len).map(|_| 0)?
+                }
+                ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT | BINARY_EXT
+                | STRING_EXT => {
+                    let len = self.len(tag)?;
+
+                    self.take(len).map(|_| 0)?
+                }
+                NIL_EXT => 0,
+                // Lists are followed by their tail.
+                LIST_EXT => self.len(tag)?.checked_add(1).ok_or_else(Self::overflow)?,
+                SMALL_TUPLE_EXT | LARGE_TUPLE_EXT => self.len(tag)?,
+                MAP_EXT => self.len(tag)?.checked_mul(2).ok_or_else(Self::overflow)?,
+                tag => return Err(EtfError::new(EtfErrorType::UnsupportedTag { tag })),
+            };
+
+            remaining = remaining.checked_add(nested).ok_or_else(Self::overflow)?;
+        }
+
+        Ok(())
+    }
+
+    const fn overflow() -> EtfError {
+        EtfError::new(EtfErrorType::LengthOverflow)
+    }
+
+    /// Visit a nested list, tuple or map, enforcing the recursion limit.
+    fn nested<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, EtfError>,
+    ) -> Result<T, EtfError> {
+        self.remaining_depth = self
+            .remaining_depth
+            .checked_sub(1)
+            .ok_or_else(|| EtfError::new(EtfErrorType::RecursionLimitExceeded))?;
+
+        let value = f(self);
+        self.remaining_depth += 1;
+
+        value
+    }
+
+    /// Consume the next term if it is the `nil` atom.
+    fn consume_nil(&mut self) -> bool {
+        let mut lookahead = Self { ..*self };
+
+        let is_nil = match lookahead.u8() {
+            Ok(tag @ (ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT)) => {
+                matches!(
+                    lookahead.len(tag).and_then(|len| lookahead.take(len)),
+                    Ok(b"nil")
+                )
+            }
+            _ => false,
+        };
+
+        if is_nil {
+            self.input = lookahead.input;
+        }
+
+        is_nil
+    }
+
+    fn list<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value, EtfError> {
+        let len = self.len(LIST_EXT)?;
+        let value = visitor.visit_seq(Access::new(self, len))?;
+
+        // Proper lists end with an empty list as their tail.
+        if self.u8()? != NIL_EXT {
+            return Err(EtfError::new(EtfErrorType::ImproperList));
+        }
+
+        Ok(value)
+    }
+}
+
+impl<'de> DeserializerTrait<'de> for &mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let tag = self.u8()?;
+
+        match tag {
+            SMALL_INTEGER_EXT => visitor.visit_u8(self.u8()?),
+            INTEGER_EXT => {
+                let value = self.take_array().map(i32::from_be_bytes)?;
+
+                // Visit non-negative integers as unsigned, as is done for JSON.
+                match u32::try_from(value) {
+                    Ok(value) => visitor.visit_u32(value),
+                    Err(_) => visitor.visit_i32(value),
+                }
+            }
+            SMALL_BIG_EXT | LARGE_BIG_EXT => self.big(tag, visitor),
+            NEW_FLOAT_EXT => visitor.visit_f64(self.take_array().map(f64::from_be_bytes)?),
+            FLOAT_EXT => {
+                let bytes = self.take(31)?;
+                let text = str::from_utf8(bytes)
+                    .map_err(|_| EtfError::new(EtfErrorType::InvalidUtf8))?
+                    .trim_end_matches('\0');
+                let value = text.parse().map_err(EtfError::custom)?;
+
+                visitor.visit_f64(value)
+            }
+            ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                self.atom(tag, visitor)
+            }
+            BINARY_EXT => {
+                let len = self.len(tag)?;
+                let bytes = self.take(len)?;
+
+                Deserializer::visit_bytes_as_str(bytes, visitor)
+            }
+            NIL_EXT => visitor.visit_seq(Access::new(self, 0)),
+            STRING_EXT => {
+                // Lists of small integers are encoded as a string of bytes.
+                let len = self.len(tag)?;
+                let bytes = self.take(len)?;
+
+                visitor.visit_seq(SeqDeserializer::new(bytes.iter().copied()))
+            }
+            LIST_EXT => self.nested(|de| de.list(visitor)),
+            SMALL_TUPLE_EXT if self.storage => self.nested(|de| de.compacted_str(visitor)),
+            SMALL_TUPLE_EXT | LARGE_TUPLE_EXT => {
+                let len = self.len(tag)?;
+
+                self.nested(|de| visitor.visit_seq(Access::new(de, len)))
+            }
+            MAP_EXT => {
+                let len = self.len(tag)?;
+
+                self.nested(|de| visitor.visit_map(Access::new(de, len)))
+            }
+            tag => Err(EtfError::new(EtfErrorType::UnsupportedTag { tag })),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.consume_nil() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.peek_tag()? == MAP_EXT {
+            self.u8()?;
+
+            if self.len(MAP_EXT)? != 1 {
+                return Err(EtfError::invalid_length(0, &"map with a single key"));
+            }
+
+            return self.nested(|de| visitor.visit_enum(Enum { de }));
+        }
+
+        let variant = self.deserialize_any(StrVisitor)?;
+
+        visitor.visit_enum(CowStrDeserializer::<EtfError>::new(variant))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.skip()?;
+
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+/// Visitor of strings, used for unit enum variants.
+///
+/// Strings are borrowed from the input unless they are transcoded Latin-1
+/// atoms.
+struct StrVisitor;
+
+impl<'de> Visitor<'de> for StrVisitor {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("an atom or binary")
+    }
+
+    fn visit_borrowed_str<E: DeError>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(v))
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(v.to_owned()))
+    }
+
+    fn visit_string<E: DeError>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(v))
+    }
+}
+
+/// Access to the elements of a list, tuple or map.
+struct Access<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'a, 'de> Access<'a, 'de> {
+    const fn new(de: &'a mut Deserializer<'de>, remaining: usize) -> Self {
+        Self { de, remaining }
+    }
+}
+
+impl<'de> SeqAccess<'de> for Access<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> MapAccess<'de> for Access<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Access to an enum variant encoded as a map with a single key.
+struct Enum<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+}
+
+impl<'de> EnumAccess<'de> for Enum<'_, 'de> {
+    type Error = EtfError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(&mut *self.de)?;
+
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Enum<'_, 'de> {
+    type Error = EtfError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Deserialize::deserialize(self.de)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.de.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.de.deserialize_any(visitor)
+    }
+}
@@ -0,0 +1,133 @@
+use serde::{de::Error as DeError, ser::Error as SerError};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Encoding or decoding an Erlang External Term Format payload failed.
+#[derive(Debug)]
+pub struct EtfError {
+    /// Type of error that occurred.
+    kind: EtfErrorType,
+    /// Source of the error, if there is any.
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl EtfError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &EtfErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (EtfErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    pub(super) const fn new(kind: EtfErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EtfErrorType::Eof => f.write_str("input ended unexpectedly"),
+            EtfErrorType::ImproperList => f.write_str("list has a tail other than nil"),
+            EtfErrorType::IntegerOverflow => {
+                f.write_str("integer doesn't fit within a 64-bit integer")
+            }
+            EtfErrorType::InvalidUtf8 => f.write_str("string isn't valid UTF-8"),
+            EtfErrorType::LengthOverflow => f.write_str("length doesn't fit within 32 bits"),
+            EtfErrorType::RecursionLimitExceeded => f.write_str("terms are nested too deeply"),
+            EtfErrorType::Serde => {
+                if let Some(source) = &self.source {
+                    Display::fmt(source, f)
+                } else {
+                    f.write_str("(de)serialization failed")
+                }
+            }
+            EtfErrorType::TrailingBytes => f.write_str("input has trailing bytes"),
+            EtfErrorType::UnsupportedTag { tag } => {
+                f.write_str("term tag ")?;
+                Display::fmt(tag, f)?;
+
+                f.write_str(" is unsupported")
+            }
+            EtfErrorType::UnsupportedVersion { version } => {
+                f.write_str("format version ")?;
+                Display::fmt(version, f)?;
+
+                f.write_str(" is unsupported")
+            }
+        }
+    }
+}
+
+impl Error for EtfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+impl DeError for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            kind: EtfErrorType::Serde,
+            source: Some(msg.to_string().into()),
+        }
+    }
+}
+
+impl SerError for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        <Self as DeError>::custom(msg)
+    }
+}
+
+/// Type of [`EtfError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EtfErrorType {
+    /// Input ended before a term was completely read.
+    Eof,
+    /// List has a tail other than an empty list.
+    ImproperList,
+    /// Integer doesn't fit within a 64-bit integer.
+    IntegerOverflow,
+    /// Binary or atom isn't valid UTF-8 where a string was expected.
+    InvalidUtf8,
+    /// Length of a sequence, map or string doesn't fit within 32 bits.
+    LengthOverflow,
+    /// Lists, tuples and maps are nested more than 128 levels deep.
+    RecursionLimitExceeded,
+    /// Model's (de)serialization implementation returned an error.
+    ///
+    /// The error's source contains the message.
+    Serde,
+    /// Input has bytes remaining after the term.
+    TrailingBytes,
+    /// Term tag isn't supported.
+    ///
+    /// Compressed terms, references, ports, PIDs and functions are not
+    /// supported since Discord doesn't send them.
+    UnsupportedTag {
+        /// Tag of the term.
+        tag: u8,
+    },
+    /// Format version isn't supported.
+    UnsupportedVersion {
+        /// Provided version.
+        version: u8,
+    },
+}
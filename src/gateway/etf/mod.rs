@@ -0,0 +1,340 @@
+//! Erlang External Term Format (ETF) encoding of gateway payloads.
+//!
+//! The gateway may be connected to with `encoding=etf`, in which case payloads
+//! are sent and must be received in the [External Term Format] instead of
+//! JSON. ETF payloads are smaller and faster to parse than their JSON
+//! equivalents.
+//!
+//! Models serialize as they do with JSON: maps are keyed by binaries, IDs are
+//! binaries containing the decimal ID, and `null`, `true` and `false` are the
+//! `nil`, `true` and `false` atoms. When deserializing, atoms and binaries are
+//! both accepted as strings and integer IDs are accepted wherever an ID is
+//! expected, which is how Discord sends them.
+//!
+//! # Examples
+//!
+//! Deserialize a gateway event from an ETF payload:
+//!
+//! ```
+//! use serde::de::DeserializeSeed;
+//! use twilight_model::gateway::{
+//!     etf,
+//!     event::{GatewayEvent, GatewayEventDeserializer},
+//!     payload::outgoing::Heartbeat,
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // A heartbeat payload is the same as a heartbeat request sent by Discord.
+//! let payload = etf::to_vec(&Heartbeat::new(Some(5)))?;
+//!
+//! let gateway_deserializer = GatewayEventDeserializer::from_etf(&payload).unwrap();
+//! let mut etf_deserializer = etf::Deserializer::from_slice(&payload)?;
+//! let event = gateway_deserializer.deserialize(&mut etf_deserializer)?;
+//!
+//! assert!(matches!(event, GatewayEvent::Heartbeat(5)));
+//! # Ok(()) }
+//! ```
+//!
+//! [External Term Format]: https://www.erlang.org/doc/apps/erts/erl_ext_dist.html
+
+mod de;
+mod error;
+mod ser;
+
+pub use self::{
+    de::Deserializer,
+    error::{EtfError, EtfErrorType},
+    ser::Serializer,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Version of the format, prefixing every payload.
+const VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const FLOAT_EXT: u8 = 99;
+const ATOM_EXT: u8 = 100;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+/// Serialize a value into an ETF payload.
+///
+/// # Errors
+///
+/// Returns an error of type [`EtfErrorType::Serde`] if the value's
+/// serialization implementation fails.
+///
+/// Returns an error of type [`EtfErrorType::LengthOverflow`] if a sequence,
+/// map or string is longer than [`u32::MAX`].
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EtfError> {
+    let mut serializer = Serializer::new();
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.into_inner())
+}
+
+/// Deserialize a value from an ETF payload.
+///
+/// # Errors
+///
+/// Returns an error of type [`EtfErrorType::TrailingBytes`] if the input
+/// contains more than a single term.
+///
+/// Refer to [`Deserializer`] for other errors that may occur.
+pub fn from_slice<'a, T: Deserialize<'a>>(input: &'a [u8]) -> Result<T, EtfError> {
+    let mut deserializer = Deserializer::from_slice(input)?;
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_slice, to_vec, EtfErrorType};
+    use crate::{
+        gateway::{
+            event::{DispatchEvent, DispatchEventWithTypeDeserializer, GatewayEvent},
+            payload::{
+                incoming::{Hello, RoleDelete},
+                outgoing::{
+                    identify::{IdentifyInfo, IdentifyProperties},
+                    Identify, RequestGuildMembers,
+                },
+            },
+            presence::Status,
+            Intents, ShardId,
+        },
+        id::{
+            marker::{GuildMarker, RoleMarker},
+            Id,
+        },
+    };
+    use serde::de::DeserializeSeed;
+    use std::collections::HashMap;
+
+    /// Role delete event as sent by Discord, with atom keys and integer IDs.
+    const ROLE_DELETE: &[u8] = &[
+        131, 116, 0, 0, 0, 2, // map with 2 pairs
+        100, 0, 8, b'g', b'u', b'i', b'l', b'd', b'_', b'i', b'd', // atom guild_id
+        110, 8, 0, 0, 0, 0, 0, 0, 0, 0, 1, // small big 2^56
+        119, 7, b'r', b'o', b'l', b'e', b'_', b'i', b'd', // utf8 atom role_id
+        97, 2, // small integer 2
+    ];
+
+    #[test]
+    fn integer_snowflakes() {
+        let value: RoleDelete = from_slice(ROLE_DELETE).unwrap();
+
+        assert_eq!(
+            RoleDelete {
+                guild_id: Id::new(1 << 56),
+                role_id: Id::new(2),
+            },
+            value
+        );
+
+        let mut deserializer = super::Deserializer::from_slice(ROLE_DELETE).unwrap();
+        let event = DispatchEventWithTypeDeserializer::new("GUILD_ROLE_DELETE")
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert!(matches!(event, DispatchEvent::GuildRoleDelete(_)));
+    }
+
+    #[test]
+    fn atoms() {
+        let values: Vec<Option<bool>> = from_slice(&[
+            131, 108, 0, 0, 0, 3, 115, 3, b'n', b'i', b'l', 115, 4, b't', b'r', b'u', b'e', 115, 5,
+            b'f', b'a', b'l', b's', b'e', 106,
+        ])
+        .unwrap();
+
+        assert_eq!(vec![None, Some(true), Some(false)], values);
+        assert_eq!(
+            vec![131, 119, 3, b'n', b'i', b'l'],
+            to_vec(&None::<u8>).unwrap()
+        );
+        assert_eq!(
+            vec![131, 119, 4, b't', b'r', b'u', b'e'],
+            to_vec(&true).unwrap()
+        );
+    }
+
+    #[test]
+    fn latin1_atoms() {
+        let value: String = from_slice(&[131, 115, 3, b'c', 0xE9, b'u']).unwrap();
+        assert_eq!("c\u{e9}u", value);
+
+        let value: String = from_slice(&[131, 100, 0, 2, b'o', b'k']).unwrap();
+        assert_eq!("ok", value);
+
+        let value: Vec<Status> =
+            from_slice(&[131, 108, 0, 0, 0, 1, 115, 3, b'd', b'n', b'd', 106]).unwrap();
+        assert_eq!(vec![Status::DoNotDisturb], value);
+    }
+
+    #[test]
+    fn recursion_limit() {
+        const DEPTH: usize = 200;
+
+        // Map of an opcode and event data of deeply nested lists.
+        let mut payload = vec![
+            131, 116, 0, 0, 0, 2, 119, 2, b'o', b'p', 97, 1, 119, 1, b'd',
+        ];
+        payload.extend([108, 0, 0, 0, 1].repeat(DEPTH));
+        payload.extend([106].repeat(DEPTH + 1));
+
+        assert!(matches!(
+            from_slice::<serde_json::Value>(&payload)
+                .unwrap_err()
+                .kind(),
+            EtfErrorType::RecursionLimitExceeded
+        ));
+
+        // The event data is skipped rather than decoded when reading the
+        // header.
+        let deserializer =
+            super::super::event::GatewayEventDeserializer::from_etf(&payload).unwrap();
+        assert_eq!(1, deserializer.op());
+
+        let shallow = [131, 108, 0, 0, 0, 1, 108, 0, 0, 0, 1, 106, 106, 106];
+        assert!(from_slice::<serde_json::Value>(&shallow).is_ok());
+    }
+
+    #[test]
+    fn integers() {
+        for value in [0, 255, 256, -1, i64::from(i32::MAX) + 1, i64::MIN] {
+            assert_eq!(value, from_slice::<i64>(&to_vec(&value).unwrap()).unwrap());
+        }
+
+        for value in [u64::from(u32::MAX), u64::MAX] {
+            assert_eq!(value, from_slice::<u64>(&to_vec(&value).unwrap()).unwrap());
+        }
+
+        assert_eq!(vec![131, 97, 7], to_vec(&7_u8).unwrap());
+        assert_eq!(vec![131, 98, 255, 255, 255, 255], to_vec(&-1_i8).unwrap());
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            from_slice::<u8>(&[130, 97, 1]).unwrap_err().kind(),
+            EtfErrorType::UnsupportedVersion { version: 130 }
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 97, 1, 0]).unwrap_err().kind(),
+            EtfErrorType::TrailingBytes
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 98, 0]).unwrap_err().kind(),
+            EtfErrorType::Eof
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[131, 80, 0, 0, 0, 0]).unwrap_err().kind(),
+            EtfErrorType::UnsupportedTag { tag: 80 }
+        ));
+        assert!(matches!(
+            from_slice::<u64>(&[131, 110, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+                .unwrap_err()
+                .kind(),
+            EtfErrorType::IntegerOverflow
+        ));
+    }
+
+    #[test]
+    fn gateway_event_round_trip() {
+        let event = GatewayEvent::Hello(Hello {
+            heartbeat_interval: 41_250,
+        });
+        let payload = to_vec(&event).unwrap();
+
+        let deserializer =
+            super::super::event::GatewayEventDeserializer::from_etf(&payload).unwrap();
+        assert_eq!(10, deserializer.op());
+        assert_eq!(None, deserializer.event_type());
+
+        let mut etf = super::Deserializer::from_slice(&payload).unwrap();
+        let value = deserializer.deserialize(&mut etf).unwrap();
+        assert!(matches!(
+            value,
+            GatewayEvent::Hello(Hello {
+                heartbeat_interval: 41_250
+            })
+        ));
+    }
+
+    #[test]
+    fn dispatch_round_trip() {
+        let event = GatewayEvent::Dispatch(
+            3,
+            DispatchEvent::GuildRoleDelete(RoleDelete {
+                guild_id: Id::new(1),
+                role_id: Id::new(2),
+            }),
+        );
+        let payload = to_vec(&event).unwrap();
+
+        let deserializer =
+            super::super::event::GatewayEventDeserializer::from_etf(&payload).unwrap();
+        assert_eq!(0, deserializer.op());
+        assert_eq!(Some(3), deserializer.sequence());
+        assert_eq!(Some("GUILD_ROLE_DELETE"), deserializer.event_type());
+
+        let mut etf = super::Deserializer::from_slice(&payload).unwrap();
+        let value = deserializer.deserialize(&mut etf).unwrap();
+        assert!(matches!(
+            value,
+            GatewayEvent::Dispatch(3, DispatchEvent::GuildRoleDelete(RoleDelete { guild_id, role_id }))
+                if guild_id.get() == 1 && role_id.get() == 2
+        ));
+    }
+
+    #[test]
+    fn outgoing_round_trip() {
+        let identify = Identify::new(IdentifyInfo {
+            compress: false,
+            intents: Intents::GUILDS | Intents::GUILD_MESSAGES,
+            large_threshold: 250,
+            presence: None,
+            properties: IdentifyProperties::new("twilight", "twilight", "linux"),
+            shard: Some(ShardId::new(1, 2)),
+            token: "token".to_owned(),
+        });
+        let value: Identify = from_slice(&to_vec(&identify).unwrap()).unwrap();
+        assert_eq!(identify, value);
+
+        let request = RequestGuildMembers::builder(Id::<GuildMarker>::new(1))
+            .presences(true)
+            .query("twi", None);
+        let value: RequestGuildMembers = from_slice(&to_vec(&request).unwrap()).unwrap();
+        assert_eq!(request, value);
+    }
+
+    #[test]
+    fn collections() {
+        let mut map = HashMap::new();
+        map.insert(Id::<RoleMarker>::new(5), vec![Status::Online, Status::Idle]);
+        map.insert(Id::new(6), Vec::new());
+
+        let value: HashMap<Id<RoleMarker>, Vec<Status>> =
+            from_slice(&to_vec(&map).unwrap()).unwrap();
+        assert_eq!(map, value);
+
+        let tuple = (1.5_f64, "text".to_owned(), ());
+        let value: (f64, String, ()) = from_slice(&to_vec(&tuple).unwrap()).unwrap();
+        assert_eq!(tuple, value);
+    }
+}
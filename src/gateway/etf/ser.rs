@@ -0,0 +1,541 @@
+use super::{
+    EtfError, EtfErrorType, BINARY_EXT, INTEGER_EXT, LIST_EXT, MAP_EXT, NEW_FLOAT_EXT, NIL_EXT,
+    SMALL_ATOM_UTF8_EXT, SMALL_BIG_EXT, SMALL_INTEGER_EXT, SMALL_TUPLE_EXT, VERSION,
+};
+use crate::{
+    id,
+    util::{ImageHash, Timestamp},
+};
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use std::{fmt::Display, str};
+
+/// Serializer into an ETF payload.
+///
+/// Strings, including map keys and unit enum variants, are serialized as
+/// binaries, or as atoms when short and serialized for [storage], in which
+/// case IDs are also serialized as integers, timestamps as a tuple of their
+/// Unix timestamp in microseconds and image hashes as a tuple of their bytes
+/// and whether they are animated. Units and `None` are serialized
+/// as the `nil` atom and booleans as the `true` and `false` atoms. Sequences
+/// and tuples are serialized as lists and maps and structs as maps.
+///
+/// [storage]: crate::util::storage
+#[derive(Debug)]
+pub struct Serializer {
+    /// Whether short strings are serialized as atoms and IDs, timestamps and
+    /// image hashes compactly.
+    compact: bool,
+    output: Vec<u8>,
+}
+
+impl Serializer {
+    /// Create a new serializer, writing the format version.
+    pub fn new() -> Self {
+        Self {
+            compact: false,
+            output: vec![VERSION],
+        }
+    }
+
+    /// Create a serializer writing short strings as atoms, which are smaller
+    /// than binaries, IDs as integers instead of their decimal strings, and
+    /// timestamps and image hashes as tuples instead of their ISO 8601 and hex
+    /// strings.
+    ///
+    /// Other tuples are serialized as lists, so tuples are only ever
+    /// timestamps and image hashes, which the storage [`Deserializer`] visits
+    /// as their strings again.
+    ///
+    /// Used by the [storage] format.
+    ///
+    /// [storage]: crate::util::storage
+    pub(crate) fn compact() -> Self {
+        Self {
+            compact: true,
+            ..Self::new()
+        }
+    }
+
+    /// Consume the serializer, returning the payload.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.output
+    }
+
+    // Atoms written by the serializer are always shorter than 256 bytes.
+    #[allow(clippy::cast_possible_truncation)]
+    fn atom(&mut self, name: &str) {
+        self.output.push(SMALL_ATOM_UTF8_EXT);
+        self.output.extend_from_slice(&[name.len() as u8]);
+        self.output.extend_from_slice(name.as_bytes());
+    }
+
+    /// ID written as a decimal atom since a position in the output.
+    fn written_id(&self, position: usize) -> Option<u64> {
+        let (tag, rest) = self.output.get(position..)?.split_first()?;
+        let (len, digits) = rest.split_first()?;
+
+        if *tag != SMALL_ATOM_UTF8_EXT || usize::from(*len) != digits.len() {
+            return None;
+        }
+
+        // Only canonical decimals are IDs, others wouldn't be written back
+        // identically.
+        match digits {
+            [b'0'] => Some(0),
+            [b'1'..=b'9', ..] => str::from_utf8(digits).ok()?.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Timestamp or image hash serialized as a string, written compactly if
+    /// the string is written back identically.
+    ///
+    /// Timestamps are written as `{Micros}` and image hashes as
+    /// `{Bytes, Animated}`.
+    fn compact_str(&mut self, value: &str) -> Result<bool, EtfError> {
+        if let Ok(timestamp) = Timestamp::parse(value) {
+            if timestamp.iso_8601().to_string() == value {
+                self.output.extend_from_slice(&[SMALL_TUPLE_EXT, 1]);
+                self.integer(timestamp.as_micros());
+
+                return Ok(true);
+            }
+        }
+
+        if let Ok(hash) = ImageHash::parse(value.as_bytes()) {
+            if hash.to_string() == value {
+                self.output.extend_from_slice(&[SMALL_TUPLE_EXT, 2]);
+                self.binary(&hash.bytes())?;
+                self.atom(if hash.is_animated() { "true" } else { "false" });
+
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn binary(&mut self, value: &[u8]) -> Result<(), EtfError> {
+        self.output.push(BINARY_EXT);
+        self.output
+            .extend_from_slice(&length(value.len())?.to_be_bytes());
+        self.output.extend_from_slice(value);
+
+        Ok(())
+    }
+
+    fn integer(&mut self, value: i64) {
+        if let Ok(value) = u8::try_from(value) {
+            self.output.extend_from_slice(&[SMALL_INTEGER_EXT, value]);
+        } else if let Ok(value) = i32::try_from(value) {
+            self.output.push(INTEGER_EXT);
+            self.output.extend_from_slice(&value.to_be_bytes());
+        } else {
+            self.big(value < 0, value.unsigned_abs());
+        }
+    }
+
+    // The length is at most 8 bytes.
+    #[allow(clippy::cast_possible_truncation)]
+    fn big(&mut self, negative: bool, magnitude: u64) {
+        let digits = magnitude.to_le_bytes();
+        let len = digits
+            .iter()
+            .rposition(|digit| *digit != 0)
+            .map_or(0, |i| i + 1);
+
+        self.output
+            .extend_from_slice(&[SMALL_BIG_EXT, len as u8, u8::from(negative)]);
+        self.output.extend_from_slice(&digits[..len]);
+    }
+
+    /// Start a list or map whose length is written when it ends.
+    fn start(&mut self, tag: u8) -> Compound<'_> {
+        self.output.push(tag);
+        let position = self.output.len();
+        self.output.extend_from_slice(&[0; 4]);
+
+        Compound {
+            len: 0,
+            position,
+            ser: self,
+            tag,
+        }
+    }
+}
+
+impl Default for Serializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn length(len: usize) -> Result<u32, EtfError> {
+    u32::try_from(len).map_err(|_| EtfError::new(EtfErrorType::LengthOverflow))
+}
+
+impl<'a> serde::ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = EtfError;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.atom(if v { "true" } else { "false" });
+
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.integer(v);
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        if let Ok(v) = i64::try_from(v) {
+            self.integer(v);
+        } else {
+            self.big(false, v);
+        }
+
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.output.push(NEW_FLOAT_EXT);
+        self.output.extend_from_slice(&v.to_be_bytes());
+
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        // Short strings are more compactly stored as atoms, as long as they
+        // aren't read back as a unit or boolean.
+        if self.compact && u8::try_from(v.len()).is_ok() && !matches!(v, "nil" | "true" | "false") {
+            self.atom(v);
+
+            return Ok(());
+        }
+
+        self.binary(v.as_bytes())
+    }
+
+    // Timestamps and image hashes serialize via their display
+    // implementations, unlike other strings.
+    fn collect_str<T: Display + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        let value = value.to_string();
+
+        if self.compact && self.compact_str(&value)? {
+            return Ok(());
+        }
+
+        self.serialize_str(&value)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.binary(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.atom("nil");
+
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let position = self.output.len();
+        value.serialize(&mut *self)?;
+
+        // IDs serialize as their decimal string, which is stored as the
+        // integer instead.
+        if self.compact && matches!(name, id::NEWTYPE_NAME | id::ANONYMIZABLE_NEWTYPE_NAME) {
+            if let Some(id) = self.written_id(position) {
+                self.output.truncate(position);
+
+                return self.serialize_u64(id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut map = self.start(MAP_EXT);
+        map.serialize_entry(variant, value)?;
+
+        SerializeMap::end(map)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        // Encoded as a map with a single key, whose list is written afterwards.
+        self.output.extend_from_slice(&[MAP_EXT, 0, 0, 0, 1]);
+        self.serialize_str(variant)?;
+
+        Ok(self.start(LIST_EXT))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(self.start(MAP_EXT))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self.start(MAP_EXT))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.output.extend_from_slice(&[MAP_EXT, 0, 0, 0, 1]);
+        self.serialize_str(variant)?;
+
+        Ok(self.start(MAP_EXT))
+    }
+}
+
+/// Serializer of a list or map, counting its elements to write the length
+/// once it ends.
+#[derive(Debug)]
+pub struct Compound<'a> {
+    len: usize,
+    position: usize,
+    ser: &'a mut Serializer,
+    tag: u8,
+}
+
+impl Compound<'_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.len += 1;
+
+        value.serialize(&mut *self.ser)
+    }
+
+    fn finish(self) -> Result<(), EtfError> {
+        if self.tag == LIST_EXT && self.len == 0 {
+            // Empty lists are encoded as nil instead.
+            self.ser.output.truncate(self.position - 1);
+            self.ser.output.push(NIL_EXT);
+
+            return Ok(());
+        }
+
+        let len = length(self.len)?.to_be_bytes();
+        self.ser.output[self.position..self.position + 4].copy_from_slice(&len);
+
+        if self.tag == LIST_EXT {
+            self.ser.output.push(NIL_EXT);
+        }
+
+        Ok(())
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
@@ -16,6 +16,7 @@ use serde::{
 // **NOTE**: When adding a variant, be sure to add it to the DeserializeSeed
 // implementation.
 #[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum DispatchEvent {
     Ready(Box<Ready>),
@@ -29,6 +30,9 @@ pub enum DispatchEvent {
     ChannelUpdate(Box<ChannelUpdate>),
     ChannelDelete(Box<ChannelDelete>),
     ChannelPinsUpdate(ChannelPinsUpdate),
+    EntitlementCreate(Box<EntitlementCreate>),
+    EntitlementUpdate(Box<EntitlementUpdate>),
+    EntitlementDelete(Box<EntitlementDelete>),
     ThreadCreate(Box<ThreadCreate>),
     ThreadUpdate(Box<ThreadUpdate>),
     ThreadDelete(ThreadDelete),
@@ -56,6 +60,10 @@ pub enum DispatchEvent {
     GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
     GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
     GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+    GuildSoundboardSoundCreate(Box<GuildSoundboardSoundCreate>),
+    GuildSoundboardSoundUpdate(Box<GuildSoundboardSoundUpdate>),
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDelete),
+    GuildSoundboardSoundsUpdate(GuildSoundboardSoundsUpdate),
     IntegrationCreate(Box<IntegrationCreate>),
     IntegrationUpdate(Box<IntegrationUpdate>),
     IntegrationDelete(IntegrationDelete),
@@ -66,16 +74,20 @@ pub enum DispatchEvent {
     MessageUpdate(Box<MessageUpdate>),
     MessageDelete(MessageDelete),
     MessageDeleteBulk(MessageDeleteBulk),
+    MessagePollVoteAdd(MessagePollVoteAdd),
+    MessagePollVoteRemove(MessagePollVoteRemove),
     ReactionAdd(Box<ReactionAdd>),
     ReactionRemove(Box<ReactionRemove>),
     ReactionRemoveAll(ReactionRemoveAll),
     ReactionRemoveEmoji(ReactionRemoveEmoji),
     PresenceUpdate(Box<PresenceUpdate>),
+    SoundboardSounds(SoundboardSounds),
     StageInstanceCreate(StageInstanceCreate),
     StageInstanceUpdate(StageInstanceUpdate),
     StageInstanceDelete(StageInstanceDelete),
     TypingStart(Box<TypingStart>),
     UserUpdate(UserUpdate),
+    VoiceChannelEffectSend(VoiceChannelEffectSend),
     VoiceStateUpdate(Box<VoiceStateUpdate>),
     VoiceServerUpdate(VoiceServerUpdate),
     WebhooksUpdate(WebhooksUpdate),
@@ -102,6 +114,9 @@ impl DispatchEvent {
             Self::ChannelPinsUpdate(_) => EventType::ChannelPinsUpdate,
             Self::ChannelUpdate(_) => EventType::ChannelUpdate,
             Self::CommandPermissionsUpdate(_) => EventType::CommandPermissionsUpdate,
+            Self::EntitlementCreate(_) => EventType::EntitlementCreate,
+            Self::EntitlementDelete(_) => EventType::EntitlementDelete,
+            Self::EntitlementUpdate(_) => EventType::EntitlementUpdate,
             Self::GiftCodeUpdate => EventType::GiftCodeUpdate,
             Self::GuildAuditLogEntryCreate(_) => EventType::GuildAuditLogEntryCreate,
             Self::GuildCreate(_) => EventType::GuildCreate,
@@ -113,6 +128,10 @@ impl DispatchEvent {
             Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
             Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
             Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
+            Self::GuildSoundboardSoundCreate(_) => EventType::GuildSoundboardSoundCreate,
+            Self::GuildSoundboardSoundDelete(_) => EventType::GuildSoundboardSoundDelete,
+            Self::GuildSoundboardSoundUpdate(_) => EventType::GuildSoundboardSoundUpdate,
+            Self::GuildSoundboardSoundsUpdate(_) => EventType::GuildSoundboardSoundsUpdate,
             Self::GuildStickersUpdate(_) => EventType::GuildStickersUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
@@ -128,6 +147,8 @@ impl DispatchEvent {
             Self::MessageCreate(_) => EventType::MessageCreate,
             Self::MessageDelete(_) => EventType::MessageDelete,
             Self::MessageDeleteBulk(_) => EventType::MessageDeleteBulk,
+            Self::MessagePollVoteAdd(_) => EventType::MessagePollVoteAdd,
+            Self::MessagePollVoteRemove(_) => EventType::MessagePollVoteRemove,
             Self::MessageUpdate(_) => EventType::MessageUpdate,
             Self::PresenceUpdate(_) => EventType::PresenceUpdate,
             Self::PresencesReplace => EventType::PresencesReplace,
@@ -140,6 +161,7 @@ impl DispatchEvent {
             Self::GuildRoleCreate(_) => EventType::RoleCreate,
             Self::GuildRoleDelete(_) => EventType::RoleDelete,
             Self::GuildRoleUpdate(_) => EventType::RoleUpdate,
+            Self::SoundboardSounds(_) => EventType::SoundboardSounds,
             Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
             Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
@@ -152,6 +174,7 @@ impl DispatchEvent {
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
+            Self::VoiceChannelEffectSend(_) => EventType::VoiceChannelEffectSend,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
             Self::WebhooksUpdate(_) => EventType::WebhooksUpdate,
@@ -176,6 +199,9 @@ impl TryFrom<Event> for DispatchEvent {
             Event::ChannelPinsUpdate(v) => Self::ChannelPinsUpdate(v),
             Event::ChannelUpdate(v) => Self::ChannelUpdate(v),
             Event::CommandPermissionsUpdate(v) => Self::CommandPermissionsUpdate(v),
+            Event::EntitlementCreate(v) => Self::EntitlementCreate(v),
+            Event::EntitlementDelete(v) => Self::EntitlementDelete(v),
+            Event::EntitlementUpdate(v) => Self::EntitlementUpdate(v),
             Event::GiftCodeUpdate => Self::GiftCodeUpdate,
             Event::GuildAuditLogEntryCreate(v) => Self::GuildAuditLogEntryCreate(v),
             Event::GuildCreate(v) => Self::GuildCreate(v),
@@ -187,6 +213,10 @@ impl TryFrom<Event> for DispatchEvent {
             Event::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
             Event::GuildScheduledEventUserAdd(v) => Self::GuildScheduledEventUserAdd(v),
             Event::GuildScheduledEventUserRemove(v) => Self::GuildScheduledEventUserRemove(v),
+            Event::GuildSoundboardSoundCreate(v) => Self::GuildSoundboardSoundCreate(v),
+            Event::GuildSoundboardSoundDelete(v) => Self::GuildSoundboardSoundDelete(v),
+            Event::GuildSoundboardSoundUpdate(v) => Self::GuildSoundboardSoundUpdate(v),
+            Event::GuildSoundboardSoundsUpdate(v) => Self::GuildSoundboardSoundsUpdate(v),
             Event::GuildUpdate(v) => Self::GuildUpdate(v),
             Event::IntegrationCreate(v) => Self::IntegrationCreate(v),
             Event::IntegrationDelete(v) => Self::IntegrationDelete(v),
@@ -201,6 +231,8 @@ impl TryFrom<Event> for DispatchEvent {
             Event::MessageCreate(v) => Self::MessageCreate(v),
             Event::MessageDelete(v) => Self::MessageDelete(v),
             Event::MessageDeleteBulk(v) => Self::MessageDeleteBulk(v),
+            Event::MessagePollVoteAdd(v) => Self::MessagePollVoteAdd(v),
+            Event::MessagePollVoteRemove(v) => Self::MessagePollVoteRemove(v),
             Event::MessageUpdate(v) => Self::MessageUpdate(v),
             Event::PresenceUpdate(v) => Self::PresenceUpdate(v),
             Event::PresencesReplace => Self::PresencesReplace,
@@ -213,6 +245,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::RoleCreate(v) => Self::GuildRoleCreate(v),
             Event::RoleDelete(v) => Self::GuildRoleDelete(v),
             Event::RoleUpdate(v) => Self::GuildRoleUpdate(v),
+            Event::SoundboardSounds(v) => Self::SoundboardSounds(v),
             Event::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
             Event::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
             Event::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
@@ -225,6 +258,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::TypingStart(v) => Self::TypingStart(v),
             Event::UnavailableGuild(v) => Self::UnavailableGuild(v),
             Event::UserUpdate(v) => Self::UserUpdate(v),
+            Event::VoiceChannelEffectSend(v) => Self::VoiceChannelEffectSend(v),
             Event::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             Event::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
             Event::WebhooksUpdate(v) => Self::WebhooksUpdate(v),
@@ -280,6 +314,15 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => DispatchEvent::CommandPermissionsUpdate(
                 CommandPermissionsUpdate::deserialize(deserializer)?,
             ),
+            "ENTITLEMENT_CREATE" => DispatchEvent::EntitlementCreate(Box::new(
+                EntitlementCreate::deserialize(deserializer)?,
+            )),
+            "ENTITLEMENT_DELETE" => DispatchEvent::EntitlementDelete(Box::new(
+                EntitlementDelete::deserialize(deserializer)?,
+            )),
+            "ENTITLEMENT_UPDATE" => DispatchEvent::EntitlementUpdate(Box::new(
+                EntitlementUpdate::deserialize(deserializer)?,
+            )),
             "GIFT_CODE_UPDATE" => {
                 deserializer.deserialize_ignored_any(IgnoredAny)?;
                 DispatchEvent::GiftCodeUpdate
@@ -316,6 +359,18 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "GUILD_SCHEDULED_EVENT_USER_REMOVE" => DispatchEvent::GuildScheduledEventUserRemove(
                 GuildScheduledEventUserRemove::deserialize(deserializer)?,
             ),
+            "GUILD_SOUNDBOARD_SOUND_CREATE" => DispatchEvent::GuildSoundboardSoundCreate(Box::new(
+                GuildSoundboardSoundCreate::deserialize(deserializer)?,
+            )),
+            "GUILD_SOUNDBOARD_SOUND_DELETE" => DispatchEvent::GuildSoundboardSoundDelete(
+                GuildSoundboardSoundDelete::deserialize(deserializer)?,
+            ),
+            "GUILD_SOUNDBOARD_SOUND_UPDATE" => DispatchEvent::GuildSoundboardSoundUpdate(Box::new(
+                GuildSoundboardSoundUpdate::deserialize(deserializer)?,
+            )),
+            "GUILD_SOUNDBOARD_SOUNDS_UPDATE" => DispatchEvent::GuildSoundboardSoundsUpdate(
+                GuildSoundboardSoundsUpdate::deserialize(deserializer)?,
+            ),
             "GUILD_MEMBERS_CHUNK" => {
                 DispatchEvent::GuildMembersChunk(MemberChunk::deserialize(deserializer)?)
             }
@@ -370,6 +425,12 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "MESSAGE_DELETE_BULK" => {
                 DispatchEvent::MessageDeleteBulk(MessageDeleteBulk::deserialize(deserializer)?)
             }
+            "MESSAGE_POLL_VOTE_ADD" => {
+                DispatchEvent::MessagePollVoteAdd(MessagePollVoteAdd::deserialize(deserializer)?)
+            }
+            "MESSAGE_POLL_VOTE_REMOVE" => DispatchEvent::MessagePollVoteRemove(
+                MessagePollVoteRemove::deserialize(deserializer)?,
+            ),
             "MESSAGE_REACTION_ADD" => {
                 DispatchEvent::ReactionAdd(Box::new(ReactionAdd::deserialize(deserializer)?))
             }
@@ -397,6 +458,9 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
                 deserializer.deserialize_ignored_any(IgnoredAny)?;
                 DispatchEvent::Resumed
             }
+            "SOUNDBOARD_SOUNDS" => {
+                DispatchEvent::SoundboardSounds(SoundboardSounds::deserialize(deserializer)?)
+            }
             "STAGE_INSTANCE_CREATE" => {
                 DispatchEvent::StageInstanceCreate(StageInstanceCreate::deserialize(deserializer)?)
             }
@@ -431,6 +495,9 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "VOICE_SERVER_UPDATE" => {
                 DispatchEvent::VoiceServerUpdate(VoiceServerUpdate::deserialize(deserializer)?)
             }
+            "VOICE_CHANNEL_EFFECT_SEND" => DispatchEvent::VoiceChannelEffectSend(
+                VoiceChannelEffectSend::deserialize(deserializer)?,
+            ),
             "VOICE_STATE_UPDATE" => DispatchEvent::VoiceStateUpdate(Box::new(
                 VoiceStateUpdate::deserialize(deserializer)?,
             )),
@@ -2,7 +2,7 @@ use super::{
     super::GatewayOpcode, DispatchEvent, DispatchEventWithTypeDeserializer, Event,
     EventConversionError,
 };
-use crate::gateway::payload::incoming::Hello;
+use crate::gateway::{etf, payload::incoming::Hello};
 use serde::{
     de::{
         value::U8Deserializer, DeserializeSeed, Deserializer, Error as DeError, IgnoredAny,
@@ -90,6 +90,34 @@ impl<'a> GatewayEventDeserializer<'a> {
         })
     }
 
+    /// Create a gateway event deserializer by reading the opcode, sequence
+    /// and dispatch event type of an [ETF] payload.
+    ///
+    /// The event data is skipped without being decoded, so it is only decoded
+    /// once the payload is deserialized.
+    ///
+    /// Returns [`None`] if the payload isn't a valid ETF map or lacks an
+    /// opcode.
+    ///
+    /// [ETF]: crate::gateway::etf
+    pub fn from_etf(input: &'a [u8]) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Header<'a> {
+            op: u8,
+            s: Option<u64>,
+            #[serde(borrow)]
+            t: Option<Cow<'a, str>>,
+        }
+
+        let header = etf::from_slice::<Header<'a>>(input).ok()?;
+
+        Some(Self {
+            event_type: header.t,
+            op: header.op,
+            sequence: header.s,
+        })
+    }
+
     /// Create a deserializer with an owned event type.
     ///
     /// This is necessary when using a mutable deserialization library such as
@@ -123,7 +151,7 @@ impl<'a> GatewayEventDeserializer<'a> {
     /// Sequence of the payload.
     ///
     /// May only be available if the deserializer was created via
-    /// [`from_json`][`Self::from_json`]
+    /// [`from_json`][`Self::from_json`] or [`from_etf`][`Self::from_etf`]
     pub const fn sequence(&self) -> Option<u64> {
         self.sequence
     }
@@ -340,6 +368,12 @@ impl<'de> Visitor<'de> for GatewayEventVisitor<'_> {
                     VALID_OPCODES,
                 ))
             }
+            GatewayOpcode::RequestSoundboardSounds => {
+                return Err(DeError::unknown_variant(
+                    "RequestSoundboardSounds",
+                    VALID_OPCODES,
+                ))
+            }
             GatewayOpcode::Resume => return Err(DeError::unknown_variant("Resume", VALID_OPCODES)),
             GatewayOpcode::PresenceUpdate => {
                 return Err(DeError::unknown_variant("PresenceUpdate", VALID_OPCODES))
@@ -414,6 +448,53 @@ impl Serialize for GatewayEvent {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for GatewayEvent {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "GatewayEvent".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        // S and T are always null when not a Dispatch event.
+        let event = |op: GatewayOpcode, d: schemars::Schema| {
+            schemars::json_schema!({
+                "type": "object",
+                "properties": {
+                    "d": d,
+                    "op": { "const": op as u8 },
+                    "s": { "type": "null" },
+                    "t": { "type": "null" },
+                },
+                "required": ["d", "op"],
+            })
+        };
+        let null = schemars::json_schema!({ "type": "null" });
+
+        schemars::json_schema!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "d": generator.subschema_for::<DispatchEvent>(),
+                        "op": { "const": GatewayOpcode::Dispatch as u8 },
+                        "s": generator.subschema_for::<u64>(),
+                        "t": { "type": "string" },
+                    },
+                    "required": ["d", "op", "s", "t"],
+                },
+                event(GatewayOpcode::Heartbeat, generator.subschema_for::<u64>()),
+                event(GatewayOpcode::HeartbeatAck, null.clone()),
+                event(GatewayOpcode::Hello, generator.subschema_for::<Hello>()),
+                event(
+                    GatewayOpcode::InvalidSession,
+                    generator.subschema_for::<bool>(),
+                ),
+                event(GatewayOpcode::Reconnect, null),
+            ],
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{DispatchEvent, GatewayEvent, GatewayEventDeserializer, GatewayOpcode};
@@ -444,6 +525,91 @@ mod tests {
         assert!(matches!(event, GatewayEvent::Dispatch(7, _)));
     }
 
+    #[test]
+    fn deserialize_dispatch_entitlement_create() {
+        let input = r#"{
+            "d": {
+                "application_id": "1",
+                "consumed": false,
+                "deleted": false,
+                "ends_at": "2024-02-01T00:00:00.000000+00:00",
+                "id": "2",
+                "sku_id": "3",
+                "starts_at": "2024-01-01T00:00:00.000000+00:00",
+                "type": 8,
+                "user_id": "4"
+            },
+            "op": 0,
+            "s": 3,
+            "t": "ENTITLEMENT_CREATE"
+        }"#;
+
+        let deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        assert!(matches!(
+            event,
+            GatewayEvent::Dispatch(3, DispatchEvent::EntitlementCreate(entitlement))
+                if entitlement.sku_id == Id::new(3)
+                    && entitlement.user_id == Some(Id::new(4))
+        ));
+    }
+
+    #[test]
+    fn deserialize_dispatch_message_poll_vote_add() {
+        let input = r#"{
+            "d": {
+                "answer_id": 1,
+                "channel_id": "2",
+                "guild_id": "3",
+                "message_id": "4",
+                "user_id": "5"
+            },
+            "op": 0,
+            "s": 9,
+            "t": "MESSAGE_POLL_VOTE_ADD"
+        }"#;
+
+        let deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        assert!(matches!(
+            event,
+            GatewayEvent::Dispatch(9, DispatchEvent::MessagePollVoteAdd(vote))
+                if vote.answer_id == 1 && vote.message_id == Id::new(4)
+        ));
+    }
+
+    #[test]
+    fn deserialize_dispatch_guild_soundboard_sound_create() {
+        let input = r#"{
+            "d": {
+                "available": true,
+                "emoji_id": null,
+                "emoji_name": "🦆",
+                "guild_id": "1",
+                "name": "quack",
+                "sound_id": "2",
+                "volume": 1.0
+            },
+            "op": 0,
+            "s": 4,
+            "t": "GUILD_SOUNDBOARD_SOUND_CREATE"
+        }"#;
+
+        let deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        assert!(matches!(
+            event,
+            GatewayEvent::Dispatch(4, DispatchEvent::GuildSoundboardSoundCreate(sound))
+                if sound.sound_id == Id::new(2) && sound.guild_id == Some(Id::new(1))
+        ));
+    }
+
     #[test]
     fn deserialize_dispatch_guild_update() {
         let input = format!(
@@ -772,10 +938,14 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("role_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::StructEnd,
                 Token::StructEnd,
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// The type of an event.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EventType {
     AutoModerationActionExecution,
@@ -20,6 +21,9 @@ pub enum EventType {
     ChannelUpdate,
     #[serde(rename = "APPLICATION_COMMAND_PERMISSIONS_UPDATE")]
     CommandPermissionsUpdate,
+    EntitlementCreate,
+    EntitlementDelete,
+    EntitlementUpdate,
     GatewayClose,
     GatewayHeartbeat,
     GatewayHeartbeatAck,
@@ -37,6 +41,10 @@ pub enum EventType {
     GuildScheduledEventUpdate,
     GuildScheduledEventUserAdd,
     GuildScheduledEventUserRemove,
+    GuildSoundboardSoundCreate,
+    GuildSoundboardSoundDelete,
+    GuildSoundboardSoundUpdate,
+    GuildSoundboardSoundsUpdate,
     GuildStickersUpdate,
     GuildUpdate,
     IntegrationCreate,
@@ -56,6 +64,8 @@ pub enum EventType {
     MessageCreate,
     MessageDelete,
     MessageDeleteBulk,
+    MessagePollVoteAdd,
+    MessagePollVoteRemove,
     MessageUpdate,
     PresenceUpdate,
     PresencesReplace,
@@ -75,6 +85,7 @@ pub enum EventType {
     RoleDelete,
     #[serde(rename = "GUILD_ROLE_UPDATE")]
     RoleUpdate,
+    SoundboardSounds,
     StageInstanceCreate,
     StageInstanceDelete,
     StageInstanceUpdate,
@@ -87,6 +98,7 @@ pub enum EventType {
     TypingStart,
     UnavailableGuild,
     UserUpdate,
+    VoiceChannelEffectSend,
     VoiceServerUpdate,
     VoiceStateUpdate,
     WebhooksUpdate,
@@ -106,6 +118,9 @@ impl EventType {
             Self::ChannelPinsUpdate => Some("CHANNEL_PINS_UPDATE"),
             Self::ChannelUpdate => Some("CHANNEL_UPDATE"),
             Self::CommandPermissionsUpdate => Some("APPLICATION_COMMAND_PERMISSIONS_UPDATE"),
+            Self::EntitlementCreate => Some("ENTITLEMENT_CREATE"),
+            Self::EntitlementDelete => Some("ENTITLEMENT_DELETE"),
+            Self::EntitlementUpdate => Some("ENTITLEMENT_UPDATE"),
             Self::GiftCodeUpdate => Some("GIFT_CODE_UPDATE"),
             Self::GuildAuditLogEntryCreate => Some("GUILD_AUDIT_LOG_ENTRY_CREATE"),
             Self::GuildCreate => Some("GUILD_CREATE"),
@@ -117,6 +132,10 @@ impl EventType {
             Self::GuildScheduledEventUpdate => Some("GUILD_SCHEDULED_EVENT_UPDATE"),
             Self::GuildScheduledEventUserAdd => Some("GUILD_SCHEDULED_EVENT_USER_ADD"),
             Self::GuildScheduledEventUserRemove => Some("GUILD_SCHEDULED_EVENT_USER_REMOVE"),
+            Self::GuildSoundboardSoundCreate => Some("GUILD_SOUNDBOARD_SOUND_CREATE"),
+            Self::GuildSoundboardSoundDelete => Some("GUILD_SOUNDBOARD_SOUND_DELETE"),
+            Self::GuildSoundboardSoundUpdate => Some("GUILD_SOUNDBOARD_SOUND_UPDATE"),
+            Self::GuildSoundboardSoundsUpdate => Some("GUILD_SOUNDBOARD_SOUNDS_UPDATE"),
             Self::GuildStickersUpdate => Some("GUILD_STICKERS_UPDATE"),
             Self::GuildUpdate => Some("GUILD_UPDATE"),
             Self::IntegrationCreate => Some("INTEGRATION_CREATE"),
@@ -132,6 +151,8 @@ impl EventType {
             Self::MessageCreate => Some("MESSAGE_CREATE"),
             Self::MessageDelete => Some("MESSAGE_DELETE"),
             Self::MessageDeleteBulk => Some("MESSAGE_DELETE_BULK"),
+            Self::MessagePollVoteAdd => Some("MESSAGE_POLL_VOTE_ADD"),
+            Self::MessagePollVoteRemove => Some("MESSAGE_POLL_VOTE_REMOVE"),
             Self::MessageUpdate => Some("MESSAGE_UPDATE"),
             Self::PresencesReplace => Some("PRESENCES_REPLACE"),
             Self::PresenceUpdate => Some("PRESENCE_UPDATE"),
@@ -144,6 +165,7 @@ impl EventType {
             Self::RoleCreate => Some("GUILD_ROLE_CREATE"),
             Self::RoleDelete => Some("GUILD_ROLE_DELETE"),
             Self::RoleUpdate => Some("GUILD_ROLE_UPDATE"),
+            Self::SoundboardSounds => Some("SOUNDBOARD_SOUNDS"),
             Self::StageInstanceCreate => Some("STAGE_INSTANCE_CREATE"),
             Self::StageInstanceDelete => Some("STAGE_INSTANCE_DELETE"),
             Self::StageInstanceUpdate => Some("STAGE_INSTANCE_UPDATE"),
@@ -156,6 +178,7 @@ impl EventType {
             Self::TypingStart => Some("TYPING_START"),
             Self::UnavailableGuild => Some("UNAVAILABLE_GUILD"),
             Self::UserUpdate => Some("USER_UPDATE"),
+            Self::VoiceChannelEffectSend => Some("VOICE_CHANNEL_EFFECT_SEND"),
             Self::VoiceServerUpdate => Some("VOICE_SERVER_UPDATE"),
             Self::VoiceStateUpdate => Some("VOICE_STATE_UPDATE"),
             Self::WebhooksUpdate => Some("WEBHOOKS_UPDATE"),
@@ -186,6 +209,9 @@ impl<'a> TryFrom<&'a str> for EventType {
             "CHANNEL_PINS_UPDATE" => Ok(Self::ChannelPinsUpdate),
             "CHANNEL_UPDATE" => Ok(Self::ChannelUpdate),
             "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => Ok(Self::CommandPermissionsUpdate),
+            "ENTITLEMENT_CREATE" => Ok(Self::EntitlementCreate),
+            "ENTITLEMENT_DELETE" => Ok(Self::EntitlementDelete),
+            "ENTITLEMENT_UPDATE" => Ok(Self::EntitlementUpdate),
             "GIFT_CODE_UPDATE" => Ok(Self::GiftCodeUpdate),
             "GUILD_CREATE" => Ok(Self::GuildCreate),
             "GUILD_DELETE" => Ok(Self::GuildDelete),
@@ -196,6 +222,10 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_SCHEDULED_EVENT_UPDATE" => Ok(Self::GuildScheduledEventUpdate),
             "GUILD_SCHEDULED_EVENT_USER_ADD" => Ok(Self::GuildScheduledEventUserAdd),
             "GUILD_SCHEDULED_EVENT_USER_REMOVE" => Ok(Self::GuildScheduledEventUserRemove),
+            "GUILD_SOUNDBOARD_SOUND_CREATE" => Ok(Self::GuildSoundboardSoundCreate),
+            "GUILD_SOUNDBOARD_SOUND_DELETE" => Ok(Self::GuildSoundboardSoundDelete),
+            "GUILD_SOUNDBOARD_SOUND_UPDATE" => Ok(Self::GuildSoundboardSoundUpdate),
+            "GUILD_SOUNDBOARD_SOUNDS_UPDATE" => Ok(Self::GuildSoundboardSoundsUpdate),
             "GUILD_UPDATE" => Ok(Self::GuildUpdate),
             "INTEGRATION_CREATE" => Ok(Self::IntegrationCreate),
             "INTEGRATION_DELETE" => Ok(Self::IntegrationDelete),
@@ -210,6 +240,8 @@ impl<'a> TryFrom<&'a str> for EventType {
             "MESSAGE_CREATE" => Ok(Self::MessageCreate),
             "MESSAGE_DELETE" => Ok(Self::MessageDelete),
             "MESSAGE_DELETE_BULK" => Ok(Self::MessageDeleteBulk),
+            "MESSAGE_POLL_VOTE_ADD" => Ok(Self::MessagePollVoteAdd),
+            "MESSAGE_POLL_VOTE_REMOVE" => Ok(Self::MessagePollVoteRemove),
             "MESSAGE_UPDATE" => Ok(Self::MessageUpdate),
             "PRESENCE_UPDATE" => Ok(Self::PresenceUpdate),
             "PRESENCES_REPLACE" => Ok(Self::PresencesReplace),
@@ -222,6 +254,7 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_ROLE_CREATE" => Ok(Self::RoleCreate),
             "GUILD_ROLE_DELETE" => Ok(Self::RoleDelete),
             "GUILD_ROLE_UPDATE" => Ok(Self::RoleUpdate),
+            "SOUNDBOARD_SOUNDS" => Ok(Self::SoundboardSounds),
             "STAGE_INSTANCE_CREATE" => Ok(Self::StageInstanceCreate),
             "STAGE_INSTANCE_DELETE" => Ok(Self::StageInstanceDelete),
             "STAGE_INSTANCE_UPDATE" => Ok(Self::StageInstanceUpdate),
@@ -234,6 +267,7 @@ impl<'a> TryFrom<&'a str> for EventType {
             "TYPING_START" => Ok(Self::TypingStart),
             "UNAVAILABLE_GUILD" => Ok(Self::UnavailableGuild),
             "USER_UPDATE" => Ok(Self::UserUpdate),
+            "VOICE_CHANNEL_EFFECT_SEND" => Ok(Self::VoiceChannelEffectSend),
             "VOICE_SERVER_UPDATE" => Ok(Self::VoiceServerUpdate),
             "VOICE_STATE_UPDATE" => Ok(Self::VoiceStateUpdate),
             "WEBHOOKS_UPDATE" => Ok(Self::WebhooksUpdate),
@@ -286,6 +320,9 @@ mod tests {
             EventType::CommandPermissionsUpdate,
             "APPLICATION_COMMAND_PERMISSIONS_UPDATE",
         );
+        assert_variant(EventType::EntitlementCreate, "ENTITLEMENT_CREATE");
+        assert_variant(EventType::EntitlementDelete, "ENTITLEMENT_DELETE");
+        assert_variant(EventType::EntitlementUpdate, "ENTITLEMENT_UPDATE");
         assert_variant(EventType::GatewayClose, "GATEWAY_CLOSE");
         assert_variant(EventType::GatewayHeartbeat, "GATEWAY_HEARTBEAT");
         assert_variant(EventType::GatewayHeartbeatAck, "GATEWAY_HEARTBEAT_ACK");
@@ -327,6 +364,22 @@ mod tests {
             EventType::GuildScheduledEventUserRemove,
             "GUILD_SCHEDULED_EVENT_USER_REMOVE",
         );
+        assert_variant(
+            EventType::GuildSoundboardSoundCreate,
+            "GUILD_SOUNDBOARD_SOUND_CREATE",
+        );
+        assert_variant(
+            EventType::GuildSoundboardSoundDelete,
+            "GUILD_SOUNDBOARD_SOUND_DELETE",
+        );
+        assert_variant(
+            EventType::GuildSoundboardSoundUpdate,
+            "GUILD_SOUNDBOARD_SOUND_UPDATE",
+        );
+        assert_variant(
+            EventType::GuildSoundboardSoundsUpdate,
+            "GUILD_SOUNDBOARD_SOUNDS_UPDATE",
+        );
         assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
         assert_variant(EventType::IntegrationCreate, "INTEGRATION_CREATE");
         assert_variant(EventType::IntegrationDelete, "INTEGRATION_DELETE");
@@ -341,6 +394,8 @@ mod tests {
         assert_variant(EventType::MessageCreate, "MESSAGE_CREATE");
         assert_variant(EventType::MessageDelete, "MESSAGE_DELETE");
         assert_variant(EventType::MessageDeleteBulk, "MESSAGE_DELETE_BULK");
+        assert_variant(EventType::MessagePollVoteAdd, "MESSAGE_POLL_VOTE_ADD");
+        assert_variant(EventType::MessagePollVoteRemove, "MESSAGE_POLL_VOTE_REMOVE");
         assert_variant(EventType::MessageUpdate, "MESSAGE_UPDATE");
         assert_variant(EventType::PresenceUpdate, "PRESENCE_UPDATE");
         assert_variant(EventType::PresencesReplace, "PRESENCES_REPLACE");
@@ -356,6 +411,7 @@ mod tests {
         assert_variant(EventType::RoleCreate, "GUILD_ROLE_CREATE");
         assert_variant(EventType::RoleDelete, "GUILD_ROLE_DELETE");
         assert_variant(EventType::RoleUpdate, "GUILD_ROLE_UPDATE");
+        assert_variant(EventType::SoundboardSounds, "SOUNDBOARD_SOUNDS");
         assert_variant(EventType::StageInstanceCreate, "STAGE_INSTANCE_CREATE");
         assert_variant(EventType::StageInstanceDelete, "STAGE_INSTANCE_DELETE");
         assert_variant(EventType::StageInstanceUpdate, "STAGE_INSTANCE_UPDATE");
@@ -368,6 +424,10 @@ mod tests {
         assert_variant(EventType::TypingStart, "TYPING_START");
         assert_variant(EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
         assert_variant(EventType::UserUpdate, "USER_UPDATE");
+        assert_variant(
+            EventType::VoiceChannelEffectSend,
+            "VOICE_CHANNEL_EFFECT_SEND",
+        );
         assert_variant(EventType::VoiceServerUpdate, "VOICE_SERVER_UPDATE");
         assert_variant(EventType::VoiceStateUpdate, "VOICE_STATE_UPDATE");
         assert_variant(EventType::WebhooksUpdate, "WEBHOOKS_UPDATE");
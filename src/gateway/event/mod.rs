@@ -46,6 +46,12 @@ pub enum Event {
     ChannelUpdate(Box<ChannelUpdate>),
     /// A command's permissions were updated.
     CommandPermissionsUpdate(CommandPermissionsUpdate),
+    /// An entitlement was created.
+    EntitlementCreate(Box<EntitlementCreate>),
+    /// An entitlement was deleted.
+    EntitlementDelete(Box<EntitlementDelete>),
+    /// An entitlement was updated.
+    EntitlementUpdate(Box<EntitlementUpdate>),
     /// Close message with an optional frame including information about the
     /// reason for the close.
     GatewayClose(Option<CloseFrame<'static>>),
@@ -83,6 +89,14 @@ pub enum Event {
     GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
     /// A user was removed from a guild scheduled event.
     GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+    /// A guild soundboard sound was created.
+    GuildSoundboardSoundCreate(Box<GuildSoundboardSoundCreate>),
+    /// A guild soundboard sound was deleted.
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDelete),
+    /// A guild soundboard sound was updated.
+    GuildSoundboardSoundUpdate(Box<GuildSoundboardSoundUpdate>),
+    /// Multiple guild soundboard sounds were updated.
+    GuildSoundboardSoundsUpdate(GuildSoundboardSoundsUpdate),
     /// A guild's stickers were updated.
     GuildStickersUpdate(GuildStickersUpdate),
     /// A guild was updated.
@@ -113,6 +127,10 @@ pub enum Event {
     MessageDelete(MessageDelete),
     /// Multiple messages were deleted in a channel.
     MessageDeleteBulk(MessageDeleteBulk),
+    /// A user voted for an answer of a poll.
+    MessagePollVoteAdd(MessagePollVoteAdd),
+    /// A user removed their vote for an answer of a poll.
+    MessagePollVoteRemove(MessagePollVoteRemove),
     /// A message was updated in a channel.
     MessageUpdate(Box<MessageUpdate>),
     /// A user's active presence (such as game or online status) was updated.
@@ -140,6 +158,8 @@ pub enum Event {
     RoleDelete(RoleDelete),
     /// A role was updated in a guild.
     RoleUpdate(RoleUpdate),
+    /// Soundboard sounds of a guild were received from the gateway.
+    SoundboardSounds(SoundboardSounds),
     /// A stage instance was created in a stage channel.
     StageInstanceCreate(StageInstanceCreate),
     /// A stage instance was deleted in a stage channel.
@@ -165,6 +185,8 @@ pub enum Event {
     UnavailableGuild(UnavailableGuild),
     /// The current user was updated.
     UserUpdate(UserUpdate),
+    /// A user sent an effect in a voice channel.
+    VoiceChannelEffectSend(VoiceChannelEffectSend),
     /// A voice server update was sent.
     VoiceServerUpdate(VoiceServerUpdate),
     /// A voice state in a voice channel was updated.
@@ -188,6 +210,9 @@ impl Event {
             Event::ChannelPinsUpdate(e) => e.guild_id,
             Event::ChannelUpdate(e) => e.0.guild_id,
             Event::CommandPermissionsUpdate(e) => Some(e.0.guild_id),
+            Event::EntitlementCreate(e) => e.0.guild_id,
+            Event::EntitlementDelete(e) => e.0.guild_id,
+            Event::EntitlementUpdate(e) => e.0.guild_id,
             Event::GuildAuditLogEntryCreate(e) => e.0.guild_id,
             Event::GuildCreate(e) => Some(e.0.id),
             Event::GuildDelete(e) => Some(e.id),
@@ -198,6 +223,10 @@ impl Event {
             Event::GuildScheduledEventUpdate(e) => Some(e.0.guild_id),
             Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
             Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
+            Event::GuildSoundboardSoundCreate(e) => e.0.guild_id,
+            Event::GuildSoundboardSoundDelete(e) => Some(e.guild_id),
+            Event::GuildSoundboardSoundUpdate(e) => e.0.guild_id,
+            Event::GuildSoundboardSoundsUpdate(e) => Some(e.guild_id),
             Event::GuildStickersUpdate(e) => Some(e.guild_id),
             Event::GuildUpdate(e) => Some(e.0.id),
             Event::IntegrationCreate(e) => e.0.guild_id,
@@ -213,6 +242,8 @@ impl Event {
             Event::MessageCreate(e) => e.0.guild_id,
             Event::MessageDelete(e) => e.guild_id,
             Event::MessageDeleteBulk(e) => e.guild_id,
+            Event::MessagePollVoteAdd(e) => e.guild_id,
+            Event::MessagePollVoteRemove(e) => e.guild_id,
             Event::MessageUpdate(e) => e.guild_id,
             Event::PresenceUpdate(_) => None,
             Event::ReactionAdd(e) => e.0.guild_id,
@@ -222,6 +253,7 @@ impl Event {
             Event::RoleCreate(e) => Some(e.guild_id),
             Event::RoleDelete(e) => Some(e.guild_id),
             Event::RoleUpdate(e) => Some(e.guild_id),
+            Event::SoundboardSounds(e) => Some(e.guild_id),
             Event::StageInstanceCreate(e) => Some(e.0.guild_id),
             Event::StageInstanceDelete(e) => Some(e.0.guild_id),
             Event::StageInstanceUpdate(e) => Some(e.0.guild_id),
@@ -233,6 +265,7 @@ impl Event {
             Event::ThreadUpdate(e) => e.0.guild_id,
             Event::TypingStart(e) => e.guild_id,
             Event::UnavailableGuild(e) => Some(e.id),
+            Event::VoiceChannelEffectSend(e) => Some(e.guild_id),
             Event::VoiceServerUpdate(e) => Some(e.guild_id),
             Event::VoiceStateUpdate(e) => e.0.guild_id,
             Event::WebhooksUpdate(e) => Some(e.guild_id),
@@ -263,6 +296,9 @@ impl Event {
             Self::ChannelPinsUpdate(_) => EventType::ChannelPinsUpdate,
             Self::ChannelUpdate(_) => EventType::ChannelUpdate,
             Self::CommandPermissionsUpdate(_) => EventType::CommandPermissionsUpdate,
+            Self::EntitlementCreate(_) => EventType::EntitlementCreate,
+            Self::EntitlementDelete(_) => EventType::EntitlementDelete,
+            Self::EntitlementUpdate(_) => EventType::EntitlementUpdate,
             Self::GatewayClose(_) => EventType::GatewayClose,
             Self::GatewayHeartbeat(_) => EventType::GatewayHeartbeat,
             Self::GatewayHeartbeatAck => EventType::GatewayHeartbeatAck,
@@ -280,6 +316,10 @@ impl Event {
             Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
             Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
             Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
+            Self::GuildSoundboardSoundCreate(_) => EventType::GuildSoundboardSoundCreate,
+            Self::GuildSoundboardSoundDelete(_) => EventType::GuildSoundboardSoundDelete,
+            Self::GuildSoundboardSoundUpdate(_) => EventType::GuildSoundboardSoundUpdate,
+            Self::GuildSoundboardSoundsUpdate(_) => EventType::GuildSoundboardSoundsUpdate,
             Self::GuildStickersUpdate(_) => EventType::GuildStickersUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
@@ -295,6 +335,8 @@ impl Event {
             Self::MessageCreate(_) => EventType::MessageCreate,
             Self::MessageDelete(_) => EventType::MessageDelete,
             Self::MessageDeleteBulk(_) => EventType::MessageDeleteBulk,
+            Self::MessagePollVoteAdd(_) => EventType::MessagePollVoteAdd,
+            Self::MessagePollVoteRemove(_) => EventType::MessagePollVoteRemove,
             Self::MessageUpdate(_) => EventType::MessageUpdate,
             Self::PresenceUpdate(_) => EventType::PresenceUpdate,
             Self::PresencesReplace => EventType::PresencesReplace,
@@ -307,6 +349,7 @@ impl Event {
             Self::RoleCreate(_) => EventType::RoleCreate,
             Self::RoleDelete(_) => EventType::RoleDelete,
             Self::RoleUpdate(_) => EventType::RoleUpdate,
+            Self::SoundboardSounds(_) => EventType::SoundboardSounds,
             Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
             Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
@@ -319,6 +362,7 @@ impl Event {
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
+            Self::VoiceChannelEffectSend(_) => EventType::VoiceChannelEffectSend,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
             Self::WebhooksUpdate(_) => EventType::WebhooksUpdate,
@@ -342,6 +386,9 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::ChannelPinsUpdate(v) => Self::ChannelPinsUpdate(v),
             DispatchEvent::ChannelUpdate(v) => Self::ChannelUpdate(v),
             DispatchEvent::CommandPermissionsUpdate(v) => Self::CommandPermissionsUpdate(v),
+            DispatchEvent::EntitlementCreate(v) => Self::EntitlementCreate(v),
+            DispatchEvent::EntitlementDelete(v) => Self::EntitlementDelete(v),
+            DispatchEvent::EntitlementUpdate(v) => Self::EntitlementUpdate(v),
             DispatchEvent::GiftCodeUpdate => Self::GiftCodeUpdate,
             DispatchEvent::GuildAuditLogEntryCreate(v) => Self::GuildAuditLogEntryCreate(v),
             DispatchEvent::GuildCreate(v) => Self::GuildCreate(v),
@@ -355,6 +402,10 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::GuildScheduledEventUserRemove(v) => {
                 Self::GuildScheduledEventUserRemove(v)
             }
+            DispatchEvent::GuildSoundboardSoundCreate(v) => Self::GuildSoundboardSoundCreate(v),
+            DispatchEvent::GuildSoundboardSoundDelete(v) => Self::GuildSoundboardSoundDelete(v),
+            DispatchEvent::GuildSoundboardSoundUpdate(v) => Self::GuildSoundboardSoundUpdate(v),
+            DispatchEvent::GuildSoundboardSoundsUpdate(v) => Self::GuildSoundboardSoundsUpdate(v),
             DispatchEvent::GuildStickersUpdate(v) => Self::GuildStickersUpdate(v),
             DispatchEvent::GuildUpdate(v) => Self::GuildUpdate(v),
             DispatchEvent::IntegrationCreate(v) => Self::IntegrationCreate(v),
@@ -373,6 +424,8 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::MessageCreate(v) => Self::MessageCreate(v),
             DispatchEvent::MessageDelete(v) => Self::MessageDelete(v),
             DispatchEvent::MessageDeleteBulk(v) => Self::MessageDeleteBulk(v),
+            DispatchEvent::MessagePollVoteAdd(v) => Self::MessagePollVoteAdd(v),
+            DispatchEvent::MessagePollVoteRemove(v) => Self::MessagePollVoteRemove(v),
             DispatchEvent::MessageUpdate(v) => Self::MessageUpdate(v),
             DispatchEvent::PresenceUpdate(v) => Self::PresenceUpdate(v),
             DispatchEvent::PresencesReplace => Self::PresencesReplace,
@@ -382,6 +435,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::ReactionRemoveEmoji(v) => Self::ReactionRemoveEmoji(v),
             DispatchEvent::Ready(v) => Self::Ready(v),
             DispatchEvent::Resumed => Self::Resumed,
+            DispatchEvent::SoundboardSounds(v) => Self::SoundboardSounds(v),
             DispatchEvent::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
             DispatchEvent::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
             DispatchEvent::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
@@ -394,6 +448,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
+            DispatchEvent::VoiceChannelEffectSend(v) => Self::VoiceChannelEffectSend(v),
             DispatchEvent::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             DispatchEvent::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
             DispatchEvent::WebhooksUpdate(v) => Self::WebhooksUpdate(v),
@@ -485,6 +540,8 @@ mod tests {
     const_assert!(mem::size_of::<GuildScheduledEventCreate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventDelete>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventUpdate>() > EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundCreate>() > EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundUpdate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildUpdate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<IntegrationCreate>() > EVENT_THRESHOLD);
     const_assert!(mem::size_of::<IntegrationUpdate>() > EVENT_THRESHOLD);
@@ -515,6 +572,8 @@ mod tests {
     const_assert!(mem::size_of::<GuildIntegrationsUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventUserAdd>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<GuildScheduledEventUserRemove>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundDelete>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<GuildSoundboardSoundsUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<IntegrationDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<InviteDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<MemberChunk>() <= EVENT_THRESHOLD);
@@ -525,6 +584,7 @@ mod tests {
     const_assert!(mem::size_of::<RoleCreate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<RoleDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<RoleUpdate>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<SoundboardSounds>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<StageInstanceCreate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<StageInstanceDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<StageInstanceUpdate>() <= EVENT_THRESHOLD);
@@ -533,6 +593,7 @@ mod tests {
     const_assert!(mem::size_of::<ThreadMembersUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<UnavailableGuild>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<UserUpdate>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<VoiceChannelEffectSend>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<VoiceServerUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<WebhooksUpdate>() <= EVENT_THRESHOLD);
 }
@@ -6,7 +6,90 @@
 //! input will not be checked and will be passed directly to the underlying
 //! websocket library.
 
-use std::borrow::Cow;
+use super::CloseCode;
+use crate::voice::CloseCode as VoiceCloseCode;
+use std::{borrow::Cow, convert::TryFrom};
+
+/// Action to take after a connection has been closed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CloseAction {
+    /// Reconnect and resume the session.
+    Resume,
+    /// Reconnect and identify a new session.
+    Reidentify,
+    /// Don't reconnect, as reconnecting with the same configuration would
+    /// fail or the client was intentionally disconnected.
+    Stop,
+}
+
+impl CloseAction {
+    /// Whether the action is to reconnect.
+    pub const fn is_reconnect(self) -> bool {
+        !matches!(self, Self::Stop)
+    }
+}
+
+/// Interpretation of the code of a [`CloseFrame`].
+///
+/// Discord's gateway and voice gateway close codes overlap, so which of the two
+/// a frame's code is interpreted as is decided by the caller through
+/// [`CloseFrame::gateway_kind`] or [`CloseFrame::voice_kind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CloseFrameKind {
+    /// Close code defined by the websocket protocol, in the range of 1000 to
+    /// 2999.
+    Standard(u16),
+    /// Gateway close code.
+    Gateway(CloseCode),
+    /// Voice gateway close code.
+    Voice(VoiceCloseCode),
+    /// Variant value is unknown to the library.
+    Unknown(u16),
+}
+
+impl CloseFrameKind {
+    /// Action to take after receiving the close code.
+    ///
+    /// The normal (1000) and going away (1001) standard close codes invalidate
+    /// the session and require identifying anew. Other standard and unknown
+    /// close codes are resumable, while gateway and voice close codes follow
+    /// their [`can_reconnect`] and [`can_resume`] semantics.
+    ///
+    /// [`can_reconnect`]: CloseCode::can_reconnect
+    /// [`can_resume`]: CloseCode::can_resume
+    pub const fn action(self) -> CloseAction {
+        match self {
+            Self::Gateway(code) if code.can_resume() => CloseAction::Resume,
+            Self::Gateway(code) if code.can_reconnect() => CloseAction::Reidentify,
+            Self::Voice(code) if code.can_resume() => CloseAction::Resume,
+            Self::Voice(code) if code.can_reconnect() => CloseAction::Reidentify,
+            Self::Gateway(_) | Self::Voice(_) => CloseAction::Stop,
+            Self::Standard(1000 | 1001) => CloseAction::Reidentify,
+            Self::Standard(_) | Self::Unknown(_) => CloseAction::Resume,
+        }
+    }
+
+    /// Numeric value of the close code.
+    pub const fn code(self) -> u16 {
+        match self {
+            Self::Gateway(code) => code as u16,
+            Self::Voice(code) => code as u16,
+            Self::Standard(code) | Self::Unknown(code) => code,
+        }
+    }
+
+    /// Description of the configuration error causing a fatal close code.
+    ///
+    /// Refer to [`CloseCode::fatal_reason`] for more information.
+    pub const fn fatal_reason(self) -> Option<&'static str> {
+        match self {
+            Self::Gateway(code) => code.fatal_reason(),
+            Self::Voice(code) => code.fatal_reason(),
+            Self::Standard(_) | Self::Unknown(_) => None,
+        }
+    }
+}
 
 /// Information about a close message.
 ///
@@ -59,13 +142,51 @@ impl<'a> CloseFrame<'a> {
             reason: Cow::Borrowed(reason),
         }
     }
+
+    /// Interpret the close code as a gateway close code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::gateway::{CloseAction, CloseCode, CloseFrame, CloseFrameKind};
+    ///
+    /// let frame = CloseFrame::new(4014, "Disallowed intent(s).");
+    /// let kind = frame.gateway_kind();
+    ///
+    /// assert_eq!(CloseFrameKind::Gateway(CloseCode::DisallowedIntents), kind);
+    /// assert_eq!(CloseAction::Stop, kind.action());
+    /// ```
+    pub fn gateway_kind(&self) -> CloseFrameKind {
+        self.standard_kind().unwrap_or_else(|| {
+            CloseCode::try_from(self.code)
+                .map_or(CloseFrameKind::Unknown(self.code), CloseFrameKind::Gateway)
+        })
+    }
+
+    /// Interpret the close code as a voice gateway close code.
+    pub fn voice_kind(&self) -> CloseFrameKind {
+        self.standard_kind().unwrap_or_else(|| {
+            VoiceCloseCode::try_from(self.code)
+                .map_or(CloseFrameKind::Unknown(self.code), CloseFrameKind::Voice)
+        })
+    }
+
+    /// Kind of the close code if it is defined by the websocket protocol.
+    const fn standard_kind(&self) -> Option<CloseFrameKind> {
+        if matches!(self.code, 1000..=2999) {
+            Some(CloseFrameKind::Standard(self.code))
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::CloseFrame;
+    use super::{CloseAction, CloseFrame, CloseFrameKind};
+    use crate::{gateway::CloseCode, voice::CloseCode as VoiceCloseCode};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, hash::Hash};
 
     assert_impl_all!(
         CloseFrame<'_>:
@@ -74,4 +195,88 @@ mod tests {
         Eq,
         PartialEq,
     );
+    assert_impl_all!(CloseAction: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(CloseFrameKind: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn gateway_kind() {
+        let frames = [
+            (
+                CloseFrame::NORMAL,
+                CloseFrameKind::Standard(1000),
+                CloseAction::Reidentify,
+            ),
+            (
+                CloseFrame::new(1001, ""),
+                CloseFrameKind::Standard(1001),
+                CloseAction::Reidentify,
+            ),
+            (
+                CloseFrame::RESUME,
+                CloseFrameKind::Gateway(CloseCode::UnknownError),
+                CloseAction::Resume,
+            ),
+            (
+                CloseFrame::new(4009, ""),
+                CloseFrameKind::Gateway(CloseCode::SessionTimedOut),
+                CloseAction::Reidentify,
+            ),
+            (
+                CloseFrame::new(4013, ""),
+                CloseFrameKind::Gateway(CloseCode::InvalidIntents),
+                CloseAction::Stop,
+            ),
+            (
+                CloseFrame::new(4006, ""),
+                CloseFrameKind::Unknown(4006),
+                CloseAction::Resume,
+            ),
+        ];
+
+        for (frame, kind, action) in frames {
+            assert_eq!(kind, frame.gateway_kind());
+            assert_eq!(action, kind.action());
+            assert_eq!(frame.code, kind.code());
+        }
+
+        assert!(CloseFrame::new(4013, "")
+            .gateway_kind()
+            .fatal_reason()
+            .is_some());
+        assert!(CloseFrame::NORMAL.gateway_kind().fatal_reason().is_none());
+    }
+
+    #[test]
+    fn voice_kind() {
+        let frames = [
+            (
+                CloseFrame::new(4006, ""),
+                CloseFrameKind::Voice(VoiceCloseCode::SessionNoLongerValid),
+                CloseAction::Reidentify,
+            ),
+            (
+                CloseFrame::new(4014, ""),
+                CloseFrameKind::Voice(VoiceCloseCode::Disconnected),
+                CloseAction::Stop,
+            ),
+            (
+                CloseFrame::new(4015, ""),
+                CloseFrameKind::Voice(VoiceCloseCode::VoiceServerCrashed),
+                CloseAction::Resume,
+            ),
+            (
+                CloseFrame::new(1006, ""),
+                CloseFrameKind::Standard(1006),
+                CloseAction::Resume,
+            ),
+        ];
+
+        for (frame, kind, action) in frames {
+            assert_eq!(kind, frame.voice_kind());
+            assert_eq!(action, kind.action());
+            assert_eq!(frame.code, kind.code());
+        }
+
+        assert!(!CloseAction::Stop.is_reconnect());
+    }
 }
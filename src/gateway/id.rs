@@ -1,3 +1,4 @@
+use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Serialize};
 use std::{
     convert::TryFrom,
@@ -93,6 +94,7 @@ pub enum ShardIdParseErrorType {
 ///
 /// [Discord Docs/Sharding]: https://discord.com/developers/docs/topics/gateway#sharding
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(try_from = "[u64; 2]", into = "[u64; 2]")]
 pub struct ShardId {
     /// Number of the shard, 0-indexed.
@@ -160,6 +162,68 @@ impl ShardId {
     pub const fn total(self) -> u64 {
         self.total.get()
     }
+
+    /// Create the shard identifier of the shard receiving events for a guild.
+    ///
+    /// # Examples
+    ///
+    /// Determine which of 16 shards receives events for a guild:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     gateway::ShardId,
+    ///     id::{marker::GuildMarker, Id},
+    /// };
+    ///
+    /// let guild_id = Id::<GuildMarker>::new(41_771_983_423_143_937);
+    ///
+    /// assert_eq!(ShardId::new(6, 16), ShardId::for_guild(guild_id, 16));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of shards is zero.
+    #[track_caller]
+    pub const fn for_guild(guild_id: Id<GuildMarker>, total: u64) -> Self {
+        Self::new(guild_id.shard_number(total), total)
+    }
+
+    /// Whether this shard receives events for a guild.
+    ///
+    /// Refer to the [type-level documentation][`ShardId`] for how guilds are
+    /// mapped to shards.
+    pub const fn receives_guild(self, guild_id: Id<GuildMarker>) -> bool {
+        guild_id.shard_number(self.total()) == self.number
+    }
+
+    /// Identify rate limit bucket of the shard.
+    ///
+    /// Discord allows one shard per bucket to identify every 5 seconds, where
+    /// the bucket is calculated as `number % max_concurrency`. Shards in
+    /// different buckets may identify concurrently.
+    ///
+    /// Refer to [`IdentifySchedule`] for planning the identification of a
+    /// group of shards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::gateway::ShardId;
+    ///
+    /// assert_eq!(3, ShardId::new(19, 32).identify_bucket(16));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum concurrency is zero.
+    ///
+    /// [`IdentifySchedule`]: super::IdentifySchedule
+    #[track_caller]
+    pub const fn identify_bucket(self, max_concurrency: u64) -> u64 {
+        assert!(max_concurrency != 0, "max_concurrency must be at least 1");
+
+        self.number % max_concurrency
+    }
 }
 
 /// Display the shard ID.
@@ -192,6 +256,7 @@ impl From<ShardId> for [u64; 2] {
 #[cfg(test)]
 mod tests {
     use super::ShardId;
+    use crate::id::{marker::GuildMarker, Id};
     use serde::{de::DeserializeOwned, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_impl_all, const_assert_eq};
@@ -228,6 +293,24 @@ mod tests {
         assert!(id.total() == 4);
     }
 
+    #[test]
+    fn guild_routing() {
+        let guild_id = Id::<GuildMarker>::new(41_771_983_423_143_937);
+        let shard = ShardId::for_guild(guild_id, 16);
+
+        assert_eq!(ShardId::new(6, 16), shard);
+        assert!(shard.receives_guild(guild_id));
+        assert!(!ShardId::new(7, 16).receives_guild(guild_id));
+        assert!(ShardId::ONE.receives_guild(guild_id));
+    }
+
+    #[test]
+    const fn identify_bucket() {
+        assert!(ShardId::new(0, 32).identify_bucket(16) == 0);
+        assert!(ShardId::new(17, 32).identify_bucket(16) == 1);
+        assert!(ShardId::new(17, 32).identify_bucket(1) == 0);
+    }
+
     #[test]
     fn serde() {
         let value = ShardId::new(0, 1);
@@ -0,0 +1,360 @@
+use super::{connection_info::BotConnectionInfo, SessionStartLimit, ShardId};
+use std::{ops::Range, time::Duration};
+
+/// Interval Discord enforces between two identifies in the same rate limit
+/// bucket.
+pub const IDENTIFY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Number of seconds after which the session start limit resets once its
+/// initial `reset_after` has elapsed.
+const SESSION_RESET_INTERVAL_SECS: u64 = 86_400;
+
+/// Group of shards that may identify concurrently.
+///
+/// Every shard in a round belongs to a different [identify bucket], so the
+/// whole round may identify at once.
+///
+/// [identify bucket]: ShardId::identify_bucket
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct IdentifyRound {
+    /// Delay, relative to the start of the schedule, before the shards may
+    /// identify.
+    pub delay: Duration,
+    /// Numbers of the shards identifying in this round.
+    pub shards: Range<u64>,
+    /// Total number of shards.
+    pub total: u64,
+}
+
+impl IdentifyRound {
+    /// Iterator over the IDs of the shards identifying in this round.
+    pub fn shard_ids(&self) -> impl Iterator<Item = ShardId> {
+        let total = self.total;
+
+        self.shards
+            .clone()
+            .map(move |number| ShardId::new(number, total))
+    }
+}
+
+/// Plan for identifying a bot's shards within Discord's rate limits.
+///
+/// Shards are grouped into [rounds] of `max_concurrency` shards, each round
+/// starting [`IDENTIFY_INTERVAL`] after the previous one. When the remaining
+/// session starts are insufficient for a round it is delayed until the session
+/// start limit resets, and rounds larger than the total session starts are
+/// split so that no round exceeds the limit.
+///
+/// The schedule is deterministic for a given [`BotConnectionInfo`], so
+/// multi-process deployments can calculate it independently on each host and
+/// use [`host_rounds`] to determine the shards to start locally. Round delays
+/// are relative to a start time shared by all hosts.
+///
+/// # Examples
+///
+/// Plan the identification of 32 shards with a maximum concurrency of 16:
+///
+/// ```
+/// use std::time::Duration;
+/// use twilight_model::gateway::{IdentifySchedule, SessionStartLimit};
+///
+/// let limit = SessionStartLimit {
+///     max_concurrency: 16,
+///     remaining: 1000,
+///     reset_after: 0,
+///     total: 1000,
+/// };
+/// let schedule = IdentifySchedule::new(32, &limit);
+///
+/// assert_eq!(2, schedule.rounds().len());
+/// assert_eq!(16..32, schedule.rounds()[1].shards);
+/// assert_eq!(Duration::from_secs(5), schedule.rounds()[1].delay);
+/// ```
+///
+/// [`host_rounds`]: Self::host_rounds
+/// [rounds]: IdentifyRound
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct IdentifySchedule {
+    max_concurrency: u64,
+    rounds: Vec<IdentifyRound>,
+    total: u64,
+}
+
+impl IdentifySchedule {
+    /// Plan the identification of a number of shards.
+    ///
+    /// A maximum concurrency or total number of session starts of zero is
+    /// treated as one.
+    pub fn new(total: u64, session_start_limit: &SessionStartLimit) -> Self {
+        let max_concurrency = session_start_limit.max_concurrency.max(1);
+        let reset_after = Duration::from_millis(session_start_limit.reset_after);
+        let session_starts = session_start_limit.total.max(1);
+
+        let mut rounds = Vec::new();
+        let mut remaining = session_start_limit.remaining;
+        let mut next_reset = reset_after;
+        let mut delay = Duration::ZERO;
+        let mut start = 0;
+
+        while start < total {
+            let mut count = max_concurrency.min(total - start);
+
+            if remaining < count {
+                delay = delay.max(next_reset);
+                next_reset = delay + Duration::from_secs(SESSION_RESET_INTERVAL_SECS);
+                remaining = session_starts;
+                count = count.min(remaining);
+            }
+
+            let end = start + count;
+            remaining -= count;
+            rounds.push(IdentifyRound {
+                delay,
+                shards: start..end,
+                total,
+            });
+
+            delay += IDENTIFY_INTERVAL;
+            start = end;
+        }
+
+        Self {
+            max_concurrency,
+            rounds,
+            total,
+        }
+    }
+
+    /// Plan the identification of the recommended number of shards.
+    pub fn from_connection_info(info: &BotConnectionInfo) -> Self {
+        Self::new(info.shards, &info.session_start_limit)
+    }
+
+    /// Maximum number of shards identifying concurrently.
+    pub const fn max_concurrency(&self) -> u64 {
+        self.max_concurrency
+    }
+
+    /// Rounds of shards in the order they identify.
+    pub fn rounds(&self) -> &[IdentifyRound] {
+        &self.rounds
+    }
+
+    /// Total number of shards.
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Range of shard numbers assigned to a host.
+    ///
+    /// Rounds are split as evenly as possible across hosts in contiguous
+    /// ranges, with earlier hosts receiving the additional rounds. Ranges
+    /// are aligned to rounds, so shards of one round are never split across
+    /// hosts.
+    ///
+    /// # Examples
+    ///
+    /// Split 48 shards with a maximum concurrency of 16 across two hosts:
+    ///
+    /// ```
+    /// use twilight_model::gateway::{IdentifySchedule, SessionStartLimit};
+    ///
+    /// let limit = SessionStartLimit {
+    ///     max_concurrency: 16,
+    ///     remaining: 1000,
+    ///     reset_after: 0,
+    ///     total: 1000,
+    /// };
+    /// let schedule = IdentifySchedule::new(48, &limit);
+    ///
+    /// assert_eq!(0..32, schedule.host_range(0, 2));
+    /// assert_eq!(32..48, schedule.host_range(1, 2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `host` is greater than or equal to `hosts`.
+    #[track_caller]
+    pub fn host_range(&self, host: u64, hosts: u64) -> Range<u64> {
+        let rounds = self.host_rounds(host, hosts);
+
+        match (rounds.first(), rounds.last()) {
+            (Some(first), Some(last)) => first.shards.start..last.shards.end,
+            _ => self.total..self.total,
+        }
+    }
+
+    /// Rounds assigned to a host.
+    ///
+    /// Refer to [`host_range`] for how rounds are split across hosts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `host` is greater than or equal to `hosts`.
+    ///
+    /// [`host_range`]: Self::host_range
+    #[track_caller]
+    pub fn host_rounds(&self, host: u64, hosts: u64) -> &[IdentifyRound] {
+        assert!(host < hosts, "host must be less than hosts");
+
+        let len = self.rounds.len();
+        let hosts = usize::try_from(hosts).unwrap_or(usize::MAX);
+        let host = usize::try_from(host).unwrap_or(usize::MAX);
+
+        let per_host = len / hosts;
+        let extra = len % hosts;
+        let start = host * per_host + host.min(extra);
+        let end = start + per_host + usize::from(host < extra);
+
+        &self.rounds[start.min(len)..end.min(len)]
+    }
+}
+
+impl From<&BotConnectionInfo> for IdentifySchedule {
+    fn from(info: &BotConnectionInfo) -> Self {
+        Self::from_connection_info(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IdentifySchedule, IDENTIFY_INTERVAL, SESSION_RESET_INTERVAL_SECS};
+    use crate::gateway::{connection_info::BotConnectionInfo, SessionStartLimit, ShardId};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash, time::Duration};
+
+    assert_impl_all!(
+        IdentifySchedule: Clone,
+        Debug,
+        Eq,
+        From<&'static BotConnectionInfo>,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    const fn limit(max_concurrency: u64, remaining: u64) -> SessionStartLimit {
+        SessionStartLimit {
+            max_concurrency,
+            remaining,
+            reset_after: 60_000,
+            total: 1000,
+        }
+    }
+
+    #[test]
+    fn rounds() {
+        let schedule = IdentifySchedule::new(40, &limit(16, 1000));
+        let rounds = schedule.rounds();
+
+        assert_eq!(3, rounds.len());
+        assert_eq!(0..16, rounds[0].shards);
+        assert_eq!(Duration::ZERO, rounds[0].delay);
+        assert_eq!(16..32, rounds[1].shards);
+        assert_eq!(IDENTIFY_INTERVAL, rounds[1].delay);
+        assert_eq!(32..40, rounds[2].shards);
+        assert_eq!(IDENTIFY_INTERVAL * 2, rounds[2].delay);
+
+        for round in rounds {
+            for (bucket, id) in round.shard_ids().enumerate() {
+                assert_eq!(40, id.total());
+                assert_eq!(bucket as u64, id.identify_bucket(16));
+            }
+        }
+
+        assert_eq!(Some(ShardId::new(39, 40)), rounds[2].shard_ids().last());
+    }
+
+    #[test]
+    fn exhausted_sessions() {
+        let limit = limit(1, 2);
+        let reset_after = Duration::from_millis(limit.reset_after);
+        let schedule = IdentifySchedule::new(4, &limit);
+        let delays = schedule
+            .rounds()
+            .iter()
+            .map(|round| round.delay)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            [
+                Duration::ZERO,
+                IDENTIFY_INTERVAL,
+                reset_after,
+                reset_after + IDENTIFY_INTERVAL,
+            ],
+            delays.as_slice()
+        );
+    }
+
+    #[test]
+    fn round_exceeding_session_starts() {
+        let limit = SessionStartLimit {
+            max_concurrency: 16,
+            remaining: 4,
+            reset_after: 60_000,
+            total: 10,
+        };
+        let schedule = IdentifySchedule::new(16, &limit);
+        let rounds = schedule.rounds();
+
+        assert_eq!(2, rounds.len());
+        assert_eq!(0..10, rounds[0].shards);
+        assert_eq!(Duration::from_millis(limit.reset_after), rounds[0].delay);
+        assert_eq!(10..16, rounds[1].shards);
+        assert!(
+            rounds[1].delay >= rounds[0].delay + Duration::from_secs(SESSION_RESET_INTERVAL_SECS)
+        );
+    }
+
+    #[test]
+    fn zero_concurrency() {
+        let schedule = IdentifySchedule::new(2, &limit(0, 1000));
+
+        assert_eq!(1, schedule.max_concurrency());
+        assert_eq!(2, schedule.rounds().len());
+    }
+
+    #[test]
+    fn from_connection_info() {
+        let info = BotConnectionInfo {
+            session_start_limit: limit(16, 1000),
+            shards: 20,
+            url: "wss://gateway.discord.gg".to_owned(),
+        };
+        let schedule = IdentifySchedule::from(&info);
+
+        assert_eq!(20, schedule.total());
+        assert_eq!(16, schedule.max_concurrency());
+        assert_eq!(2, schedule.rounds().len());
+    }
+
+    #[test]
+    fn hosts() {
+        let schedule = IdentifySchedule::new(80, &limit(16, 1000));
+
+        assert_eq!(0..32, schedule.host_range(0, 3));
+        assert_eq!(32..64, schedule.host_range(1, 3));
+        assert_eq!(64..80, schedule.host_range(2, 3));
+
+        let rounds = schedule.host_rounds(2, 3);
+        assert_eq!(1, rounds.len());
+        assert_eq!(IDENTIFY_INTERVAL * 4, rounds[0].delay);
+    }
+
+    #[test]
+    fn more_hosts_than_rounds() {
+        let schedule = IdentifySchedule::new(16, &limit(16, 1000));
+
+        assert_eq!(0..16, schedule.host_range(0, 2));
+        assert_eq!(16..16, schedule.host_range(1, 2));
+        assert!(schedule.host_rounds(1, 2).is_empty());
+    }
+
+    #[should_panic(expected = "host must be less than hosts")]
+    #[test]
+    fn host_out_of_range() {
+        IdentifySchedule::new(16, &limit(16, 1000)).host_range(2, 2);
+    }
+}
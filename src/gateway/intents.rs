@@ -98,9 +98,17 @@ bitflags! {
         ///
         /// Event(s) received:
         ///  - [`GUILD_EMOJIS_UPDATE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUND_CREATE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUND_DELETE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUND_UPDATE`]
+        ///  - [`GUILD_SOUNDBOARD_SOUNDS_UPDATE`]
         ///  - [`GUILD_STICKERS_UPDATE`]
         ///
         /// [`GUILD_EMOJIS_UPDATE`]: super::event::Event::GuildEmojisUpdate
+        /// [`GUILD_SOUNDBOARD_SOUND_CREATE`]: super::event::Event::GuildSoundboardSoundCreate
+        /// [`GUILD_SOUNDBOARD_SOUND_DELETE`]: super::event::Event::GuildSoundboardSoundDelete
+        /// [`GUILD_SOUNDBOARD_SOUND_UPDATE`]: super::event::Event::GuildSoundboardSoundUpdate
+        /// [`GUILD_SOUNDBOARD_SOUNDS_UPDATE`]: super::event::Event::GuildSoundboardSoundsUpdate
         /// [`GUILD_STICKERS_UPDATE`]: super::event::Event::GuildStickersUpdate
         const GUILD_EMOJIS_AND_STICKERS = 1 << 3;
         /// Guild integrations intent.
@@ -135,8 +143,10 @@ bitflags! {
         /// Guild voice states intent.
         ///
         /// Event(s) received:
+        ///  - [`VOICE_CHANNEL_EFFECT_SEND`]
         ///  - [`VOICE_STATE_UPDATE`]
         ///
+        /// [`VOICE_CHANNEL_EFFECT_SEND`]: super::event::Event::VoiceChannelEffectSend
         /// [`VOICE_STATE_UPDATE`]: super::event::Event::VoiceStateUpdate
         const GUILD_VOICE_STATES = 1 << 7;
         /// Guild presences intent.
@@ -271,6 +281,24 @@ bitflags! {
         ///
         /// [`AUTO_MODERATION_ACTION_EXECUTION`]: super::event::Event::AutoModerationActionExecution
         const AUTO_MODERATION_EXECUTION = 1 << 21;
+        /// Guild message polls intent.
+        ///
+        /// Event(s) received:
+        /// - [`MESSAGE_POLL_VOTE_ADD`]
+        /// - [`MESSAGE_POLL_VOTE_REMOVE`]
+        ///
+        /// [`MESSAGE_POLL_VOTE_ADD`]: super::event::Event::MessagePollVoteAdd
+        /// [`MESSAGE_POLL_VOTE_REMOVE`]: super::event::Event::MessagePollVoteRemove
+        const GUILD_MESSAGE_POLLS = 1 << 24;
+        /// Direct message polls intent.
+        ///
+        /// Event(s) received:
+        /// - [`MESSAGE_POLL_VOTE_ADD`]
+        /// - [`MESSAGE_POLL_VOTE_REMOVE`]
+        ///
+        /// [`MESSAGE_POLL_VOTE_ADD`]: super::event::Event::MessagePollVoteAdd
+        /// [`MESSAGE_POLL_VOTE_REMOVE`]: super::event::Event::MessagePollVoteRemove
+        const DIRECT_MESSAGE_POLLS = 1 << 25;
     }
 }
 
@@ -289,6 +317,17 @@ impl Serialize for Intents {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Intents {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Intents".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(deprecated)]
@@ -355,6 +394,8 @@ mod tests {
     const_assert_eq!(Intents::GUILD_SCHEDULED_EVENTS.bits(), 1 << 16);
     const_assert_eq!(Intents::AUTO_MODERATION_CONFIGURATION.bits(), 1 << 20);
     const_assert_eq!(Intents::AUTO_MODERATION_EXECUTION.bits(), 1 << 21);
+    const_assert_eq!(Intents::GUILD_MESSAGE_POLLS.bits(), 1 << 24);
+    const_assert_eq!(Intents::DIRECT_MESSAGE_POLLS.bits(), 1 << 25);
 
     #[test]
     fn serde() {
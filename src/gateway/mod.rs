@@ -1,4 +1,5 @@
 pub mod connection_info;
+pub mod etf;
 pub mod event;
 pub mod payload;
 pub mod presence;
@@ -6,6 +7,7 @@ pub mod presence;
 mod close_code;
 mod frame;
 mod id;
+mod identify_schedule;
 mod intents;
 mod opcode;
 mod reaction;
@@ -13,8 +15,9 @@ mod session_start_limit;
 
 pub use self::{
     close_code::{CloseCode, CloseCodeConversionError},
-    frame::CloseFrame,
+    frame::{CloseAction, CloseFrame, CloseFrameKind},
     id::{ShardId, ShardIdParseError, ShardIdParseErrorType},
+    identify_schedule::{IdentifyRound, IdentifySchedule, IDENTIFY_INTERVAL},
     intents::Intents,
     opcode::GatewayOpcode,
     reaction::GatewayReaction,
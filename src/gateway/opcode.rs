@@ -2,6 +2,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// Gateway event's payload type.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize_repr, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum GatewayOpcode {
@@ -37,6 +38,8 @@ pub enum GatewayOpcode {
     ///
     /// [`Heartbeat`]: Self::Heartbeat
     HeartbeatAck = 11,
+    /// Request the soundboard sounds of guilds.
+    RequestSoundboardSounds = 31,
 }
 
 impl GatewayOpcode {
@@ -56,6 +59,7 @@ impl GatewayOpcode {
             9 => Self::InvalidSession,
             10 => Self::Hello,
             11 => Self::HeartbeatAck,
+            31 => Self::RequestSoundboardSounds,
             _ => return None,
         })
     }
@@ -98,6 +102,7 @@ impl GatewayOpcode {
     /// - [`PresenceUpdate`]
     /// - [`Resume`]
     /// - [`RequestGuildMembers`]
+    /// - [`RequestSoundboardSounds`]
     /// - [`VoiceStateUpdate`]
     ///
     /// [`Heartbeat`]: Self::Heartbeat
@@ -105,6 +110,7 @@ impl GatewayOpcode {
     /// [`PresenceUpdate`]: Self::PresenceUpdate
     /// [`Resume`]: Self::Resume
     /// [`RequestGuildMembers`]: Self::RequestGuildMembers
+    /// [`RequestSoundboardSounds`]: Self::RequestSoundboardSounds
     /// [`VoiceStateUpdate`]: Self::VoiceStateUpdate
     pub const fn is_sent(self) -> bool {
         matches!(
@@ -114,6 +120,7 @@ impl GatewayOpcode {
                 | Self::PresenceUpdate
                 | Self::Resume
                 | Self::RequestGuildMembers
+                | Self::RequestSoundboardSounds
                 | Self::VoiceStateUpdate
         )
     }
@@ -151,6 +158,7 @@ mod tests {
         (GatewayOpcode::InvalidSession, 9, true, false),
         (GatewayOpcode::Hello, 10, true, false),
         (GatewayOpcode::HeartbeatAck, 11, true, false),
+        (GatewayOpcode::RequestSoundboardSounds, 31, false, true),
     ];
 
     #[test]
@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 /// [`Permissions::MANAGE_GUILD`]: crate::guild::Permissions::MANAGE_GUILD
 #[allow(clippy::doc_markdown)]
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutoModerationActionExecution {
     /// Action which was executed.
     pub action: AutoModerationAction,
@@ -146,16 +147,22 @@ mod tests {
                 Token::StructEnd,
                 Token::Str("alert_system_message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("content"),
                 Token::Str("darn"),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("matched_content"),
                 Token::Some,
@@ -165,15 +172,21 @@ mod tests {
                 Token::Str("darn"),
                 Token::Str("message_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("rule_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("5"),
                 Token::Str("rule_trigger_type"),
                 Token::U8(u8::from(AutoModerationTriggerType::Keyword)),
                 Token::Str("user_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("6"),
                 Token::StructEnd,
             ],
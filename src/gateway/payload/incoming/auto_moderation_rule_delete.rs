@@ -8,6 +8,7 @@ use std::ops::{Deref, DerefMut};
 ///
 /// [`Permissions::MANAGE_GUILD`]: crate::guild::Permissions::MANAGE_GUILD
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutoModerationRuleDelete(pub AutoModerationRule);
 
 impl Deref for AutoModerationRuleDelete {
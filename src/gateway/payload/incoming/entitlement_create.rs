@@ -0,0 +1,22 @@
+use crate::application::monetization::Entitlement;
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// Entitlement has been created, such as when a user subscribes to a SKU.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntitlementCreate(pub Entitlement);
+
+impl Deref for EntitlementCreate {
+    type Target = Entitlement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for EntitlementCreate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
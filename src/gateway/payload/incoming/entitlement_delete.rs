@@ -0,0 +1,22 @@
+use crate::application::monetization::Entitlement;
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// Entitlement has been deleted, such as when a purchase is refunded.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntitlementDelete(pub Entitlement);
+
+impl Deref for EntitlementDelete {
+    type Target = Entitlement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for EntitlementDelete {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,22 @@
+use crate::application::monetization::Entitlement;
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// Entitlement has been updated, such as when a subscription renews.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntitlementUpdate(pub Entitlement);
+
+impl Deref for EntitlementUpdate {
+    type Target = Entitlement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for EntitlementUpdate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -13,6 +13,7 @@ use std::ops::{Deref, DerefMut};
 /// [`Event`]:crate::gateway::event::Event
 /// [`VIEW_AUDIT_LOG`]: crate::guild::Permissions::VIEW_AUDIT_LOG
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildAuditLogEntryCreate(pub AuditLogEntry);
 
 impl Deref for GuildAuditLogEntryCreate {
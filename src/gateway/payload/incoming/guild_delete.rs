@@ -2,6 +2,7 @@ use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildDelete {
     pub id: Id<GuildMarker>,
     // If `unavailable` is `None` the user was removed from the guild.
@@ -35,7 +36,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
@@ -50,7 +53,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
@@ -74,7 +79,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(false),
@@ -89,7 +96,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::Bool(false),
@@ -113,7 +122,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123"),
                 Token::Str("unavailable"),
                 Token::None,
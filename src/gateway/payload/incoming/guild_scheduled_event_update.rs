@@ -6,6 +6,7 @@ use std::ops::{Deref, DerefMut};
 ///
 /// [`GuildScheduledEvent`]: crate::guild::scheduled_event::GuildScheduledEvent
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildScheduledEventUpdate(pub GuildScheduledEvent);
 
 impl Deref for GuildScheduledEventUpdate {
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// Sent when a user has subscribed to a guild scheduled event.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildScheduledEventUserAdd {
     /// Guild ID of the scheduled event.
     pub guild_id: Id<GuildMarker>,
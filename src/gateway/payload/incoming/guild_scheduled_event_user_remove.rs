@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// Sent when a user has unsubscribed from a guild scheduled event.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildScheduledEventUserRemove {
     /// Guild ID of the scheduled event.
     pub guild_id: Id<GuildMarker>,
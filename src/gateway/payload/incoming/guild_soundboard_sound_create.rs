@@ -0,0 +1,22 @@
+use crate::guild::SoundboardSound;
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// A guild soundboard sound was created.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GuildSoundboardSoundCreate(pub SoundboardSound);
+
+impl Deref for GuildSoundboardSoundCreate {
+    type Target = SoundboardSound;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for GuildSoundboardSoundCreate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,15 @@
+use crate::id::{
+    marker::{GuildMarker, SoundboardSoundMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// A guild soundboard sound was deleted.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GuildSoundboardSoundDelete {
+    /// ID of the guild the sound was in.
+    pub guild_id: Id<GuildMarker>,
+    /// ID of the deleted sound.
+    pub sound_id: Id<SoundboardSoundMarker>,
+}
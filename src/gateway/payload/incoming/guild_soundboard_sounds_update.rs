@@ -0,0 +1,15 @@
+use crate::{
+    guild::SoundboardSound,
+    id::{marker::GuildMarker, Id},
+};
+use serde::{Deserialize, Serialize};
+
+/// Multiple guild soundboard sounds were updated.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GuildSoundboardSoundsUpdate {
+    /// ID of the guild the sounds are in.
+    pub guild_id: Id<GuildMarker>,
+    /// Updated sounds.
+    pub soundboard_sounds: Vec<SoundboardSound>,
+}
@@ -5,6 +5,7 @@ use crate::id::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IntegrationDelete {
     /// ID of the Bot/OAuth2 application for this integration.
     #[serde(skip_serializing_if = "Option::is_none")]
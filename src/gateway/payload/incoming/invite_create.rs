@@ -15,6 +15,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Invite`]: crate::guild::invite::Invite
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InviteCreate {
     /// ID of the channel invited users will first see.
     pub channel_id: Id<ChannelMarker>,
@@ -53,6 +54,7 @@ pub struct InviteCreate {
 /// Information about the user whose stream to display for a voice channel
 /// stream invite.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PartialUser {
     /// Hash of the user's avatar.
     pub avatar: Option<ImageHash>,
@@ -66,6 +68,10 @@ pub struct PartialUser {
     /// integer. The field will always serialize into a string due to that being
     /// the type Discord's API uses.
     #[serde(with = "user::discriminator")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "user::discriminator::schema")
+    )]
     pub discriminator: u16,
     /// ID of the user.
     pub id: Id<UserMarker>,
@@ -154,14 +160,18 @@ mod tests {
                     len: 8,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("code"),
                 Token::Str("aaaaaaa"),
                 Token::Str("created_at"),
                 Token::Str("2021-01-01T00:00:00.000000+00:00"),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("max_age"),
                 Token::U64(3600),
@@ -198,7 +208,9 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0123"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("username"),
                 Token::Str("twilight"),
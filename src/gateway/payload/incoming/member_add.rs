@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MemberAdd {
     pub guild_id: Id<GuildMarker>,
     #[serde(flatten)]
@@ -82,7 +83,9 @@ mod tests {
             &[
                 Token::Map { len: None },
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("communication_disabled_until"),
                 Token::None,
@@ -119,7 +122,9 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("0987"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("username"),
                 Token::Str("ab"),
@@ -9,6 +9,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MemberUpdate {
     /// Member's guild avatar.
     pub avatar: Option<ImageHash>,
@@ -91,7 +92,9 @@ mod tests {
                 Token::Some,
                 Token::Str("2022-01-01T09:00:00.000000+00:00"),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1234"),
                 Token::Str("deaf"),
                 Token::Some,
@@ -133,7 +136,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("424242"),
                 Token::Str("username"),
                 Token::Str("Twilight Sparkle"),
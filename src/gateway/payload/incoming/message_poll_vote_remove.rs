@@ -0,0 +1,74 @@
+use crate::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// User removed their vote for an answer of a poll.
+///
+/// Requires [`Intents::GUILD_MESSAGE_POLLS`] for polls in guilds and
+/// [`Intents::DIRECT_MESSAGE_POLLS`] for polls in direct messages.
+///
+/// [`Intents::DIRECT_MESSAGE_POLLS`]: crate::gateway::Intents::DIRECT_MESSAGE_POLLS
+/// [`Intents::GUILD_MESSAGE_POLLS`]: crate::gateway::Intents::GUILD_MESSAGE_POLLS
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MessagePollVoteRemove {
+    /// ID of the answer.
+    pub answer_id: u8,
+    /// ID of the channel the poll's message is in.
+    pub channel_id: Id<ChannelMarker>,
+    /// ID of the guild the poll's message is in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the poll's message.
+    pub message_id: Id<MessageMarker>,
+    /// ID of the user.
+    pub user_id: Id<UserMarker>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessagePollVoteRemove;
+    use crate::id::Id;
+    use serde_test::Token;
+
+    #[test]
+    fn message_poll_vote_remove() {
+        let value = MessagePollVoteRemove {
+            answer_id: 2,
+            channel_id: Id::new(1),
+            guild_id: None,
+            message_id: Id::new(4),
+            user_id: Id::new(5),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessagePollVoteRemove",
+                    len: 4,
+                },
+                Token::Str("answer_id"),
+                Token::U8(2),
+                Token::Str("channel_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::Str("message_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("4"),
+                Token::Str("user_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("5"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
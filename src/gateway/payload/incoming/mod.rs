@@ -25,6 +25,9 @@ mod channel_delete;
 mod channel_pins_update;
 mod channel_update;
 mod command_permissions_update;
+mod entitlement_create;
+mod entitlement_delete;
+mod entitlement_update;
 mod guild_audit_log_entry_create;
 mod guild_create;
 mod guild_delete;
@@ -35,6 +38,10 @@ mod guild_scheduled_event_delete;
 mod guild_scheduled_event_update;
 mod guild_scheduled_event_user_add;
 mod guild_scheduled_event_user_remove;
+mod guild_soundboard_sound_create;
+mod guild_soundboard_sound_delete;
+mod guild_soundboard_sound_update;
+mod guild_soundboard_sounds_update;
 mod guild_stickers_update;
 mod guild_update;
 mod hello;
@@ -50,6 +57,8 @@ mod member_update;
 mod message_create;
 mod message_delete;
 mod message_delete_bulk;
+mod message_poll_vote_add;
+mod message_poll_vote_remove;
 mod message_update;
 mod presence_update;
 mod reaction_add;
@@ -59,6 +68,7 @@ mod ready;
 mod role_create;
 mod role_delete;
 mod role_update;
+mod soundboard_sounds;
 mod stage_instance_create;
 mod stage_instance_delete;
 mod stage_instance_update;
@@ -70,6 +80,7 @@ mod thread_members_update;
 mod thread_update;
 mod typing_start;
 mod user_update;
+mod voice_channel_effect_send;
 mod voice_server_update;
 mod voice_state_update;
 mod webhooks_update;
@@ -81,7 +92,8 @@ pub use self::{
     auto_moderation_rule_update::AutoModerationRuleUpdate, ban_add::BanAdd, ban_remove::BanRemove,
     channel_create::ChannelCreate, channel_delete::ChannelDelete,
     channel_pins_update::ChannelPinsUpdate, channel_update::ChannelUpdate,
-    command_permissions_update::CommandPermissionsUpdate,
+    command_permissions_update::CommandPermissionsUpdate, entitlement_create::EntitlementCreate,
+    entitlement_delete::EntitlementDelete, entitlement_update::EntitlementUpdate,
     guild_audit_log_entry_create::GuildAuditLogEntryCreate, guild_create::GuildCreate,
     guild_delete::GuildDelete, guild_emojis_update::GuildEmojisUpdate,
     guild_integrations_update::GuildIntegrationsUpdate,
@@ -90,21 +102,26 @@ pub use self::{
     guild_scheduled_event_update::GuildScheduledEventUpdate,
     guild_scheduled_event_user_add::GuildScheduledEventUserAdd,
     guild_scheduled_event_user_remove::GuildScheduledEventUserRemove,
+    guild_soundboard_sound_create::GuildSoundboardSoundCreate,
+    guild_soundboard_sound_delete::GuildSoundboardSoundDelete,
+    guild_soundboard_sound_update::GuildSoundboardSoundUpdate,
+    guild_soundboard_sounds_update::GuildSoundboardSoundsUpdate,
     guild_stickers_update::GuildStickersUpdate, guild_update::GuildUpdate, hello::Hello,
     integration_create::IntegrationCreate, integration_delete::IntegrationDelete,
     integration_update::IntegrationUpdate, interaction_create::InteractionCreate,
     invite_create::InviteCreate, invite_delete::InviteDelete, member_add::MemberAdd,
     member_chunk::MemberChunk, member_remove::MemberRemove, member_update::MemberUpdate,
     message_create::MessageCreate, message_delete::MessageDelete,
-    message_delete_bulk::MessageDeleteBulk, message_update::MessageUpdate,
+    message_delete_bulk::MessageDeleteBulk, message_poll_vote_add::MessagePollVoteAdd,
+    message_poll_vote_remove::MessagePollVoteRemove, message_update::MessageUpdate,
     presence_update::PresenceUpdate, reaction_add::ReactionAdd, reaction_remove::ReactionRemove,
     reaction_remove_all::ReactionRemoveAll, reaction_remove_emoji::ReactionRemoveEmoji,
     ready::Ready, role_create::RoleCreate, role_delete::RoleDelete, role_update::RoleUpdate,
-    stage_instance_create::StageInstanceCreate, stage_instance_delete::StageInstanceDelete,
-    stage_instance_update::StageInstanceUpdate, thread_create::ThreadCreate,
-    thread_delete::ThreadDelete, thread_list_sync::ThreadListSync,
+    soundboard_sounds::SoundboardSounds, stage_instance_create::StageInstanceCreate,
+    stage_instance_delete::StageInstanceDelete, stage_instance_update::StageInstanceUpdate,
+    thread_create::ThreadCreate, thread_delete::ThreadDelete, thread_list_sync::ThreadListSync,
     thread_member_update::ThreadMemberUpdate, thread_members_update::ThreadMembersUpdate,
-    thread_update::ThreadUpdate, typing_start::TypingStart,
-    user_update::UserUpdate, voice_server_update::VoiceServerUpdate,
+    thread_update::ThreadUpdate, typing_start::TypingStart, user_update::UserUpdate,
+    voice_channel_effect_send::VoiceChannelEffectSend, voice_server_update::VoiceServerUpdate,
     voice_state_update::VoiceStateUpdate, webhooks_update::WebhooksUpdate,
 };
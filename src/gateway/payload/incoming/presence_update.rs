@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 /// [`Intents::GUILD_PRESENCES`]: crate::gateway::Intents
 /// [Discord Docs/Presence Update]: https://discord.com/developers/docs/topics/gateway#presence-update
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PresenceUpdate {
     #[serde(default)]
     pub activities: Vec<Activity>,
@@ -5,6 +5,7 @@ use crate::id::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ReactionRemoveAll {
     pub channel_id: Id<ChannelMarker>,
     pub message_id: Id<MessageMarker>,
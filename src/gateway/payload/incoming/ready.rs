@@ -1,9 +1,8 @@
-use crate::{
-    gateway::ShardId, guild::UnavailableGuild, user::CurrentUser,
-};
+use crate::{gateway::ShardId, guild::UnavailableGuild, user::CurrentUser};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Ready {
     pub guilds: Vec<UnavailableGuild>,
     pub resume_gateway_url: String,
@@ -18,24 +17,15 @@ pub struct Ready {
 #[cfg(test)]
 mod tests {
     use super::Ready;
-    use crate::{
-        gateway::ShardId,
-        guild::UnavailableGuild,
-        id::Id,
-        user::CurrentUser,
-    };
+    use crate::{gateway::ShardId, guild::UnavailableGuild, id::Id, user::CurrentUser};
     use serde_test::Token;
 
     #[test]
     #[allow(clippy::too_many_lines)]
     fn ready() {
         let guilds = vec![
-            UnavailableGuild {
-                id: Id::new(1),
-            },
-            UnavailableGuild {
-                id: Id::new(2),
-            },
+            UnavailableGuild { id: Id::new(1) },
+            UnavailableGuild { id: Id::new(2) },
         ];
 
         let ready = Ready {
@@ -77,7 +67,9 @@ mod tests {
                 Token::Str("flags"),
                 Token::U64(0),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::StructEnd,
                 Token::Str("guilds"),
@@ -87,7 +79,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
@@ -97,7 +91,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("unavailable"),
                 Token::Bool(true),
@@ -129,7 +125,9 @@ mod tests {
                 Token::Str("discriminator"),
                 Token::Str("1212"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("mfa_enabled"),
                 Token::Bool(false),
@@ -0,0 +1,114 @@
+use crate::{
+    channel::message::ReactionType,
+    guild::SoundboardSoundVolume,
+    id::{
+        marker::{ChannelMarker, GuildMarker, SoundboardSoundMarker, UserMarker},
+        Id,
+    },
+    voice::VoiceChannelEffectAnimationType,
+};
+use serde::{Deserialize, Serialize};
+
+/// A user sent an effect, such as an emoji reaction or a soundboard sound, in
+/// a voice channel.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VoiceChannelEffectSend {
+    /// ID of the emoji animation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_id: Option<u64>,
+    /// Type of the emoji animation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_type: Option<VoiceChannelEffectAnimationType>,
+    /// ID of the voice channel the effect was sent in.
+    pub channel_id: Id<ChannelMarker>,
+    /// Emoji sent, or emoji of the sent soundboard sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// ID of the guild the effect was sent in.
+    pub guild_id: Id<GuildMarker>,
+    /// ID of the soundboard sound that was played.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound_id: Option<Id<SoundboardSoundMarker>>,
+    /// Volume of the soundboard sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound_volume: Option<SoundboardSoundVolume>,
+    /// ID of the user who sent the effect.
+    pub user_id: Id<UserMarker>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VoiceChannelEffectSend;
+    use crate::{
+        channel::message::ReactionType, guild::SoundboardSoundVolume, id::Id,
+        voice::VoiceChannelEffectAnimationType,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn voice_channel_effect_send() {
+        let value = VoiceChannelEffectSend {
+            animation_id: Some(5),
+            animation_type: Some(VoiceChannelEffectAnimationType::Basic),
+            channel_id: Id::new(1),
+            emoji: Some(ReactionType::Unicode {
+                name: "🦆".to_owned(),
+            }),
+            guild_id: Id::new(2),
+            sound_id: Some(Id::new(3)),
+            sound_volume: Some(SoundboardSoundVolume::new(0.5)),
+            user_id: Id::new(4),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "VoiceChannelEffectSend",
+                    len: 8,
+                },
+                Token::Str("animation_id"),
+                Token::Some,
+                Token::U64(5),
+                Token::Str("animation_type"),
+                Token::Some,
+                Token::U8(1),
+                Token::Str("channel_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("🦆"),
+                Token::StructEnd,
+                Token::Str("guild_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("2"),
+                Token::Str("sound_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("3"),
+                Token::Str("sound_volume"),
+                Token::Some,
+                Token::F64(0.5),
+                Token::Str("user_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("4"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
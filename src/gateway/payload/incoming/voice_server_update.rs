@@ -2,6 +2,7 @@ use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VoiceServerUpdate {
     /// Discord voice server endpoint.
     pub endpoint: Option<String>,
@@ -2,6 +2,7 @@ use crate::gateway::GatewayOpcode;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Heartbeat {
     pub d: Option<u64>,
     pub op: GatewayOpcode,
@@ -3,6 +3,7 @@ use crate::gateway::{intents::Intents, opcode::GatewayOpcode, ShardId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Identify {
     pub d: IdentifyInfo,
     pub op: GatewayOpcode,
@@ -18,6 +19,7 @@ impl Identify {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IdentifyInfo {
     pub compress: bool,
     pub intents: Intents,
@@ -29,6 +31,7 @@ pub struct IdentifyInfo {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IdentifyProperties {
     pub browser: String,
     pub device: String,
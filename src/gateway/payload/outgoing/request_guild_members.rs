@@ -71,6 +71,7 @@ pub enum UserIdsErrorType {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RequestGuildMembers {
     pub d: RequestGuildMembersInfo,
     pub op: GatewayOpcode,
@@ -270,6 +271,7 @@ impl RequestGuildMembersBuilder {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RequestGuildMembersInfo {
     /// Guild ID.
     pub guild_id: Id<GuildMarker>,
@@ -288,6 +290,7 @@ pub struct RequestGuildMembersInfo {
 
 /// One or a list of IDs in a request.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum RequestGuildMemberId<T> {
     /// Single ID specified.
@@ -0,0 +1,100 @@
+use crate::{
+    gateway::opcode::GatewayOpcode,
+    id::{marker::GuildMarker, Id},
+};
+use serde::{Deserialize, Serialize};
+
+/// Request the soundboard sounds of guilds.
+///
+/// Sounds are received as a [`SoundboardSounds`] event per guild.
+///
+/// [`SoundboardSounds`]: crate::gateway::payload::incoming::SoundboardSounds
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RequestSoundboardSounds {
+    /// Data of the request.
+    pub d: RequestSoundboardSoundsInfo,
+    /// Opcode of the payload, always
+    /// [`GatewayOpcode::RequestSoundboardSounds`].
+    pub op: GatewayOpcode,
+}
+
+impl RequestSoundboardSounds {
+    /// Create a new request for the soundboard sounds of guilds.
+    ///
+    /// # Examples
+    ///
+    /// Request the sounds of two guilds:
+    ///
+    /// ```
+    /// use twilight_model::{gateway::payload::outgoing::RequestSoundboardSounds, id::Id};
+    ///
+    /// let request = RequestSoundboardSounds::new([Id::new(1), Id::new(2)]);
+    ///
+    /// assert_eq!(2, request.d.guild_ids.len());
+    /// ```
+    pub fn new(guild_ids: impl Into<Vec<Id<GuildMarker>>>) -> Self {
+        Self {
+            d: RequestSoundboardSoundsInfo {
+                guild_ids: guild_ids.into(),
+            },
+            op: GatewayOpcode::RequestSoundboardSounds,
+        }
+    }
+}
+
+/// Data of a [`RequestSoundboardSounds`] payload.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RequestSoundboardSoundsInfo {
+    /// IDs of the guilds to request the sounds of.
+    pub guild_ids: Vec<Id<GuildMarker>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestSoundboardSounds, RequestSoundboardSoundsInfo};
+    use crate::{gateway::opcode::GatewayOpcode, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn request_soundboard_sounds() {
+        let value = RequestSoundboardSounds::new([Id::new(1)]);
+
+        assert_eq!(
+            RequestSoundboardSounds {
+                d: RequestSoundboardSoundsInfo {
+                    guild_ids: vec![Id::new(1)],
+                },
+                op: GatewayOpcode::RequestSoundboardSounds,
+            },
+            value
+        );
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RequestSoundboardSounds",
+                    len: 2,
+                },
+                Token::Str("d"),
+                Token::Struct {
+                    name: "RequestSoundboardSoundsInfo",
+                    len: 1,
+                },
+                Token::Str("guild_ids"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::Str("op"),
+                Token::U8(31),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -2,6 +2,7 @@ use crate::gateway::GatewayOpcode;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Resume {
     pub d: ResumeInfo,
     pub op: GatewayOpcode,
@@ -17,6 +18,7 @@ impl Resume {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ResumeInfo {
     pub seq: u64,
     pub session_id: String,
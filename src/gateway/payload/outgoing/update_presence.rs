@@ -61,6 +61,7 @@ pub enum UpdatePresenceErrorType {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdatePresence {
     pub d: UpdatePresencePayload,
     pub op: GatewayOpcode,
@@ -89,6 +90,7 @@ impl UpdatePresence {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdatePresencePayload {
     /// User's activities.
     ///
@@ -8,6 +8,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateVoiceState {
     pub d: UpdateVoiceStateInfo,
     pub op: GatewayOpcode,
@@ -28,6 +29,7 @@ impl UpdateVoiceState {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateVoiceStateInfo {
     pub channel_id: Option<Id<ChannelMarker>>,
     pub guild_id: Id<GuildMarker>,
@@ -181,8 +181,26 @@ impl Serialize for ActivityButton {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ActivityButton {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ActivityButton".into()
+    }
+
+    // Text buttons are their label.
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "anyOf": [
+                generator.subschema_for::<ActivityButtonLink>(),
+                { "type": "string" },
+            ],
+        })
+    }
+}
+
 /// Button used in an activity with a URL.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ActivityButtonLink {
     /// Text shown on the button.
     pub label: String,
@@ -209,6 +227,7 @@ pub struct ActivityButtonLink {
 /// # Ok(()) }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(transparent)]
 pub struct ActivityButtonText {
     /// Text shown on the button.
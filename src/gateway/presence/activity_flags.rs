@@ -33,6 +33,17 @@ impl Serialize for ActivityFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ActivityFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ActivityFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ActivityFlags;
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ActivityTimestamps {
     /// Unix time of when the activity started, in milliseconds.
     #[serde(skip_serializing_if = "Option::is_none")]
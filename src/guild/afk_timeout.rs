@@ -15,6 +15,7 @@ use std::time::Duration;
 ///
 /// [`Guild::afk_timeout`]: super::Guild::afk_timeout
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct AfkTimeout(u16);
 
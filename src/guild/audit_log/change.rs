@@ -1,4 +1,4 @@
-use super::change_key::AuditLogChangeKey;
+use super::{change_key::AuditLogChangeKey, AuditLogChangeDisplay};
 use crate::{
     application::command::permissions::GuildCommandPermissions,
     channel::{
@@ -18,6 +18,7 @@ use crate::{
     util::{ImageHash, Timestamp},
 };
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
 
 /// Minimal amount of information about an affected [role].
 ///
@@ -28,6 +29,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [role]: super::super::Role
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AffectedRole {
     /// ID of the role.
     pub id: Id<RoleMarker>,
@@ -37,6 +39,7 @@ pub struct AffectedRole {
 
 /// Value of a change which may be one of multiple types.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum AuditLogChangeTypeValue {
     /// Value is an unsigned integer.
@@ -49,6 +52,7 @@ pub enum AuditLogChangeTypeValue {
 ///
 /// [`AuditLogEntry`]: super::AuditLogEntry
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(rename_all = "snake_case", tag = "key")]
 pub enum AuditLogChange {
@@ -716,6 +720,31 @@ pub enum AuditLogChange {
 }
 
 impl AuditLogChange {
+    /// Display formatter to render the change as human-readable text.
+    ///
+    /// The formatter renders plain text without looking up names by default.
+    /// Refer to [`AuditLogChangeDisplay`] for rendering markdown and looking
+    /// up the names of referenced channels and users.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::guild::audit_log::{AuditLogChange, AuditLogFormat};
+    ///
+    /// let change = AuditLogChange::Nick {
+    ///     new: Some("new_name".to_owned()),
+    ///     old: Some("old_name".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "**Nickname**: old\\_name → new\\_name",
+    ///     change.display().format(AuditLogFormat::Markdown).to_string(),
+    /// );
+    /// ```
+    pub const fn display(&self) -> AuditLogChangeDisplay<'_, ()> {
+        AuditLogChangeDisplay::new(self)
+    }
+
     /// Key of an audit log change.
     ///
     /// This may return no key if the variant is [`Other`].
@@ -823,6 +852,12 @@ impl AuditLogChange {
     }
 }
 
+impl Display for AuditLogChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.display(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{super::AuditLogChangeKey, AffectedRole, AuditLogChange, AuditLogChangeTypeValue};
@@ -944,7 +979,9 @@ mod tests {
                 Token::String("afk_channel_id"),
                 Token::String("new_value"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::String("1"),
                 Token::StructEnd,
             ],
@@ -0,0 +1,1325 @@
+//! Human-readable rendering of [`AuditLogChange`]s and [`AuditLogEntry`]s.
+
+use super::{
+    AffectedRole, AuditLogChange, AuditLogChangeTypeValue, AuditLogEntry, AuditLogEventType,
+};
+use crate::{
+    channel::{
+        message::sticker::StickerFormatType,
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        stage_instance::PrivacyLevel,
+        thread::AutoArchiveDuration,
+    },
+    guild::{
+        DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, NSFWLevel, Permissions,
+        VerificationLevel,
+    },
+    id::{
+        marker::{
+            ApplicationMarker, ChannelMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker,
+        },
+        Id,
+    },
+    util::{ImageHash, Timestamp},
+};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+
+/// Readable names of permissions, in the order of their bits.
+const PERMISSION_NAMES: &[(Permissions, &str)] = &[
+    (Permissions::CREATE_INVITE, "Create Invite"),
+    (Permissions::KICK_MEMBERS, "Kick Members"),
+    (Permissions::BAN_MEMBERS, "Ban Members"),
+    (Permissions::ADMINISTRATOR, "Administrator"),
+    (Permissions::MANAGE_CHANNELS, "Manage Channels"),
+    (Permissions::MANAGE_GUILD, "Manage Server"),
+    (Permissions::ADD_REACTIONS, "Add Reactions"),
+    (Permissions::VIEW_AUDIT_LOG, "View Audit Log"),
+    (Permissions::PRIORITY_SPEAKER, "Priority Speaker"),
+    (Permissions::STREAM, "Video"),
+    (Permissions::VIEW_CHANNEL, "View Channel"),
+    (Permissions::SEND_MESSAGES, "Send Messages"),
+    (
+        Permissions::SEND_TTS_MESSAGES,
+        "Send Text-to-Speech Messages",
+    ),
+    (Permissions::MANAGE_MESSAGES, "Manage Messages"),
+    (Permissions::EMBED_LINKS, "Embed Links"),
+    (Permissions::ATTACH_FILES, "Attach Files"),
+    (Permissions::READ_MESSAGE_HISTORY, "Read Message History"),
+    (Permissions::MENTION_EVERYONE, "Mention Everyone"),
+    (Permissions::USE_EXTERNAL_EMOJIS, "Use External Emojis"),
+    (Permissions::VIEW_GUILD_INSIGHTS, "View Server Insights"),
+    (Permissions::CONNECT, "Connect"),
+    (Permissions::SPEAK, "Speak"),
+    (Permissions::MUTE_MEMBERS, "Mute Members"),
+    (Permissions::DEAFEN_MEMBERS, "Deafen Members"),
+    (Permissions::MOVE_MEMBERS, "Move Members"),
+    (Permissions::USE_VAD, "Use Voice Activity"),
+    (Permissions::CHANGE_NICKNAME, "Change Nickname"),
+    (Permissions::MANAGE_NICKNAMES, "Manage Nicknames"),
+    (Permissions::MANAGE_ROLES, "Manage Roles"),
+    (Permissions::MANAGE_WEBHOOKS, "Manage Webhooks"),
+    (Permissions::MANAGE_GUILD_EXPRESSIONS, "Manage Expressions"),
+    (Permissions::USE_SLASH_COMMANDS, "Use Application Commands"),
+    (Permissions::REQUEST_TO_SPEAK, "Request to Speak"),
+    (Permissions::MANAGE_EVENTS, "Manage Events"),
+    (Permissions::MANAGE_THREADS, "Manage Threads"),
+    (Permissions::CREATE_PUBLIC_THREADS, "Create Public Threads"),
+    (
+        Permissions::CREATE_PRIVATE_THREADS,
+        "Create Private Threads",
+    ),
+    (Permissions::USE_EXTERNAL_STICKERS, "Use External Stickers"),
+    (
+        Permissions::SEND_MESSAGES_IN_THREADS,
+        "Send Messages in Threads",
+    ),
+    (Permissions::USE_EMBEDDED_ACTIVITIES, "Use Activities"),
+    (Permissions::MODERATE_MEMBERS, "Timeout Members"),
+    (
+        Permissions::VIEW_CREATOR_MONETIZATION_ANALYTICS,
+        "View Creator Monetization Analytics",
+    ),
+    (Permissions::USE_SOUNDBOARD, "Use Soundboard"),
+    (Permissions::USE_EXTERNAL_SOUNDS, "Use External Sounds"),
+    (Permissions::SEND_VOICE_MESSAGES, "Send Voice Messages"),
+    (Permissions::SEND_POLLS, "Create Polls"),
+];
+
+/// Format of rendered audit log text.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum AuditLogFormat {
+    /// Discord markdown.
+    ///
+    /// Names are bold and escaped, and channels, roles and users without a
+    /// known name are mentions.
+    Markdown,
+    /// Plain text.
+    ///
+    /// Channels, roles, users and other entities without a known name are
+    /// their ID.
+    #[default]
+    Plain,
+}
+
+/// Lookup of the names of entities referenced by ID in audit logs.
+///
+/// All methods return no name by default. `()` looks up no names, while
+/// [`AuditLogTimeline`] looks up the names of entities included in the audit
+/// logs it was built from.
+///
+/// [`AuditLogTimeline`]: super::AuditLogTimeline
+pub trait AuditLogNames {
+    /// Name of a channel or thread.
+    fn channel(&self, channel_id: Id<ChannelMarker>) -> Option<&str> {
+        let _ = channel_id;
+
+        None
+    }
+
+    /// Name of another entity, such as a webhook, emoji or scheduled event.
+    fn entity(&self, entity_id: Id<GenericMarker>) -> Option<&str> {
+        let _ = entity_id;
+
+        None
+    }
+
+    /// Name of a role.
+    fn role(&self, role_id: Id<RoleMarker>) -> Option<&str> {
+        let _ = role_id;
+
+        None
+    }
+
+    /// Name of a user.
+    fn user(&self, user_id: Id<UserMarker>) -> Option<&str> {
+        let _ = user_id;
+
+        None
+    }
+}
+
+impl AuditLogNames for () {}
+
+/// Display implementation to render an [`AuditLogChange`].
+///
+/// Changes are rendered as their label followed by their previous and new
+/// values, such as "Bitrate: 64000 → 96000". Permission changes list the added
+/// and removed permissions and role changes list the affected roles.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::guild::{audit_log::AuditLogChange, Permissions};
+///
+/// let bitrate = AuditLogChange::Bitrate {
+///     new: Some(96_000),
+///     old: Some(64_000),
+/// };
+/// assert_eq!("Bitrate: 64000 → 96000", bitrate.to_string());
+///
+/// let permissions = AuditLogChange::Permissions {
+///     new: Some(Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS),
+///     old: Some(Permissions::KICK_MEMBERS | Permissions::ADMINISTRATOR),
+/// };
+/// assert_eq!(
+///     "Permissions: added Ban Members; removed Administrator",
+///     permissions.to_string(),
+/// );
+/// ```
+#[derive(Debug)]
+pub struct AuditLogChangeDisplay<'a, N> {
+    change: &'a AuditLogChange,
+    format: AuditLogFormat,
+    names: &'a N,
+}
+
+impl<'a> AuditLogChangeDisplay<'a, ()> {
+    /// Create a new plain text display formatter for a change.
+    pub(super) const fn new(change: &'a AuditLogChange) -> Self {
+        Self {
+            change,
+            format: AuditLogFormat::Plain,
+            names: &(),
+        }
+    }
+}
+
+impl<'a, N: AuditLogNames> AuditLogChangeDisplay<'a, N> {
+    /// Get the inner change.
+    pub const fn get(&self) -> &'a AuditLogChange {
+        self.change
+    }
+
+    /// Set the format to render the change in.
+    ///
+    /// Defaults to [`AuditLogFormat::Plain`].
+    #[must_use]
+    pub const fn format(mut self, format: AuditLogFormat) -> Self {
+        self.format = format;
+
+        self
+    }
+
+    /// Set the lookup of names of referenced channels and users.
+    ///
+    /// Defaults to looking up no names.
+    pub const fn names<M: AuditLogNames>(self, names: &'a M) -> AuditLogChangeDisplay<'a, M> {
+        AuditLogChangeDisplay {
+            change: self.change,
+            format: self.format,
+            names,
+        }
+    }
+}
+
+impl<N: AuditLogNames> Display for AuditLogChangeDisplay<'_, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut writer = Writer {
+            f,
+            format: self.format,
+            names: self.names,
+        };
+
+        writer.change(self.change)
+    }
+}
+
+/// Display implementation to render a summary of an [`AuditLogEntry`].
+///
+/// Entries are rendered as who performed which action on what, followed by the
+/// reason for the action and a line for each of the entry's changes.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{
+///     guild::audit_log::{AuditLogEntry, AuditLogNames},
+///     id::{marker::UserMarker, Id},
+/// };
+///
+/// struct Names;
+///
+/// impl AuditLogNames for Names {
+///     fn user(&self, user_id: Id<UserMarker>) -> Option<&str> {
+///         Some(if user_id.get() == 1 { "moderator" } else { "spammer" })
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let entry: AuditLogEntry = serde_json::from_str(
+///     r#"{
+///         "action_type": 20,
+///         "id": "3",
+///         "reason": "spam",
+///         "target_id": "2",
+///         "user_id": "1"
+///     }"#,
+/// )?;
+///
+/// assert_eq!(
+///     "moderator kicked spammer (reason: spam)",
+///     entry.display().names(&Names).to_string(),
+/// );
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct AuditLogEntryDisplay<'a, N> {
+    entry: &'a AuditLogEntry,
+    format: AuditLogFormat,
+    names: &'a N,
+}
+
+impl<'a> AuditLogEntryDisplay<'a, ()> {
+    /// Create a new plain text display formatter for an entry.
+    pub(super) const fn new(entry: &'a AuditLogEntry) -> Self {
+        Self {
+            entry,
+            format: AuditLogFormat::Plain,
+            names: &(),
+        }
+    }
+}
+
+impl<'a, N: AuditLogNames> AuditLogEntryDisplay<'a, N> {
+    /// Get the inner entry.
+    pub const fn get(&self) -> &'a AuditLogEntry {
+        self.entry
+    }
+
+    /// Set the format to render the entry in.
+    ///
+    /// Defaults to [`AuditLogFormat::Plain`].
+    #[must_use]
+    pub const fn format(mut self, format: AuditLogFormat) -> Self {
+        self.format = format;
+
+        self
+    }
+
+    /// Set the lookup of names of the entry's user, target and referenced
+    /// entities.
+    ///
+    /// Defaults to looking up no names.
+    pub const fn names<M: AuditLogNames>(self, names: &'a M) -> AuditLogEntryDisplay<'a, M> {
+        AuditLogEntryDisplay {
+            entry: self.entry,
+            format: self.format,
+            names,
+        }
+    }
+}
+
+impl<N: AuditLogNames> Display for AuditLogEntryDisplay<'_, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut writer = Writer {
+            f,
+            format: self.format,
+            names: self.names,
+        };
+
+        writer.entry(self.entry)
+    }
+}
+
+/// Kind of entity targeted by an audit log entry.
+#[derive(Clone, Copy)]
+enum Target {
+    Channel,
+    Entity,
+    None,
+    Role,
+    User,
+}
+
+/// Description of the action of an event type and the kind of its target.
+const fn action(event_type: AuditLogEventType) -> (&'static str, Target) {
+    match event_type {
+        AuditLogEventType::GuildUpdate => ("updated the server", Target::None),
+        AuditLogEventType::ChannelCreate => ("created channel", Target::Channel),
+        AuditLogEventType::ChannelUpdate => ("updated channel", Target::Channel),
+        AuditLogEventType::ChannelDelete => ("deleted channel", Target::Channel),
+        AuditLogEventType::ChannelOverwriteCreate => {
+            ("created a permission overwrite in", Target::Channel)
+        }
+        AuditLogEventType::ChannelOverwriteUpdate => {
+            ("updated a permission overwrite in", Target::Channel)
+        }
+        AuditLogEventType::ChannelOverwriteDelete => {
+            ("deleted a permission overwrite in", Target::Channel)
+        }
+        AuditLogEventType::MemberKick => ("kicked", Target::User),
+        AuditLogEventType::MemberPrune => ("pruned members", Target::None),
+        AuditLogEventType::MemberBanAdd => ("banned", Target::User),
+        AuditLogEventType::MemberBanRemove => ("unbanned", Target::User),
+        AuditLogEventType::MemberUpdate => ("updated member", Target::User),
+        AuditLogEventType::MemberRoleUpdate => ("updated the roles of", Target::User),
+        AuditLogEventType::MemberMove => ("moved members", Target::None),
+        AuditLogEventType::MemberDisconnect => ("disconnected members", Target::None),
+        AuditLogEventType::BotAdd => ("added bot", Target::User),
+        AuditLogEventType::RoleCreate => ("created role", Target::Role),
+        AuditLogEventType::RoleUpdate => ("updated role", Target::Role),
+        AuditLogEventType::RoleDelete => ("deleted role", Target::Role),
+        AuditLogEventType::InviteCreate => ("created an invite", Target::None),
+        AuditLogEventType::InviteUpdate => ("updated an invite", Target::None),
+        AuditLogEventType::InviteDelete => ("deleted an invite", Target::None),
+        AuditLogEventType::WebhookCreate => ("created webhook", Target::Entity),
+        AuditLogEventType::WebhookUpdate => ("updated webhook", Target::Entity),
+        AuditLogEventType::WebhookDelete => ("deleted webhook", Target::Entity),
+        AuditLogEventType::EmojiCreate => ("created emoji", Target::Entity),
+        AuditLogEventType::EmojiUpdate => ("updated emoji", Target::Entity),
+        AuditLogEventType::EmojiDelete => ("deleted emoji", Target::Entity),
+        AuditLogEventType::MessageDelete => ("deleted a message by", Target::User),
+        AuditLogEventType::MessageBulkDelete => ("bulk deleted messages in", Target::Channel),
+        AuditLogEventType::MessagePin => ("pinned a message by", Target::User),
+        AuditLogEventType::MessageUnpin => ("unpinned a message by", Target::User),
+        AuditLogEventType::IntegrationCreate => ("created integration", Target::Entity),
+        AuditLogEventType::IntegrationUpdate => ("updated integration", Target::Entity),
+        AuditLogEventType::IntegrationDelete => ("deleted integration", Target::Entity),
+        AuditLogEventType::StageInstanceCreate => ("started stage instance", Target::Entity),
+        AuditLogEventType::StageInstanceUpdate => ("updated stage instance", Target::Entity),
+        AuditLogEventType::StageInstanceDelete => ("ended stage instance", Target::Entity),
+        AuditLogEventType::StickerCreate => ("created sticker", Target::Entity),
+        AuditLogEventType::StickerUpdate => ("updated sticker", Target::Entity),
+        AuditLogEventType::StickerDelete => ("deleted sticker", Target::Entity),
+        AuditLogEventType::GuildScheduledEventCreate => ("created scheduled event", Target::Entity),
+        AuditLogEventType::GuildScheduledEventUpdate => ("updated scheduled event", Target::Entity),
+        AuditLogEventType::GuildScheduledEventDelete => ("deleted scheduled event", Target::Entity),
+        AuditLogEventType::ThreadCreate => ("created thread", Target::Channel),
+        AuditLogEventType::ThreadUpdate => ("updated thread", Target::Channel),
+        AuditLogEventType::ThreadDelete => ("deleted thread", Target::Channel),
+        AuditLogEventType::ApplicationCommandPermissionUpdate => {
+            ("updated the permissions of command", Target::Entity)
+        }
+        AuditLogEventType::AutoModerationRuleCreate => ("created AutoMod rule", Target::Entity),
+        AuditLogEventType::AutoModerationRuleUpdate => ("updated AutoMod rule", Target::Entity),
+        AuditLogEventType::AutoModerationRuleDelete => ("deleted AutoMod rule", Target::Entity),
+        AuditLogEventType::AutoModerationBlockMessage => ("blocked a message by", Target::User),
+        AuditLogEventType::AutoModerationFlagToChannel => ("flagged a message by", Target::User),
+        AuditLogEventType::AutoModerationUserCommunicationDisabled => ("timed out", Target::User),
+        AuditLogEventType::CreatorMonetizationRequestCreated => {
+            ("created a creator monetization request", Target::None)
+        }
+        AuditLogEventType::CreatorMonetizationTermsAccepted => {
+            ("accepted the creator monetization terms", Target::None)
+        }
+        AuditLogEventType::Unknown(_) => ("performed an unknown action on", Target::Entity),
+    }
+}
+
+/// Value of a change which may be rendered.
+trait Value {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult;
+}
+
+impl<T: Value> Value for &T {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        (*self).write(writer)
+    }
+}
+
+impl Value for AffectedRole {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        writer.name(Some(&self.name), Some("@&"), self.id.get())
+    }
+}
+
+impl Value for AuditLogChangeTypeValue {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        match self {
+            Self::Unsigned(value) => value.write(writer),
+            Self::String(value) => value.write(writer),
+        }
+    }
+}
+
+impl Value for AutoArchiveDuration {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        Display::fmt(&self.number(), writer.f)?;
+
+        writer.f.write_str(" minutes")
+    }
+}
+
+impl Value for bool {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        writer.f.write_str(if *self { "yes" } else { "no" })
+    }
+}
+
+impl Value for Id<ChannelMarker> {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        writer.name(writer.names.channel(*self), Some("#"), self.get())
+    }
+}
+
+impl Value for Id<UserMarker> {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        writer.name(writer.names.user(*self), Some("@"), self.get())
+    }
+}
+
+impl Value for ImageHash {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        writer.code(self)
+    }
+}
+
+impl Value for String {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        writer.text(self)
+    }
+}
+
+impl Value for Timestamp {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        match writer.format {
+            AuditLogFormat::Markdown => {
+                writer.f.write_str("<t:")?;
+                Display::fmt(&self.as_secs(), writer.f)?;
+
+                writer.f.write_str(":f>")
+            }
+            AuditLogFormat::Plain => {
+                Display::fmt(&self.iso_8601().with_microseconds(false), writer.f)
+            }
+        }
+    }
+}
+
+impl Value for u64 {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        Display::fmt(self, writer.f)
+    }
+}
+
+/// Implement [`Value`] for IDs that are rendered as is.
+macro_rules! id_value {
+    ($($marker: ty),+) => {
+        $(
+            impl Value for Id<$marker> {
+                fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+                    writer.code(self)
+                }
+            }
+        )+
+    };
+}
+
+id_value!(ApplicationMarker, GenericMarker, GuildMarker);
+
+/// Implement [`Value`] for enums that are rendered as the lowercase words of
+/// their variant names.
+macro_rules! words_value {
+    ($($kind: ty),+) => {
+        $(
+            impl Value for $kind {
+                fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+                    writer.words(self)
+                }
+            }
+        )+
+    };
+}
+
+words_value!(
+    DefaultMessageNotificationLevel,
+    ExplicitContentFilter,
+    MfaLevel,
+    NSFWLevel,
+    PrivacyLevel,
+    StickerFormatType,
+    VerificationLevel
+);
+
+/// Color rendered as a hex code.
+struct Color(u64);
+
+impl Value for Color {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        write!(writer.f, "#{:06x}", self.0)
+    }
+}
+
+/// Writer of rendered audit log text.
+struct Writer<'w, 'f, N> {
+    f: &'w mut Formatter<'f>,
+    format: AuditLogFormat,
+    names: &'w N,
+}
+
+impl<N: AuditLogNames> Writer<'_, '_, N> {
+    /// Write an entry's summary, reason and changes.
+    fn entry(&mut self, entry: &AuditLogEntry) -> FmtResult {
+        match entry.user_id {
+            Some(user_id) => user_id.write(self)?,
+            None => self.f.write_str("Someone")?,
+        }
+
+        let (action, target) = action(entry.action_type);
+        self.f.write_str(" ")?;
+        self.f.write_str(action)?;
+
+        if let Some(target_id) = entry.target_id {
+            self.f.write_str(" ")?;
+
+            match target {
+                Target::Channel => target_id.cast::<ChannelMarker>().write(self)?,
+                Target::Entity => {
+                    self.name(self.names.entity(target_id), None, target_id.get())?;
+                }
+                Target::None => {}
+                Target::Role => {
+                    let role_id = target_id.cast();
+                    self.name(self.names.role(role_id), Some("@&"), role_id.get())?;
+                }
+                Target::User => target_id.cast::<UserMarker>().write(self)?,
+            }
+        }
+
+        if let Some(reason) = &entry.reason {
+            self.f.write_str(" (reason: ")?;
+            self.text(reason)?;
+            self.f.write_str(")")?;
+        }
+
+        for change in &entry.changes {
+            self.f.write_str("\n- ")?;
+            self.change(change)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a change's label and values.
+    #[allow(clippy::too_many_lines)]
+    fn change(&mut self, change: &AuditLogChange) -> FmtResult {
+        match change {
+            AuditLogChange::AfkChannelId { new, old } => {
+                self.values("AFK channel", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::AfkTimeout { new, old } => {
+                self.values("AFK timeout", Some(old), Some(new))
+            }
+            AuditLogChange::Allow { new, old } => {
+                self.permissions("Allowed permissions", *old, *new)
+            }
+            AuditLogChange::ApplicationId { new, old } => {
+                self.values("Application", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Archived { new, old } => {
+                self.values("Archived", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Asset { new, old } => self.values("Asset", old.as_ref(), new.as_ref()),
+            AuditLogChange::AutoArchiveDuration { new, old } => {
+                self.values("Auto archive duration", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Available { new, old } => {
+                self.values("Available", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::AvatarHash { new, old } => {
+                self.values("Avatar", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::BannerHash { new, old } => {
+                self.values("Banner", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Bitrate { new, old } => {
+                self.values("Bitrate", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::ChannelId { new, old } => {
+                self.values("Channel", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Code { new, old } => {
+                self.values("Invite code", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Color { new, old } => {
+                self.values("Color", old.map(Color), new.map(Color))
+            }
+            AuditLogChange::CommandId { new, old } => {
+                self.label("Command permissions")?;
+                self.f.write_str("updated")?;
+
+                if let Some(command_id) = new.as_ref().or(old.as_ref()).map(|command| command.id) {
+                    self.f.write_str(" for command ")?;
+                    command_id.cast::<GenericMarker>().write(self)?;
+                }
+
+                Ok(())
+            }
+            AuditLogChange::CommunicationDisabledUntil { new, old } => {
+                self.values("Timed out until", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Deaf { new, old } => {
+                self.values("Deafened", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::DefaultAutoArchiveDuration { new, old } => {
+                self.values("Default auto archive duration", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::DefaultMessageNotifications { new, old } => {
+                self.values("Default notifications", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Deny { new, old } => self.permissions("Denied permissions", *old, *new),
+            AuditLogChange::Description { new, old } => {
+                self.values("Description", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::DiscoverySplashHash { new, old } => {
+                self.values("Discovery splash", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::EnableEmoticons { new, old } => {
+                self.values("Emoticons enabled", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::EntityType { new, old } => {
+                self.values("Entity type", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::ExpireBehavior { new, old } => {
+                self.values("Expire behavior", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::ExpireGracePeriod { new, old } => {
+                self.values("Expire grace period", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::ExplicitContentFilter { new, old } => {
+                self.values("Explicit content filter", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::FormatType { new, old } => {
+                self.values("Format", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::GuildId { new, old } => {
+                self.values("Server", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Hoist { new, old } => {
+                self.values("Displayed separately", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::IconHash { new, old } => {
+                self.values("Icon", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Id { new, old } => self.values("ID", old.as_ref(), new.as_ref()),
+            AuditLogChange::ImageHash { new, old } => {
+                self.values("Image", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Invitable { new, old } => {
+                self.values("Invitable", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::InviterId { new, old } => {
+                self.values("Inviter", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Location { new, old } => {
+                self.values("Location", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Locked { new, old } => {
+                self.values("Locked", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::MaxAge { new, old } => {
+                self.values("Max age", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::MaxUses { new, old } => {
+                self.values("Max uses", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Mentionable { new, old } => {
+                self.values("Mentionable", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::MfaLevel { new, old } => {
+                self.values("MFA level", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Mute { new, old } => self.values("Muted", old.as_ref(), new.as_ref()),
+            AuditLogChange::Name { new, old } => self.values("Name", old.as_ref(), new.as_ref()),
+            AuditLogChange::Nick { new, old } => {
+                self.values("Nickname", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Nsfw { new, old } => {
+                self.values("Age-restricted", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::NsfwLevel { new, old } => {
+                self.values("NSFW level", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::OwnerId { new, old } => {
+                self.values("Owner", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::PermissionOverwrites { new, old } => self.overwrites(
+                old.as_deref().unwrap_or_default(),
+                new.as_deref().unwrap_or_default(),
+            ),
+            AuditLogChange::Permissions { new, old } => self.permissions("Permissions", *old, *new),
+            AuditLogChange::Position { new, old } => {
+                self.values("Position", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::PreferredLocale { new, old } => {
+                self.values("Preferred locale", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::PrivacyLevel { new, old } => {
+                self.values("Privacy level", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::PruneDeleteDays { new, old } => {
+                self.values("Prune days", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::PublicUpdatesChannelId { new, old } => {
+                self.values("Community updates channel", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::RateLimitPerUser { new, old } => {
+                self.values("Slowmode", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Region { new, old } => {
+                self.values("Region", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::RoleAdded { new, .. } => {
+                self.label("Roles added")?;
+
+                self.list(new)
+            }
+            AuditLogChange::RoleRemoved { new, .. } => {
+                self.label("Roles removed")?;
+
+                self.list(new)
+            }
+            AuditLogChange::RulesChannelId { new, old } => {
+                self.values("Rules channel", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::SplashHash { new, old } => {
+                self.values("Invite splash", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Status { new, old } => {
+                self.values("Status", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::SystemChannelId { new, old } => {
+                self.values("System channel", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Tags { new, old } => self.values("Tags", old.as_ref(), new.as_ref()),
+            AuditLogChange::Temporary { new, old } => {
+                self.values("Temporary", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Topic { new, old } => self.values("Topic", old.as_ref(), new.as_ref()),
+            AuditLogChange::Type { new, old } => self.values("Type", old.as_ref(), new.as_ref()),
+            AuditLogChange::UnicodeEmoji { new, old } => {
+                self.values("Emoji", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::UserLimit { new, old } => {
+                self.values("User limit", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Uses { new, old } => self.values("Uses", old.as_ref(), new.as_ref()),
+            AuditLogChange::VanityUrlCode { new, old } => {
+                self.values("Vanity URL code", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::VerificationLevel { new, old } => {
+                self.values("Verification level", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::WidgetChannelId { new, old } => {
+                self.values("Widget channel", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::WidgetEnabled { new, old } => {
+                self.values("Widget enabled", old.as_ref(), new.as_ref())
+            }
+            AuditLogChange::Other => self.f.write_str("Other change"),
+        }
+    }
+
+    /// Write a value formatted as code.
+    fn code(&mut self, value: &dyn Display) -> FmtResult {
+        match self.format {
+            AuditLogFormat::Markdown => write!(self.f, "`{value}`"),
+            AuditLogFormat::Plain => Display::fmt(value, self.f),
+        }
+    }
+
+    /// Write a change's label and the separator from its value.
+    fn label(&mut self, label: &str) -> FmtResult {
+        match self.format {
+            AuditLogFormat::Markdown => {
+                self.f.write_str("**")?;
+                self.f.write_str(label)?;
+                self.f.write_str("**: ")
+            }
+            AuditLogFormat::Plain => {
+                self.f.write_str(label)?;
+                self.f.write_str(": ")
+            }
+        }
+    }
+
+    /// Write a comma separated list of values, or "none" if there are none.
+    fn list<T: Value>(&mut self, values: impl IntoIterator<Item = T>) -> FmtResult {
+        let mut values = values.into_iter().peekable();
+
+        if values.peek().is_none() {
+            return self.none();
+        }
+
+        while let Some(value) = values.next() {
+            value.write(self)?;
+
+            if values.peek().is_some() {
+                self.f.write_str(", ")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the name of an entity, falling back to a mention or ID.
+    ///
+    /// Entities without a mention prefix fall back to their ID in both
+    /// formats.
+    fn name(&mut self, name: Option<&str>, mention: Option<&str>, id: u64) -> FmtResult {
+        match (name, self.format, mention) {
+            (Some(name), AuditLogFormat::Markdown, _) => {
+                self.f.write_str("**")?;
+                self.text(name)?;
+                self.f.write_str("**")
+            }
+            (Some(name), AuditLogFormat::Plain, _) => self.f.write_str(name),
+            (None, AuditLogFormat::Markdown, Some(mention)) => {
+                write!(self.f, "<{mention}{id}>")
+            }
+            (None, _, _) => self.code(&id),
+        }
+    }
+
+    /// Write the absence of a value.
+    fn none(&mut self) -> FmtResult {
+        self.f.write_str(match self.format {
+            AuditLogFormat::Markdown => "*none*",
+            AuditLogFormat::Plain => "none",
+        })
+    }
+
+    /// Write the targets of added, removed and updated permission overwrites.
+    fn overwrites(
+        &mut self,
+        old: &[PermissionOverwrite],
+        new: &[PermissionOverwrite],
+    ) -> FmtResult {
+        self.label("Permission overwrites")?;
+
+        let added = new
+            .iter()
+            .filter(|overwrite| !old.iter().any(|other| other.id == overwrite.id));
+        let removed = old
+            .iter()
+            .filter(|overwrite| !new.iter().any(|other| other.id == overwrite.id));
+        let updated = new.iter().filter(|overwrite| {
+            old.iter()
+                .any(|other| other.id == overwrite.id && other != *overwrite)
+        });
+
+        let mut written = false;
+
+        for (description, overwrites) in [
+            ("added ", added.collect::<Vec<_>>()),
+            ("removed ", removed.collect()),
+            ("updated ", updated.collect()),
+        ] {
+            if overwrites.is_empty() {
+                continue;
+            }
+
+            if written {
+                self.f.write_str("; ")?;
+            }
+
+            self.f.write_str(description)?;
+            self.list(overwrites.into_iter().map(Overwrite))?;
+            written = true;
+        }
+
+        if !written {
+            self.f.write_str("unchanged")?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a permission change as the added and removed permissions.
+    fn permissions(
+        &mut self,
+        label: &str,
+        old: Option<Permissions>,
+        new: Option<Permissions>,
+    ) -> FmtResult {
+        self.label(label)?;
+
+        let (old, new) = match (old, new) {
+            (Some(old), Some(new)) => (old, new),
+            (None, Some(new)) => return self.permission_list(new),
+            (Some(old), None) => {
+                self.permission_list(old)?;
+                self.f.write_str(" → ")?;
+
+                return self.none();
+            }
+            (None, None) => return self.none(),
+        };
+
+        let added = new - old;
+        let removed = old - new;
+
+        if added.is_empty() && removed.is_empty() {
+            return self.f.write_str("unchanged");
+        }
+
+        if !added.is_empty() {
+            self.f.write_str("added ")?;
+            self.permission_list(added)?;
+        }
+
+        if !added.is_empty() && !removed.is_empty() {
+            self.f.write_str("; ")?;
+        }
+
+        if !removed.is_empty() {
+            self.f.write_str("removed ")?;
+            self.permission_list(removed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the names of a set of permissions.
+    fn permission_list(&mut self, permissions: Permissions) -> FmtResult {
+        self.list(
+            PERMISSION_NAMES
+                .iter()
+                .filter(|(permission, _)| permissions.contains(*permission))
+                .map(|(_, name)| Label(name)),
+        )
+    }
+
+    /// Write user provided text, escaping markdown and breaking up mentions
+    /// so that they don't ping when the text is sent.
+    ///
+    /// Headings and lists are only formatted at the start of a line, so `#`
+    /// and `-` are only escaped there.
+    fn text(&mut self, text: &str) -> FmtResult {
+        if self.format == AuditLogFormat::Plain {
+            return self.f.write_str(text);
+        }
+
+        let mut line_start = true;
+
+        for character in text.chars() {
+            match character {
+                '\\' | '*' | '_' | '~' | '`' | '|' | '>' | '<' | '[' => self.f.write_char('\\')?,
+                '#' | '-' if line_start => self.f.write_char('\\')?,
+                _ => {}
+            }
+
+            self.f.write_char(character)?;
+
+            // A zero-width space keeps `@everyone`, `@here` and user and role
+            // mentions from being parsed.
+            if character == '@' {
+                self.f.write_char('\u{200B}')?;
+            }
+
+            line_start = character == '\n' || (line_start && character.is_whitespace());
+        }
+
+        Ok(())
+    }
+
+    /// Write a change's previous and new values.
+    ///
+    /// Only the new value is written if there is no previous value.
+    fn values<T: Value>(&mut self, label: &str, old: Option<T>, new: Option<T>) -> FmtResult {
+        self.label(label)?;
+
+        match (old, new) {
+            (Some(old), Some(new)) => {
+                old.write(self)?;
+                self.f.write_str(" → ")?;
+                new.write(self)
+            }
+            (None, Some(new)) => new.write(self),
+            (Some(old), None) => {
+                old.write(self)?;
+                self.f.write_str(" → ")?;
+                self.none()
+            }
+            (None, None) => self.none(),
+        }
+    }
+
+    /// Write the variant name of an enum as lowercase words.
+    fn words(&mut self, value: &dyn Debug) -> FmtResult {
+        let name = format!("{value:?}");
+        let mut previous_lowercase = false;
+
+        for character in name.chars() {
+            if character.is_uppercase() && previous_lowercase {
+                self.f.write_char(' ')?;
+            }
+
+            previous_lowercase = character.is_lowercase();
+
+            for lowercase in character.to_lowercase() {
+                self.f.write_char(lowercase)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Static text rendered as is.
+struct Label(&'static str);
+
+impl Value for Label {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        writer.f.write_str(self.0)
+    }
+}
+
+/// Target of a permission overwrite.
+struct Overwrite<'a>(&'a PermissionOverwrite);
+
+impl Value for Overwrite<'_> {
+    fn write<N: AuditLogNames>(&self, writer: &mut Writer<'_, '_, N>) -> FmtResult {
+        match self.0.kind {
+            PermissionOverwriteType::Member => self.0.id.cast::<UserMarker>().write(writer),
+            PermissionOverwriteType::Role => {
+                let role_id = self.0.id.cast();
+
+                writer.name(writer.names.role(role_id), Some("@&"), role_id.get())
+            }
+            _ => self.0.id.write(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditLogChangeDisplay, AuditLogEntryDisplay, AuditLogFormat, AuditLogNames};
+    use crate::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        guild::{
+            audit_log::{AffectedRole, AuditLogChange, AuditLogEntry},
+            Permissions, VerificationLevel,
+        },
+        id::{
+            marker::{ChannelMarker, RoleMarker, UserMarker},
+            Id,
+        },
+        util::Timestamp,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::{Debug, Display};
+
+    assert_impl_all!(AuditLogChangeDisplay<'_, ()>: Debug, Display, Send, Sync);
+    assert_impl_all!(AuditLogEntryDisplay<'_, ()>: Debug, Display, Send, Sync);
+    assert_impl_all!(AuditLogFormat: Clone, Copy, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    struct Names;
+
+    impl AuditLogNames for Names {
+        fn channel(&self, channel_id: Id<ChannelMarker>) -> Option<&str> {
+            (channel_id.get() == 1).then_some("general")
+        }
+
+        fn role(&self, role_id: Id<RoleMarker>) -> Option<&str> {
+            (role_id.get() == 2).then_some("mods")
+        }
+
+        fn user(&self, user_id: Id<UserMarker>) -> Option<&str> {
+            (user_id.get() == 3).then_some("some_user")
+        }
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(
+            "Bitrate: 64000 → 96000",
+            AuditLogChange::Bitrate {
+                new: Some(96_000),
+                old: Some(64_000),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Name: general",
+            AuditLogChange::Name {
+                new: Some("general".to_owned()),
+                old: None,
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Topic: rules → none",
+            AuditLogChange::Topic {
+                new: None,
+                old: Some("rules".to_owned()),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Color: #000000 → #ff00aa",
+            AuditLogChange::Color {
+                new: Some(0x00ff_00aa),
+                old: Some(0),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Verification level: very high",
+            AuditLogChange::VerificationLevel {
+                new: Some(VerificationLevel::VeryHigh),
+                old: None,
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Timed out until: 2021-08-10T11:16:37+00:00",
+            AuditLogChange::CommunicationDisabledUntil {
+                new: Some(Timestamp::from_secs(1_628_594_197).unwrap()),
+                old: None,
+            }
+            .to_string()
+        );
+        assert_eq!("Other change", AuditLogChange::Other.to_string());
+    }
+
+    #[test]
+    fn permissions() {
+        assert_eq!(
+            "Permissions: added Kick Members, Ban Members; removed Administrator",
+            AuditLogChange::Permissions {
+                new: Some(Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS),
+                old: Some(Permissions::ADMINISTRATOR),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Denied permissions: unchanged",
+            AuditLogChange::Deny {
+                new: Some(Permissions::SPEAK),
+                old: Some(Permissions::SPEAK),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Allowed permissions: Connect, Speak",
+            AuditLogChange::Allow {
+                new: Some(Permissions::CONNECT | Permissions::SPEAK),
+                old: None,
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn roles_and_overwrites() {
+        let change = AuditLogChange::RoleAdded {
+            new: vec![
+                AffectedRole {
+                    id: Id::new(2),
+                    name: "mods".to_owned(),
+                },
+                AffectedRole {
+                    id: Id::new(4),
+                    name: "helpers".to_owned(),
+                },
+            ],
+            old: Vec::new(),
+        };
+        assert_eq!("Roles added: mods, helpers", change.to_string());
+        assert_eq!(
+            "**Roles added**: **mods**, **helpers**",
+            change
+                .display()
+                .format(AuditLogFormat::Markdown)
+                .to_string()
+        );
+
+        let overwrite = |id, kind| PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::SEND_MESSAGES,
+            id: Id::new(id),
+            kind,
+        };
+        let change = AuditLogChange::PermissionOverwrites {
+            new: Some(vec![overwrite(2, PermissionOverwriteType::Role)]),
+            old: Some(vec![overwrite(3, PermissionOverwriteType::Member)]),
+        };
+        assert_eq!(
+            "Permission overwrites: added mods; removed some_user",
+            change.display().names(&Names).to_string()
+        );
+    }
+
+    #[test]
+    fn names() {
+        let change = AuditLogChange::AfkChannelId {
+            new: Some(Id::new(1)),
+            old: Some(Id::new(5)),
+        };
+
+        assert_eq!("AFK channel: 5 → 1", change.to_string());
+        assert_eq!(
+            "AFK channel: 5 → general",
+            change.display().names(&Names).to_string()
+        );
+        assert_eq!(
+            "**AFK channel**: <#5> → **general**",
+            change
+                .display()
+                .names(&Names)
+                .format(AuditLogFormat::Markdown)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn entry() {
+        let entry: AuditLogEntry = serde_json::from_str(
+            r#"{
+                "action_type": 22,
+                "changes": [{"key": "nick", "new_value": "*new*"}],
+                "id": "10",
+                "reason": "rule 1",
+                "target_id": "6",
+                "user_id": "3"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            "some_user banned 6 (reason: rule 1)\n- Nickname: *new*",
+            entry.display().names(&Names).to_string()
+        );
+        assert_eq!(
+            "**some\\_user** banned <@6> (reason: rule 1)\n- **Nickname**: \\*new\\*",
+            entry
+                .display()
+                .names(&Names)
+                .format(AuditLogFormat::Markdown)
+                .to_string()
+        );
+        assert_eq!(entry.display().to_string(), entry.to_string());
+    }
+
+    #[test]
+    fn mentions() {
+        let change = AuditLogChange::RoleAdded {
+            new: vec![AffectedRole {
+                id: Id::new(5),
+                name: "@everyone".to_owned(),
+            }],
+            old: Vec::new(),
+        };
+        assert_eq!(
+            "**Roles added**: **@\u{200B}everyone**",
+            change
+                .display()
+                .format(AuditLogFormat::Markdown)
+                .to_string()
+        );
+
+        let change = AuditLogChange::Name {
+            new: Some("# <@&2> [click](https://example.com)\n- @here".to_owned()),
+            old: None,
+        };
+        assert_eq!(
+            "**Name**: \\# \\<@\u{200B}&2\\> \\[click](https://example.com)\n\\- @\u{200B}here",
+            change
+                .display()
+                .format(AuditLogFormat::Markdown)
+                .to_string()
+        );
+    }
+}
@@ -1,14 +1,19 @@
-use super::{AuditLogChange, AuditLogEventType, AuditLogOptionalEntryInfo};
+use super::{
+    AuditLogChange, AuditLogEntryDisplay, AuditLogEntryInfo, AuditLogEntryInfoError,
+    AuditLogEventType, AuditLogOptionalEntryInfo,
+};
 use crate::id::{
     marker::{AuditLogEntryMarker, GenericMarker, GuildMarker, UserMarker},
     Id,
 };
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
 
 /// Entry in an [`AuditLog`] possibly containing a number of detailed changes.
 ///
 /// [`AuditLog`]: super::AuditLog
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AuditLogEntry {
     /// Type of event to cause the entry.
     pub action_type: AuditLogEventType,
@@ -40,6 +45,75 @@ pub struct AuditLogEntry {
     pub user_id: Option<Id<UserMarker>>,
 }
 
+impl AuditLogEntry {
+    /// Display formatter to render a summary of the entry as human-readable
+    /// text.
+    ///
+    /// The formatter renders plain text without looking up names by default.
+    /// Refer to [`AuditLogEntryDisplay`] for an example of rendering markdown
+    /// and looking up the names of the entry's user and target.
+    pub const fn display(&self) -> AuditLogEntryDisplay<'_, ()> {
+        AuditLogEntryDisplay::new(self)
+    }
+
+    /// Typed additional information of the entry for its [type of event].
+    ///
+    /// Returns `None` if the type of event has no additional information.
+    ///
+    /// # Examples
+    ///
+    /// Determine the number of members removed by a prune:
+    ///
+    /// ```
+    /// use twilight_model::guild::audit_log::{AuditLogEntry, AuditLogEntryInfo};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let entry: AuditLogEntry = serde_json::from_str(
+    ///     r#"{
+    ///         "action_type": 21,
+    ///         "id": "1",
+    ///         "options": {
+    ///             "delete_member_days": "7",
+    ///             "members_removed": "42"
+    ///         },
+    ///         "target_id": null,
+    ///         "user_id": "2"
+    ///     }"#,
+    /// )?;
+    ///
+    /// assert!(matches!(
+    ///     entry.info()?,
+    ///     Some(AuditLogEntryInfo::MemberPrune {
+    ///         delete_member_days: 7,
+    ///         members_removed: 42,
+    ///     }),
+    /// ));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`FieldMissing`] if a field required by the
+    /// type of event is missing, including when the entry has no additional
+    /// information.
+    ///
+    /// Returns an error of type [`FieldInvalid`] if a field's value could not
+    /// be parsed.
+    ///
+    /// [`FieldInvalid`]: super::AuditLogEntryInfoErrorType::FieldInvalid
+    /// [`FieldMissing`]: super::AuditLogEntryInfoErrorType::FieldMissing
+    /// [type of event]: Self::action_type
+    pub fn info(&self) -> Result<Option<AuditLogEntryInfo>, AuditLogEntryInfoError> {
+        AuditLogEntryInfo::parse(self.action_type, self.options.as_ref())
+    }
+}
+
+impl Display for AuditLogEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.display(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -113,18 +187,24 @@ mod tests {
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("reason"),
                 Token::Some,
                 Token::Str("some reason"),
                 Token::Str("target_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("user_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
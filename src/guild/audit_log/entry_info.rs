@@ -0,0 +1,587 @@
+use super::{AuditLogEventType, AuditLogOptionalEntryInfo};
+use crate::{
+    channel::permission_overwrite::PermissionOverwriteType,
+    guild::auto_moderation::AutoModerationTriggerType,
+    id::{
+        marker::{ChannelMarker, GenericMarker, MessageMarker},
+        Id,
+    },
+};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// Additional information of an [`AuditLogOptionalEntryInfo`] could not be
+/// converted into an [`AuditLogEntryInfo`].
+#[derive(Debug)]
+pub struct AuditLogEntryInfoError {
+    kind: AuditLogEntryInfoErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl AuditLogEntryInfoError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AuditLogEntryInfoErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        AuditLogEntryInfoErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for AuditLogEntryInfoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            AuditLogEntryInfoErrorType::FieldInvalid { event_type, name } => {
+                f.write_str("field `")?;
+                f.write_str(name)?;
+                f.write_str("` of ")?;
+                Debug::fmt(event_type, f)?;
+
+                f.write_str(" entry info is invalid")
+            }
+            AuditLogEntryInfoErrorType::FieldMissing { event_type, name } => {
+                f.write_str("field `")?;
+                f.write_str(name)?;
+                f.write_str("` of ")?;
+                Debug::fmt(event_type, f)?;
+
+                f.write_str(" entry info is missing")
+            }
+        }
+    }
+}
+
+impl Error for AuditLogEntryInfoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`AuditLogEntryInfoError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AuditLogEntryInfoErrorType {
+    /// Field's value could not be parsed.
+    ///
+    /// The error's source contains the parsing error.
+    FieldInvalid {
+        /// Type of event the entry is for.
+        event_type: AuditLogEventType,
+        /// Name of the field.
+        name: &'static str,
+    },
+    /// Field required by the type of event is missing.
+    FieldMissing {
+        /// Type of event the entry is for.
+        event_type: AuditLogEventType,
+        /// Name of the field.
+        name: &'static str,
+    },
+}
+
+/// Typed additional information of an [`AuditLogEntry`], determined by its
+/// [`AuditLogEventType`].
+///
+/// Created via [`AuditLogEntry::info`] or
+/// [`AuditLogOptionalEntryInfo::typed`], parsing fields that Discord sends as
+/// strings.
+///
+/// [`AuditLogEntry`]: super::AuditLogEntry
+/// [`AuditLogEntry::info`]: super::AuditLogEntry::info
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AuditLogEntryInfo {
+    /// Message was blocked by an Auto Moderation rule.
+    AutoModerationBlockMessage {
+        /// Channel in which the message was sent.
+        channel_id: Id<ChannelMarker>,
+        /// Name of the rule that was triggered.
+        rule_name: String,
+        /// Trigger type of the rule that was triggered.
+        trigger_type: AutoModerationTriggerType,
+    },
+    /// Message was flagged by an Auto Moderation rule.
+    AutoModerationFlagToChannel {
+        /// Channel in which the message was sent.
+        channel_id: Id<ChannelMarker>,
+        /// Name of the rule that was triggered.
+        rule_name: String,
+        /// Trigger type of the rule that was triggered.
+        trigger_type: AutoModerationTriggerType,
+    },
+    /// Member was timed out by an Auto Moderation rule.
+    AutoModerationUserCommunicationDisabled {
+        /// Channel in which the member's message was sent.
+        channel_id: Id<ChannelMarker>,
+        /// Name of the rule that was triggered.
+        rule_name: String,
+        /// Trigger type of the rule that was triggered.
+        trigger_type: AutoModerationTriggerType,
+    },
+    /// Permission overwrite was added to a channel.
+    ChannelOverwriteCreate {
+        /// ID of the overwritten role or member.
+        id: Id<GenericMarker>,
+        /// Type of the overwritten entity.
+        kind: PermissionOverwriteType,
+        /// Name of the overwritten role, if the entity is a role.
+        role_name: Option<String>,
+    },
+    /// Permission overwrite was removed from a channel.
+    ChannelOverwriteDelete {
+        /// ID of the overwritten role or member.
+        id: Id<GenericMarker>,
+        /// Type of the overwritten entity.
+        kind: PermissionOverwriteType,
+        /// Name of the overwritten role, if the entity is a role.
+        role_name: Option<String>,
+    },
+    /// Permission overwrite of a channel was updated.
+    ChannelOverwriteUpdate {
+        /// ID of the overwritten role or member.
+        id: Id<GenericMarker>,
+        /// Type of the overwritten entity.
+        kind: PermissionOverwriteType,
+        /// Name of the overwritten role, if the entity is a role.
+        role_name: Option<String>,
+    },
+    /// Members were disconnected from a voice channel.
+    MemberDisconnect {
+        /// Number of disconnected members.
+        count: u64,
+    },
+    /// Members were moved to a voice channel.
+    MemberMove {
+        /// Channel the members were moved to.
+        channel_id: Id<ChannelMarker>,
+        /// Number of moved members.
+        count: u64,
+    },
+    /// Inactive members were pruned.
+    MemberPrune {
+        /// Number of days of inactivity after which members were pruned.
+        delete_member_days: u32,
+        /// Number of pruned members.
+        members_removed: u64,
+    },
+    /// Messages were deleted in bulk.
+    MessageBulkDelete {
+        /// Number of deleted messages.
+        count: u64,
+    },
+    /// Messages of a single user were deleted.
+    MessageDelete {
+        /// Channel the messages were deleted from.
+        channel_id: Id<ChannelMarker>,
+        /// Number of deleted messages.
+        count: u64,
+    },
+    /// Message was pinned.
+    MessagePin {
+        /// Channel of the message.
+        channel_id: Id<ChannelMarker>,
+        /// ID of the message.
+        message_id: Id<MessageMarker>,
+    },
+    /// Message was unpinned.
+    MessageUnpin {
+        /// Channel of the message.
+        channel_id: Id<ChannelMarker>,
+        /// ID of the message.
+        message_id: Id<MessageMarker>,
+    },
+    /// Stage instance was created.
+    StageInstanceCreate {
+        /// Stage channel of the instance.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Stage instance was deleted.
+    StageInstanceDelete {
+        /// Stage channel of the instance.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Stage instance was updated.
+    StageInstanceUpdate {
+        /// Stage channel of the instance.
+        channel_id: Id<ChannelMarker>,
+    },
+}
+
+impl AuditLogEntryInfo {
+    /// Type of event the information is for.
+    pub const fn event_type(&self) -> AuditLogEventType {
+        match self {
+            Self::AutoModerationBlockMessage { .. } => {
+                AuditLogEventType::AutoModerationBlockMessage
+            }
+            Self::AutoModerationFlagToChannel { .. } => {
+                AuditLogEventType::AutoModerationFlagToChannel
+            }
+            Self::AutoModerationUserCommunicationDisabled { .. } => {
+                AuditLogEventType::AutoModerationUserCommunicationDisabled
+            }
+            Self::ChannelOverwriteCreate { .. } => AuditLogEventType::ChannelOverwriteCreate,
+            Self::ChannelOverwriteDelete { .. } => AuditLogEventType::ChannelOverwriteDelete,
+            Self::ChannelOverwriteUpdate { .. } => AuditLogEventType::ChannelOverwriteUpdate,
+            Self::MemberDisconnect { .. } => AuditLogEventType::MemberDisconnect,
+            Self::MemberMove { .. } => AuditLogEventType::MemberMove,
+            Self::MemberPrune { .. } => AuditLogEventType::MemberPrune,
+            Self::MessageBulkDelete { .. } => AuditLogEventType::MessageBulkDelete,
+            Self::MessageDelete { .. } => AuditLogEventType::MessageDelete,
+            Self::MessagePin { .. } => AuditLogEventType::MessagePin,
+            Self::MessageUnpin { .. } => AuditLogEventType::MessageUnpin,
+            Self::StageInstanceCreate { .. } => AuditLogEventType::StageInstanceCreate,
+            Self::StageInstanceDelete { .. } => AuditLogEventType::StageInstanceDelete,
+            Self::StageInstanceUpdate { .. } => AuditLogEventType::StageInstanceUpdate,
+        }
+    }
+
+    /// Parse the possibly absent additional information of an entry for a
+    /// type of event.
+    pub(super) fn parse(
+        event_type: AuditLogEventType,
+        options: Option<&AuditLogOptionalEntryInfo>,
+    ) -> Result<Option<Self>, AuditLogEntryInfoError> {
+        let fields = Fields {
+            event_type,
+            options,
+        };
+
+        Ok(Some(match event_type {
+            AuditLogEventType::AutoModerationBlockMessage => Self::AutoModerationBlockMessage {
+                channel_id: fields.channel_id()?,
+                rule_name: fields.rule_name()?,
+                trigger_type: fields.trigger_type()?,
+            },
+            AuditLogEventType::AutoModerationFlagToChannel => Self::AutoModerationFlagToChannel {
+                channel_id: fields.channel_id()?,
+                rule_name: fields.rule_name()?,
+                trigger_type: fields.trigger_type()?,
+            },
+            AuditLogEventType::AutoModerationUserCommunicationDisabled => {
+                Self::AutoModerationUserCommunicationDisabled {
+                    channel_id: fields.channel_id()?,
+                    rule_name: fields.rule_name()?,
+                    trigger_type: fields.trigger_type()?,
+                }
+            }
+            AuditLogEventType::ChannelOverwriteCreate => Self::ChannelOverwriteCreate {
+                id: fields.id()?,
+                kind: fields.kind()?,
+                role_name: fields.role_name(),
+            },
+            AuditLogEventType::ChannelOverwriteDelete => Self::ChannelOverwriteDelete {
+                id: fields.id()?,
+                kind: fields.kind()?,
+                role_name: fields.role_name(),
+            },
+            AuditLogEventType::ChannelOverwriteUpdate => Self::ChannelOverwriteUpdate {
+                id: fields.id()?,
+                kind: fields.kind()?,
+                role_name: fields.role_name(),
+            },
+            AuditLogEventType::MemberDisconnect => Self::MemberDisconnect {
+                count: fields.count()?,
+            },
+            AuditLogEventType::MemberMove => Self::MemberMove {
+                channel_id: fields.channel_id()?,
+                count: fields.count()?,
+            },
+            AuditLogEventType::MemberPrune => Self::MemberPrune {
+                delete_member_days: fields.integer(
+                    "delete_member_days",
+                    fields.options.and_then(|o| o.delete_member_days.as_deref()),
+                )?,
+                members_removed: fields.integer(
+                    "members_removed",
+                    fields.options.and_then(|o| o.members_removed.as_deref()),
+                )?,
+            },
+            AuditLogEventType::MessageBulkDelete => Self::MessageBulkDelete {
+                count: fields.count()?,
+            },
+            AuditLogEventType::MessageDelete => Self::MessageDelete {
+                channel_id: fields.channel_id()?,
+                count: fields.count()?,
+            },
+            AuditLogEventType::MessagePin => Self::MessagePin {
+                channel_id: fields.channel_id()?,
+                message_id: fields.message_id()?,
+            },
+            AuditLogEventType::MessageUnpin => Self::MessageUnpin {
+                channel_id: fields.channel_id()?,
+                message_id: fields.message_id()?,
+            },
+            AuditLogEventType::StageInstanceCreate => Self::StageInstanceCreate {
+                channel_id: fields.channel_id()?,
+            },
+            AuditLogEventType::StageInstanceDelete => Self::StageInstanceDelete {
+                channel_id: fields.channel_id()?,
+            },
+            AuditLogEventType::StageInstanceUpdate => Self::StageInstanceUpdate {
+                channel_id: fields.channel_id()?,
+            },
+            _ => return Ok(None),
+        }))
+    }
+}
+
+/// Accessor of the fields of additional information for a type of event.
+struct Fields<'a> {
+    event_type: AuditLogEventType,
+    options: Option<&'a AuditLogOptionalEntryInfo>,
+}
+
+impl Fields<'_> {
+    fn channel_id(&self) -> Result<Id<ChannelMarker>, AuditLogEntryInfoError> {
+        self.required("channel_id", self.options.and_then(|o| o.channel_id))
+    }
+
+    fn count(&self) -> Result<u64, AuditLogEntryInfoError> {
+        self.integer("count", self.options.and_then(|o| o.count.as_deref()))
+    }
+
+    fn id(&self) -> Result<Id<GenericMarker>, AuditLogEntryInfoError> {
+        self.required("id", self.options.and_then(|o| o.id))
+    }
+
+    fn kind(&self) -> Result<PermissionOverwriteType, AuditLogEntryInfoError> {
+        self.integer::<u8>("type", self.options.and_then(|o| o.kind.as_deref()))
+            .map(PermissionOverwriteType::from)
+    }
+
+    fn message_id(&self) -> Result<Id<MessageMarker>, AuditLogEntryInfoError> {
+        self.required("message_id", self.options.and_then(|o| o.message_id))
+    }
+
+    fn role_name(&self) -> Option<String> {
+        self.options.and_then(|o| o.role_name.clone())
+    }
+
+    fn rule_name(&self) -> Result<String, AuditLogEntryInfoError> {
+        self.required(
+            "auto_moderation_rule_name",
+            self.options
+                .and_then(|o| o.auto_moderation_rule_name.clone()),
+        )
+    }
+
+    fn trigger_type(&self) -> Result<AutoModerationTriggerType, AuditLogEntryInfoError> {
+        self.integer::<u8>(
+            "auto_moderation_rule_trigger_type",
+            self.options
+                .and_then(|o| o.auto_moderation_rule_trigger_type.as_deref()),
+        )
+        .map(AutoModerationTriggerType::from)
+    }
+
+    /// Parse a field containing a stringified integer.
+    fn integer<T: FromStr>(
+        &self,
+        name: &'static str,
+        value: Option<&str>,
+    ) -> Result<T, AuditLogEntryInfoError>
+    where
+        T::Err: Error + Send + Sync + 'static,
+    {
+        self.required(name, value)?
+            .parse()
+            .map_err(|source| AuditLogEntryInfoError {
+                kind: AuditLogEntryInfoErrorType::FieldInvalid {
+                    event_type: self.event_type,
+                    name,
+                },
+                source: Some(Box::new(source)),
+            })
+    }
+
+    fn required<T>(
+        &self,
+        name: &'static str,
+        value: Option<T>,
+    ) -> Result<T, AuditLogEntryInfoError> {
+        value.ok_or(AuditLogEntryInfoError {
+            kind: AuditLogEntryInfoErrorType::FieldMissing {
+                event_type: self.event_type,
+                name,
+            },
+            source: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditLogEntryInfo, AuditLogEntryInfoError, AuditLogEntryInfoErrorType};
+    use crate::{
+        channel::permission_overwrite::PermissionOverwriteType,
+        guild::{
+            audit_log::{AuditLogEntry, AuditLogEventType, AuditLogOptionalEntryInfo},
+            auto_moderation::AutoModerationTriggerType,
+        },
+        id::Id,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        AuditLogEntryInfo: Clone,
+        Debug,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(AuditLogEntryInfoErrorType: Debug, Send, Sync);
+    assert_impl_all!(AuditLogEntryInfoError: Error, Send, Sync);
+
+    const fn options() -> AuditLogOptionalEntryInfo {
+        AuditLogOptionalEntryInfo {
+            auto_moderation_rule_name: None,
+            auto_moderation_rule_trigger_type: None,
+            channel_id: None,
+            count: None,
+            delete_member_days: None,
+            id: None,
+            kind: None,
+            members_removed: None,
+            message_id: None,
+            role_name: None,
+        }
+    }
+
+    fn entry(
+        action_type: AuditLogEventType,
+        options: Option<AuditLogOptionalEntryInfo>,
+    ) -> AuditLogEntry {
+        AuditLogEntry {
+            action_type,
+            changes: Vec::new(),
+            guild_id: None,
+            id: Id::new(1),
+            options,
+            reason: None,
+            target_id: None,
+            user_id: Some(Id::new(2)),
+        }
+    }
+
+    #[test]
+    fn member_prune() {
+        let entry = entry(
+            AuditLogEventType::MemberPrune,
+            Some(AuditLogOptionalEntryInfo {
+                delete_member_days: Some("7".to_owned()),
+                members_removed: Some("42".to_owned()),
+                ..options()
+            }),
+        );
+
+        let info = entry.info().unwrap().unwrap();
+        assert_eq!(AuditLogEventType::MemberPrune, info.event_type());
+        assert_eq!(
+            AuditLogEntryInfo::MemberPrune {
+                delete_member_days: 7,
+                members_removed: 42,
+            },
+            info
+        );
+    }
+
+    #[test]
+    fn channel_overwrite() {
+        let options = AuditLogOptionalEntryInfo {
+            id: Some(Id::new(3)),
+            kind: Some("0".to_owned()),
+            role_name: Some("moderators".to_owned()),
+            ..options()
+        };
+
+        assert_eq!(
+            Some(AuditLogEntryInfo::ChannelOverwriteUpdate {
+                id: Id::new(3),
+                kind: PermissionOverwriteType::Role,
+                role_name: Some("moderators".to_owned()),
+            }),
+            options
+                .typed(AuditLogEventType::ChannelOverwriteUpdate)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn auto_moderation() {
+        let options = AuditLogOptionalEntryInfo {
+            auto_moderation_rule_name: Some("no links".to_owned()),
+            auto_moderation_rule_trigger_type: Some("1".to_owned()),
+            channel_id: Some(Id::new(4)),
+            ..options()
+        };
+
+        assert_eq!(
+            Some(AuditLogEntryInfo::AutoModerationBlockMessage {
+                channel_id: Id::new(4),
+                rule_name: "no links".to_owned(),
+                trigger_type: AutoModerationTriggerType::Keyword,
+            }),
+            options
+                .typed(AuditLogEventType::AutoModerationBlockMessage)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn no_info() {
+        assert!(entry(AuditLogEventType::MemberKick, None)
+            .info()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn errors() {
+        let error = entry(AuditLogEventType::MessageBulkDelete, None)
+            .info()
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            AuditLogEntryInfoErrorType::FieldMissing {
+                event_type: AuditLogEventType::MessageBulkDelete,
+                name: "count",
+            }
+        ));
+
+        let error = AuditLogOptionalEntryInfo {
+            count: Some("many".to_owned()),
+            ..options()
+        }
+        .typed(AuditLogEventType::MessageBulkDelete)
+        .unwrap_err();
+        assert_eq!(
+            "field `count` of MessageBulkDelete entry info is invalid",
+            error.to_string()
+        );
+        assert!(error.into_source().is_some());
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`AuditLogEntry`]: super::AuditLogEntry
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(from = "u16", into = "u16")]
 pub enum AuditLogEventType {
     /// [Guild] was updated.
@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 /// [audit log]: super::AuditLog
 /// [guild integration]: super::super::GuildIntegration
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AuditLogGuildIntegration {
     /// Account of the integration.
     #[serde(skip_serializing_if = "Option::is_none")]
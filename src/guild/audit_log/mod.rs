@@ -7,18 +7,24 @@
 
 mod change;
 mod change_key;
+mod display;
 mod entry;
+mod entry_info;
 mod event_type;
 mod integration;
 mod optional_entry_info;
+mod timeline;
 
 pub use self::{
     change::{AffectedRole, AuditLogChange, AuditLogChangeTypeValue},
     change_key::AuditLogChangeKey,
+    display::{AuditLogChangeDisplay, AuditLogEntryDisplay, AuditLogFormat, AuditLogNames},
     entry::AuditLogEntry,
+    entry_info::{AuditLogEntryInfo, AuditLogEntryInfoError, AuditLogEntryInfoErrorType},
     event_type::AuditLogEventType,
     integration::AuditLogGuildIntegration,
     optional_entry_info::AuditLogOptionalEntryInfo,
+    timeline::{AuditLogTarget, AuditLogTimeline, ResolvedAuditLogEntry},
 };
 
 use super::auto_moderation::AutoModerationRule;
@@ -36,6 +42,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [1]: https://discord.com/developers/docs/resources/audit-log#audit-log-object
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AuditLog {
     /// List of referenced application commands.
     pub application_commands: Vec<Command>,
@@ -1,3 +1,4 @@
+use super::{AuditLogEntryInfo, AuditLogEntryInfoError, AuditLogEventType};
 use crate::id::{
     marker::{ChannelMarker, GenericMarker, MessageMarker},
     Id,
@@ -8,6 +9,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`AuditLogEventType`]: super::AuditLogEventType
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AuditLogOptionalEntryInfo {
     /// Name of the Auto Moderation rule that was triggered.
     ///
@@ -147,6 +149,30 @@ pub struct AuditLogOptionalEntryInfo {
     pub role_name: Option<String>,
 }
 
+impl AuditLogOptionalEntryInfo {
+    /// Convert into the typed representation of the information for a type of
+    /// event.
+    ///
+    /// Returns `None` if the type of event has no additional information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`FieldMissing`] if a field required by the
+    /// type of event is missing.
+    ///
+    /// Returns an error of type [`FieldInvalid`] if a field's value could not
+    /// be parsed.
+    ///
+    /// [`FieldInvalid`]: super::AuditLogEntryInfoErrorType::FieldInvalid
+    /// [`FieldMissing`]: super::AuditLogEntryInfoErrorType::FieldMissing
+    pub fn typed(
+        &self,
+        event_type: AuditLogEventType,
+    ) -> Result<Option<AuditLogEntryInfo>, AuditLogEntryInfoError> {
+        AuditLogEntryInfo::parse(event_type, Some(self))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::AuditLogOptionalEntryInfo;
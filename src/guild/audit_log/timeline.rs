@@ -0,0 +1,440 @@
+use super::{AuditLog, AuditLogEntry, AuditLogEventType, AuditLogGuildIntegration, AuditLogNames};
+use crate::{
+    application::command::Command,
+    channel::{Channel, Webhook},
+    guild::{auto_moderation::AutoModerationRule, scheduled_event::GuildScheduledEvent},
+    id::{
+        marker::{AuditLogEntryMarker, ChannelMarker, GenericMarker, UserMarker},
+        Id,
+    },
+    user::User,
+    util::Timestamp,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    iter::FromIterator,
+};
+
+/// Timeline of audit log entries resolved against the [`AuditLog`]s they were
+/// received in.
+///
+/// Audit log entries refer to the users that performed actions and the targets
+/// of actions by ID, with the referenced users, webhooks, threads and other
+/// entities included in the [`AuditLog`]'s lists. The timeline merges pages of
+/// audit logs, deduplicating their entries and referenced entities, and joins
+/// entries with what they refer to.
+///
+/// Entries are ordered from newest to oldest, which is the order in which
+/// Discord returns them.
+///
+/// # Examples
+///
+/// Merge two pages of an audit log and list who performed each action:
+///
+/// ```
+/// use twilight_model::guild::audit_log::{AuditLog, AuditLogTimeline};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let first_page: AuditLog = serde_json::from_str(r#"{"application_commands":[],"audit_log_entries":[],"auto_moderation_rules":[],"guild_scheduled_events":[],"integrations":[],"threads":[],"users":[],"webhooks":[]}"#)?;
+/// # let second_page = first_page.clone();
+/// let mut timeline = AuditLogTimeline::from(first_page);
+/// timeline.push(second_page);
+///
+/// for entry in timeline.entries() {
+///     let user = entry.user.map_or("unknown user", |user| user.name.as_str());
+///
+///     println!("{:?} by {user} at {}", entry.entry.action_type, entry.created_at().iso_8601());
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuditLogTimeline {
+    application_commands: HashMap<Id<GenericMarker>, Command>,
+    auto_moderation_rules: HashMap<Id<GenericMarker>, AutoModerationRule>,
+    entries: BTreeMap<Id<AuditLogEntryMarker>, AuditLogEntry>,
+    guild_scheduled_events: HashMap<Id<GenericMarker>, GuildScheduledEvent>,
+    integrations: HashMap<Id<GenericMarker>, AuditLogGuildIntegration>,
+    threads: HashMap<Id<GenericMarker>, Channel>,
+    users: HashMap<Id<UserMarker>, User>,
+    webhooks: HashMap<Id<GenericMarker>, Webhook>,
+}
+
+impl AuditLogTimeline {
+    /// Create a new, empty timeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolved entries of the timeline, from newest to oldest.
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = ResolvedAuditLogEntry<'_>> {
+        self.entries
+            .values()
+            .rev()
+            .map(move |entry| self.resolve(entry))
+    }
+
+    /// Resolved entry by its ID.
+    pub fn get(&self, entry_id: Id<AuditLogEntryMarker>) -> Option<ResolvedAuditLogEntry<'_>> {
+        self.entries.get(&entry_id).map(|entry| self.resolve(entry))
+    }
+
+    /// Whether the timeline has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of entries in the timeline.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Merge a page of an audit log into the timeline.
+    ///
+    /// Entries and referenced entities already in the timeline are replaced by
+    /// those of the page.
+    pub fn push(&mut self, page: AuditLog) {
+        self.application_commands.extend(
+            page.application_commands
+                .into_iter()
+                .filter_map(|command| Some((command.id?.cast(), command))),
+        );
+        self.auto_moderation_rules.extend(
+            page.auto_moderation_rules
+                .into_iter()
+                .map(|rule| (rule.id.cast(), rule)),
+        );
+        self.entries
+            .extend(page.entries.into_iter().map(|entry| (entry.id, entry)));
+        self.guild_scheduled_events.extend(
+            page.guild_scheduled_events
+                .into_iter()
+                .map(|event| (event.id.cast(), event)),
+        );
+        self.integrations.extend(
+            page.integrations
+                .into_iter()
+                .filter_map(|integration| Some((integration.id?.cast(), integration))),
+        );
+        self.threads.extend(
+            page.threads
+                .into_iter()
+                .map(|thread| (thread.id.cast(), thread)),
+        );
+        self.users
+            .extend(page.users.into_iter().map(|user| (user.id, user)));
+        self.webhooks.extend(
+            page.webhooks
+                .into_iter()
+                .map(|webhook| (webhook.id.cast(), webhook)),
+        );
+    }
+
+    /// User referenced by the timeline's entries.
+    pub fn user(&self, user_id: Id<UserMarker>) -> Option<&User> {
+        self.users.get(&user_id)
+    }
+
+    fn resolve<'a>(&'a self, entry: &'a AuditLogEntry) -> ResolvedAuditLogEntry<'a> {
+        ResolvedAuditLogEntry {
+            entry,
+            target: entry
+                .target_id
+                .map(|target_id| self.target(entry.action_type, target_id)),
+            user: entry.user_id.and_then(|user_id| self.users.get(&user_id)),
+        }
+    }
+
+    fn target(
+        &self,
+        event_type: AuditLogEventType,
+        target_id: Id<GenericMarker>,
+    ) -> AuditLogTarget<'_> {
+        let target = match event_type {
+            AuditLogEventType::ApplicationCommandPermissionUpdate => self
+                .application_commands
+                .get(&target_id)
+                .map(AuditLogTarget::ApplicationCommand),
+            AuditLogEventType::AutoModerationRuleCreate
+            | AuditLogEventType::AutoModerationRuleDelete
+            | AuditLogEventType::AutoModerationRuleUpdate => self
+                .auto_moderation_rules
+                .get(&target_id)
+                .map(AuditLogTarget::AutoModerationRule),
+            AuditLogEventType::GuildScheduledEventCreate
+            | AuditLogEventType::GuildScheduledEventDelete
+            | AuditLogEventType::GuildScheduledEventUpdate => self
+                .guild_scheduled_events
+                .get(&target_id)
+                .map(AuditLogTarget::GuildScheduledEvent),
+            AuditLogEventType::IntegrationCreate
+            | AuditLogEventType::IntegrationDelete
+            | AuditLogEventType::IntegrationUpdate => self
+                .integrations
+                .get(&target_id)
+                .map(AuditLogTarget::Integration),
+            AuditLogEventType::ThreadCreate
+            | AuditLogEventType::ThreadDelete
+            | AuditLogEventType::ThreadUpdate => {
+                self.threads.get(&target_id).map(AuditLogTarget::Thread)
+            }
+            AuditLogEventType::AutoModerationBlockMessage
+            | AuditLogEventType::AutoModerationFlagToChannel
+            | AuditLogEventType::AutoModerationUserCommunicationDisabled
+            | AuditLogEventType::BotAdd
+            | AuditLogEventType::MemberBanAdd
+            | AuditLogEventType::MemberBanRemove
+            | AuditLogEventType::MemberKick
+            | AuditLogEventType::MemberRoleUpdate
+            | AuditLogEventType::MemberUpdate
+            | AuditLogEventType::MessageDelete
+            | AuditLogEventType::MessagePin
+            | AuditLogEventType::MessageUnpin => {
+                self.users.get(&target_id.cast()).map(AuditLogTarget::User)
+            }
+            AuditLogEventType::WebhookCreate
+            | AuditLogEventType::WebhookDelete
+            | AuditLogEventType::WebhookUpdate => {
+                self.webhooks.get(&target_id).map(AuditLogTarget::Webhook)
+            }
+            _ => None,
+        };
+
+        target.unwrap_or(AuditLogTarget::Unresolved(target_id))
+    }
+}
+
+impl AuditLogNames for AuditLogTimeline {
+    fn channel(&self, channel_id: Id<ChannelMarker>) -> Option<&str> {
+        self.threads.get(&channel_id.cast())?.name.as_deref()
+    }
+
+    fn entity(&self, entity_id: Id<GenericMarker>) -> Option<&str> {
+        if let Some(webhook) = self.webhooks.get(&entity_id) {
+            return webhook.name.as_deref();
+        }
+
+        if let Some(integration) = self.integrations.get(&entity_id) {
+            return integration.name.as_deref();
+        }
+
+        self.application_commands
+            .get(&entity_id)
+            .map(|command| command.name.as_str())
+            .or_else(|| {
+                self.auto_moderation_rules
+                    .get(&entity_id)
+                    .map(|rule| rule.name.as_str())
+            })
+            .or_else(|| {
+                self.guild_scheduled_events
+                    .get(&entity_id)
+                    .map(|event| event.name.as_str())
+            })
+    }
+
+    fn user(&self, user_id: Id<UserMarker>) -> Option<&str> {
+        self.users.get(&user_id).map(|user| user.name.as_str())
+    }
+}
+
+impl Extend<AuditLog> for AuditLogTimeline {
+    fn extend<T: IntoIterator<Item = AuditLog>>(&mut self, iter: T) {
+        for page in iter {
+            self.push(page);
+        }
+    }
+}
+
+impl From<AuditLog> for AuditLogTimeline {
+    fn from(page: AuditLog) -> Self {
+        let mut timeline = Self::new();
+        timeline.push(page);
+
+        timeline
+    }
+}
+
+impl FromIterator<AuditLog> for AuditLogTimeline {
+    fn from_iter<T: IntoIterator<Item = AuditLog>>(iter: T) -> Self {
+        let mut timeline = Self::new();
+        timeline.extend(iter);
+
+        timeline
+    }
+}
+
+/// Audit log entry joined with the entities it refers to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ResolvedAuditLogEntry<'a> {
+    /// Entry of the audit log.
+    pub entry: &'a AuditLogEntry,
+    /// Target of the entry's action, if it has one.
+    pub target: Option<AuditLogTarget<'a>>,
+    /// User that performed the entry's action, if they are known.
+    pub user: Option<&'a User>,
+}
+
+impl ResolvedAuditLogEntry<'_> {
+    /// When the entry was created, derived from its ID.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn created_at(&self) -> Timestamp {
+        // Snowflake timestamps are between 2015 and 2154, within the range
+        // of timestamps.
+        Timestamp::from_micros(self.entry.id.timestamp() * 1_000)
+            .expect("snowflake timestamps are valid")
+    }
+}
+
+/// Target of an audit log entry's action.
+///
+/// The type of target is chosen by the entry's [`AuditLogEventType`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum AuditLogTarget<'a> {
+    /// Command whose permissions were updated.
+    ApplicationCommand(&'a Command),
+    /// Auto Moderation rule that was created, updated or deleted.
+    AutoModerationRule(&'a AutoModerationRule),
+    /// Scheduled event that was created, updated or deleted.
+    GuildScheduledEvent(&'a GuildScheduledEvent),
+    /// Integration that was created, updated or deleted.
+    Integration(&'a AuditLogGuildIntegration),
+    /// Thread that was created, updated or deleted.
+    Thread(&'a Channel),
+    /// User that was the subject of the action, such as the member that was
+    /// kicked or the author of deleted messages.
+    User(&'a User),
+    /// Webhook that was created, updated or deleted.
+    Webhook(&'a Webhook),
+    /// ID of a target that isn't included in the audit log.
+    ///
+    /// Channels, roles, emojis, invites, stickers and the guild itself aren't
+    /// included in audit logs.
+    Unresolved(Id<GenericMarker>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditLogTarget, AuditLogTimeline, ResolvedAuditLogEntry};
+    use crate::{
+        guild::audit_log::{AuditLog, AuditLogEventType},
+        id::Id,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(
+        AuditLogTimeline: Clone,
+        Debug,
+        Default,
+        Extend<AuditLog>,
+        From<AuditLog>,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(ResolvedAuditLogEntry<'_>: Clone, Copy, Debug, Send, Sync);
+    assert_impl_all!(AuditLogTarget<'_>: Clone, Copy, Debug, Send, Sync);
+
+    const USERS: &str = r#"[
+        {"id": "100", "username": "moderator", "discriminator": "0001", "avatar": null},
+        {"id": "200", "username": "spammer", "discriminator": "0002", "avatar": null}
+    ]"#;
+
+    const WEBHOOK: &str = r#"{
+        "id": "300",
+        "type": 1,
+        "channel_id": "400",
+        "name": "announcements"
+    }"#;
+
+    fn page(entries: &str, webhooks: &str) -> AuditLog {
+        serde_json::from_str(&format!(
+            r#"{{
+                "application_commands": [],
+                "audit_log_entries": {entries},
+                "auto_moderation_rules": [],
+                "guild_scheduled_events": [],
+                "integrations": [],
+                "threads": [],
+                "users": {USERS},
+                "webhooks": {webhooks}
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    fn entry(id: u64, action_type: u16, target_id: &str) -> String {
+        format!(
+            r#"{{
+                "action_type": {action_type},
+                "id": "{id}",
+                "target_id": {target_id},
+                "user_id": "100"
+            }}"#
+        )
+    }
+
+    #[test]
+    fn merge_pages() {
+        // Kick, then a webhook creation, then a channel creation.
+        let kick = entry(175_928_847_299_117_063, 20, r#""200""#);
+        let webhook = entry(175_928_847_299_117_064, 50, r#""300""#);
+        let channel = entry(175_928_847_299_117_065, 10, r#""400""#);
+
+        let first = page(&format!("[{channel}, {webhook}]"), &format!("[{WEBHOOK}]"));
+        let second = page(&format!("[{webhook}, {kick}]"), "[]");
+
+        let timeline = [first, second].into_iter().collect::<AuditLogTimeline>();
+        assert_eq!(3, timeline.len());
+
+        let entries = timeline.entries().collect::<Vec<_>>();
+        let kinds = entries
+            .iter()
+            .map(|entry| entry.entry.action_type)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            [
+                AuditLogEventType::ChannelCreate,
+                AuditLogEventType::WebhookCreate,
+                AuditLogEventType::MemberKick,
+            ],
+            kinds.as_slice()
+        );
+
+        assert!(entries
+            .iter()
+            .all(|entry| entry.user.map(|user| user.name.as_str()) == Some("moderator")));
+        assert!(matches!(
+            entries[0].target,
+            Some(AuditLogTarget::Unresolved(id)) if id == Id::new(400)
+        ));
+        assert!(matches!(
+            entries[1].target,
+            Some(AuditLogTarget::Webhook(webhook)) if webhook.name.as_deref() == Some("announcements")
+        ));
+        assert!(matches!(
+            entries[2].target,
+            Some(AuditLogTarget::User(user)) if user.name == "spammer"
+        ));
+
+        assert_eq!(1_462_015_105_796_000, entries[2].created_at().as_micros());
+        assert_eq!(
+            Some(AuditLogEventType::MemberKick),
+            timeline
+                .get(Id::new(175_928_847_299_117_063))
+                .map(|entry| entry.entry.action_type)
+        );
+    }
+
+    #[test]
+    fn missing_references() {
+        let timeline =
+            AuditLogTimeline::from(page(&format!("[{}]", entry(1 << 22, 20, "null")), "[]"));
+        let entry = timeline.entries().next().unwrap();
+
+        assert!(entry.target.is_none());
+        assert!(entry.user.is_some());
+        assert!(timeline.user(Id::new(300)).is_none());
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 /// An action which will execute whenever a rule is triggered.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutoModerationAction {
     /// Type of action.
     #[serde(rename = "type")]
@@ -16,6 +17,7 @@ pub struct AutoModerationAction {
 /// Additional metadata needed during execution for a specific
 /// [`AutoModerationActionType`].
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutoModerationActionMetadata {
     /// Channel to which user content should be logged.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -34,6 +36,7 @@ pub struct AutoModerationActionMetadata {
 
 /// Type of [`AutoModerationAction`].
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(from = "u8", into = "u8")]
 pub enum AutoModerationActionType {
     /// Blocks the content of a message according to the rule.
@@ -42,13 +45,22 @@ pub enum AutoModerationActionType {
     SendAlertMessage,
     /// Timeout user for a specified duration.
     ///
-    /// A `Timeout` action can only be setup for [`Keyword`] rules.
+    /// A `Timeout` action can only be setup for [`Keyword`] and
+    /// [`MentionSpam`] rules.
     /// [`Permissions::MODERATE_MEMBERS`] is required to use the `Timeout` action
     /// type.
     ///
     /// [`Keyword`]: super::AutoModerationTriggerType::Keyword
+    /// [`MentionSpam`]: super::AutoModerationTriggerType::MentionSpam
     /// [`Permissions::MODERATE_MEMBERS`]: crate::guild::Permissions::MODERATE_MEMBERS
     Timeout,
+    /// Prevents a member from using text, voice, or other interactions.
+    ///
+    /// A `BlockMemberInteraction` action can only be setup for
+    /// [`MemberProfile`] rules.
+    ///
+    /// [`MemberProfile`]: super::AutoModerationTriggerType::MemberProfile
+    BlockMemberInteraction,
     /// Variant value is unknown to the library.
     Unknown(u8),
 }
@@ -59,6 +71,7 @@ impl From<u8> for AutoModerationActionType {
             1 => Self::BlockMessage,
             2 => Self::SendAlertMessage,
             3 => Self::Timeout,
+            4 => Self::BlockMemberInteraction,
             _ => Self::Unknown(value),
         }
     }
@@ -70,6 +83,7 @@ impl From<AutoModerationActionType> for u8 {
             AutoModerationActionType::BlockMessage => 1,
             AutoModerationActionType::SendAlertMessage => 2,
             AutoModerationActionType::Timeout => 3,
+            AutoModerationActionType::BlockMemberInteraction => 4,
             AutoModerationActionType::Unknown(unknown) => unknown,
         }
     }
@@ -128,6 +142,10 @@ mod tests {
         assert_eq!(1, u8::from(AutoModerationActionType::BlockMessage));
         assert_eq!(2, u8::from(AutoModerationActionType::SendAlertMessage));
         assert_eq!(3, u8::from(AutoModerationActionType::Timeout));
+        assert_eq!(
+            4,
+            u8::from(AutoModerationActionType::BlockMemberInteraction)
+        );
         assert_eq!(250, u8::from(AutoModerationActionType::Unknown(250)));
     }
 }
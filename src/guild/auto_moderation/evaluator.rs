@@ -0,0 +1,487 @@
+use super::{
+    AutoModerationAction, AutoModerationEventType, AutoModerationRule, AutoModerationTriggerType,
+};
+use crate::id::{
+    marker::{ChannelMarker, RoleMarker},
+    Id,
+};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+/// Function matching a regular expression pattern against content, returning
+/// the matched content.
+pub type AutoModerationRegexMatcher<'a> = &'a dyn Fn(&str, &str) -> Option<String>;
+
+/// Message to evaluate auto moderation rules against.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AutoModerationMessage<'a> {
+    /// ID of the channel the message is sent in.
+    pub channel_id: Id<ChannelMarker>,
+    /// Content of the message.
+    pub content: &'a str,
+    /// Number of unique role and user mentions in the message.
+    pub mention_count: usize,
+    /// Roles of the member sending the message.
+    pub roles: &'a [Id<RoleMarker>],
+}
+
+/// Evaluates auto moderation rules locally, such as to dry-run changes to
+/// rules before saving them.
+///
+/// Only rules of the [`MessageSend`] event type are evaluated. Rules which are
+/// disabled, or which exempt the message's channel or one of its author's
+/// roles, never match.
+///
+/// Some rules can't be evaluated locally and are reported as
+/// [unevaluated]:
+///
+/// - [`KeywordPreset`] and [`Spam`] rules, since their wordsets and
+///   heuristics are internal to Discord;
+/// - [`Keyword`] rules with regular expression patterns that didn't match a
+///   keyword, unless a [regex matcher] is provided.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{
+///     guild::auto_moderation::{AutoModerationEvaluator, AutoModerationMessage},
+///     id::Id,
+/// };
+/// # use twilight_model::guild::auto_moderation::AutoModerationRule;
+/// # let rules: Vec<AutoModerationRule> = Vec::new();
+///
+/// let message = AutoModerationMessage {
+///     channel_id: Id::new(1),
+///     content: "message content",
+///     mention_count: 0,
+///     roles: &[],
+/// };
+///
+/// let evaluation = AutoModerationEvaluator::new(&rules).evaluate(&message);
+///
+/// for matched in &evaluation.matches {
+///     println!(
+///         "rule {} matched {:?}",
+///         matched.rule.name, matched.matched_content
+///     );
+/// }
+/// ```
+///
+/// [`Keyword`]: AutoModerationTriggerType::Keyword
+/// [`KeywordPreset`]: AutoModerationTriggerType::KeywordPreset
+/// [`MessageSend`]: AutoModerationEventType::MessageSend
+/// [`Spam`]: AutoModerationTriggerType::Spam
+/// [regex matcher]: Self::regex_matcher
+/// [unevaluated]: AutoModerationEvaluation::unevaluated
+#[derive(Clone, Copy)]
+pub struct AutoModerationEvaluator<'a> {
+    regex_matcher: Option<AutoModerationRegexMatcher<'a>>,
+    rules: &'a [AutoModerationRule],
+}
+
+impl<'a> AutoModerationEvaluator<'a> {
+    /// Create an evaluator for a set of rules.
+    pub const fn new(rules: &'a [AutoModerationRule]) -> Self {
+        Self {
+            regex_matcher: None,
+            rules,
+        }
+    }
+
+    /// Set the function used to match regular expression patterns.
+    ///
+    /// The function is called with a pattern and the message content, and
+    /// returns the matched content if the pattern matches. Discord uses Rust
+    /// flavored regular expressions, such as those of the `regex` crate.
+    #[must_use = "has no effect if not used to evaluate rules"]
+    pub const fn regex_matcher(mut self, regex_matcher: AutoModerationRegexMatcher<'a>) -> Self {
+        self.regex_matcher = Some(regex_matcher);
+
+        self
+    }
+
+    /// Evaluate the rules against a message.
+    pub fn evaluate(&self, message: &AutoModerationMessage<'_>) -> AutoModerationEvaluation<'a> {
+        let mut evaluation = AutoModerationEvaluation {
+            matches: Vec::new(),
+            unevaluated: Vec::new(),
+        };
+
+        for rule in self.rules {
+            if !rule.enabled
+                || rule.event_type != AutoModerationEventType::MessageSend
+                || rule.exempt_channels.contains(&message.channel_id)
+                || message
+                    .roles
+                    .iter()
+                    .any(|id| rule.exempt_roles.contains(id))
+            {
+                continue;
+            }
+
+            match self.evaluate_rule(rule, message) {
+                Some(Some(matched)) => evaluation.matches.push(matched),
+                Some(None) => {}
+                None => evaluation.unevaluated.push(rule),
+            }
+        }
+
+        evaluation
+    }
+
+    /// Evaluate a rule, returning [`None`] if it can't be evaluated.
+    #[allow(clippy::option_option)]
+    fn evaluate_rule(
+        &self,
+        rule: &'a AutoModerationRule,
+        message: &AutoModerationMessage<'_>,
+    ) -> Option<Option<AutoModerationRuleMatch<'a>>> {
+        let metadata = &rule.trigger_metadata;
+        let allow_list = metadata.allow_list.as_deref().unwrap_or_default();
+
+        match rule.trigger_type {
+            AutoModerationTriggerType::Keyword => {
+                for keyword in metadata.keyword_filter.as_deref().unwrap_or_default() {
+                    let matched = keyword_matches(keyword, message.content)
+                        .find(|matched| !allowed(allow_list, matched));
+
+                    if let Some(matched) = matched {
+                        return Some(Some(AutoModerationRuleMatch {
+                            matched_content: Some(matched.to_owned()),
+                            matched_keyword: Some(keyword.clone()),
+                            rule,
+                        }));
+                    }
+                }
+
+                let patterns = metadata.regex_patterns.as_deref().unwrap_or_default();
+
+                if patterns.is_empty() {
+                    return Some(None);
+                }
+
+                let regex_matcher = self.regex_matcher?;
+
+                Some(patterns.iter().find_map(|pattern| {
+                    regex_matcher(pattern, message.content)
+                        .filter(|matched| !allowed(allow_list, matched))
+                        .map(|matched| AutoModerationRuleMatch {
+                            matched_content: Some(matched),
+                            matched_keyword: Some(pattern.clone()),
+                            rule,
+                        })
+                }))
+            }
+            AutoModerationTriggerType::MentionSpam => {
+                let limit = metadata.mention_total_limit?;
+
+                Some((message.mention_count > usize::from(limit)).then_some(
+                    AutoModerationRuleMatch {
+                        matched_content: None,
+                        matched_keyword: None,
+                        rule,
+                    },
+                ))
+            }
+            AutoModerationTriggerType::KeywordPreset
+            | AutoModerationTriggerType::MemberProfile
+            | AutoModerationTriggerType::Spam
+            | AutoModerationTriggerType::Unknown(_) => None,
+        }
+    }
+}
+
+impl Debug for AutoModerationEvaluator<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AutoModerationEvaluator")
+            .field("regex_matcher", &self.regex_matcher.is_some())
+            .field("rules", &self.rules)
+            .finish()
+    }
+}
+
+/// Result of evaluating auto moderation rules against a message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoModerationEvaluation<'a> {
+    /// Rules which matched the message.
+    pub matches: Vec<AutoModerationRuleMatch<'a>>,
+    /// Rules which could not be evaluated locally.
+    pub unevaluated: Vec<&'a AutoModerationRule>,
+}
+
+impl<'a> AutoModerationEvaluation<'a> {
+    /// Actions of all matched rules.
+    pub fn actions(&self) -> impl Iterator<Item = &'a AutoModerationAction> + '_ {
+        self.matches
+            .iter()
+            .flat_map(AutoModerationRuleMatch::actions)
+    }
+
+    /// Whether no rule matched the message.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+}
+
+/// Rule which matched a message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoModerationRuleMatch<'a> {
+    /// Content of the message which matched the rule.
+    ///
+    /// [`None`] for [`MentionSpam`] rules.
+    ///
+    /// [`MentionSpam`]: AutoModerationTriggerType::MentionSpam
+    pub matched_content: Option<String>,
+    /// Keyword or regular expression pattern which matched the content.
+    ///
+    /// [`None`] for [`MentionSpam`] rules.
+    ///
+    /// [`MentionSpam`]: AutoModerationTriggerType::MentionSpam
+    pub matched_keyword: Option<String>,
+    /// Rule which matched.
+    pub rule: &'a AutoModerationRule,
+}
+
+impl<'a> AutoModerationRuleMatch<'a> {
+    /// Actions which would execute because of the match.
+    pub fn actions(&self) -> &'a [AutoModerationAction] {
+        &self.rule.actions
+    }
+}
+
+/// Whether matched content is exempt through an allow list.
+fn allowed(allow_list: &[String], matched: &str) -> bool {
+    allow_list
+        .iter()
+        .any(|allowed| keyword_matches(allowed, matched).next().is_some())
+}
+
+/// Whether a character is part of a word.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Iterator over the words of content matching a keyword, using Discord's
+/// keyword matching strategies.
+///
+/// Keywords are matched case insensitively. A leading `*` matches words ending
+/// with the keyword, a trailing `*` matches words starting with the keyword,
+/// and both match the keyword anywhere within words. Keywords without
+/// wildcards only match whole words.
+///
+/// Items are the words of the content containing the match.
+fn keyword_matches<'a>(keyword: &str, content: &'a str) -> impl Iterator<Item = &'a str> {
+    let any_start = keyword.starts_with('*');
+    let any_end = keyword.len() > 1 && keyword.ends_with('*');
+    let needle = keyword
+        .trim_matches('*')
+        .chars()
+        .flat_map(char::to_lowercase)
+        .collect::<Vec<_>>();
+
+    // Lowercase characters paired with the byte index of and the original
+    // character they come from.
+    let haystack = content
+        .char_indices()
+        .flat_map(|(index, c)| c.to_lowercase().map(move |lower| (lower, index, c)))
+        .collect::<Vec<_>>();
+
+    let windows = if needle.is_empty() {
+        0
+    } else {
+        (haystack.len() + 1).saturating_sub(needle.len())
+    };
+
+    (0..windows).filter_map(move |start| {
+        let end = start + needle.len();
+
+        if !haystack[start..end]
+            .iter()
+            .zip(&needle)
+            .all(|((lower, ..), c)| lower == c)
+        {
+            return None;
+        }
+
+        let before = start.checked_sub(1).map(|index| haystack[index].2);
+        let after = haystack.get(end).map(|(_, _, c)| *c);
+
+        if (!any_start && matches!(before, Some(c) if is_word_char(c)))
+            || (!any_end && matches!(after, Some(c) if is_word_char(c)))
+        {
+            return None;
+        }
+
+        // Expand the match to the whole words it is part of.
+        let (_, first, _) = haystack[start];
+        let (_, last, _) = haystack[end - 1];
+        let word_start = content[..first]
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| is_word_char(*c))
+            .last()
+            .map_or(first, |(index, _)| index);
+        let word_end = content[last..]
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| !is_word_char(*c))
+            .map_or(content.len(), |(index, _)| last + index);
+
+        Some(&content[word_start..word_end])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{keyword_matches, AutoModerationEvaluator, AutoModerationMessage};
+    use crate::{
+        guild::auto_moderation::AutoModerationTriggerType, id::Id, test::auto_moderation::rule,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(AutoModerationEvaluator<'_>: Clone, Copy, Debug);
+    assert_impl_all!(AutoModerationMessage<'_>: Clone, Copy, Debug, Eq, PartialEq);
+
+    fn matches(keyword: &str, content: &str) -> Vec<String> {
+        keyword_matches(keyword, content)
+            .map(ToOwned::to_owned)
+            .collect()
+    }
+
+    fn message(content: &str) -> AutoModerationMessage<'_> {
+        AutoModerationMessage {
+            channel_id: Id::new(1),
+            content,
+            mention_count: 0,
+            roles: &[],
+        }
+    }
+
+    #[test]
+    fn keyword_matching_strategies() {
+        assert_eq!(["Catapult"], matches("cat*", "a Catapult").as_slice());
+        assert!(matches("cat*", "bobcat").is_empty());
+
+        assert_eq!(["bobcat"], matches("*cat", "bobcat!").as_slice());
+        assert!(matches("*cat", "catapult").is_empty());
+
+        assert_eq!(
+            ["location", "concatenate"],
+            matches("*cat*", "location, concatenate").as_slice()
+        );
+
+        assert_eq!(["CAT"], matches("cat", "CAT.").as_slice());
+        assert!(matches("cat", "cats").is_empty());
+        assert_eq!(
+            ["ice cream"],
+            matches("ice cream", "I like ice cream").as_slice()
+        );
+
+        assert!(matches("*", "anything").is_empty());
+        assert!(matches("", "anything").is_empty());
+    }
+
+    #[test]
+    fn keyword_rule() {
+        let mut keyword = rule(1, AutoModerationTriggerType::Keyword);
+        keyword.trigger_metadata.keyword_filter = Some(Vec::from(["*cat*".to_owned()]));
+        keyword.trigger_metadata.allow_list = Some(Vec::from(["location".to_owned()]));
+        let rules = [keyword];
+        let evaluator = AutoModerationEvaluator::new(&rules);
+
+        assert!(evaluator.evaluate(&message("a location")).is_empty());
+
+        let evaluation = evaluator.evaluate(&message("location and concatenate"));
+        assert_eq!(1, evaluation.matches.len());
+        assert_eq!(
+            Some("concatenate"),
+            evaluation.matches[0].matched_content.as_deref()
+        );
+        assert_eq!(
+            Some("*cat*"),
+            evaluation.matches[0].matched_keyword.as_deref()
+        );
+        assert_eq!(1, evaluation.actions().count());
+    }
+
+    #[test]
+    fn regex_rule() {
+        let mut regex = rule(1, AutoModerationTriggerType::Keyword);
+        regex.trigger_metadata.regex_patterns = Some(Vec::from(["[0-9]+".to_owned()]));
+        let rules = [regex];
+
+        let evaluation = AutoModerationEvaluator::new(&rules).evaluate(&message("call 555"));
+        assert!(evaluation.is_empty());
+        assert_eq!(1, evaluation.unevaluated.len());
+
+        let digits = |_: &str, content: &str| {
+            let matched = content
+                .chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>();
+
+            (!matched.is_empty()).then_some(matched)
+        };
+        let evaluation = AutoModerationEvaluator::new(&rules)
+            .regex_matcher(&digits)
+            .evaluate(&message("call 555"));
+        assert!(evaluation.unevaluated.is_empty());
+        assert_eq!(
+            Some("555"),
+            evaluation.matches[0].matched_content.as_deref()
+        );
+        assert_eq!(
+            Some("[0-9]+"),
+            evaluation.matches[0].matched_keyword.as_deref()
+        );
+    }
+
+    #[test]
+    fn mention_spam_rule() {
+        let mut mention_spam = rule(1, AutoModerationTriggerType::MentionSpam);
+        mention_spam.trigger_metadata.mention_total_limit = Some(5);
+        let rules = [mention_spam];
+        let evaluator = AutoModerationEvaluator::new(&rules);
+
+        let mut message = message("hi");
+        message.mention_count = 5;
+        assert!(evaluator.evaluate(&message).is_empty());
+
+        message.mention_count = 6;
+        let evaluation = evaluator.evaluate(&message);
+        assert_eq!(1, evaluation.matches.len());
+        assert!(evaluation.matches[0].matched_content.is_none());
+    }
+
+    #[test]
+    fn exemptions() {
+        let mut keyword = rule(1, AutoModerationTriggerType::Keyword);
+        keyword.trigger_metadata.keyword_filter = Some(Vec::from(["darn".to_owned()]));
+        keyword.exempt_channels = Vec::from([Id::new(2)]);
+        keyword.exempt_roles = Vec::from([Id::new(3)]);
+        let mut disabled = keyword.clone();
+        disabled.enabled = false;
+        let rules = [
+            keyword,
+            disabled,
+            rule(2, AutoModerationTriggerType::KeywordPreset),
+        ];
+        let evaluator = AutoModerationEvaluator::new(&rules);
+
+        let mut message = message("darn it");
+        let evaluation = evaluator.evaluate(&message);
+        assert_eq!(1, evaluation.matches.len());
+        assert_eq!(Id::new(1), evaluation.matches[0].rule.id);
+        assert_eq!(1, evaluation.unevaluated.len());
+        assert_eq!(Id::new(2), evaluation.unevaluated[0].id);
+
+        message.channel_id = Id::new(2);
+        assert!(evaluator.evaluate(&message).is_empty());
+
+        let exempt_roles = [Id::new(3)];
+        message.channel_id = Id::new(1);
+        message.roles = &exempt_roles;
+        assert!(evaluator.evaluate(&message).is_empty());
+    }
+}
@@ -11,17 +11,24 @@
 #![warn(missing_docs)]
 
 mod action;
+mod evaluator;
 mod event_type;
 mod preset_type;
 mod trigger_metadata;
 mod trigger_type;
+mod validate;
 
 pub use self::{
     action::{AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType},
+    evaluator::{
+        AutoModerationEvaluation, AutoModerationEvaluator, AutoModerationMessage,
+        AutoModerationRegexMatcher, AutoModerationRuleMatch,
+    },
     event_type::AutoModerationEventType,
     preset_type::AutoModerationKeywordPresetType,
     trigger_metadata::AutoModerationTriggerMetadata,
     trigger_type::AutoModerationTriggerType,
+    validate::{AutoModerationRuleError, AutoModerationRuleErrorType},
 };
 
 use crate::id::{
@@ -32,6 +39,7 @@ use serde::{Deserialize, Serialize};
 
 /// Configured auto moderation rule.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutoModerationRule {
     /// Actions which will execute when the rule is triggered.
     pub actions: Vec<AutoModerationAction>,
@@ -184,7 +192,9 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::StructEnd,
                 Token::StructEnd,
@@ -207,7 +217,9 @@ mod tests {
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::Str("creator_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("enabled"),
                 Token::Bool(true),
@@ -215,19 +227,27 @@ mod tests {
                 Token::U8(u8::from(AutoModerationEventType::MessageSend)),
                 Token::Str("exempt_channels"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::SeqEnd,
                 Token::Str("exempt_roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("5"),
                 Token::SeqEnd,
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("6"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("name"),
                 Token::Str("rule"),
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// Internally pre-defined wordsets which will be searched for in content.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(from = "u8", into = "u8")]
 pub enum AutoModerationKeywordPresetType {
     /// Words that may be considered forms of swearing or cursing.
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`AutoModerationRule::trigger_type`]: super::AutoModerationRule::trigger_type
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AutoModerationTriggerMetadata {
     /// Substrings that will be exempt from triggering the preset type.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,925 @@
+use super::{
+    AutoModerationActionType, AutoModerationEventType, AutoModerationRule,
+    AutoModerationTriggerMetadata, AutoModerationTriggerType,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when an auto moderation rule exceeds Discord's limits.
+#[derive(Debug)]
+pub struct AutoModerationRuleError {
+    kind: AutoModerationRuleErrorType,
+}
+
+impl AutoModerationRuleError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AutoModerationRuleErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        AutoModerationRuleErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+
+    const fn new(kind: AutoModerationRuleErrorType) -> Self {
+        Self { kind }
+    }
+}
+
+impl Display for AutoModerationRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            AutoModerationRuleErrorType::ActionEventType { index, kind } => {
+                f.write_str("action ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" of type ")?;
+                Display::fmt(&u8::from(*kind), f)?;
+
+                f.write_str(" is not supported by the rule's event type")
+            }
+            AutoModerationRuleErrorType::ActionMetadataMissing { index, field } => {
+                f.write_str("action ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" is missing the required `")?;
+                f.write_str(field)?;
+
+                f.write_str("` metadata field")
+            }
+            AutoModerationRuleErrorType::ActionTriggerType { index, kind } => {
+                f.write_str("action ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" of type ")?;
+                Display::fmt(&u8::from(*kind), f)?;
+
+                f.write_str(" is not supported by the rule's trigger type")
+            }
+            AutoModerationRuleErrorType::AllowListCount { count, max } => {
+                count_error(f, *count, "allow list entries", *max)
+            }
+            AutoModerationRuleErrorType::AllowListEntryLength { index } => length_error(
+                f,
+                "allow list entry",
+                *index,
+                AutoModerationRule::ALLOW_LIST_ENTRY_LENGTH_MAX,
+            ),
+            AutoModerationRuleErrorType::CustomMessageLength { index } => {
+                f.write_str("custom message of action ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" is longer than ")?;
+                Display::fmt(&AutoModerationRule::CUSTOM_MESSAGE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            AutoModerationRuleErrorType::EventType { event_type } => {
+                f.write_str("event type ")?;
+                Display::fmt(&u8::from(*event_type), f)?;
+
+                f.write_str(" is not supported by the rule's trigger type")
+            }
+            AutoModerationRuleErrorType::ExemptChannelCount { count } => count_error(
+                f,
+                *count,
+                "exempt channels",
+                AutoModerationRule::EXEMPT_CHANNELS_MAX,
+            ),
+            AutoModerationRuleErrorType::ExemptRoleCount { count } => count_error(
+                f,
+                *count,
+                "exempt roles",
+                AutoModerationRule::EXEMPT_ROLES_MAX,
+            ),
+            AutoModerationRuleErrorType::KeywordCount { count } => count_error(
+                f,
+                *count,
+                "keywords",
+                AutoModerationRule::KEYWORD_FILTER_MAX,
+            ),
+            AutoModerationRuleErrorType::KeywordLength { index } => {
+                length_error(f, "keyword", *index, AutoModerationRule::KEYWORD_LENGTH_MAX)
+            }
+            AutoModerationRuleErrorType::MentionTotalLimit { limit } => {
+                f.write_str("mention total limit ")?;
+                Display::fmt(limit, f)?;
+                f.write_str(" is greater than ")?;
+
+                Display::fmt(&AutoModerationRule::MENTION_TOTAL_LIMIT_MAX, f)
+            }
+            AutoModerationRuleErrorType::MetadataField { field } => {
+                f.write_str("trigger metadata field `")?;
+                f.write_str(field)?;
+
+                f.write_str("` is not supported by the rule's trigger type")
+            }
+            AutoModerationRuleErrorType::RegexPatternCount { count } => count_error(
+                f,
+                *count,
+                "regex patterns",
+                AutoModerationRule::REGEX_PATTERNS_MAX,
+            ),
+            AutoModerationRuleErrorType::RegexPatternLength { index } => length_error(
+                f,
+                "regex pattern",
+                *index,
+                AutoModerationRule::REGEX_PATTERN_LENGTH_MAX,
+            ),
+            AutoModerationRuleErrorType::TimeoutDuration {
+                index,
+                duration_seconds,
+            } => {
+                f.write_str("timeout duration of action ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" is ")?;
+                Display::fmt(duration_seconds, f)?;
+                f.write_str(" seconds, but at most ")?;
+                Display::fmt(&AutoModerationRule::TIMEOUT_DURATION_MAX, f)?;
+
+                f.write_str(" seconds are allowed")
+            }
+        }
+    }
+}
+
+impl Error for AutoModerationRuleError {}
+
+/// Type of [`AutoModerationRuleError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AutoModerationRuleErrorType {
+    /// Action type is not supported by the rule's event type.
+    ///
+    /// [`BlockMessage`] and [`Timeout`] actions require the
+    /// [`MessageSend`] event type, and [`BlockMemberInteraction`] actions
+    /// require the [`MemberUpdate`] event type.
+    ///
+    /// [`BlockMemberInteraction`]: AutoModerationActionType::BlockMemberInteraction
+    /// [`BlockMessage`]: AutoModerationActionType::BlockMessage
+    /// [`MemberUpdate`]: AutoModerationEventType::MemberUpdate
+    /// [`MessageSend`]: AutoModerationEventType::MessageSend
+    /// [`Timeout`]: AutoModerationActionType::Timeout
+    ActionEventType {
+        /// Index of the action.
+        index: usize,
+        /// Type of the action.
+        kind: AutoModerationActionType,
+    },
+    /// Action is missing metadata required by its type.
+    ActionMetadataMissing {
+        /// Index of the action.
+        index: usize,
+        /// Name of the missing metadata field.
+        field: &'static str,
+    },
+    /// Action type is not supported by the rule's trigger type.
+    ActionTriggerType {
+        /// Index of the action.
+        index: usize,
+        /// Type of the action.
+        kind: AutoModerationActionType,
+    },
+    /// Too many allow list entries were provided.
+    AllowListCount {
+        /// Number of provided entries.
+        count: usize,
+        /// Maximum number of entries for the rule's trigger type.
+        max: usize,
+    },
+    /// Allow list entry is longer than
+    /// [`AutoModerationRule::ALLOW_LIST_ENTRY_LENGTH_MAX`] characters.
+    AllowListEntryLength {
+        /// Index of the entry.
+        index: usize,
+    },
+    /// Custom message of a block action is longer than
+    /// [`AutoModerationRule::CUSTOM_MESSAGE_LENGTH_MAX`] characters.
+    CustomMessageLength {
+        /// Index of the action.
+        index: usize,
+    },
+    /// Event type is not supported by the rule's trigger type.
+    EventType {
+        /// Event type of the rule.
+        event_type: AutoModerationEventType,
+    },
+    /// More than [`AutoModerationRule::EXEMPT_CHANNELS_MAX`] exempt channels
+    /// were provided.
+    ExemptChannelCount {
+        /// Number of provided channels.
+        count: usize,
+    },
+    /// More than [`AutoModerationRule::EXEMPT_ROLES_MAX`] exempt roles were
+    /// provided.
+    ExemptRoleCount {
+        /// Number of provided roles.
+        count: usize,
+    },
+    /// More than [`AutoModerationRule::KEYWORD_FILTER_MAX`] keywords were
+    /// provided.
+    KeywordCount {
+        /// Number of provided keywords.
+        count: usize,
+    },
+    /// Keyword is longer than [`AutoModerationRule::KEYWORD_LENGTH_MAX`]
+    /// characters.
+    KeywordLength {
+        /// Index of the keyword.
+        index: usize,
+    },
+    /// Mention total limit is greater than
+    /// [`AutoModerationRule::MENTION_TOTAL_LIMIT_MAX`].
+    MentionTotalLimit {
+        /// Provided limit.
+        limit: u8,
+    },
+    /// Trigger metadata field is not supported by the rule's trigger type.
+    MetadataField {
+        /// Name of the unsupported field.
+        field: &'static str,
+    },
+    /// More than [`AutoModerationRule::REGEX_PATTERNS_MAX`] regex patterns
+    /// were provided.
+    RegexPatternCount {
+        /// Number of provided patterns.
+        count: usize,
+    },
+    /// Regex pattern is longer than
+    /// [`AutoModerationRule::REGEX_PATTERN_LENGTH_MAX`] characters.
+    RegexPatternLength {
+        /// Index of the pattern.
+        index: usize,
+    },
+    /// Timeout duration is greater than
+    /// [`AutoModerationRule::TIMEOUT_DURATION_MAX`] seconds.
+    TimeoutDuration {
+        /// Index of the action.
+        index: usize,
+        /// Provided duration in seconds.
+        duration_seconds: u32,
+    },
+}
+
+impl AutoModerationRule {
+    /// Maximum number of characters of an allow list entry.
+    pub const ALLOW_LIST_ENTRY_LENGTH_MAX: usize = 60;
+
+    /// Maximum number of allow list entries of [`Keyword`] and
+    /// [`MemberProfile`] rules.
+    ///
+    /// [`Keyword`]: AutoModerationTriggerType::Keyword
+    /// [`MemberProfile`]: AutoModerationTriggerType::MemberProfile
+    pub const ALLOW_LIST_KEYWORD_MAX: usize = 100;
+
+    /// Maximum number of allow list entries of [`KeywordPreset`] rules.
+    ///
+    /// [`KeywordPreset`]: AutoModerationTriggerType::KeywordPreset
+    pub const ALLOW_LIST_PRESET_MAX: usize = 1000;
+
+    /// Maximum number of characters of a block action's custom message.
+    pub const CUSTOM_MESSAGE_LENGTH_MAX: usize = 150;
+
+    /// Maximum number of exempt channels.
+    pub const EXEMPT_CHANNELS_MAX: usize = 50;
+
+    /// Maximum number of exempt roles.
+    pub const EXEMPT_ROLES_MAX: usize = 20;
+
+    /// Maximum number of keywords.
+    pub const KEYWORD_FILTER_MAX: usize = 1000;
+
+    /// Maximum number of characters of a keyword.
+    pub const KEYWORD_LENGTH_MAX: usize = 60;
+
+    /// Maximum total number of unique mentions allowed per message.
+    pub const MENTION_TOTAL_LIMIT_MAX: u8 = 50;
+
+    /// Maximum number of regex patterns.
+    pub const REGEX_PATTERNS_MAX: usize = 10;
+
+    /// Maximum number of characters of a regex pattern.
+    pub const REGEX_PATTERN_LENGTH_MAX: usize = 260;
+
+    /// Maximum duration of a timeout action in seconds, equal to four weeks.
+    pub const TIMEOUT_DURATION_MAX: u32 = 2_419_200;
+
+    /// Validate the rule against Discord's limits for its trigger type and
+    /// event type.
+    ///
+    /// Rules with an [`Unknown`] trigger type or event type are only checked
+    /// against the limits which don't depend on them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`EventType`] if the event type is not
+    /// supported by the trigger type.
+    ///
+    /// Returns an error of type [`MetadataField`] if a trigger metadata field
+    /// is set which is not supported by the trigger type.
+    ///
+    /// Returns an error of type [`KeywordCount`], [`KeywordLength`],
+    /// [`RegexPatternCount`], [`RegexPatternLength`], [`AllowListCount`],
+    /// [`AllowListEntryLength`] or [`MentionTotalLimit`] if trigger metadata
+    /// exceeds its limits.
+    ///
+    /// Returns an error of type [`ExemptChannelCount`] or [`ExemptRoleCount`]
+    /// if too many exemptions are provided.
+    ///
+    /// Returns an error of type [`ActionEventType`] or [`ActionTriggerType`]
+    /// if an action is not supported by the rule, [`ActionMetadataMissing`]
+    /// if an action is missing required metadata, and
+    /// [`CustomMessageLength`] or [`TimeoutDuration`] if action metadata
+    /// exceeds its limits.
+    ///
+    /// [`ActionEventType`]: AutoModerationRuleErrorType::ActionEventType
+    /// [`ActionMetadataMissing`]: AutoModerationRuleErrorType::ActionMetadataMissing
+    /// [`ActionTriggerType`]: AutoModerationRuleErrorType::ActionTriggerType
+    /// [`AllowListCount`]: AutoModerationRuleErrorType::AllowListCount
+    /// [`AllowListEntryLength`]: AutoModerationRuleErrorType::AllowListEntryLength
+    /// [`CustomMessageLength`]: AutoModerationRuleErrorType::CustomMessageLength
+    /// [`EventType`]: AutoModerationRuleErrorType::EventType
+    /// [`ExemptChannelCount`]: AutoModerationRuleErrorType::ExemptChannelCount
+    /// [`ExemptRoleCount`]: AutoModerationRuleErrorType::ExemptRoleCount
+    /// [`KeywordCount`]: AutoModerationRuleErrorType::KeywordCount
+    /// [`KeywordLength`]: AutoModerationRuleErrorType::KeywordLength
+    /// [`MentionTotalLimit`]: AutoModerationRuleErrorType::MentionTotalLimit
+    /// [`MetadataField`]: AutoModerationRuleErrorType::MetadataField
+    /// [`RegexPatternCount`]: AutoModerationRuleErrorType::RegexPatternCount
+    /// [`RegexPatternLength`]: AutoModerationRuleErrorType::RegexPatternLength
+    /// [`TimeoutDuration`]: AutoModerationRuleErrorType::TimeoutDuration
+    /// [`Unknown`]: AutoModerationTriggerType::Unknown
+    pub fn validate(&self) -> Result<(), AutoModerationRuleError> {
+        self.validate_event_type()?;
+        validate_trigger_metadata(self.trigger_type, &self.trigger_metadata)?;
+
+        if self.exempt_channels.len() > Self::EXEMPT_CHANNELS_MAX {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::ExemptChannelCount {
+                    count: self.exempt_channels.len(),
+                },
+            ));
+        }
+
+        if self.exempt_roles.len() > Self::EXEMPT_ROLES_MAX {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::ExemptRoleCount {
+                    count: self.exempt_roles.len(),
+                },
+            ));
+        }
+
+        self.validate_actions()
+    }
+
+    fn validate_event_type(&self) -> Result<(), AutoModerationRuleError> {
+        let expected = match self.trigger_type {
+            AutoModerationTriggerType::MemberProfile => AutoModerationEventType::MemberUpdate,
+            AutoModerationTriggerType::Unknown(_) => return Ok(()),
+            _ => AutoModerationEventType::MessageSend,
+        };
+
+        if matches!(self.event_type, AutoModerationEventType::Unknown(_))
+            || self.event_type == expected
+        {
+            return Ok(());
+        }
+
+        Err(AutoModerationRuleError::new(
+            AutoModerationRuleErrorType::EventType {
+                event_type: self.event_type,
+            },
+        ))
+    }
+
+    fn validate_actions(&self) -> Result<(), AutoModerationRuleError> {
+        for (index, action) in self.actions.iter().enumerate() {
+            let event_supported = match action.kind {
+                AutoModerationActionType::BlockMessage | AutoModerationActionType::Timeout => {
+                    self.event_type == AutoModerationEventType::MessageSend
+                }
+                AutoModerationActionType::BlockMemberInteraction => {
+                    self.event_type == AutoModerationEventType::MemberUpdate
+                }
+                _ => true,
+            };
+
+            if !event_supported && !matches!(self.event_type, AutoModerationEventType::Unknown(_)) {
+                return Err(AutoModerationRuleError::new(
+                    AutoModerationRuleErrorType::ActionEventType {
+                        index,
+                        kind: action.kind,
+                    },
+                ));
+            }
+
+            let metadata = action.metadata.as_ref();
+
+            match action.kind {
+                AutoModerationActionType::BlockMessage => {
+                    if let Some(custom_message) =
+                        metadata.and_then(|metadata| metadata.custom_message.as_ref())
+                    {
+                        if custom_message.chars().count() > Self::CUSTOM_MESSAGE_LENGTH_MAX {
+                            return Err(AutoModerationRuleError::new(
+                                AutoModerationRuleErrorType::CustomMessageLength { index },
+                            ));
+                        }
+                    }
+                }
+                AutoModerationActionType::SendAlertMessage => {
+                    if metadata.and_then(|metadata| metadata.channel_id).is_none() {
+                        return Err(AutoModerationRuleError::new(
+                            AutoModerationRuleErrorType::ActionMetadataMissing {
+                                index,
+                                field: "channel_id",
+                            },
+                        ));
+                    }
+                }
+                AutoModerationActionType::Timeout => {
+                    if !matches!(
+                        self.trigger_type,
+                        AutoModerationTriggerType::Keyword
+                            | AutoModerationTriggerType::MentionSpam
+                            | AutoModerationTriggerType::Unknown(_)
+                    ) {
+                        return Err(AutoModerationRuleError::new(
+                            AutoModerationRuleErrorType::ActionTriggerType {
+                                index,
+                                kind: action.kind,
+                            },
+                        ));
+                    }
+
+                    let Some(duration_seconds) =
+                        metadata.and_then(|metadata| metadata.duration_seconds)
+                    else {
+                        return Err(AutoModerationRuleError::new(
+                            AutoModerationRuleErrorType::ActionMetadataMissing {
+                                index,
+                                field: "duration_seconds",
+                            },
+                        ));
+                    };
+
+                    if duration_seconds > Self::TIMEOUT_DURATION_MAX {
+                        return Err(AutoModerationRuleError::new(
+                            AutoModerationRuleErrorType::TimeoutDuration {
+                                index,
+                                duration_seconds,
+                            },
+                        ));
+                    }
+                }
+                AutoModerationActionType::BlockMemberInteraction => {
+                    if !matches!(
+                        self.trigger_type,
+                        AutoModerationTriggerType::MemberProfile
+                            | AutoModerationTriggerType::Unknown(_)
+                    ) {
+                        return Err(AutoModerationRuleError::new(
+                            AutoModerationRuleErrorType::ActionTriggerType {
+                                index,
+                                kind: action.kind,
+                            },
+                        ));
+                    }
+                }
+                AutoModerationActionType::Unknown(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate trigger metadata against the fields and limits of a trigger type.
+fn validate_trigger_metadata(
+    trigger_type: AutoModerationTriggerType,
+    metadata: &AutoModerationTriggerMetadata,
+) -> Result<(), AutoModerationRuleError> {
+    let (keywords, presets, mentions, allow_list_max) = match trigger_type {
+        AutoModerationTriggerType::Keyword | AutoModerationTriggerType::MemberProfile => (
+            true,
+            false,
+            false,
+            Some(AutoModerationRule::ALLOW_LIST_KEYWORD_MAX),
+        ),
+        AutoModerationTriggerType::KeywordPreset => (
+            false,
+            true,
+            false,
+            Some(AutoModerationRule::ALLOW_LIST_PRESET_MAX),
+        ),
+        AutoModerationTriggerType::MentionSpam => (false, false, true, None),
+        AutoModerationTriggerType::Spam => (false, false, false, None),
+        AutoModerationTriggerType::Unknown(_) => return Ok(()),
+    };
+
+    let unsupported = [
+        (
+            "keyword_filter",
+            !keywords && metadata.keyword_filter.is_some(),
+        ),
+        (
+            "regex_patterns",
+            !keywords && metadata.regex_patterns.is_some(),
+        ),
+        ("presets", !presets && metadata.presets.is_some()),
+        (
+            "allow_list",
+            allow_list_max.is_none() && metadata.allow_list.is_some(),
+        ),
+        (
+            "mention_total_limit",
+            !mentions && metadata.mention_total_limit.is_some(),
+        ),
+        (
+            "mention_raid_protection_enabled",
+            !mentions && metadata.mention_raid_protection_enabled.is_some(),
+        ),
+    ];
+
+    if let Some((field, _)) = unsupported
+        .into_iter()
+        .find(|(_, unsupported)| *unsupported)
+    {
+        return Err(AutoModerationRuleError::new(
+            AutoModerationRuleErrorType::MetadataField { field },
+        ));
+    }
+
+    validate_trigger_metadata_limits(metadata, allow_list_max)
+}
+
+/// Validate the lengths and counts of trigger metadata fields.
+fn validate_trigger_metadata_limits(
+    metadata: &AutoModerationTriggerMetadata,
+    allow_list_max: Option<usize>,
+) -> Result<(), AutoModerationRuleError> {
+    if let Some(keywords) = &metadata.keyword_filter {
+        if keywords.len() > AutoModerationRule::KEYWORD_FILTER_MAX {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::KeywordCount {
+                    count: keywords.len(),
+                },
+            ));
+        }
+
+        if let Some(index) = position_too_long(keywords, AutoModerationRule::KEYWORD_LENGTH_MAX) {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::KeywordLength { index },
+            ));
+        }
+    }
+
+    if let Some(patterns) = &metadata.regex_patterns {
+        if patterns.len() > AutoModerationRule::REGEX_PATTERNS_MAX {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::RegexPatternCount {
+                    count: patterns.len(),
+                },
+            ));
+        }
+
+        if let Some(index) =
+            position_too_long(patterns, AutoModerationRule::REGEX_PATTERN_LENGTH_MAX)
+        {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::RegexPatternLength { index },
+            ));
+        }
+    }
+
+    if let (Some(allow_list), Some(max)) = (&metadata.allow_list, allow_list_max) {
+        if allow_list.len() > max {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::AllowListCount {
+                    count: allow_list.len(),
+                    max,
+                },
+            ));
+        }
+
+        if let Some(index) =
+            position_too_long(allow_list, AutoModerationRule::ALLOW_LIST_ENTRY_LENGTH_MAX)
+        {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::AllowListEntryLength { index },
+            ));
+        }
+    }
+
+    if let Some(limit) = metadata.mention_total_limit {
+        if limit > AutoModerationRule::MENTION_TOTAL_LIMIT_MAX {
+            return Err(AutoModerationRuleError::new(
+                AutoModerationRuleErrorType::MentionTotalLimit { limit },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Index of the first value longer than `max` characters.
+fn position_too_long(values: &[String], max: usize) -> Option<usize> {
+    values.iter().position(|value| value.chars().count() > max)
+}
+
+fn count_error(f: &mut Formatter<'_>, count: usize, name: &str, max: usize) -> FmtResult {
+    Display::fmt(&count, f)?;
+    f.write_str(" ")?;
+    f.write_str(name)?;
+    f.write_str(" were provided when at most ")?;
+    Display::fmt(&max, f)?;
+
+    f.write_str(" are allowed")
+}
+
+fn length_error(f: &mut Formatter<'_>, name: &str, index: usize, max: usize) -> FmtResult {
+    f.write_str(name)?;
+    f.write_str(" ")?;
+    Display::fmt(&index, f)?;
+    f.write_str(" is longer than ")?;
+    Display::fmt(&max, f)?;
+
+    f.write_str(" characters")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoModerationRuleError, AutoModerationRuleErrorType};
+    use crate::{
+        guild::auto_moderation::{
+            AutoModerationActionMetadata, AutoModerationActionType, AutoModerationEventType,
+            AutoModerationRule, AutoModerationTriggerType,
+        },
+        id::Id,
+        test::{self, auto_moderation::action},
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(AutoModerationRuleError: Debug, Error, Send, Sync);
+    assert_impl_all!(AutoModerationRuleErrorType: Debug, Send, Sync);
+
+    fn rule(trigger_type: AutoModerationTriggerType) -> AutoModerationRule {
+        test::auto_moderation::rule(1, trigger_type)
+    }
+
+    fn kind(rule: &AutoModerationRule) -> AutoModerationRuleErrorType {
+        rule.validate().unwrap_err().into_parts().0
+    }
+
+    #[test]
+    fn valid() {
+        let mut keyword = rule(AutoModerationTriggerType::Keyword);
+        keyword.trigger_metadata.keyword_filter = Some(vec!["a".repeat(60); 1000]);
+        keyword.trigger_metadata.regex_patterns = Some(vec!["b".repeat(260); 10]);
+        keyword.trigger_metadata.allow_list = Some(vec!["c".repeat(60); 100]);
+        keyword.actions.push(action(
+            AutoModerationActionType::SendAlertMessage,
+            Some(AutoModerationActionMetadata {
+                channel_id: Some(Id::new(1)),
+                custom_message: None,
+                duration_seconds: None,
+            }),
+        ));
+        keyword.actions.push(action(
+            AutoModerationActionType::Timeout,
+            Some(AutoModerationActionMetadata {
+                channel_id: None,
+                custom_message: None,
+                duration_seconds: Some(AutoModerationRule::TIMEOUT_DURATION_MAX),
+            }),
+        ));
+        assert!(keyword.validate().is_ok());
+
+        let mut preset = rule(AutoModerationTriggerType::KeywordPreset);
+        preset.trigger_metadata.presets = Some(Vec::new());
+        preset.trigger_metadata.allow_list = Some(vec!["c".to_owned(); 1000]);
+        assert!(preset.validate().is_ok());
+
+        let mut mention = rule(AutoModerationTriggerType::MentionSpam);
+        mention.trigger_metadata.mention_total_limit = Some(50);
+        mention.trigger_metadata.mention_raid_protection_enabled = Some(true);
+        assert!(mention.validate().is_ok());
+
+        let mut profile = rule(AutoModerationTriggerType::MemberProfile);
+        profile.trigger_metadata.keyword_filter = Some(Vec::from(["name".to_owned()]));
+        assert!(profile.validate().is_ok());
+
+        assert!(rule(AutoModerationTriggerType::Spam).validate().is_ok());
+    }
+
+    #[test]
+    fn trigger_metadata() {
+        let mut value = rule(AutoModerationTriggerType::Keyword);
+        value.trigger_metadata.keyword_filter = Some(vec![String::new(); 1001]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::KeywordCount { count: 1001 }
+        ));
+
+        value.trigger_metadata.keyword_filter = Some(Vec::from(["a".to_owned(), "a".repeat(61)]));
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::KeywordLength { index: 1 }
+        ));
+
+        value.trigger_metadata.keyword_filter = None;
+        value.trigger_metadata.regex_patterns = Some(vec![String::new(); 11]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::RegexPatternCount { count: 11 }
+        ));
+
+        value.trigger_metadata.regex_patterns = Some(Vec::from(["a".repeat(261)]));
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::RegexPatternLength { index: 0 }
+        ));
+
+        value.trigger_metadata.regex_patterns = None;
+        value.trigger_metadata.allow_list = Some(vec![String::new(); 101]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::AllowListCount {
+                count: 101,
+                max: 100
+            }
+        ));
+
+        value.trigger_metadata.allow_list = None;
+        value.trigger_metadata.mention_total_limit = Some(5);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::MetadataField {
+                field: "mention_total_limit"
+            }
+        ));
+
+        let mut value = rule(AutoModerationTriggerType::MentionSpam);
+        value.trigger_metadata.mention_total_limit = Some(51);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::MentionTotalLimit { limit: 51 }
+        ));
+
+        value.trigger_metadata.mention_total_limit = None;
+        value.trigger_metadata.allow_list = Some(Vec::new());
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::MetadataField {
+                field: "allow_list"
+            }
+        ));
+    }
+
+    #[test]
+    fn event_type() {
+        let mut value = rule(AutoModerationTriggerType::MemberProfile);
+        value.event_type = AutoModerationEventType::MessageSend;
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::EventType {
+                event_type: AutoModerationEventType::MessageSend
+            }
+        ));
+
+        let mut value = rule(AutoModerationTriggerType::Keyword);
+        value.event_type = AutoModerationEventType::MemberUpdate;
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::EventType {
+                event_type: AutoModerationEventType::MemberUpdate
+            }
+        ));
+    }
+
+    #[test]
+    fn actions() {
+        let mut value = rule(AutoModerationTriggerType::Spam);
+        value.actions = Vec::from([action(AutoModerationActionType::SendAlertMessage, None)]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::ActionMetadataMissing {
+                index: 0,
+                field: "channel_id"
+            }
+        ));
+
+        value.actions = Vec::from([action(
+            AutoModerationActionType::Timeout,
+            Some(AutoModerationActionMetadata {
+                channel_id: None,
+                custom_message: None,
+                duration_seconds: Some(60),
+            }),
+        )]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::ActionTriggerType {
+                index: 0,
+                kind: AutoModerationActionType::Timeout
+            }
+        ));
+
+        value.trigger_type = AutoModerationTriggerType::MentionSpam;
+        value.actions[0].metadata = None;
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::ActionMetadataMissing {
+                index: 0,
+                field: "duration_seconds"
+            }
+        ));
+
+        value.actions[0].metadata = Some(AutoModerationActionMetadata {
+            channel_id: None,
+            custom_message: None,
+            duration_seconds: Some(AutoModerationRule::TIMEOUT_DURATION_MAX + 1),
+        });
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::TimeoutDuration { index: 0, .. }
+        ));
+
+        value.actions = Vec::from([action(
+            AutoModerationActionType::BlockMessage,
+            Some(AutoModerationActionMetadata {
+                channel_id: None,
+                custom_message: Some("a".repeat(151)),
+                duration_seconds: None,
+            }),
+        )]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::CustomMessageLength { index: 0 }
+        ));
+
+        value.actions = Vec::from([action(
+            AutoModerationActionType::BlockMemberInteraction,
+            None,
+        )]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::ActionEventType {
+                index: 0,
+                kind: AutoModerationActionType::BlockMemberInteraction
+            }
+        ));
+
+        let mut value = rule(AutoModerationTriggerType::MemberProfile);
+        value.actions = Vec::from([action(AutoModerationActionType::BlockMessage, None)]);
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::ActionEventType {
+                index: 0,
+                kind: AutoModerationActionType::BlockMessage
+            }
+        ));
+    }
+
+    #[test]
+    fn exemptions() {
+        let mut value = rule(AutoModerationTriggerType::Spam);
+        value.exempt_roles = (1..=21).map(Id::new).collect();
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::ExemptRoleCount { count: 21 }
+        ));
+
+        value.exempt_roles.clear();
+        value.exempt_channels = (1..=51).map(Id::new).collect();
+        assert!(matches!(
+            kind(&value),
+            AutoModerationRuleErrorType::ExemptChannelCount { count: 51 }
+        ));
+    }
+
+    #[test]
+    fn display() {
+        let mut value = rule(AutoModerationTriggerType::Keyword);
+        value.trigger_metadata.keyword_filter = Some(vec![String::new(); 1001]);
+
+        assert_eq!(
+            "1001 keywords were provided when at most 1000 are allowed",
+            value.validate().unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Portable backups of a guild's configuration.
+//!
+//! A [`GuildBackup`] is built from a [`Guild`] and optionally the guild's auto
+//! moderation rules, onboarding, welcome screen and widget settings, which are
+//! not part of the guild model. Backups are versioned and serializable, and
+//! can be restored to a guild by following a [`GuildRestorePlan`].
+//!
+//! Images aren't part of backups. The guild's icon, banner and splashes are
+//! referenced by their [`ImageHash`] and emojis and stickers by their ID,
+//! which can be used to download them from Discord's CDN while the source
+//! guild exists.
+
+mod restore;
+
+pub use self::restore::{
+    GuildBackupIdMap, GuildRestoreConflict, GuildRestoreError, GuildRestoreErrorType,
+    GuildRestorePlan, GuildRestoreStep,
+};
+
+use crate::{
+    channel::{message::sticker::Sticker, Channel},
+    guild::{
+        auto_moderation::AutoModerationRule, invite::WelcomeScreen, onboarding::Onboarding,
+        widget::GuildWidgetSettings, AfkTimeout, DefaultMessageNotificationLevel, Emoji,
+        ExplicitContentFilter, Guild, Role, SystemChannelFlags, VerificationLevel,
+    },
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+    util::{ImageHash, Locale},
+};
+use serde::{Deserialize, Serialize};
+
+/// Versioned backup of a guild's configuration.
+///
+/// # Examples
+///
+/// Back up a guild along with its auto moderation rules and serialize the
+/// backup:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::guild::{
+///     auto_moderation::AutoModerationRule, backup::GuildBackup, Guild,
+/// };
+///
+/// # fn backup(guild: &Guild, rules: Vec<AutoModerationRule>) -> Result<String, serde_json::Error> {
+/// let backup = GuildBackup::new(guild).auto_moderation_rules(rules);
+///
+/// serde_json::to_string(&backup)
+/// # }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GuildBackup {
+    /// Auto moderation rules of the guild.
+    #[serde(default)]
+    pub auto_moderation_rules: Vec<AutoModerationRule>,
+    /// Channels of the guild, including their permission overwrites and forum
+    /// tags.
+    ///
+    /// Threads are not included.
+    pub channels: Vec<Channel>,
+    /// Custom emojis of the guild.
+    pub emojis: Vec<Emoji>,
+    /// ID of the guild the backup was created from.
+    pub guild_id: Id<GuildMarker>,
+    /// Onboarding of the guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub onboarding: Option<Onboarding>,
+    /// Roles of the guild, including its `@everyone` role.
+    pub roles: Vec<Role>,
+    /// Settings of the guild.
+    pub settings: GuildBackupSettings,
+    /// Custom stickers of the guild.
+    pub stickers: Vec<Sticker>,
+    /// Version of the backup format.
+    ///
+    /// Backups created by this version of the library have a version of
+    /// [`VERSION`].
+    ///
+    /// [`VERSION`]: Self::VERSION
+    pub version: u16,
+    /// Welcome screen of the guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub welcome_screen: Option<WelcomeScreen>,
+    /// Widget settings of the guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub widget: Option<GuildWidgetSettings>,
+}
+
+impl GuildBackup {
+    /// Current version of the backup format.
+    pub const VERSION: u16 = 1;
+
+    /// Create a backup of a guild's settings, roles, channels, emojis and
+    /// stickers.
+    pub fn new(guild: &Guild) -> Self {
+        Self {
+            auto_moderation_rules: Vec::new(),
+            channels: guild.channels.clone(),
+            emojis: guild.emojis.clone(),
+            guild_id: guild.id,
+            onboarding: None,
+            roles: guild.roles.clone(),
+            settings: GuildBackupSettings::new(guild),
+            stickers: guild.stickers.clone(),
+            version: Self::VERSION,
+            welcome_screen: None,
+            widget: None,
+        }
+    }
+
+    /// Set the auto moderation rules of the guild.
+    #[must_use = "setting the rules has no effect if the backup is left unused"]
+    pub fn auto_moderation_rules(mut self, auto_moderation_rules: Vec<AutoModerationRule>) -> Self {
+        self.auto_moderation_rules = auto_moderation_rules;
+
+        self
+    }
+
+    /// Set the onboarding of the guild.
+    #[must_use = "setting the onboarding has no effect if the backup is left unused"]
+    pub fn onboarding(mut self, onboarding: Onboarding) -> Self {
+        self.onboarding = Some(onboarding);
+
+        self
+    }
+
+    /// Set the welcome screen of the guild.
+    #[must_use = "setting the welcome screen has no effect if the backup is left unused"]
+    pub fn welcome_screen(mut self, welcome_screen: WelcomeScreen) -> Self {
+        self.welcome_screen = Some(welcome_screen);
+
+        self
+    }
+
+    /// Set the widget settings of the guild.
+    #[must_use = "setting the widget settings has no effect if the backup is left unused"]
+    pub const fn widget(mut self, widget: GuildWidgetSettings) -> Self {
+        self.widget = Some(widget);
+
+        self
+    }
+}
+
+/// Settings of a guild in a [`GuildBackup`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GuildBackupSettings {
+    /// ID of the AFK channel.
+    pub afk_channel_id: Option<Id<ChannelMarker>>,
+    /// AFK timeout.
+    pub afk_timeout: AfkTimeout,
+    /// Hash of the banner.
+    pub banner: Option<ImageHash>,
+    /// Default message notification level.
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+    /// Description of the guild.
+    pub description: Option<String>,
+    /// Hash of the discovery splash.
+    pub discovery_splash: Option<ImageHash>,
+    /// Explicit content filter level.
+    pub explicit_content_filter: ExplicitContentFilter,
+    /// Hash of the icon.
+    pub icon: Option<ImageHash>,
+    /// Name of the guild.
+    pub name: String,
+    /// Preferred locale of the guild.
+    pub preferred_locale: Locale,
+    /// Whether the premium progress bar is enabled.
+    pub premium_progress_bar_enabled: bool,
+    /// ID of the channel where admins and moderators receive notices from
+    /// Discord.
+    pub public_updates_channel_id: Option<Id<ChannelMarker>>,
+    /// ID of the rules channel.
+    pub rules_channel_id: Option<Id<ChannelMarker>>,
+    /// ID of the channel where admins and moderators receive safety alerts
+    /// from Discord.
+    pub safety_alerts_channel_id: Option<Id<ChannelMarker>>,
+    /// Hash of the invite splash.
+    pub splash: Option<ImageHash>,
+    /// Flags of the system channel.
+    pub system_channel_flags: SystemChannelFlags,
+    /// ID of the system channel.
+    pub system_channel_id: Option<Id<ChannelMarker>>,
+    /// Verification level.
+    pub verification_level: VerificationLevel,
+}
+
+impl GuildBackupSettings {
+    /// Settings of a guild.
+    pub fn new(guild: &Guild) -> Self {
+        Self {
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            banner: guild.banner,
+            default_message_notifications: guild.default_message_notifications,
+            description: guild.description.clone(),
+            discovery_splash: guild.discovery_splash,
+            explicit_content_filter: guild.explicit_content_filter,
+            icon: guild.icon,
+            name: guild.name.clone(),
+            preferred_locale: guild.preferred_locale.clone(),
+            premium_progress_bar_enabled: guild.premium_progress_bar_enabled,
+            public_updates_channel_id: guild.public_updates_channel_id,
+            rules_channel_id: guild.rules_channel_id,
+            safety_alerts_channel_id: guild.safety_alerts_channel_id,
+            splash: guild.splash,
+            system_channel_flags: guild.system_channel_flags,
+            system_channel_id: guild.system_channel_id,
+            verification_level: guild.verification_level,
+        }
+    }
+}
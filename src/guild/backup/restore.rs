@@ -0,0 +1,1142 @@
+use super::{GuildBackup, GuildBackupSettings};
+use crate::{
+    channel::{message::sticker::Sticker, Channel},
+    guild::{
+        auto_moderation::AutoModerationRule,
+        invite::WelcomeScreen,
+        onboarding::Onboarding,
+        template::{self, TemplateIdMap, TemplatePlanError, TemplatePlanErrorType},
+        widget::GuildWidgetSettings,
+        Emoji, Guild, Role,
+    },
+    id::{
+        marker::{ChannelMarker, EmojiMarker, GuildMarker, RoleMarker, StickerMarker},
+        Id,
+    },
+};
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when a backup can't be restored.
+#[derive(Debug)]
+pub struct GuildRestoreError {
+    kind: GuildRestoreErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GuildRestoreError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GuildRestoreErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (GuildRestoreErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Error of a type without a source error.
+    const fn new(kind: GuildRestoreErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for GuildRestoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GuildRestoreErrorType::ChannelRemap { channel_id } => {
+                f.write_str("backup channel ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" could not be remapped")
+            }
+            GuildRestoreErrorType::UnmappedChannel { channel_id } => {
+                f.write_str("backup channel ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" has not been mapped to a restored channel")
+            }
+            GuildRestoreErrorType::UnmappedEmoji { emoji_id } => {
+                f.write_str("backup emoji ")?;
+                Display::fmt(emoji_id, f)?;
+
+                f.write_str(" has not been mapped to a restored emoji")
+            }
+            GuildRestoreErrorType::UnmappedRole { role_id } => {
+                f.write_str("backup role ")?;
+                Display::fmt(role_id, f)?;
+
+                f.write_str(" has not been mapped to a restored role")
+            }
+            GuildRestoreErrorType::Version { version } => {
+                f.write_str("backup version ")?;
+                Display::fmt(version, f)?;
+                f.write_str(" is not supported, the supported version is ")?;
+
+                Display::fmt(&GuildBackup::VERSION, f)
+            }
+        }
+    }
+}
+
+impl Error for GuildRestoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GuildRestoreError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GuildRestoreErrorType {
+    /// Channel of the backup could not be remapped by the [`TemplateIdMap`]
+    /// for a reason other than an unmapped channel or role.
+    ///
+    /// The source error is the [`TemplatePlanError`].
+    ChannelRemap {
+        /// ID of the channel in the backup.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Channel of the backup has not been mapped to a restored channel.
+    UnmappedChannel {
+        /// ID of the channel in the backup.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Emoji of the backup has not been mapped to a restored emoji.
+    UnmappedEmoji {
+        /// ID of the emoji in the backup.
+        emoji_id: Id<EmojiMarker>,
+    },
+    /// Role of the backup has not been mapped to a restored role.
+    UnmappedRole {
+        /// ID of the role in the backup.
+        role_id: Id<RoleMarker>,
+    },
+    /// Version of the backup is not supported.
+    Version {
+        /// Version of the backup.
+        version: u16,
+    },
+}
+
+/// Conflict between a backup and the guild it is restored to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum GuildRestoreConflict {
+    /// Guild already has a channel with the name and type of a channel of the
+    /// backup.
+    Channel {
+        /// ID of the channel in the backup.
+        channel_id: Id<ChannelMarker>,
+        /// ID of the guild's existing channel.
+        existing_id: Id<ChannelMarker>,
+    },
+    /// Restoring the backup would exceed the guild's channel limit.
+    ChannelLimit {
+        /// Number of channels after restoring.
+        count: usize,
+        /// Maximum number of channels.
+        limit: usize,
+    },
+    /// Guild already has an emoji with the name of an emoji of the backup.
+    Emoji {
+        /// ID of the emoji in the backup.
+        emoji_id: Id<EmojiMarker>,
+        /// ID of the guild's existing emoji.
+        existing_id: Id<EmojiMarker>,
+    },
+    /// Guild has no counterpart of an emoji of the backup which is managed by
+    /// an integration.
+    ///
+    /// References to the emoji can't be remapped unless it is mapped to
+    /// another emoji of the guild.
+    ManagedEmoji {
+        /// ID of the emoji in the backup.
+        emoji_id: Id<EmojiMarker>,
+    },
+    /// Guild has no counterpart of a role of the backup which is managed by an
+    /// integration, such as the role of a bot which isn't in the guild.
+    ///
+    /// References to the role, such as permission overwrites, can't be
+    /// remapped unless it is mapped to another role of the guild.
+    ManagedRole {
+        /// ID of the role in the backup.
+        role_id: Id<RoleMarker>,
+    },
+    /// Restoring the backup would exceed the guild's static or animated emoji
+    /// limit.
+    EmojiLimit {
+        /// Whether the limit of animated emojis is exceeded.
+        animated: bool,
+        /// Number of emojis after restoring.
+        count: usize,
+        /// Maximum number of emojis.
+        limit: usize,
+    },
+    /// Guild already has a role with the name of a role of the backup.
+    Role {
+        /// ID of the guild's existing role.
+        existing_id: Id<RoleMarker>,
+        /// ID of the role in the backup.
+        role_id: Id<RoleMarker>,
+    },
+    /// Restoring the backup would exceed the guild's role limit.
+    RoleLimit {
+        /// Number of roles after restoring.
+        count: usize,
+        /// Maximum number of roles.
+        limit: usize,
+    },
+    /// Guild already has a sticker with the name of a sticker of the backup.
+    Sticker {
+        /// ID of the guild's existing sticker.
+        existing_id: Id<StickerMarker>,
+        /// ID of the sticker in the backup.
+        sticker_id: Id<StickerMarker>,
+    },
+    /// Restoring the backup would exceed the guild's sticker limit.
+    StickerLimit {
+        /// Number of stickers after restoring.
+        count: usize,
+        /// Maximum number of stickers.
+        limit: usize,
+    },
+}
+
+/// Step of a [`GuildRestorePlan`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GuildRestoreStep<'a> {
+    /// Update the guild's `@everyone` role, whose ID is the guild's ID.
+    EveryoneRole(&'a Role),
+    /// Create a role.
+    Role(&'a Role),
+    /// Create an emoji.
+    Emoji(&'a Emoji),
+    /// Create a sticker.
+    Sticker(&'a Sticker),
+    /// Create a category channel.
+    Category(&'a Channel),
+    /// Create a non-category channel.
+    Channel(&'a Channel),
+    /// Create an auto moderation rule.
+    AutoModerationRule(&'a AutoModerationRule),
+    /// Update the guild's onboarding.
+    Onboarding(&'a Onboarding),
+    /// Update the guild's welcome screen.
+    WelcomeScreen(&'a WelcomeScreen),
+    /// Update the guild's widget settings.
+    Widget(&'a GuildWidgetSettings),
+    /// Update the guild's settings.
+    Settings(&'a GuildBackupSettings),
+}
+
+/// Ordered steps to restore a [`GuildBackup`] to a guild.
+///
+/// Steps are ordered so that every entity is created before it is referenced:
+/// roles, emojis and stickers come first, followed by categories, other
+/// channels, auto moderation rules, and finally the guild's onboarding,
+/// welcome screen, widget and settings. Roles are ordered from the highest to
+/// the lowest position, as Discord creates roles directly above the
+/// `@everyone` role. Managed roles and emojis, which are created by
+/// integrations, are not restored; the plan's ID map maps them to their
+/// counterparts in the guild, such as the role of the same bot, and reports
+/// a conflict for those without one.
+///
+/// Steps reference the backup's entities, which use the source guild's IDs;
+/// record the ID of every created entity in the [`GuildBackupIdMap`] of the
+/// plan and use it to remap later steps before applying them.
+///
+/// Created via [`GuildBackup::restore_plan`].
+#[derive(Clone, Debug)]
+pub struct GuildRestorePlan<'a> {
+    /// Backup the plan was created from.
+    backup: &'a GuildBackup,
+    /// Conflicts with the guild's existing state.
+    conflicts: Vec<GuildRestoreConflict>,
+    /// Mapping of the backup's `@everyone` role and managed roles and emojis
+    /// to the guild's.
+    ids: GuildBackupIdMap,
+    /// Ordered steps of the plan.
+    steps: Vec<GuildRestoreStep<'a>>,
+}
+
+impl<'a> GuildRestorePlan<'a> {
+    /// Maximum number of channels of a guild.
+    pub const CHANNELS_MAX: usize = 500;
+
+    /// Maximum number of roles of a guild.
+    pub const ROLES_MAX: usize = 250;
+
+    /// Backup the plan was created from.
+    pub const fn backup(&self) -> &'a GuildBackup {
+        self.backup
+    }
+
+    /// Conflicts between the backup and the guild's existing state.
+    ///
+    /// Conflicting entities are still part of the steps; skip them and record
+    /// the existing entity in the ID map to reuse it instead.
+    pub fn conflicts(&self) -> &[GuildRestoreConflict] {
+        &self.conflicts
+    }
+
+    /// Create an ID map which maps the backup's guild and `@everyone` role to
+    /// the guild the backup is restored to, and the backup's managed roles and
+    /// emojis to their counterparts in the guild.
+    pub fn id_map(&self) -> GuildBackupIdMap {
+        self.ids.clone()
+    }
+
+    /// Ordered steps of the plan.
+    pub fn steps(&self) -> &[GuildRestoreStep<'a>] {
+        &self.steps
+    }
+}
+
+impl GuildBackup {
+    /// Plan restoring the backup to a guild, detecting conflicts with the
+    /// guild's existing roles, channels, emojis and stickers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Version`] if the backup's version is not
+    /// supported.
+    ///
+    /// [`Version`]: GuildRestoreErrorType::Version
+    pub fn restore_plan(&self, target: &Guild) -> Result<GuildRestorePlan<'_>, GuildRestoreError> {
+        if self.version != Self::VERSION {
+            return Err(GuildRestoreError::new(GuildRestoreErrorType::Version {
+                version: self.version,
+            }));
+        }
+
+        let mut conflicts = Vec::new();
+        let mut ids = GuildBackupIdMap::new(self.guild_id, target.id);
+        let mut steps = Vec::new();
+
+        self.plan_roles(target, &mut conflicts, &mut ids, &mut steps);
+        self.plan_emojis_and_stickers(target, &mut conflicts, &mut ids, &mut steps);
+        self.plan_channels(target, &mut conflicts, &mut steps);
+
+        steps.extend(
+            self.auto_moderation_rules
+                .iter()
+                .map(GuildRestoreStep::AutoModerationRule),
+        );
+        steps.extend(self.onboarding.iter().map(GuildRestoreStep::Onboarding));
+        steps.extend(
+            self.welcome_screen
+                .iter()
+                .map(GuildRestoreStep::WelcomeScreen),
+        );
+        steps.extend(self.widget.iter().map(GuildRestoreStep::Widget));
+        steps.push(GuildRestoreStep::Settings(&self.settings));
+
+        Ok(GuildRestorePlan {
+            backup: self,
+            conflicts,
+            ids,
+            steps,
+        })
+    }
+
+    /// Plan the update of the `@everyone` role followed by the creation of
+    /// roles that aren't managed by an integration, mapping managed roles to
+    /// their counterparts.
+    fn plan_roles<'a>(
+        &'a self,
+        target: &Guild,
+        conflicts: &mut Vec<GuildRestoreConflict>,
+        ids: &mut GuildBackupIdMap,
+        steps: &mut Vec<GuildRestoreStep<'a>>,
+    ) {
+        let everyone_id = self.guild_id.cast::<RoleMarker>();
+
+        if let Some(everyone) = self.roles.iter().find(|role| role.id == everyone_id) {
+            steps.push(GuildRestoreStep::EveryoneRole(everyone));
+        }
+
+        let mut roles: Vec<&Role> = self
+            .roles
+            .iter()
+            .filter(|role| role.id != everyone_id && !role.managed)
+            .collect();
+        roles.sort_by_key(|role| Reverse(role.position));
+
+        let target_everyone_id = target.id.cast::<RoleMarker>();
+
+        // Roles of bots are matched by their bot, which is renamed
+        // independently of the role, and other managed roles by name.
+        let bot_id = |role: &Role| role.tags.as_ref().and_then(|tags| tags.bot_id);
+
+        for role in self.roles.iter().filter(|role| role.managed) {
+            let counterpart = target.roles.iter().find(|existing| {
+                existing.managed
+                    && match bot_id(role) {
+                        Some(id) => bot_id(existing) == Some(id),
+                        None => existing.name == role.name,
+                    }
+            });
+
+            if let Some(existing) = counterpart {
+                ids.insert_role(role.id, existing.id);
+            } else {
+                conflicts.push(GuildRestoreConflict::ManagedRole { role_id: role.id });
+            }
+        }
+
+        for role in &roles {
+            if let Some(existing) = target.roles.iter().find(|existing| {
+                existing.id != target_everyone_id && !existing.managed && existing.name == role.name
+            }) {
+                conflicts.push(GuildRestoreConflict::Role {
+                    existing_id: existing.id,
+                    role_id: role.id,
+                });
+            }
+        }
+
+        push_limit(
+            conflicts,
+            target.roles.len() + roles.len(),
+            GuildRestorePlan::ROLES_MAX,
+            |count, limit| GuildRestoreConflict::RoleLimit { count, limit },
+        );
+        steps.extend(roles.into_iter().map(GuildRestoreStep::Role));
+    }
+
+    /// Plan the creation of emojis that aren't managed by an integration
+    /// followed by stickers, mapping managed emojis to their counterparts.
+    fn plan_emojis_and_stickers<'a>(
+        &'a self,
+        target: &Guild,
+        conflicts: &mut Vec<GuildRestoreConflict>,
+        ids: &mut GuildBackupIdMap,
+        steps: &mut Vec<GuildRestoreStep<'a>>,
+    ) {
+        let emojis: Vec<&Emoji> = self.emojis.iter().filter(|emoji| !emoji.managed).collect();
+
+        for emoji in self.emojis.iter().filter(|emoji| emoji.managed) {
+            if let Some(existing) = target
+                .emojis
+                .iter()
+                .find(|existing| existing.managed && existing.name == emoji.name)
+            {
+                ids.insert_emoji(emoji.id, existing.id);
+            } else {
+                conflicts.push(GuildRestoreConflict::ManagedEmoji { emoji_id: emoji.id });
+            }
+        }
+
+        for emoji in &emojis {
+            if let Some(existing) = target
+                .emojis
+                .iter()
+                .find(|existing| existing.name == emoji.name)
+            {
+                conflicts.push(GuildRestoreConflict::Emoji {
+                    emoji_id: emoji.id,
+                    existing_id: existing.id,
+                });
+            }
+        }
+
+        for animated in [false, true] {
+            let count = target
+                .emojis
+                .iter()
+                .chain(emojis.iter().copied())
+                .filter(|emoji| emoji.animated == animated)
+                .count();
+
+            push_limit(
+                conflicts,
+                count,
+                usize::from(target.emoji_limit()),
+                |count, limit| GuildRestoreConflict::EmojiLimit {
+                    animated,
+                    count,
+                    limit,
+                },
+            );
+        }
+
+        steps.extend(emojis.into_iter().map(GuildRestoreStep::Emoji));
+
+        for sticker in &self.stickers {
+            if let Some(existing) = target
+                .stickers
+                .iter()
+                .find(|existing| existing.name == sticker.name)
+            {
+                conflicts.push(GuildRestoreConflict::Sticker {
+                    existing_id: existing.id,
+                    sticker_id: sticker.id,
+                });
+            }
+        }
+
+        push_limit(
+            conflicts,
+            target.stickers.len() + self.stickers.len(),
+            usize::from(target.sticker_limit()),
+            |count, limit| GuildRestoreConflict::StickerLimit { count, limit },
+        );
+        steps.extend(self.stickers.iter().map(GuildRestoreStep::Sticker));
+    }
+
+    /// Plan the creation of categories followed by other channels.
+    fn plan_channels<'a>(
+        &'a self,
+        target: &Guild,
+        conflicts: &mut Vec<GuildRestoreConflict>,
+        steps: &mut Vec<GuildRestoreStep<'a>>,
+    ) {
+        let (categories, channels) = template::order_channels(&self.channels);
+
+        for channel in categories.iter().chain(&channels) {
+            if let Some(existing) = target
+                .channels
+                .iter()
+                .find(|existing| existing.kind == channel.kind && existing.name == channel.name)
+            {
+                conflicts.push(GuildRestoreConflict::Channel {
+                    channel_id: channel.id,
+                    existing_id: existing.id,
+                });
+            }
+        }
+
+        push_limit(
+            conflicts,
+            target.channels.len() + self.channels.len(),
+            GuildRestorePlan::CHANNELS_MAX,
+            |count, limit| GuildRestoreConflict::ChannelLimit { count, limit },
+        );
+
+        steps.extend(categories.into_iter().map(GuildRestoreStep::Category));
+        steps.extend(channels.into_iter().map(GuildRestoreStep::Channel));
+    }
+}
+
+/// Convert an error remapping a channel with a [`TemplateIdMap`] into a
+/// restore error.
+fn unmapped(channel_id: Id<ChannelMarker>, source: TemplatePlanError) -> GuildRestoreError {
+    match source.kind() {
+        TemplatePlanErrorType::UnmappedChannel { channel_id } => {
+            GuildRestoreError::new(GuildRestoreErrorType::UnmappedChannel {
+                channel_id: *channel_id,
+            })
+        }
+        TemplatePlanErrorType::UnmappedRole { role_id } => {
+            GuildRestoreError::new(GuildRestoreErrorType::UnmappedRole { role_id: *role_id })
+        }
+        _ => GuildRestoreError {
+            kind: GuildRestoreErrorType::ChannelRemap { channel_id },
+            source: Some(Box::new(source)),
+        },
+    }
+}
+
+/// Record a limit conflict if a count exceeds its limit.
+fn push_limit(
+    conflicts: &mut Vec<GuildRestoreConflict>,
+    count: usize,
+    limit: usize,
+    conflict: impl FnOnce(usize, usize) -> GuildRestoreConflict,
+) {
+    if count > limit {
+        conflicts.push(conflict(count, limit));
+    }
+}
+
+/// Mapping of a backup's IDs to the IDs of restored entities.
+///
+/// The `remap` methods clone an entity of the backup, replacing every
+/// referenced channel, role and emoji with its restored ID and the guild ID
+/// with the ID of the guild the backup is restored to. The IDs of the entities
+/// themselves remain the backup's.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuildBackupIdMap {
+    /// Restored emoji IDs by backup emoji ID.
+    emojis: HashMap<Id<EmojiMarker>, Id<EmojiMarker>>,
+    /// ID of the guild the backup is restored to.
+    guild_id: Id<GuildMarker>,
+    /// Restored channel and role IDs by backup ID.
+    ids: TemplateIdMap,
+}
+
+impl GuildBackupIdMap {
+    /// Create a mapping from a backed up guild to the guild it is restored
+    /// to, mapping the `@everyone` roles of the guilds.
+    pub fn new(source_guild_id: Id<GuildMarker>, target_guild_id: Id<GuildMarker>) -> Self {
+        let mut ids = TemplateIdMap::new();
+        ids.insert_role(source_guild_id.cast(), target_guild_id.cast());
+
+        Self {
+            emojis: HashMap::new(),
+            guild_id: target_guild_id,
+            ids,
+        }
+    }
+
+    /// Restored ID of a backup channel.
+    pub fn channel(&self, backup_id: Id<ChannelMarker>) -> Option<Id<ChannelMarker>> {
+        self.ids.channel(backup_id)
+    }
+
+    /// Restored ID of a backup emoji.
+    pub fn emoji(&self, backup_id: Id<EmojiMarker>) -> Option<Id<EmojiMarker>> {
+        self.emojis.get(&backup_id).copied()
+    }
+
+    /// Restored ID of a backup role.
+    pub fn role(&self, backup_id: Id<RoleMarker>) -> Option<Id<RoleMarker>> {
+        self.ids.role(backup_id)
+    }
+
+    /// Record the restored ID of a backup channel, returning the previously
+    /// recorded ID if there is one.
+    pub fn insert_channel(
+        &mut self,
+        backup_id: Id<ChannelMarker>,
+        id: Id<ChannelMarker>,
+    ) -> Option<Id<ChannelMarker>> {
+        self.ids.insert_channel(backup_id, id)
+    }
+
+    /// Record the restored ID of a backup emoji, returning the previously
+    /// recorded ID if there is one.
+    pub fn insert_emoji(
+        &mut self,
+        backup_id: Id<EmojiMarker>,
+        id: Id<EmojiMarker>,
+    ) -> Option<Id<EmojiMarker>> {
+        self.emojis.insert(backup_id, id)
+    }
+
+    /// Record the restored ID of a backup role, returning the previously
+    /// recorded ID if there is one.
+    pub fn insert_role(
+        &mut self,
+        backup_id: Id<RoleMarker>,
+        id: Id<RoleMarker>,
+    ) -> Option<Id<RoleMarker>> {
+        self.ids.insert_role(backup_id, id)
+    }
+
+    /// Remap the channels and roles referenced by an auto moderation rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`] or [`UnmappedRole`] if a
+    /// referenced channel or role has not been mapped.
+    ///
+    /// [`UnmappedChannel`]: GuildRestoreErrorType::UnmappedChannel
+    /// [`UnmappedRole`]: GuildRestoreErrorType::UnmappedRole
+    pub fn remap_auto_moderation_rule(
+        &self,
+        rule: &AutoModerationRule,
+    ) -> Result<AutoModerationRule, GuildRestoreError> {
+        let mut rule = rule.clone();
+        rule.guild_id = self.guild_id;
+
+        for channel_id in &mut rule.exempt_channels {
+            *channel_id = self.channel_id(*channel_id)?;
+        }
+
+        for role_id in &mut rule.exempt_roles {
+            *role_id = self.role_id(*role_id)?;
+        }
+
+        for metadata in rule
+            .actions
+            .iter_mut()
+            .filter_map(|action| action.metadata.as_mut())
+        {
+            metadata.channel_id = metadata
+                .channel_id
+                .map(|channel_id| self.channel_id(channel_id))
+                .transpose()?;
+        }
+
+        Ok(rule)
+    }
+
+    /// Remap the parent, role permission overwrites, forum tag emojis and
+    /// default reaction emoji of a channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`], [`UnmappedRole`] or
+    /// [`UnmappedEmoji`] if a referenced channel, role or custom emoji has not
+    /// been mapped.
+    ///
+    /// [`UnmappedChannel`]: GuildRestoreErrorType::UnmappedChannel
+    /// [`UnmappedEmoji`]: GuildRestoreErrorType::UnmappedEmoji
+    /// [`UnmappedRole`]: GuildRestoreErrorType::UnmappedRole
+    pub fn remap_channel(&self, channel: &Channel) -> Result<Channel, GuildRestoreError> {
+        let mut channel = self
+            .ids
+            .remap_channel(channel)
+            .map_err(|source| unmapped(channel.id, source))?;
+        channel.guild_id = Some(self.guild_id);
+
+        for tag in channel.available_tags.iter_mut().flatten() {
+            tag.emoji_id = self.emoji_id(tag.emoji_id)?;
+        }
+
+        if let Some(reaction) = &mut channel.default_reaction_emoji {
+            reaction.emoji_id = self.emoji_id(reaction.emoji_id)?;
+        }
+
+        Ok(channel)
+    }
+
+    /// Remap the roles an emoji is limited to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedRole`] if a role has not been
+    /// mapped.
+    ///
+    /// [`UnmappedRole`]: GuildRestoreErrorType::UnmappedRole
+    pub fn remap_emoji(&self, emoji: &Emoji) -> Result<Emoji, GuildRestoreError> {
+        let mut emoji = emoji.clone();
+
+        for role_id in &mut emoji.roles {
+            *role_id = self.role_id(*role_id)?;
+        }
+
+        Ok(emoji)
+    }
+
+    /// Remap the channels, roles and emojis referenced by the onboarding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`], [`UnmappedRole`] or
+    /// [`UnmappedEmoji`] if a referenced channel, role or custom emoji has not
+    /// been mapped.
+    ///
+    /// [`UnmappedChannel`]: GuildRestoreErrorType::UnmappedChannel
+    /// [`UnmappedEmoji`]: GuildRestoreErrorType::UnmappedEmoji
+    /// [`UnmappedRole`]: GuildRestoreErrorType::UnmappedRole
+    pub fn remap_onboarding(
+        &self,
+        onboarding: &Onboarding,
+    ) -> Result<Onboarding, GuildRestoreError> {
+        let mut onboarding = onboarding.clone();
+        onboarding.guild_id = self.guild_id;
+
+        for channel_id in &mut onboarding.default_channel_ids {
+            *channel_id = self.channel_id(*channel_id)?;
+        }
+
+        for option in onboarding
+            .prompts
+            .iter_mut()
+            .flat_map(|prompt| prompt.options.iter_mut())
+        {
+            for channel_id in &mut option.channel_ids {
+                *channel_id = self.channel_id(*channel_id)?;
+            }
+
+            for role_id in &mut option.role_ids {
+                *role_id = self.role_id(*role_id)?;
+            }
+
+            let emoji_id = option.emoji.id_mut();
+            *emoji_id = self.emoji_id(*emoji_id)?;
+        }
+
+        Ok(onboarding)
+    }
+
+    /// Remap the channels referenced by the guild's settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`] if a referenced channel
+    /// has not been mapped.
+    ///
+    /// [`UnmappedChannel`]: GuildRestoreErrorType::UnmappedChannel
+    pub fn remap_settings(
+        &self,
+        settings: &GuildBackupSettings,
+    ) -> Result<GuildBackupSettings, GuildRestoreError> {
+        let mut settings = settings.clone();
+
+        for channel_id in [
+            &mut settings.afk_channel_id,
+            &mut settings.public_updates_channel_id,
+            &mut settings.rules_channel_id,
+            &mut settings.safety_alerts_channel_id,
+            &mut settings.system_channel_id,
+        ] {
+            *channel_id = channel_id.map(|id| self.channel_id(id)).transpose()?;
+        }
+
+        Ok(settings)
+    }
+
+    /// Remap the channels and emojis referenced by a welcome screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`] or [`UnmappedEmoji`] if a
+    /// referenced channel or custom emoji has not been mapped.
+    ///
+    /// [`UnmappedChannel`]: GuildRestoreErrorType::UnmappedChannel
+    /// [`UnmappedEmoji`]: GuildRestoreErrorType::UnmappedEmoji
+    pub fn remap_welcome_screen(
+        &self,
+        welcome_screen: &WelcomeScreen,
+    ) -> Result<WelcomeScreen, GuildRestoreError> {
+        let mut welcome_screen = welcome_screen.clone();
+
+        for channel in &mut welcome_screen.welcome_channels {
+            channel.channel_id = self.channel_id(channel.channel_id)?;
+            channel.emoji_id = self.emoji_id(channel.emoji_id)?;
+        }
+
+        Ok(welcome_screen)
+    }
+
+    /// Remap the channel of widget settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`] if the channel has not
+    /// been mapped.
+    ///
+    /// [`UnmappedChannel`]: GuildRestoreErrorType::UnmappedChannel
+    pub fn remap_widget(
+        &self,
+        widget: &GuildWidgetSettings,
+    ) -> Result<GuildWidgetSettings, GuildRestoreError> {
+        Ok(GuildWidgetSettings {
+            channel_id: self.channel_id(widget.channel_id)?,
+            enabled: widget.enabled,
+        })
+    }
+
+    /// Restored ID of a channel, or an error if it has not been mapped.
+    fn channel_id(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<Id<ChannelMarker>, GuildRestoreError> {
+        self.channel(channel_id).ok_or(GuildRestoreError::new(
+            GuildRestoreErrorType::UnmappedChannel { channel_id },
+        ))
+    }
+
+    /// Restored ID of an optional custom emoji, or an error if it has not been
+    /// mapped.
+    fn emoji_id(
+        &self,
+        emoji_id: Option<Id<EmojiMarker>>,
+    ) -> Result<Option<Id<EmojiMarker>>, GuildRestoreError> {
+        emoji_id
+            .map(|emoji_id| {
+                self.emoji(emoji_id).ok_or(GuildRestoreError::new(
+                    GuildRestoreErrorType::UnmappedEmoji { emoji_id },
+                ))
+            })
+            .transpose()
+    }
+
+    /// Restored ID of a role, or an error if it has not been mapped.
+    fn role_id(&self, role_id: Id<RoleMarker>) -> Result<Id<RoleMarker>, GuildRestoreError> {
+        self.role(role_id).ok_or(GuildRestoreError::new(
+            GuildRestoreErrorType::UnmappedRole { role_id },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        GuildBackupIdMap, GuildRestoreConflict, GuildRestoreError, GuildRestoreErrorType,
+        GuildRestorePlan, GuildRestoreStep,
+    };
+    use crate::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        guild::{
+            backup::GuildBackup,
+            invite::{WelcomeScreen, WelcomeScreenChannel},
+            widget::GuildWidgetSettings,
+            Guild, Permissions,
+        },
+        id::Id,
+        test::guild::{channel, guild, role},
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(GuildBackupIdMap: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(GuildRestoreConflict: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(GuildRestoreError: Debug, Error, Send, Sync);
+    assert_impl_all!(GuildRestorePlan<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(GuildRestoreStep<'_>: Clone, Copy, Debug, Send, Sync);
+
+    fn source() -> Guild {
+        let mut bot = role(4, "bot", "1024", 3);
+        bot["managed"] = true.into();
+
+        let mut general = channel(11, "general", 0);
+        general["parent_id"] = "10".into();
+
+        let mut guild = guild(
+            1,
+            &[
+                role(1, "@everyone", "1024", 0),
+                role(3, "member", "1024", 1),
+                role(2, "moderator", "1024", 2),
+                bot,
+            ],
+            &[general, channel(10, "text", 4)],
+        );
+        guild.system_channel_id = Some(Id::new(11));
+        guild.emojis = serde_json::from_value(serde_json::json!([
+            {
+                "animated": false,
+                "id": "20",
+                "name": "wave",
+                "roles": ["2"],
+            },
+            {
+                "animated": true,
+                "id": "21",
+                "managed": true,
+                "name": "twitch",
+            },
+        ]))
+        .unwrap();
+
+        guild
+    }
+
+    fn target() -> Guild {
+        guild(
+            1000,
+            &[
+                role(1000, "@everyone", "1024", 0),
+                role(1001, "moderator", "1024", 1),
+            ],
+            &[channel(1011, "general", 0)],
+        )
+    }
+
+    fn backup() -> GuildBackup {
+        GuildBackup::new(&source())
+            .welcome_screen(WelcomeScreen {
+                description: None,
+                welcome_channels: Vec::from([WelcomeScreenChannel {
+                    channel_id: Id::new(11),
+                    description: "talk here".to_owned(),
+                    emoji_id: Some(Id::new(20)),
+                    emoji_name: Some("wave".to_owned()),
+                }]),
+            })
+            .widget(GuildWidgetSettings {
+                channel_id: Id::new(11),
+                enabled: true,
+            })
+    }
+
+    #[test]
+    fn steps() {
+        let backup = backup();
+        let plan = backup.restore_plan(&target()).unwrap();
+
+        assert_eq!(
+            [
+                GuildRestoreStep::EveryoneRole(&backup.roles[0]),
+                GuildRestoreStep::Role(&backup.roles[2]),
+                GuildRestoreStep::Role(&backup.roles[1]),
+                GuildRestoreStep::Emoji(&backup.emojis[0]),
+                GuildRestoreStep::Category(&backup.channels[1]),
+                GuildRestoreStep::Channel(&backup.channels[0]),
+                GuildRestoreStep::WelcomeScreen(backup.welcome_screen.as_ref().unwrap()),
+                GuildRestoreStep::Widget(backup.widget.as_ref().unwrap()),
+                GuildRestoreStep::Settings(&backup.settings),
+            ]
+            .as_slice(),
+            plan.steps(),
+        );
+    }
+
+    #[test]
+    fn conflicts() {
+        let backup = backup();
+        let plan = backup.restore_plan(&target()).unwrap();
+
+        assert_eq!(
+            [
+                GuildRestoreConflict::ManagedRole {
+                    role_id: Id::new(4),
+                },
+                GuildRestoreConflict::Role {
+                    existing_id: Id::new(1001),
+                    role_id: Id::new(2),
+                },
+                GuildRestoreConflict::ManagedEmoji {
+                    emoji_id: Id::new(21),
+                },
+                GuildRestoreConflict::Channel {
+                    channel_id: Id::new(11),
+                    existing_id: Id::new(1011),
+                },
+            ]
+            .as_slice(),
+            plan.conflicts(),
+        );
+    }
+
+    #[test]
+    fn managed() {
+        let mut backup = backup();
+        backup.channels[1].permission_overwrites = Some(Vec::from([PermissionOverwrite {
+            allow: Permissions::SEND_MESSAGES,
+            deny: Permissions::empty(),
+            id: Id::new(4),
+            kind: PermissionOverwriteType::Role,
+        }]));
+
+        let plan = backup.restore_plan(&target()).unwrap();
+        assert!(plan
+            .conflicts()
+            .contains(&GuildRestoreConflict::ManagedRole {
+                role_id: Id::new(4)
+            }));
+        assert!(matches!(
+            plan.id_map().remap_channel(&backup.channels[1]).unwrap_err().kind(),
+            GuildRestoreErrorType::UnmappedRole { role_id } if *role_id == Id::new(4)
+        ));
+
+        let mut bot = role(1004, "bot", "1024", 2);
+        bot["managed"] = true.into();
+
+        let mut target = guild(
+            1000,
+            &[role(1000, "@everyone", "1024", 0), bot],
+            &[channel(1011, "general", 0)],
+        );
+        target.emojis = serde_json::from_value(serde_json::json!([{
+            "animated": true,
+            "id": "1021",
+            "managed": true,
+            "name": "twitch",
+        }]))
+        .unwrap();
+
+        let plan = backup.restore_plan(&target).unwrap();
+        assert!(!plan.conflicts().iter().any(|conflict| matches!(
+            conflict,
+            GuildRestoreConflict::ManagedEmoji { .. } | GuildRestoreConflict::ManagedRole { .. }
+        )));
+
+        let ids = plan.id_map();
+        assert_eq!(Some(Id::new(1004)), ids.role(Id::new(4)));
+        assert_eq!(Some(Id::new(1021)), ids.emoji(Id::new(21)));
+
+        let channel = ids.remap_channel(&backup.channels[1]).unwrap();
+        assert_eq!(Id::new(1004), channel.permission_overwrites.unwrap()[0].id);
+    }
+
+    #[test]
+    fn version() {
+        let mut backup = backup();
+        backup.version = 2;
+
+        let error = backup.restore_plan(&target()).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            GuildRestoreErrorType::Version { version: 2 }
+        ));
+        assert_eq!(
+            "backup version 2 is not supported, the supported version is 1",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn remap() {
+        let mut backup = backup();
+        backup.channels[0].permission_overwrites = Some(Vec::from([PermissionOverwrite {
+            allow: Permissions::SEND_MESSAGES,
+            deny: Permissions::empty(),
+            id: Id::new(2),
+            kind: PermissionOverwriteType::Role,
+        }]));
+
+        let plan = backup.restore_plan(&target()).unwrap();
+        let mut ids = plan.id_map();
+
+        assert_eq!(Some(Id::new(1000)), ids.role(Id::new(1)));
+        assert!(matches!(
+            ids.remap_channel(&backup.channels[0]).unwrap_err().kind(),
+            GuildRestoreErrorType::UnmappedChannel { channel_id } if *channel_id == Id::new(10)
+        ));
+
+        ids.insert_channel(Id::new(10), Id::new(1010));
+        assert!(matches!(
+            ids.remap_channel(&backup.channels[0]).unwrap_err().kind(),
+            GuildRestoreErrorType::UnmappedRole { role_id } if *role_id == Id::new(2)
+        ));
+
+        ids.insert_role(Id::new(2), Id::new(1002));
+        let channel = ids.remap_channel(&backup.channels[0]).unwrap();
+        assert_eq!(Some(Id::new(1000)), channel.guild_id);
+        assert_eq!(Some(Id::new(1010)), channel.parent_id);
+        assert_eq!(Id::new(1002), channel.permission_overwrites.unwrap()[0].id);
+
+        let emoji = ids.remap_emoji(&backup.emojis[0]).unwrap();
+        assert_eq!(Vec::from([Id::new(1002)]), emoji.roles);
+
+        ids.insert_channel(Id::new(11), Id::new(1011));
+        let welcome_screen = backup.welcome_screen.as_ref().unwrap();
+        assert!(matches!(
+            ids.remap_welcome_screen(welcome_screen).unwrap_err().kind(),
+            GuildRestoreErrorType::UnmappedEmoji { emoji_id } if *emoji_id == Id::new(20)
+        ));
+
+        ids.insert_emoji(Id::new(20), Id::new(1020));
+        let welcome_screen = ids.remap_welcome_screen(welcome_screen).unwrap();
+        assert_eq!(Id::new(1011), welcome_screen.welcome_channels[0].channel_id);
+        assert_eq!(
+            Some(Id::new(1020)),
+            welcome_screen.welcome_channels[0].emoji_id
+        );
+
+        let widget = ids.remap_widget(backup.widget.as_ref().unwrap()).unwrap();
+        assert_eq!(Id::new(1011), widget.channel_id);
+
+        let settings = ids.remap_settings(&backup.settings).unwrap();
+        assert_eq!(Some(Id::new(1011)), settings.system_channel_id);
+    }
+
+    #[test]
+    fn round_trip() {
+        let backup = backup();
+        let json = serde_json::to_string(&backup).unwrap();
+
+        assert_eq!(backup, serde_json::from_str::<GuildBackup>(&json).unwrap());
+    }
+}
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Emoji {
     #[serde(default)]
     pub animated: bool,
@@ -78,7 +79,9 @@ mod tests {
                 Token::Str("available"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100000000000000000"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -108,7 +111,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("username"),
                 Token::Str("test"),
@@ -161,7 +166,9 @@ mod tests {
                 Token::Str("available"),
                 Token::Bool(true),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100000000000000000"),
                 Token::Str("managed"),
                 Token::Bool(false),
@@ -171,7 +178,9 @@ mod tests {
                 Token::Bool(true),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::SeqEnd,
                 Token::Str("user"),
@@ -196,7 +205,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("username"),
                 Token::Str("test"),
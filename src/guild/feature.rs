@@ -1,6 +1,7 @@
 #![allow(deprecated)]
 use std::borrow::Cow;
 
+use crate::guild::{GuildFeatureField, Permissions};
 use serde::{Deserialize, Serialize};
 
 /// Special and optional guild features.
@@ -8,7 +9,8 @@ use serde::{Deserialize, Serialize};
 /// See [Discord Docs/Guild Features].
 ///
 /// [Discord Docs/Guild Features]: https://discord.com/developers/docs/resources/guild#guild-object-guild-features
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "String", into = "Cow<'static, str>")]
 pub enum GuildFeature {
@@ -77,6 +79,136 @@ pub enum GuildFeature {
     Unknown(String),
 }
 
+impl GuildFeature {
+    /// Human readable description of the feature.
+    ///
+    /// Returns [`None`] for [`Unknown`] features.
+    ///
+    /// [`Unknown`]: Self::Unknown
+    pub const fn description(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::AnimatedBanner => "Has access to set an animated guild banner image",
+            Self::AnimatedIcon => "Has access to set an animated guild icon",
+            Self::AutoModeration => "Has set up auto moderation rules",
+            Self::Banner => "Has access to set a guild banner image",
+            Self::Commerce => "Has access to use commerce features",
+            Self::Community => {
+                "Can enable welcome screen, membership screening, stage channels, discovery, \
+                 and receives community updates"
+            }
+            Self::CreatorMonetizableProvisional => "Has enabled monetization",
+            Self::CreatorStorePage => "Has enabled the role subscription promotional page",
+            Self::DeveloperSupportServer => "Has been set as a support server on the App Directory",
+            Self::Discoverable => "Is able to be discovered in the directory",
+            Self::Featurable => "Is able to be featured in the directory",
+            Self::InvitesDisabled => "Has paused invites, preventing new users from joining",
+            Self::InviteSplash => "Has access to set an invite splash background",
+            Self::MemberVerificationGateEnabled => "Has enabled membership screening",
+            Self::MonetizationEnabled => "Has enabled monetization, no longer in use by Discord",
+            Self::MoreStickers => "Has increased custom sticker slots",
+            Self::News => "Has access to create news channels",
+            Self::Partnered => "Is partnered",
+            Self::PreviewEnabled => {
+                "Can be previewed before joining via membership screening or the directory"
+            }
+            Self::PrivateThreads => "Has access to create private threads",
+            Self::RaidAlertsDisabled => {
+                "Has disabled alerts for join raids in the configured safety alerts channel"
+            }
+            Self::RoleIcons => "Is able to set role icons",
+            Self::RoleSubscriptionsAvailableForPurchase => {
+                "Has role subscriptions that can be purchased"
+            }
+            Self::RoleSubscriptionsEnabled => "Has enabled role subscriptions",
+            Self::TicketedEventsEnabled => "Has enabled ticketed events",
+            Self::VanityUrl => "Has access to set a vanity URL",
+            Self::Verified => "Is verified",
+            Self::VipRegions => "Has access to set 384kbps bitrate in voice channels",
+            Self::WelcomeScreenEnabled => "Has enabled the welcome screen",
+            Self::Unknown(_) => return None,
+        })
+    }
+
+    /// Model fields which can only be set, or are only meaningful, when the
+    /// guild has the feature.
+    pub const fn gated_fields(&self) -> &'static [GuildFeatureField] {
+        match self {
+            Self::AnimatedBanner => &[GuildFeatureField::GuildAnimatedBanner],
+            Self::AnimatedIcon => &[GuildFeatureField::GuildAnimatedIcon],
+            Self::Banner => &[GuildFeatureField::GuildBanner],
+            Self::Community => &[
+                GuildFeatureField::GuildPublicUpdatesChannelId,
+                GuildFeatureField::GuildRulesChannelId,
+                GuildFeatureField::GuildSafetyAlertsChannelId,
+            ],
+            Self::Discoverable => &[GuildFeatureField::GuildDiscoverySplash],
+            Self::InviteSplash => &[GuildFeatureField::GuildSplash],
+            Self::MemberVerificationGateEnabled => &[GuildFeatureField::MemberPending],
+            Self::RaidAlertsDisabled => &[GuildFeatureField::GuildSafetyAlertsChannelId],
+            Self::RoleIcons => &[
+                GuildFeatureField::RoleIcon,
+                GuildFeatureField::RoleUnicodeEmoji,
+            ],
+            Self::VanityUrl => &[GuildFeatureField::GuildVanityUrlCode],
+            Self::VipRegions => &[GuildFeatureField::ChannelBitrate],
+            Self::WelcomeScreenEnabled => &[
+                GuildFeatureField::WelcomeScreenDescription,
+                GuildFeatureField::WelcomeScreenWelcomeChannels,
+            ],
+            Self::AutoModeration
+            | Self::Commerce
+            | Self::CreatorMonetizableProvisional
+            | Self::CreatorStorePage
+            | Self::DeveloperSupportServer
+            | Self::Featurable
+            | Self::InvitesDisabled
+            | Self::MonetizationEnabled
+            | Self::MoreStickers
+            | Self::News
+            | Self::Partnered
+            | Self::PreviewEnabled
+            | Self::PrivateThreads
+            | Self::RoleSubscriptionsAvailableForPurchase
+            | Self::RoleSubscriptionsEnabled
+            | Self::TicketedEventsEnabled
+            | Self::Verified
+            | Self::Unknown(_) => &[],
+        }
+    }
+
+    /// Permissions whose use depends on the guild having the feature.
+    ///
+    /// [`InvitesDisabled`] restricts rather than enables its permissions.
+    ///
+    /// [`InvitesDisabled`]: Self::InvitesDisabled
+    pub const fn gated_permissions(&self) -> Permissions {
+        match self {
+            Self::Community => Permissions::VIEW_GUILD_INSIGHTS,
+            Self::CreatorMonetizableProvisional => Permissions::VIEW_CREATOR_MONETIZATION_ANALYTICS,
+            Self::InvitesDisabled => Permissions::CREATE_INVITE,
+            Self::PrivateThreads => Permissions::CREATE_PRIVATE_THREADS,
+            _ => Permissions::empty(),
+        }
+    }
+
+    /// Whether the feature can be enabled and disabled by the guild's owner
+    /// by modifying the guild.
+    ///
+    /// Mutable features are [`Community`], [`Discoverable`],
+    /// [`InvitesDisabled`] and [`RaidAlertsDisabled`].
+    ///
+    /// [`Community`]: Self::Community
+    /// [`Discoverable`]: Self::Discoverable
+    /// [`InvitesDisabled`]: Self::InvitesDisabled
+    /// [`RaidAlertsDisabled`]: Self::RaidAlertsDisabled
+    pub const fn is_mutable(&self) -> bool {
+        matches!(
+            self,
+            Self::Community | Self::Discoverable | Self::InvitesDisabled | Self::RaidAlertsDisabled
+        )
+    }
+}
+
 impl From<GuildFeature> for Cow<'static, str> {
     fn from(value: GuildFeature) -> Self {
         match value {
@@ -160,8 +292,41 @@ impl From<String> for GuildFeature {
 #[cfg(test)]
 mod tests {
     use super::GuildFeature;
+    use crate::guild::{GuildFeatureField, Permissions};
     use serde_test::Token;
 
+    #[test]
+    fn metadata() {
+        assert_eq!(
+            Some("Has access to set a vanity URL"),
+            GuildFeature::VanityUrl.description()
+        );
+        assert_eq!(None, GuildFeature::Unknown("NEW".to_owned()).description());
+
+        assert!(GuildFeature::Community.is_mutable());
+        assert!(GuildFeature::RaidAlertsDisabled.is_mutable());
+        assert!(!GuildFeature::Verified.is_mutable());
+
+        assert_eq!(
+            &[GuildFeatureField::GuildVanityUrlCode],
+            GuildFeature::VanityUrl.gated_fields()
+        );
+        assert_eq!(
+            &[GuildFeatureField::GuildAnimatedIcon],
+            GuildFeature::AnimatedIcon.gated_fields()
+        );
+        assert_eq!(
+            &[GuildFeatureField::GuildAnimatedBanner],
+            GuildFeature::AnimatedBanner.gated_fields()
+        );
+        assert!(GuildFeature::Partnered.gated_fields().is_empty());
+        assert_eq!(
+            Permissions::CREATE_PRIVATE_THREADS,
+            GuildFeature::PrivateThreads.gated_permissions()
+        );
+        assert!(GuildFeature::Banner.gated_permissions().is_empty());
+    }
+
     #[test]
     fn variants() {
         serde_test::assert_tokens(
@@ -0,0 +1,93 @@
+/// Model field gated by a [`GuildFeature`].
+///
+/// Gated fields can only be set, or are only meaningful, when the guild has
+/// the feature.
+///
+/// [`GuildFeature`]: super::GuildFeature
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildFeatureField {
+    /// [`Channel::bitrate`].
+    ///
+    /// [`Channel::bitrate`]: crate::channel::Channel::bitrate
+    ChannelBitrate,
+    /// Animated [`Guild::banner`] hashes.
+    ///
+    /// Static banners are gated by the [`Banner`] feature instead.
+    ///
+    /// [`Banner`]: super::GuildFeature::Banner
+    /// [`Guild::banner`]: super::Guild::banner
+    GuildAnimatedBanner,
+    /// Animated [`Guild::icon`] hashes.
+    ///
+    /// Every guild can set a static icon.
+    ///
+    /// [`Guild::icon`]: super::Guild::icon
+    GuildAnimatedIcon,
+    /// [`Guild::banner`].
+    ///
+    /// [`Guild::banner`]: super::Guild::banner
+    GuildBanner,
+    /// [`Guild::discovery_splash`].
+    ///
+    /// [`Guild::discovery_splash`]: super::Guild::discovery_splash
+    GuildDiscoverySplash,
+    /// [`Guild::public_updates_channel_id`].
+    ///
+    /// [`Guild::public_updates_channel_id`]: super::Guild::public_updates_channel_id
+    GuildPublicUpdatesChannelId,
+    /// [`Guild::rules_channel_id`].
+    ///
+    /// [`Guild::rules_channel_id`]: super::Guild::rules_channel_id
+    GuildRulesChannelId,
+    /// [`Guild::safety_alerts_channel_id`].
+    ///
+    /// [`Guild::safety_alerts_channel_id`]: super::Guild::safety_alerts_channel_id
+    GuildSafetyAlertsChannelId,
+    /// [`Guild::splash`].
+    ///
+    /// [`Guild::splash`]: super::Guild::splash
+    GuildSplash,
+    /// [`Guild::vanity_url_code`].
+    ///
+    /// [`Guild::vanity_url_code`]: super::Guild::vanity_url_code
+    GuildVanityUrlCode,
+    /// [`Member::pending`].
+    ///
+    /// [`Member::pending`]: super::Member::pending
+    MemberPending,
+    /// [`Role::icon`].
+    ///
+    /// [`Role::icon`]: super::Role::icon
+    RoleIcon,
+    /// [`Role::unicode_emoji`].
+    ///
+    /// [`Role::unicode_emoji`]: super::Role::unicode_emoji
+    RoleUnicodeEmoji,
+    /// [`WelcomeScreen::description`].
+    ///
+    /// [`WelcomeScreen::description`]: super::invite::WelcomeScreen::description
+    WelcomeScreenDescription,
+    /// [`WelcomeScreen::welcome_channels`].
+    ///
+    /// [`WelcomeScreen::welcome_channels`]: super::invite::WelcomeScreen::welcome_channels
+    WelcomeScreenWelcomeChannels,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildFeatureField;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        GuildFeatureField: Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+}
@@ -0,0 +1,256 @@
+use super::GuildFeature;
+use serde::{Deserialize, Serialize};
+use std::collections::{
+    btree_set::{IntoIter, Iter},
+    BTreeSet,
+};
+
+/// Set of [`GuildFeature`]s.
+///
+/// Features are kept in the order of [`GuildFeature`]'s variants, with unknown
+/// features last.
+///
+/// # Examples
+///
+/// Compare the features of a guild before and after an update:
+///
+/// ```
+/// use twilight_model::guild::{GuildFeature, GuildFeatureSet};
+///
+/// let before = GuildFeatureSet::from_iter([GuildFeature::Banner, GuildFeature::Community]);
+/// let after = GuildFeatureSet::from_iter([GuildFeature::Community, GuildFeature::VanityUrl]);
+///
+/// let added = after.difference(&before);
+/// let removed = before.difference(&after);
+///
+/// assert_eq!(GuildFeatureSet::from_iter([GuildFeature::VanityUrl]), added);
+/// assert_eq!(GuildFeatureSet::from_iter([GuildFeature::Banner]), removed);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(from = "Vec<GuildFeature>", into = "Vec<GuildFeature>")]
+pub struct GuildFeatureSet {
+    features: BTreeSet<GuildFeature>,
+}
+
+impl GuildFeatureSet {
+    /// Create an empty set.
+    pub const fn new() -> Self {
+        Self {
+            features: BTreeSet::new(),
+        }
+    }
+
+    /// Whether the set contains a feature.
+    pub fn contains(&self, feature: &GuildFeature) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Features contained in this set but not in the other set.
+    #[must_use = "computing the difference has no effect if left unused"]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.features.difference(&other.features).cloned().collect()
+    }
+
+    /// Insert a feature, returning whether it was not already contained.
+    pub fn insert(&mut self, feature: GuildFeature) -> bool {
+        self.features.insert(feature)
+    }
+
+    /// Features contained in both sets.
+    #[must_use = "computing the intersection has no effect if left unused"]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.features
+            .intersection(&other.features)
+            .cloned()
+            .collect()
+    }
+
+    /// Whether the set contains no features.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Whether every feature of this set is contained in the other set.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.features.is_subset(&other.features)
+    }
+
+    /// Iterator over the features of the set.
+    pub fn iter(&self) -> Iter<'_, GuildFeature> {
+        self.features.iter()
+    }
+
+    /// Number of features of the set.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Remove a feature, returning whether it was contained.
+    pub fn remove(&mut self, feature: &GuildFeature) -> bool {
+        self.features.remove(feature)
+    }
+
+    /// Features contained in exactly one of the sets.
+    #[must_use = "computing the symmetric difference has no effect if left unused"]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.features
+            .symmetric_difference(&other.features)
+            .cloned()
+            .collect()
+    }
+
+    /// Features contained in either set.
+    #[must_use = "computing the union has no effect if left unused"]
+    pub fn union(&self, other: &Self) -> Self {
+        self.features.union(&other.features).cloned().collect()
+    }
+}
+
+impl Extend<GuildFeature> for GuildFeatureSet {
+    fn extend<T: IntoIterator<Item = GuildFeature>>(&mut self, iter: T) {
+        self.features.extend(iter);
+    }
+}
+
+impl From<GuildFeatureSet> for Vec<GuildFeature> {
+    fn from(set: GuildFeatureSet) -> Self {
+        set.features.into_iter().collect()
+    }
+}
+
+impl From<Vec<GuildFeature>> for GuildFeatureSet {
+    fn from(features: Vec<GuildFeature>) -> Self {
+        features.into_iter().collect()
+    }
+}
+
+impl FromIterator<GuildFeature> for GuildFeatureSet {
+    fn from_iter<T: IntoIterator<Item = GuildFeature>>(iter: T) -> Self {
+        Self {
+            features: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for GuildFeatureSet {
+    type Item = GuildFeature;
+    type IntoIter = IntoIter<GuildFeature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.features.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GuildFeatureSet {
+    type Item = &'a GuildFeature;
+    type IntoIter = Iter<'a, GuildFeature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildFeature, GuildFeatureSet};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        GuildFeatureSet: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn set_operations() {
+        let a = GuildFeatureSet::from_iter([
+            GuildFeature::Banner,
+            GuildFeature::Community,
+            GuildFeature::Banner,
+        ]);
+        let b = GuildFeatureSet::from(Vec::from([
+            GuildFeature::Unknown("NEW".to_owned()),
+            GuildFeature::Community,
+        ]));
+
+        assert_eq!(2, a.len());
+        assert_eq!(
+            GuildFeatureSet::from_iter([GuildFeature::Community]),
+            a.intersection(&b)
+        );
+        assert_eq!(
+            GuildFeatureSet::from_iter([
+                GuildFeature::Banner,
+                GuildFeature::Community,
+                GuildFeature::Unknown("NEW".to_owned()),
+            ]),
+            a.union(&b)
+        );
+        assert_eq!(
+            GuildFeatureSet::from_iter([
+                GuildFeature::Unknown("NEW".to_owned()),
+                GuildFeature::Banner,
+            ]),
+            a.symmetric_difference(&b)
+        );
+        assert!(a.intersection(&b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = GuildFeatureSet::new();
+
+        assert!(set.insert(GuildFeature::VanityUrl));
+        assert!(!set.insert(GuildFeature::VanityUrl));
+        assert!(set.contains(&GuildFeature::VanityUrl));
+        assert!(set.remove(&GuildFeature::VanityUrl));
+        assert!(!set.remove(&GuildFeature::VanityUrl));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn sorted() {
+        let set = GuildFeatureSet::from(Vec::from([
+            GuildFeature::Unknown("NEW".to_owned()),
+            GuildFeature::News,
+            GuildFeature::Banner,
+            GuildFeature::News,
+        ]));
+
+        assert_eq!(
+            Vec::from([
+                GuildFeature::Banner,
+                GuildFeature::News,
+                GuildFeature::Unknown("NEW".to_owned()),
+            ]),
+            Vec::from(set)
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let set = GuildFeatureSet::from_iter([GuildFeature::Banner, GuildFeature::News]);
+
+        serde_test::assert_tokens(
+            &set,
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("BANNER"),
+                Token::Str("NEWS"),
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildIntegration {
     pub account: IntegrationAccount,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -146,7 +147,9 @@ mod tests {
                 Token::Some,
                 Token::U64(3_600),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::Str("discord"),
@@ -157,7 +160,9 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("role_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("scopes"),
                 Token::Some,
@@ -197,7 +202,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("username"),
                 Token::Str("user"),
@@ -292,7 +299,9 @@ mod tests {
                 Token::Str("icon"),
                 Token::None,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123"),
                 Token::Str("name"),
                 Token::Str("Twilight"),
@@ -309,7 +318,9 @@ mod tests {
                 Token::Some,
                 Token::U64(3_600),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::Str("discord"),
@@ -320,7 +331,9 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("role_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("scopes"),
                 Token::Some,
@@ -360,7 +373,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("username"),
                 Token::Str("user"),
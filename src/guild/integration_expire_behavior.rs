@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// Behavior to perform when the user's integration expires.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum IntegrationExpireBehavior {
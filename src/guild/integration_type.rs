@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Guild Features]: https://discord.com/developers/docs/resources/guild#guild-object-guild-features
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "String", into = "Cow<'static, str>")]
 pub enum GuildIntegrationType {
@@ -7,6 +7,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InviteGuild {
     /// Hash of the banner image.
     pub banner: Option<ImageHash>,
@@ -98,7 +99,9 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::ICON_INPUT),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("name"),
                 Token::Str("guild name"),
@@ -129,7 +132,9 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123"),
                 Token::Str("description"),
                 Token::Str("channel description"),
@@ -144,13 +149,17 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("456"),
                 Token::Str("description"),
                 Token::Str("custom description"),
                 Token::Str("emoji_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("789"),
                 Token::Str("emoji_name"),
                 Token::Some,
@@ -31,3 +31,14 @@ impl Serialize for MemberFlags {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MemberFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "MemberFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
@@ -7,6 +7,7 @@
 
 pub mod audit_log;
 pub mod auto_moderation;
+pub mod backup;
 pub mod invite;
 pub mod onboarding;
 pub mod scheduled_event;
@@ -19,6 +20,8 @@ mod default_message_notification_level;
 mod emoji;
 mod explicit_content_filter;
 mod feature;
+mod feature_field;
+mod feature_set;
 mod info;
 mod integration;
 mod integration_account;
@@ -38,6 +41,7 @@ mod prune;
 mod role;
 mod role_flags;
 mod role_tags;
+mod soundboard_sound;
 mod system_channel_flags;
 mod unavailable_guild;
 mod vanity_url;
@@ -45,10 +49,12 @@ mod verification_level;
 
 pub use self::nsfw_level::NSFWLevel;
 pub use self::permissions::Permissions;
+pub use self::soundboard_sound::{SoundboardSound, SoundboardSoundVolume};
 pub use self::{
     afk_timeout::AfkTimeout, ban::Ban,
     default_message_notification_level::DefaultMessageNotificationLevel, emoji::Emoji,
-    explicit_content_filter::ExplicitContentFilter, feature::GuildFeature, info::GuildInfo,
+    explicit_content_filter::ExplicitContentFilter, feature::GuildFeature,
+    feature_field::GuildFeatureField, feature_set::GuildFeatureSet, info::GuildInfo,
     integration::GuildIntegration, integration_account::IntegrationAccount,
     integration_application::IntegrationApplication,
     integration_expire_behavior::IntegrationExpireBehavior, integration_type::GuildIntegrationType,
@@ -67,7 +73,7 @@ use crate::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, UserMarker},
         Id,
     },
-    util::{ImageHash, Timestamp},
+    util::{ImageHash, Locale, Timestamp},
     voice::VoiceState,
 };
 use serde::{
@@ -77,6 +83,7 @@ use serde::{
 use std::fmt::{Formatter, Result as FmtResult};
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Guild {
     pub afk_channel_id: Option<Id<ChannelMarker>>,
     pub afk_timeout: AfkTimeout,
@@ -118,7 +125,7 @@ pub struct Guild {
     pub owner: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<Permissions>,
-    pub preferred_locale: String,
+    pub preferred_locale: Locale,
     /// Whether the premium progress bar is enabled in the guild.
     pub premium_progress_bar_enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -135,6 +142,9 @@ pub struct Guild {
     /// The ID of the channel where admins and moderators of Community guilds receive safety alerts from Discord.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub safety_alerts_channel_id: Option<Id<ChannelMarker>>,
+    /// Custom sounds of the guild's soundboard.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub soundboard_sounds: Vec<SoundboardSound>,
     pub splash: Option<ImageHash>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub stage_instances: Vec<StageInstance>,
@@ -156,6 +166,85 @@ pub struct Guild {
     pub widget_enabled: Option<bool>,
 }
 
+impl Guild {
+    /// Maximum number of custom emojis of the guild.
+    ///
+    /// The limit applies separately to static and animated emojis. See
+    /// [`PremiumTier::emoji_limit`].
+    pub const fn emoji_limit(&self) -> u16 {
+        self.premium_tier.emoji_limit()
+    }
+
+    /// Set of the guild's features.
+    pub fn feature_set(&self) -> GuildFeatureSet {
+        self.features.iter().cloned().collect()
+    }
+
+    /// Whether the guild has a feature.
+    pub fn has_feature(&self, feature: &GuildFeature) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Maximum bitrate of the guild's voice channels, in bits per second.
+    ///
+    /// Guilds with the [`VipRegions`] feature have the maximum bitrate of
+    /// [`PremiumTier::Tier3`], otherwise see [`PremiumTier::max_bitrate`].
+    ///
+    /// [`VipRegions`]: GuildFeature::VipRegions
+    pub fn max_bitrate(&self) -> u32 {
+        if self.has_feature(&GuildFeature::VipRegions) {
+            return PremiumTier::Tier3.max_bitrate();
+        }
+
+        self.premium_tier.max_bitrate()
+    }
+
+    /// Maximum size of files uploaded to the guild, in bytes.
+    ///
+    /// See [`PremiumTier::max_upload_size`].
+    pub const fn max_upload_size(&self) -> u64 {
+        self.premium_tier.max_upload_size()
+    }
+
+    /// Maximum number of custom stickers of the guild.
+    ///
+    /// Guilds with the [`MoreStickers`] feature have the sticker limit of
+    /// [`PremiumTier::Tier3`], otherwise see [`PremiumTier::sticker_limit`].
+    ///
+    /// [`MoreStickers`]: GuildFeature::MoreStickers
+    pub fn sticker_limit(&self) -> u16 {
+        if self.has_feature(&GuildFeature::MoreStickers) {
+            return PremiumTier::Tier3.sticker_limit();
+        }
+
+        self.premium_tier.sticker_limit()
+    }
+
+    /// Whether the guild can set an animated banner, either through its
+    /// premium tier or the [`AnimatedBanner`] feature.
+    ///
+    /// [`AnimatedBanner`]: GuildFeature::AnimatedBanner
+    pub fn supports_animated_banner(&self) -> bool {
+        self.premium_tier.animated_banner() || self.has_feature(&GuildFeature::AnimatedBanner)
+    }
+
+    /// Whether the guild can set an animated icon, either through its premium
+    /// tier or the [`AnimatedIcon`] feature.
+    ///
+    /// [`AnimatedIcon`]: GuildFeature::AnimatedIcon
+    pub fn supports_animated_icon(&self) -> bool {
+        self.premium_tier.animated_icon() || self.has_feature(&GuildFeature::AnimatedIcon)
+    }
+
+    /// Whether the guild can set a banner, either through its premium tier or
+    /// the [`Banner`] feature.
+    ///
+    /// [`Banner`]: GuildFeature::Banner
+    pub fn supports_banner(&self) -> bool {
+        self.premium_tier.banner() || self.has_feature(&GuildFeature::Banner)
+    }
+}
+
 impl<'de> Deserialize<'de> for Guild {
     #[allow(clippy::too_many_lines)]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -198,6 +287,7 @@ impl<'de> Deserialize<'de> for Guild {
             PublicUpdatesChannelId,
             Roles,
             SafetyAlertsChannelId,
+            SoundboardSounds,
             Splash,
             StageInstances,
             Stickers,
@@ -260,6 +350,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut public_updates_channel_id = None::<Option<_>>;
                 let mut roles = None;
                 let mut safety_alerts_channel_id = None::<Option<_>>;
+                let mut soundboard_sounds = None::<Vec<SoundboardSound>>;
                 let mut splash = None::<Option<_>>;
                 let mut stage_instances = None::<Vec<StageInstance>>;
                 let mut stickers = None::<Vec<Sticker>>;
@@ -551,6 +642,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             splash = Some(map.next_value()?);
                         }
+                        Field::SoundboardSounds => {
+                            if soundboard_sounds.is_some() {
+                                return Err(DeError::duplicate_field("soundboard_sounds"));
+                            }
+
+                            soundboard_sounds = Some(map.next_value()?);
+                        }
                         Field::StageInstances => {
                             if stage_instances.is_some() {
                                 return Err(DeError::duplicate_field("stage_instances"));
@@ -685,6 +783,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let public_updates_channel_id = public_updates_channel_id.unwrap_or_default();
                 let rules_channel_id = rules_channel_id.unwrap_or_default();
                 let safety_alerts_channel_id = safety_alerts_channel_id.unwrap_or_default();
+                let mut soundboard_sounds = soundboard_sounds.unwrap_or_default();
                 let splash = splash.unwrap_or_default();
                 let stage_instances = stage_instances.unwrap_or_default();
                 let stickers = stickers.unwrap_or_default();
@@ -704,6 +803,10 @@ impl<'de> Deserialize<'de> for Guild {
                     presence.guild_id = id;
                 }
 
+                for sound in &mut soundboard_sounds {
+                    sound.guild_id = Some(id);
+                }
+
                 for thread in &mut threads {
                     thread.guild_id = Some(id);
                 }
@@ -750,6 +853,7 @@ impl<'de> Deserialize<'de> for Guild {
                     roles,
                     rules_channel_id,
                     safety_alerts_channel_id,
+                    soundboard_sounds,
                     splash,
                     stage_instances,
                     stickers,
@@ -823,7 +927,8 @@ impl<'de> Deserialize<'de> for Guild {
 mod tests {
     use super::{
         AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, GuildFeature,
-        MfaLevel, NSFWLevel, Permissions, PremiumTier, SystemChannelFlags, VerificationLevel,
+        GuildFeatureSet, MfaLevel, NSFWLevel, Permissions, PremiumTier, SystemChannelFlags,
+        VerificationLevel,
     };
     use crate::{
         id::Id,
@@ -867,7 +972,7 @@ mod tests {
             owner_id: Id::new(5),
             owner: Some(false),
             permissions: Some(Permissions::SEND_MESSAGES),
-            preferred_locale: "en-us".to_owned(),
+            preferred_locale: "en-us".into(),
             premium_progress_bar_enabled: false,
             premium_subscription_count: Some(3),
             premium_tier: PremiumTier::Tier1,
@@ -876,6 +981,7 @@ mod tests {
             roles: Vec::new(),
             rules_channel_id: Some(Id::new(6)),
             safety_alerts_channel_id: Some(Id::new(9)),
+            soundboard_sounds: Vec::new(),
             splash: Some(image_hash::SPLASH),
             stage_instances: Vec::new(),
             stickers: Vec::new(),
@@ -890,6 +996,23 @@ mod tests {
             widget_enabled: Some(true),
         };
 
+        assert_eq!(100, value.emoji_limit());
+        assert_eq!(15, value.sticker_limit());
+        assert_eq!(128_000, value.max_bitrate());
+        assert!(value.supports_animated_icon());
+        assert!(value.supports_banner());
+        assert!(!value.supports_animated_banner());
+
+        let mut features = value.clone();
+        features.features = vec![GuildFeature::MoreStickers, GuildFeature::VipRegions];
+        assert_eq!(60, features.sticker_limit());
+        assert_eq!(384_000, features.max_bitrate());
+        assert!(!features.supports_banner());
+        assert_eq!(
+            GuildFeatureSet::from_iter([GuildFeature::VipRegions, GuildFeature::MoreStickers]),
+            features.feature_set()
+        );
+
         serde_test::assert_tokens(
             &value,
             &[
@@ -899,14 +1022,18 @@ mod tests {
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("afk_timeout"),
                 Token::NewtypeStruct { name: "AfkTimeout" },
                 Token::U16(900),
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("approximate_member_count"),
                 Token::Some,
@@ -941,7 +1068,9 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::ICON_INPUT),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("joined_at"),
                 Token::Some,
@@ -970,7 +1099,9 @@ mod tests {
                 Token::Str("nsfw_level"),
                 Token::U8(0),
                 Token::Str("owner_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("5"),
                 Token::Str("owner"),
                 Token::Some,
@@ -997,11 +1128,15 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("rules_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("6"),
                 Token::Str("safety_alerts_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("9"),
                 Token::Str("splash"),
                 Token::Some,
@@ -1010,7 +1145,9 @@ mod tests {
                 Token::U64(2),
                 Token::Str("system_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("7"),
                 Token::Str("threads"),
                 Token::Seq { len: Some(0) },
@@ -1027,7 +1164,9 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("widget_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("8"),
                 Token::Str("widget_enabled"),
                 Token::Some,
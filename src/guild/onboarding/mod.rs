@@ -1,8 +1,11 @@
 //! Types for guild onboarding.
 
+mod mode;
 mod option;
 mod prompt;
 mod prompt_type;
+mod simulate;
+mod validate;
 
 use crate::id::{
     marker::{ChannelMarker, GuildMarker},
@@ -11,11 +14,17 @@ use crate::id::{
 use serde::{Deserialize, Serialize};
 
 pub use self::{
-    option::OnboardingPromptOption, prompt::OnboardingPrompt, prompt_type::OnboardingPromptType,
+    mode::OnboardingMode,
+    option::{OnboardingPromptEmoji, OnboardingPromptOption},
+    prompt::OnboardingPrompt,
+    prompt_type::OnboardingPromptType,
+    simulate::{OnboardingOutcome, OnboardingSelectionError, OnboardingSelectionErrorType},
+    validate::{OnboardingError, OnboardingErrorType},
 };
 
 /// The onboarding data for a guild.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Onboarding {
     /// Channel IDs that new members get opted into automatically
     pub default_channel_ids: Vec<Id<ChannelMarker>>,
@@ -23,13 +32,15 @@ pub struct Onboarding {
     pub enabled: bool,
     /// ID of the guild this onboarding is a part of.
     pub guild_id: Id<GuildMarker>,
+    /// Criteria used to satisfy the constraints of the onboarding.
+    pub mode: OnboardingMode,
     /// Array of [`OnboardingPrompt`]s for the guild onboarding flow.
     pub prompts: Vec<OnboardingPrompt>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Onboarding;
+    use super::{Onboarding, OnboardingMode};
     use crate::id::Id;
     use serde_test::Token;
 
@@ -39,6 +50,7 @@ mod tests {
             default_channel_ids: Vec::new(),
             enabled: true,
             guild_id: Id::new(123_456_789),
+            mode: OnboardingMode::Advanced,
             prompts: Vec::new(),
         };
 
@@ -47,7 +59,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Onboarding",
-                    len: 4,
+                    len: 5,
                 },
                 Token::Str("default_channel_ids"),
                 Token::Seq { len: Some(0) },
@@ -55,8 +67,12 @@ mod tests {
                 Token::Str("enabled"),
                 Token::Bool(true),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123456789"),
+                Token::Str("mode"),
+                Token::U8(1),
                 Token::Str("prompts"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// Criteria used to satisfy the constraints of an enabled onboarding.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum OnboardingMode {
+    /// Only default channels count towards the constraints.
+    Default,
+    /// Default channels and the channels of prompts count towards the
+    /// constraints.
+    Advanced,
+    /// An unknown onboarding mode.
+    Unknown(u8),
+}
+
+impl OnboardingMode {
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Advanced => "Advanced",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+impl From<u8> for OnboardingMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Default,
+            1 => Self::Advanced,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<OnboardingMode> for u8 {
+    fn from(value: OnboardingMode) -> Self {
+        match value {
+            OnboardingMode::Default => 0,
+            OnboardingMode::Advanced => 1,
+            OnboardingMode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnboardingMode;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn onboarding_mode() {
+        const MAP: &[(OnboardingMode, u8, &str)] = &[
+            (OnboardingMode::Default, 0, "Default"),
+            (OnboardingMode::Advanced, 1, "Advanced"),
+            (OnboardingMode::Unknown(2), 2, "Unknown"),
+        ];
+
+        for (mode, number, name) in MAP {
+            assert_eq!(mode.kind(), *name);
+            assert_eq!(u8::from(*mode), *number);
+            assert_eq!(OnboardingMode::from(*number), *mode);
+            assert_tokens(mode, &[Token::U8(*number)]);
+        }
+    }
+}
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Emoji`]: crate::guild::Emoji
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OnboardingPromptEmoji {
     name: Option<String>,
     id: Option<Id<EmojiMarker>>,
@@ -19,6 +20,13 @@ pub struct OnboardingPromptEmoji {
     animated: bool,
 }
 
+impl OnboardingPromptEmoji {
+    /// Mutable reference to the ID of the emoji if it is custom.
+    pub(crate) const fn id_mut(&mut self) -> &mut Option<Id<EmojiMarker>> {
+        &mut self.id
+    }
+}
+
 impl From<Emoji> for OnboardingPromptEmoji {
     fn from(value: Emoji) -> Self {
         Self {
@@ -31,6 +39,7 @@ impl From<Emoji> for OnboardingPromptEmoji {
 
 /// A prompt option for a guild onboarding screen.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OnboardingPromptOption {
     /// Channels opted into when this option is selected.
     pub channel_ids: Vec<Id<ChannelMarker>>,
@@ -90,11 +99,17 @@ mod tests {
                 },
                 Token::Str("channel_ids"),
                 Token::Seq { len: Some(3) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::SeqEnd,
                 Token::Str("description"),
@@ -110,21 +125,31 @@ mod tests {
                 Token::Str("test"),
                 Token::Str("id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("7"),
                 Token::Str("animated"),
                 Token::Bool(false),
                 Token::StructEnd,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123456789"),
                 Token::Str("role_ids"),
                 Token::Seq { len: Some(3) },
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("5"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("6"),
                 Token::SeqEnd,
                 Token::Str("title"),
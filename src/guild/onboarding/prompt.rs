@@ -5,6 +5,7 @@ use super::{OnboardingPromptOption, OnboardingPromptType};
 
 /// A prompt in the onboarding flow.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OnboardingPrompt {
     /// ID of the prompt.
     pub id: Id<OnboardingPromptMarker>,
@@ -49,7 +50,9 @@ mod tests {
                     len: 7,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("123456789"),
                 Token::Str("in_onboarding"),
                 Token::Bool(true),
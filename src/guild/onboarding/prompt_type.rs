@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// The type of an onboarding prompt.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum OnboardingPromptType {
@@ -60,7 +61,7 @@ mod tests {
             assert_eq!(prompt_type.kind(), *name);
             assert_eq!(u8::from(*prompt_type), *number);
             assert_eq!(OnboardingPromptType::from(*number), *prompt_type);
-            assert_tokens(number, &[Token::U8(*number)])
+            assert_tokens(prompt_type, &[Token::U8(*number)]);
         }
     }
 }
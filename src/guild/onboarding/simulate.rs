@@ -0,0 +1,429 @@
+use super::{Onboarding, OnboardingPrompt};
+use crate::{
+    channel::{permission_overwrite::PermissionOverwriteType, Channel},
+    guild::{Guild, Permissions},
+    id::{
+        marker::{ChannelMarker, OnboardingPromptMarker, OnboardingPromptOptionMarker, RoleMarker},
+        Id,
+    },
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when options chosen in an onboarding are invalid.
+#[derive(Debug)]
+pub struct OnboardingSelectionError {
+    kind: OnboardingSelectionErrorType,
+}
+
+impl OnboardingSelectionError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &OnboardingSelectionErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        OnboardingSelectionErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for OnboardingSelectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            OnboardingSelectionErrorType::RequiredPrompt { prompt_id } => {
+                f.write_str("no option of required prompt ")?;
+                Display::fmt(prompt_id, f)?;
+
+                f.write_str(" was chosen")
+            }
+            OnboardingSelectionErrorType::SingleSelect { prompt_id } => {
+                f.write_str("multiple options of single select prompt ")?;
+                Display::fmt(prompt_id, f)?;
+
+                f.write_str(" were chosen")
+            }
+            OnboardingSelectionErrorType::UnknownOption { option_id } => {
+                f.write_str("option ")?;
+                Display::fmt(option_id, f)?;
+
+                f.write_str(" is not part of any prompt")
+            }
+        }
+    }
+}
+
+impl Error for OnboardingSelectionError {}
+
+/// Type of [`OnboardingSelectionError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum OnboardingSelectionErrorType {
+    /// No option of a required prompt of the onboarding flow was chosen.
+    RequiredPrompt {
+        /// ID of the prompt.
+        prompt_id: Id<OnboardingPromptMarker>,
+    },
+    /// Multiple options of a single select prompt were chosen.
+    SingleSelect {
+        /// ID of the prompt.
+        prompt_id: Id<OnboardingPromptMarker>,
+    },
+    /// Chosen option is not part of any prompt.
+    UnknownOption {
+        /// ID of the option.
+        option_id: Id<OnboardingPromptOptionMarker>,
+    },
+}
+
+/// Roles and channels a member gets by completing an onboarding.
+///
+/// Created via [`Onboarding::simulate`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OnboardingOutcome {
+    /// Channels the member is opted into and can view, in the order they were
+    /// opted into.
+    pub channel_ids: Vec<Id<ChannelMarker>>,
+    /// Roles assigned to the member, in the order of the chosen options.
+    pub role_ids: Vec<Id<RoleMarker>>,
+}
+
+impl Onboarding {
+    /// Simulate a new member completing the onboarding by choosing options.
+    ///
+    /// The member is assigned the roles of the chosen options and opted into
+    /// the default channels and the channels of the chosen options. Opted in
+    /// channels are only part of the outcome if the member can view them with
+    /// the `@everyone` role and the assigned roles, taking the channels'
+    /// role permission overwrites into account.
+    ///
+    /// Choosing an option more than once has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnknownOption`] if a chosen option is not
+    /// part of any prompt.
+    ///
+    /// Returns an error of type [`SingleSelect`] if multiple options of a
+    /// single select prompt were chosen.
+    ///
+    /// Returns an error of type [`RequiredPrompt`] if no option of a required
+    /// prompt shown in the onboarding flow was chosen.
+    ///
+    /// [`RequiredPrompt`]: OnboardingSelectionErrorType::RequiredPrompt
+    /// [`SingleSelect`]: OnboardingSelectionErrorType::SingleSelect
+    /// [`UnknownOption`]: OnboardingSelectionErrorType::UnknownOption
+    pub fn simulate(
+        &self,
+        guild: &Guild,
+        option_ids: &[Id<OnboardingPromptOptionMarker>],
+    ) -> Result<OnboardingOutcome, OnboardingSelectionError> {
+        let mut chosen = Vec::new();
+
+        for option_id in option_ids {
+            let Some((prompt, option)) = self.prompts.iter().find_map(|prompt| {
+                prompt
+                    .options
+                    .iter()
+                    .find(|option| option.id == *option_id)
+                    .map(|option| (prompt, option))
+            }) else {
+                return Err(OnboardingSelectionError {
+                    kind: OnboardingSelectionErrorType::UnknownOption {
+                        option_id: *option_id,
+                    },
+                });
+            };
+
+            if chosen.iter().any(|(_, chosen)| chosen == &option) {
+                continue;
+            }
+
+            if prompt.single_select && chosen_from(&chosen, prompt) {
+                return Err(OnboardingSelectionError {
+                    kind: OnboardingSelectionErrorType::SingleSelect {
+                        prompt_id: prompt.id,
+                    },
+                });
+            }
+
+            chosen.push((prompt, option));
+        }
+
+        if let Some(prompt) = self
+            .prompts
+            .iter()
+            .find(|prompt| prompt.in_onboarding && prompt.required && !chosen_from(&chosen, prompt))
+        {
+            return Err(OnboardingSelectionError {
+                kind: OnboardingSelectionErrorType::RequiredPrompt {
+                    prompt_id: prompt.id,
+                },
+            });
+        }
+
+        let mut role_ids = Vec::new();
+        let mut opted_in = self.default_channel_ids.clone();
+
+        for (_, option) in chosen {
+            role_ids.extend(&option.role_ids);
+            opted_in.extend(&option.channel_ids);
+        }
+
+        dedup_in_order(&mut role_ids);
+        dedup_in_order(&mut opted_in);
+
+        let channel_ids = opted_in
+            .into_iter()
+            .filter(|channel_id| {
+                guild.channels.iter().any(|channel| {
+                    channel.id == *channel_id
+                        && channel_permissions(guild, channel, &role_ids)
+                            .contains(Permissions::VIEW_CHANNEL)
+                })
+            })
+            .collect();
+
+        Ok(OnboardingOutcome {
+            channel_ids,
+            role_ids,
+        })
+    }
+}
+
+/// Whether an option of a prompt has been chosen.
+fn chosen_from<T>(chosen: &[(&OnboardingPrompt, T)], prompt: &OnboardingPrompt) -> bool {
+    chosen.iter().any(|(chosen, _)| chosen.id == prompt.id)
+}
+
+/// Remove duplicate IDs, keeping the first occurrence of each.
+fn dedup_in_order<T: PartialEq>(ids: &mut Vec<T>) {
+    let mut index = 0;
+
+    while index < ids.len() {
+        if ids[..index].contains(&ids[index]) {
+            ids.remove(index);
+        } else {
+            index += 1;
+        }
+    }
+}
+
+/// Permissions that can't be used without [`Permissions::SEND_MESSAGES`].
+const MESSAGING_PERMISSIONS: Permissions = Permissions::from_bits_truncate(
+    Permissions::ATTACH_FILES.bits()
+        | Permissions::EMBED_LINKS.bits()
+        | Permissions::MENTION_EVERYONE.bits()
+        | Permissions::SEND_TTS_MESSAGES.bits(),
+);
+
+/// Permissions of a member with the `@everyone` role and the provided roles in
+/// a channel, taking the channel's role permission overwrites into account.
+///
+/// Members who can't view a channel have no permissions in it, and members who
+/// can't send messages can't use the permissions that depend on it.
+// Keep in sync with `twilight_util::permission_calculator::PermissionCalculator`!
+pub(super) fn channel_permissions(
+    guild: &Guild,
+    channel: &Channel,
+    role_ids: &[Id<RoleMarker>],
+) -> Permissions {
+    let everyone_id = guild.id.cast::<RoleMarker>();
+    let mut permissions = guild
+        .roles
+        .iter()
+        .filter(|role| role.id == everyone_id || role_ids.contains(&role.id))
+        .fold(Permissions::empty(), |permissions, role| {
+            permissions | role.permissions
+        });
+
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    let overwrites = channel.permission_overwrites.as_deref().unwrap_or_default();
+    let role_overwrites = overwrites
+        .iter()
+        .filter(|overwrite| overwrite.kind == PermissionOverwriteType::Role);
+
+    if let Some(everyone) = role_overwrites
+        .clone()
+        .find(|overwrite| overwrite.id == everyone_id.cast())
+    {
+        permissions.remove(everyone.deny);
+        permissions.insert(everyone.allow);
+    }
+
+    let (allow, deny) = role_overwrites
+        .filter(|overwrite| {
+            overwrite.id != everyone_id.cast() && role_ids.contains(&overwrite.id.cast())
+        })
+        .fold(
+            (Permissions::empty(), Permissions::empty()),
+            |(allow, deny), overwrite| (allow | overwrite.allow, deny | overwrite.deny),
+        );
+
+    permissions.remove(deny);
+    permissions.insert(allow);
+
+    if !permissions.contains(Permissions::VIEW_CHANNEL) {
+        return Permissions::empty();
+    }
+
+    if !permissions.contains(Permissions::SEND_MESSAGES) {
+        permissions.remove(MESSAGING_PERMISSIONS);
+    }
+
+    permissions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        channel_permissions, OnboardingOutcome, OnboardingSelectionError,
+        OnboardingSelectionErrorType,
+    };
+    use crate::{
+        guild::{onboarding::Onboarding, Guild, Permissions},
+        id::Id,
+        test::{
+            self,
+            guild::role,
+            onboarding::{deny_everyone, option, prompt, role_overwrite},
+        },
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(OnboardingOutcome: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(OnboardingSelectionError: Debug, Error, Send, Sync);
+
+    fn guild() -> Guild {
+        let mut guild = test::onboarding::guild(&[role(3, "admin", "8", 2)]);
+        guild.channels[7].permission_overwrites = Some(Vec::from([
+            deny_everyone(Permissions::VIEW_CHANNEL),
+            role_overwrite(2, Permissions::VIEW_CHANNEL, Permissions::empty()),
+        ]));
+
+        guild
+    }
+
+    fn onboarding() -> Onboarding {
+        test::onboarding::onboarding(&[
+            prompt(
+                100,
+                "who are you?",
+                true,
+                true,
+                &[
+                    option(200, "member", &[17], &[2]),
+                    option(201, "admin", &[], &[3]),
+                ],
+            ),
+            prompt(
+                101,
+                "what are you interested in?",
+                false,
+                false,
+                &[option(202, "chat", &[16], &[])],
+            ),
+        ])
+    }
+
+    #[test]
+    fn outcome() {
+        let outcome = onboarding()
+            .simulate(&guild(), &[Id::new(202), Id::new(200), Id::new(200)])
+            .unwrap();
+
+        assert_eq!(
+            OnboardingOutcome {
+                channel_ids: (10..=17).map(Id::new).collect(),
+                role_ids: Vec::from([Id::new(2)]),
+            },
+            outcome
+        );
+    }
+
+    #[test]
+    fn hidden_channel() {
+        let mut onboarding = onboarding();
+        onboarding.prompts[0].options[1].channel_ids = Vec::from([Id::new(17)]);
+        let mut guild = guild();
+        guild.roles[2].permissions = Permissions::empty();
+
+        let outcome = onboarding.simulate(&guild, &[Id::new(201)]).unwrap();
+
+        assert_eq!(
+            (10..=16).map(Id::new).collect::<Vec<_>>(),
+            outcome.channel_ids
+        );
+        assert_eq!(Vec::from([Id::new(3)]), outcome.role_ids);
+    }
+
+    #[test]
+    fn administrator() {
+        let outcome = onboarding().simulate(&guild(), &[Id::new(201)]).unwrap();
+
+        assert_eq!(
+            (10..=16).map(Id::new).collect::<Vec<_>>(),
+            outcome.channel_ids
+        );
+    }
+
+    #[test]
+    fn implicit_permissions() {
+        let mut guild = guild();
+        guild.roles[0].permissions |= Permissions::EMBED_LINKS;
+        let channel = &mut guild.channels[0];
+        channel.permission_overwrites =
+            Some(Vec::from([deny_everyone(Permissions::SEND_MESSAGES)]));
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            channel_permissions(&guild, &guild.channels[0], &[])
+        );
+        assert!(channel_permissions(&guild, &guild.channels[7], &[]).is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let onboarding = onboarding();
+        let guild = guild();
+
+        assert!(matches!(
+            onboarding.simulate(&guild, &[Id::new(202)]).unwrap_err().kind(),
+            OnboardingSelectionErrorType::RequiredPrompt { prompt_id } if *prompt_id == Id::new(100)
+        ));
+        assert!(matches!(
+            onboarding
+                .simulate(&guild, &[Id::new(200), Id::new(201)])
+                .unwrap_err()
+                .kind(),
+            OnboardingSelectionErrorType::SingleSelect { prompt_id } if *prompt_id == Id::new(100)
+        ));
+
+        let error = onboarding.simulate(&guild, &[Id::new(999)]).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            OnboardingSelectionErrorType::UnknownOption { option_id } if *option_id == Id::new(999)
+        ));
+        assert_eq!("option 999 is not part of any prompt", error.to_string());
+    }
+}
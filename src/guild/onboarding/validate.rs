@@ -0,0 +1,548 @@
+use super::{simulate::channel_permissions, Onboarding, OnboardingMode};
+use crate::{
+    guild::{Guild, Permissions},
+    id::{
+        marker::{
+            ChannelMarker, GuildMarker, OnboardingPromptMarker, OnboardingPromptOptionMarker,
+            RoleMarker,
+        },
+        Id,
+    },
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when an onboarding doesn't satisfy Discord's constraints.
+#[derive(Debug)]
+pub struct OnboardingError {
+    kind: OnboardingErrorType,
+}
+
+impl OnboardingError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &OnboardingErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (OnboardingErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+
+    const fn new(kind: OnboardingErrorType) -> Self {
+        Self { kind }
+    }
+}
+
+impl Display for OnboardingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            OnboardingErrorType::ChannelCount { count } => {
+                f.write_str("onboarding has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" channels, but at least ")?;
+                Display::fmt(&Onboarding::CHANNELS_MIN, f)?;
+
+                f.write_str(" are required")
+            }
+            OnboardingErrorType::GuildId { guild_id } => {
+                f.write_str("onboarding belongs to guild ")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str(", not the validated guild")
+            }
+            OnboardingErrorType::OptionDescriptionLength { option_id } => {
+                f.write_str("description of option ")?;
+                Display::fmt(option_id, f)?;
+                f.write_str(" is longer than ")?;
+                Display::fmt(&Onboarding::OPTION_DESCRIPTION_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            OnboardingErrorType::OptionEmpty { option_id } => {
+                f.write_str("option ")?;
+                Display::fmt(option_id, f)?;
+
+                f.write_str(" neither assigns roles nor opts into channels")
+            }
+            OnboardingErrorType::OptionTitleLength { option_id } => {
+                f.write_str("title of option ")?;
+                Display::fmt(option_id, f)?;
+                f.write_str(" is longer than ")?;
+                Display::fmt(&Onboarding::OPTION_TITLE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            OnboardingErrorType::PromptCount { count } => {
+                f.write_str("onboarding has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" prompts, but at most ")?;
+                Display::fmt(&Onboarding::PROMPTS_MAX, f)?;
+
+                f.write_str(" are allowed")
+            }
+            OnboardingErrorType::PromptOptionCount { count, prompt_id } => {
+                f.write_str("prompt ")?;
+                Display::fmt(prompt_id, f)?;
+                f.write_str(" has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" options, but between 1 and ")?;
+                Display::fmt(&Onboarding::PROMPT_OPTIONS_MAX, f)?;
+
+                f.write_str(" are required")
+            }
+            OnboardingErrorType::PromptTitleLength { prompt_id } => {
+                f.write_str("title of prompt ")?;
+                Display::fmt(prompt_id, f)?;
+                f.write_str(" is longer than ")?;
+                Display::fmt(&Onboarding::PROMPT_TITLE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            OnboardingErrorType::SendableChannelCount { count } => {
+                f.write_str("`@everyone` can send messages in ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" onboarding channels, but at least ")?;
+                Display::fmt(&Onboarding::SENDABLE_CHANNELS_MIN, f)?;
+
+                f.write_str(" are required")
+            }
+            OnboardingErrorType::UnknownChannel { channel_id } => {
+                f.write_str("channel ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" does not exist in the guild")
+            }
+            OnboardingErrorType::UnknownRole { role_id } => {
+                f.write_str("role ")?;
+                Display::fmt(role_id, f)?;
+
+                f.write_str(" does not exist in the guild")
+            }
+        }
+    }
+}
+
+impl Error for OnboardingError {}
+
+/// Type of [`OnboardingError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum OnboardingErrorType {
+    /// Enabled onboarding has fewer than [`Onboarding::CHANNELS_MIN`]
+    /// channels.
+    ChannelCount {
+        /// Number of channels.
+        count: usize,
+    },
+    /// Onboarding belongs to another guild.
+    GuildId {
+        /// ID of the guild of the onboarding.
+        guild_id: Id<GuildMarker>,
+    },
+    /// Description of an option is longer than
+    /// [`Onboarding::OPTION_DESCRIPTION_LENGTH_MAX`] characters.
+    OptionDescriptionLength {
+        /// ID of the option.
+        option_id: Id<OnboardingPromptOptionMarker>,
+    },
+    /// Option neither assigns roles nor opts into channels.
+    OptionEmpty {
+        /// ID of the option.
+        option_id: Id<OnboardingPromptOptionMarker>,
+    },
+    /// Title of an option is longer than
+    /// [`Onboarding::OPTION_TITLE_LENGTH_MAX`] characters.
+    OptionTitleLength {
+        /// ID of the option.
+        option_id: Id<OnboardingPromptOptionMarker>,
+    },
+    /// More than [`Onboarding::PROMPTS_MAX`] prompts were provided.
+    PromptCount {
+        /// Number of provided prompts.
+        count: usize,
+    },
+    /// Prompt has no options or more than [`Onboarding::PROMPT_OPTIONS_MAX`]
+    /// options.
+    PromptOptionCount {
+        /// Number of options of the prompt.
+        count: usize,
+        /// ID of the prompt.
+        prompt_id: Id<OnboardingPromptMarker>,
+    },
+    /// Title of a prompt is longer than
+    /// [`Onboarding::PROMPT_TITLE_LENGTH_MAX`] characters.
+    PromptTitleLength {
+        /// ID of the prompt.
+        prompt_id: Id<OnboardingPromptMarker>,
+    },
+    /// Enabled onboarding has fewer than [`Onboarding::SENDABLE_CHANNELS_MIN`]
+    /// channels in which `@everyone` can send messages.
+    SendableChannelCount {
+        /// Number of channels in which `@everyone` can send messages.
+        count: usize,
+    },
+    /// Channel referenced by the onboarding does not exist in the guild.
+    UnknownChannel {
+        /// ID of the channel.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Role referenced by the onboarding does not exist in the guild.
+    UnknownRole {
+        /// ID of the role.
+        role_id: Id<RoleMarker>,
+    },
+}
+
+impl Onboarding {
+    /// Minimum number of channels of an enabled onboarding.
+    pub const CHANNELS_MIN: usize = 7;
+
+    /// Maximum number of characters of an option's description.
+    pub const OPTION_DESCRIPTION_LENGTH_MAX: usize = 100;
+
+    /// Maximum number of characters of an option's title.
+    pub const OPTION_TITLE_LENGTH_MAX: usize = 50;
+
+    /// Maximum number of prompts.
+    pub const PROMPTS_MAX: usize = 15;
+
+    /// Maximum number of options of a prompt.
+    pub const PROMPT_OPTIONS_MAX: usize = 50;
+
+    /// Maximum number of characters of a prompt's title.
+    pub const PROMPT_TITLE_LENGTH_MAX: usize = 100;
+
+    /// Minimum number of channels of an enabled onboarding in which
+    /// `@everyone` can send messages.
+    pub const SENDABLE_CHANNELS_MIN: usize = 5;
+
+    /// Validate the onboarding against Discord's constraints and the guild it
+    /// belongs to.
+    ///
+    /// Enabled onboarding must have at least [`CHANNELS_MIN`] channels, in at
+    /// least [`SENDABLE_CHANNELS_MIN`] of which `@everyone` can send messages.
+    /// In the [`Default`] mode only default channels are counted, while the
+    /// [`Advanced`] mode also counts the channels of options of prompts shown
+    /// in the onboarding flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`GuildId`] if the onboarding belongs to
+    /// another guild.
+    ///
+    /// Returns an error of type [`PromptCount`], [`PromptOptionCount`],
+    /// [`PromptTitleLength`], [`OptionTitleLength`] or
+    /// [`OptionDescriptionLength`] if prompts exceed their limits, and
+    /// [`OptionEmpty`] if an option neither assigns roles nor opts into
+    /// channels.
+    ///
+    /// Returns an error of type [`UnknownChannel`] or [`UnknownRole`] if a
+    /// referenced channel or role does not exist in the guild.
+    ///
+    /// Returns an error of type [`ChannelCount`] or [`SendableChannelCount`]
+    /// if enabled onboarding has too few channels.
+    ///
+    /// [`Advanced`]: OnboardingMode::Advanced
+    /// [`CHANNELS_MIN`]: Self::CHANNELS_MIN
+    /// [`ChannelCount`]: OnboardingErrorType::ChannelCount
+    /// [`Default`]: OnboardingMode::Default
+    /// [`GuildId`]: OnboardingErrorType::GuildId
+    /// [`OptionDescriptionLength`]: OnboardingErrorType::OptionDescriptionLength
+    /// [`OptionEmpty`]: OnboardingErrorType::OptionEmpty
+    /// [`OptionTitleLength`]: OnboardingErrorType::OptionTitleLength
+    /// [`PromptCount`]: OnboardingErrorType::PromptCount
+    /// [`PromptOptionCount`]: OnboardingErrorType::PromptOptionCount
+    /// [`PromptTitleLength`]: OnboardingErrorType::PromptTitleLength
+    /// [`SENDABLE_CHANNELS_MIN`]: Self::SENDABLE_CHANNELS_MIN
+    /// [`SendableChannelCount`]: OnboardingErrorType::SendableChannelCount
+    /// [`UnknownChannel`]: OnboardingErrorType::UnknownChannel
+    /// [`UnknownRole`]: OnboardingErrorType::UnknownRole
+    pub fn validate(&self, guild: &Guild) -> Result<(), OnboardingError> {
+        if self.guild_id != guild.id {
+            return Err(OnboardingError::new(OnboardingErrorType::GuildId {
+                guild_id: self.guild_id,
+            }));
+        }
+
+        self.validate_prompts()?;
+        self.validate_references(guild)?;
+
+        if self.enabled {
+            self.validate_channel_counts(guild)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_prompts(&self) -> Result<(), OnboardingError> {
+        if self.prompts.len() > Self::PROMPTS_MAX {
+            return Err(OnboardingError::new(OnboardingErrorType::PromptCount {
+                count: self.prompts.len(),
+            }));
+        }
+
+        for prompt in &self.prompts {
+            if prompt.options.is_empty() || prompt.options.len() > Self::PROMPT_OPTIONS_MAX {
+                return Err(OnboardingError::new(
+                    OnboardingErrorType::PromptOptionCount {
+                        count: prompt.options.len(),
+                        prompt_id: prompt.id,
+                    },
+                ));
+            }
+
+            if prompt.title.chars().count() > Self::PROMPT_TITLE_LENGTH_MAX {
+                return Err(OnboardingError::new(
+                    OnboardingErrorType::PromptTitleLength {
+                        prompt_id: prompt.id,
+                    },
+                ));
+            }
+
+            for option in &prompt.options {
+                if option.title.chars().count() > Self::OPTION_TITLE_LENGTH_MAX {
+                    return Err(OnboardingError::new(
+                        OnboardingErrorType::OptionTitleLength {
+                            option_id: option.id,
+                        },
+                    ));
+                }
+
+                if matches!(
+                    &option.description,
+                    Some(description)
+                        if description.chars().count() > Self::OPTION_DESCRIPTION_LENGTH_MAX
+                ) {
+                    return Err(OnboardingError::new(
+                        OnboardingErrorType::OptionDescriptionLength {
+                            option_id: option.id,
+                        },
+                    ));
+                }
+
+                if option.channel_ids.is_empty() && option.role_ids.is_empty() {
+                    return Err(OnboardingError::new(OnboardingErrorType::OptionEmpty {
+                        option_id: option.id,
+                    }));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_references(&self, guild: &Guild) -> Result<(), OnboardingError> {
+        let options = self.prompts.iter().flat_map(|prompt| &prompt.options);
+        let channel_ids = self
+            .default_channel_ids
+            .iter()
+            .chain(options.clone().flat_map(|option| &option.channel_ids));
+
+        for channel_id in channel_ids {
+            if !guild
+                .channels
+                .iter()
+                .any(|channel| channel.id == *channel_id)
+            {
+                return Err(OnboardingError::new(OnboardingErrorType::UnknownChannel {
+                    channel_id: *channel_id,
+                }));
+            }
+        }
+
+        for role_id in options.flat_map(|option| &option.role_ids) {
+            if !guild.roles.iter().any(|role| role.id == *role_id) {
+                return Err(OnboardingError::new(OnboardingErrorType::UnknownRole {
+                    role_id: *role_id,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_channel_counts(&self, guild: &Guild) -> Result<(), OnboardingError> {
+        let mut channel_ids = self.default_channel_ids.clone();
+
+        if self.mode == OnboardingMode::Advanced {
+            channel_ids.extend(
+                self.prompts
+                    .iter()
+                    .filter(|prompt| prompt.in_onboarding)
+                    .flat_map(|prompt| &prompt.options)
+                    .flat_map(|option| &option.channel_ids),
+            );
+        }
+
+        channel_ids.sort_unstable();
+        channel_ids.dedup();
+
+        if channel_ids.len() < Self::CHANNELS_MIN {
+            return Err(OnboardingError::new(OnboardingErrorType::ChannelCount {
+                count: channel_ids.len(),
+            }));
+        }
+
+        let sendable = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        let count = guild
+            .channels
+            .iter()
+            .filter(|channel| channel_ids.contains(&channel.id))
+            .filter(|channel| channel_permissions(guild, channel, &[]).contains(sendable))
+            .count();
+
+        if count < Self::SENDABLE_CHANNELS_MIN {
+            return Err(OnboardingError::new(
+                OnboardingErrorType::SendableChannelCount { count },
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OnboardingError, OnboardingErrorType};
+    use crate::{
+        guild::{
+            onboarding::{Onboarding, OnboardingMode},
+            Guild, Permissions,
+        },
+        id::Id,
+        test::{
+            self,
+            onboarding::{deny_everyone, option, prompt},
+        },
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(OnboardingError: Debug, Error, Send, Sync);
+
+    fn onboarding() -> Onboarding {
+        test::onboarding::onboarding(&[prompt(
+            100,
+            "who are you?",
+            true,
+            true,
+            &[option(200, "member", &[17], &[2])],
+        )])
+    }
+
+    fn guild() -> Guild {
+        test::onboarding::guild(&[])
+    }
+
+    #[test]
+    fn valid() {
+        assert!(onboarding().validate(&guild()).is_ok());
+    }
+
+    #[test]
+    fn channel_count() {
+        let mut onboarding = onboarding();
+        onboarding.default_channel_ids.pop();
+
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::ChannelCount { count: 6 }
+        ));
+
+        onboarding.mode = OnboardingMode::Advanced;
+        assert!(onboarding.validate(&guild()).is_ok());
+
+        onboarding.enabled = false;
+        onboarding.mode = OnboardingMode::Default;
+        assert!(onboarding.validate(&guild()).is_ok());
+    }
+
+    #[test]
+    fn sendable_channel_count() {
+        let mut guild = guild();
+        guild.channels[0].permission_overwrites =
+            Some(Vec::from([deny_everyone(Permissions::SEND_MESSAGES)]));
+        guild.channels[1].permission_overwrites =
+            Some(Vec::from([deny_everyone(Permissions::VIEW_CHANNEL)]));
+        assert!(onboarding().validate(&guild).is_ok());
+
+        guild.channels[2].permission_overwrites =
+            Some(Vec::from([deny_everyone(Permissions::SEND_MESSAGES)]));
+        let error = onboarding().validate(&guild).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            OnboardingErrorType::SendableChannelCount { count: 4 }
+        ));
+        assert_eq!(
+            "`@everyone` can send messages in 4 onboarding channels, but at least 5 are required",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn references() {
+        let mut onboarding = onboarding();
+        onboarding.prompts[0].options[0].role_ids.push(Id::new(3));
+
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::UnknownRole { role_id } if *role_id == Id::new(3)
+        ));
+
+        onboarding.default_channel_ids.push(Id::new(18));
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::UnknownChannel { channel_id } if *channel_id == Id::new(18)
+        ));
+
+        onboarding.guild_id = Id::new(2);
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::GuildId { guild_id } if *guild_id == Id::new(2)
+        ));
+    }
+
+    #[test]
+    fn prompts() {
+        let mut onboarding = onboarding();
+        onboarding.prompts[0].options[0].title = "a".repeat(51);
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::OptionTitleLength { option_id } if *option_id == Id::new(200)
+        ));
+
+        onboarding.prompts[0].options[0].channel_ids.clear();
+        onboarding.prompts[0].options[0].role_ids.clear();
+        onboarding.prompts[0].options[0].title = "member".to_owned();
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::OptionEmpty { option_id } if *option_id == Id::new(200)
+        ));
+
+        onboarding.prompts[0].options.clear();
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::PromptOptionCount { count: 0, prompt_id }
+                if *prompt_id == Id::new(100)
+        ));
+
+        onboarding.prompts = vec![onboarding.prompts[0].clone(); 16];
+        assert!(matches!(
+            onboarding.validate(&guild()).unwrap_err().kind(),
+            OnboardingErrorType::PromptCount { count: 16 }
+        ));
+    }
+}
@@ -1,17 +1,19 @@
 use super::{
     AfkTimeout, DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, GuildFeature,
-    MfaLevel, NSFWLevel, Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
+    GuildFeatureSet, MfaLevel, NSFWLevel, Permissions, PremiumTier, Role, SystemChannelFlags,
+    VerificationLevel,
 };
 use crate::{
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, UserMarker},
         Id,
     },
-    util::image_hash::ImageHash,
+    util::{image_hash::ImageHash, Locale},
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PartialGuild {
     pub afk_channel_id: Option<Id<ChannelMarker>>,
     pub afk_timeout: AfkTimeout,
@@ -39,7 +41,7 @@ pub struct PartialGuild {
     pub owner: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<Permissions>,
-    pub preferred_locale: String,
+    pub preferred_locale: Locale,
     /// Whether the premium progress bar is enabled in the guild.
     pub premium_progress_bar_enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,10 +63,28 @@ pub struct PartialGuild {
     pub widget_enabled: Option<bool>,
 }
 
+impl PartialGuild {
+    /// Set of the guild's features.
+    ///
+    /// Compare the sets of two [`GuildUpdate`]s with
+    /// [`GuildFeatureSet::difference`] to find the features that were added
+    /// or removed.
+    ///
+    /// [`GuildUpdate`]: crate::gateway::payload::incoming::GuildUpdate
+    pub fn feature_set(&self) -> GuildFeatureSet {
+        self.features.iter().cloned().collect()
+    }
+
+    /// Whether the guild has a feature.
+    pub fn has_feature(&self, feature: &GuildFeature) -> bool {
+        self.features.contains(feature)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        guild::{AfkTimeout, GuildFeature},
+        guild::{AfkTimeout, GuildFeature, GuildFeatureSet},
         test::image_hash,
     };
 
@@ -100,7 +120,7 @@ mod tests {
             owner_id: Id::new(5),
             owner: Some(false),
             permissions: Some(Permissions::SEND_MESSAGES),
-            preferred_locale: "en-us".to_owned(),
+            preferred_locale: "en-us".into(),
             premium_progress_bar_enabled: true,
             premium_subscription_count: Some(3),
             premium_tier: PremiumTier::Tier1,
@@ -125,14 +145,18 @@ mod tests {
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("afk_timeout"),
                 Token::NewtypeStruct { name: "AfkTimeout" },
                 Token::U16(900),
                 Token::Str("application_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("banner"),
                 Token::Some,
@@ -158,7 +182,9 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::ICON_INPUT),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("max_members"),
                 Token::Some,
@@ -176,7 +202,9 @@ mod tests {
                 Token::Str("nsfw_level"),
                 Token::U8(0),
                 Token::Str("owner_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("5"),
                 Token::Str("owner"),
                 Token::Some,
@@ -200,7 +228,9 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("rules_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("6"),
                 Token::Str("splash"),
                 Token::Some,
@@ -209,7 +239,9 @@ mod tests {
                 Token::U64(2),
                 Token::Str("system_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("7"),
                 Token::Str("verification_level"),
                 Token::U8(2),
@@ -218,7 +250,9 @@ mod tests {
                 Token::Str("twilight"),
                 Token::Str("widget_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("8"),
                 Token::Str("widget_enabled"),
                 Token::Some,
@@ -226,5 +260,17 @@ mod tests {
                 Token::StructEnd,
             ],
         );
+
+        let mut updated = value.clone();
+        updated.features = Vec::from([GuildFeature::VanityUrl, GuildFeature::AnimatedBanner]);
+
+        assert_eq!(
+            GuildFeatureSet::from_iter([GuildFeature::VanityUrl]),
+            updated.feature_set().difference(&value.feature_set())
+        );
+        assert!(value
+            .feature_set()
+            .difference(&updated.feature_set())
+            .is_empty());
     }
 }
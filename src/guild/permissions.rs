@@ -76,6 +76,8 @@ bitflags! {
         const USE_EXTERNAL_SOUNDS = 1 << 45;
         /// Allows sending voice messages
         const SEND_VOICE_MESSAGES = 1 << 46;
+        /// Allows sending polls
+        const SEND_POLLS = 1 << 49;
     }
 }
 
@@ -114,6 +116,20 @@ impl Serialize for Permissions {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Permissions {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Permissions".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[0-9]+$",
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Permissions;
@@ -205,6 +221,8 @@ mod tests {
     );
     const_assert_eq!(Permissions::USE_SOUNDBOARD.bits(), 1 << 42);
     const_assert_eq!(Permissions::USE_EXTERNAL_SOUNDS.bits(), 1 << 45);
+    const_assert_eq!(Permissions::SEND_VOICE_MESSAGES.bits(), 1 << 46);
+    const_assert_eq!(Permissions::SEND_POLLS.bits(), 1 << 49);
 
     #[test]
     fn serde() {
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum PremiumTier {
@@ -11,6 +12,68 @@ pub enum PremiumTier {
     Other(u8),
 }
 
+impl PremiumTier {
+    /// Whether the guild can set an animated banner.
+    pub const fn animated_banner(self) -> bool {
+        matches!(self, Self::Tier3)
+    }
+
+    /// Whether the guild can set an animated icon.
+    pub const fn animated_icon(self) -> bool {
+        matches!(self, Self::Tier1 | Self::Tier2 | Self::Tier3)
+    }
+
+    /// Whether the guild can set a banner.
+    pub const fn banner(self) -> bool {
+        matches!(self, Self::Tier2 | Self::Tier3)
+    }
+
+    /// Maximum number of custom emojis of the guild.
+    ///
+    /// The limit applies separately to static and animated emojis.
+    pub const fn emoji_limit(self) -> u16 {
+        match self {
+            Self::Tier1 => 100,
+            Self::Tier2 => 150,
+            Self::Tier3 => 250,
+            Self::None | Self::Other(_) => 50,
+        }
+    }
+
+    /// Maximum bitrate of the guild's voice channels, in bits per second.
+    ///
+    /// The minimum bitrate is 8000 regardless of the tier.
+    pub const fn max_bitrate(self) -> u32 {
+        match self {
+            Self::Tier1 => 128_000,
+            Self::Tier2 => 256_000,
+            Self::Tier3 => 384_000,
+            Self::None | Self::Other(_) => 96_000,
+        }
+    }
+
+    /// Maximum size of files uploaded to the guild, in bytes.
+    pub const fn max_upload_size(self) -> u64 {
+        const MEBIBYTE: u64 = 1024 * 1024;
+
+        match self {
+            Self::Tier2 => 50 * MEBIBYTE,
+            Self::Tier3 => 100 * MEBIBYTE,
+            Self::None | Self::Tier1 | Self::Other(_) => 10 * MEBIBYTE,
+        }
+    }
+
+    /// Maximum number of custom stickers of the guild.
+    pub const fn sticker_limit(self) -> u16 {
+        match self {
+            Self::Tier1 => 15,
+            Self::Tier2 => 30,
+            Self::Tier3 => 60,
+            Self::None | Self::Other(_) => 5,
+        }
+    }
+}
+
 impl From<u8> for PremiumTier {
     fn from(value: u8) -> Self {
         match value {
@@ -54,4 +117,39 @@ mod tests {
         serde_test::assert_tokens(&PremiumTier::Tier3, &[Token::U8(3)]);
         serde_test::assert_tokens(&PremiumTier::Other(99), &[Token::U8(99)]);
     }
+
+    #[test]
+    fn limits() {
+        const LIMITS: &[(PremiumTier, u16, u16, u32, u64)] = &[
+            (PremiumTier::None, 50, 5, 96_000, 10),
+            (PremiumTier::Tier1, 100, 15, 128_000, 10),
+            (PremiumTier::Tier2, 150, 30, 256_000, 50),
+            (PremiumTier::Tier3, 250, 60, 384_000, 100),
+            (PremiumTier::Other(99), 50, 5, 96_000, 10),
+        ];
+
+        for (tier, emojis, stickers, bitrate, upload) in LIMITS {
+            assert_eq!(*emojis, tier.emoji_limit());
+            assert_eq!(*stickers, tier.sticker_limit());
+            assert_eq!(*bitrate, tier.max_bitrate());
+            assert_eq!(*upload * 1024 * 1024, tier.max_upload_size());
+        }
+    }
+
+    #[test]
+    fn perks() {
+        const PERKS: &[(PremiumTier, bool, bool, bool)] = &[
+            (PremiumTier::None, false, false, false),
+            (PremiumTier::Tier1, true, false, false),
+            (PremiumTier::Tier2, true, true, false),
+            (PremiumTier::Tier3, true, true, true),
+            (PremiumTier::Other(99), false, false, false),
+        ];
+
+        for (tier, animated_icon, banner, animated_banner) in PERKS {
+            assert_eq!(*animated_icon, tier.animated_icon());
+            assert_eq!(*banner, tier.banner());
+            assert_eq!(*animated_banner, tier.animated_banner());
+        }
+    }
 }
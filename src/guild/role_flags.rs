@@ -27,6 +27,17 @@ impl Serialize for RoleFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RoleFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "RoleFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::RoleFlags;
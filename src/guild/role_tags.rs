@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Role`]: super::Role
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RoleTags {
     /// Whether this role is available for purchase.
     #[serde(
@@ -18,6 +19,7 @@ pub struct RoleTags {
         skip_serializing_if = "is_false",
         with = "crate::visitor::null_boolean"
     )]
+    #[cfg_attr(feature = "schemars", schemars(with = "()"))]
     pub available_for_purchase: bool,
     /// ID of the bot the role belongs to.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,6 +30,7 @@ pub struct RoleTags {
         skip_serializing_if = "is_false",
         with = "crate::visitor::null_boolean"
     )]
+    #[cfg_attr(feature = "schemars", schemars(with = "()"))]
     pub guild_connections: bool,
     /// ID of the integration the role belongs to.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,6 +44,7 @@ pub struct RoleTags {
         skip_serializing_if = "is_false",
         with = "crate::visitor::null_boolean"
     )]
+    #[cfg_attr(feature = "schemars", schemars(with = "()"))]
     pub premium_subscriber: bool,
 }
 
@@ -70,11 +74,15 @@ mod tests {
                 },
                 Token::Str("bot_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("integration_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::StructEnd,
             ],
@@ -128,11 +136,15 @@ mod tests {
                 Token::None,
                 Token::Str("integration_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("subscription_listing_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::StructEnd,
             ],
@@ -0,0 +1,553 @@
+//! Display implementations for formatting scheduled events as iCalendar
+//! ([RFC 5545]) data.
+//!
+//! [RFC 5545]: https://datatracker.ietf.org/doc/html/rfc5545
+
+use super::{
+    GuildScheduledEvent, RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleWeekday, Status,
+};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use time::OffsetDateTime;
+
+/// Maximum number of octets of a content line, excluding the line break.
+const LINE_LENGTH_MAX: usize = 75;
+
+/// Product identifier of calendars.
+const PRODUCT_ID: &str = "-//twilight-rs//twilight-model//EN";
+
+/// Display implementation to format a [`GuildScheduledEvent`] as an iCalendar
+/// `VEVENT` component.
+///
+/// The component uses the event ID as its `UID` and the event's creation time
+/// as its `DTSTAMP`, so formatting the same event always results in the same
+/// component. Recurring events are formatted with an `RRULE` property
+/// starting at the rule's start.
+///
+/// Created via [`GuildScheduledEvent::icalendar`]. Components have to be
+/// wrapped in a `VCALENDAR` component before being consumed by calendar
+/// applications, see [`ICalendarDisplay`].
+#[derive(Clone, Copy, Debug)]
+pub struct ICalendarEventDisplay<'a> {
+    /// Event to format.
+    event: &'a GuildScheduledEvent,
+}
+
+impl<'a> ICalendarEventDisplay<'a> {
+    /// Create a new iCalendar display formatter for an event.
+    pub(super) const fn new(event: &'a GuildScheduledEvent) -> Self {
+        Self { event }
+    }
+
+    /// Get the inner event.
+    pub const fn get(self) -> &'a GuildScheduledEvent {
+        self.event
+    }
+}
+
+impl Display for ICalendarEventDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let event = self.event;
+
+        write_line(f, format_args!("BEGIN:VEVENT"))?;
+        write_line(f, format_args!("UID:{}@discord.com", event.id))?;
+        write_line(
+            f,
+            format_args!(
+                "DTSTAMP:{}",
+                DateTimeDisplay(event.id.timestamp().div_euclid(1000))
+            ),
+        )?;
+
+        // Recurring events start at the start of the recurrence rule, keeping
+        // the duration of the scheduled occurrence.
+        let start = event
+            .recurrence_rule
+            .as_ref()
+            .map_or(event.scheduled_start_time, |rule| rule.start);
+
+        write_line(
+            f,
+            format_args!("DTSTART:{}", DateTimeDisplay(start.as_secs())),
+        )?;
+
+        if let Some(end) = event.scheduled_end_time {
+            let duration = end.as_secs() - event.scheduled_start_time.as_secs();
+
+            write_line(
+                f,
+                format_args!("DTEND:{}", DateTimeDisplay(start.as_secs() + duration)),
+            )?;
+        }
+
+        if let Some(rule) = &event.recurrence_rule {
+            if !matches!(rule.frequency, RecurrenceRuleFrequency::Unknown(_)) {
+                write_line(f, format_args!("RRULE:{}", RecurrenceRuleDisplay(rule)))?;
+            }
+        }
+
+        write_line(f, format_args!("SUMMARY:{}", TextDisplay(&event.name)))?;
+
+        if let Some(description) = &event.description {
+            write_line(f, format_args!("DESCRIPTION:{}", TextDisplay(description)))?;
+        }
+
+        if let Some(location) = event
+            .entity_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.location.as_deref())
+        {
+            write_line(f, format_args!("LOCATION:{}", TextDisplay(location)))?;
+        }
+
+        write_line(
+            f,
+            format_args!(
+                "URL:https://discord.com/events/{}/{}",
+                event.guild_id, event.id
+            ),
+        )?;
+
+        let status = match event.status {
+            Status::Scheduled | Status::Active | Status::Completed => Some("CONFIRMED"),
+            Status::Cancelled => Some("CANCELLED"),
+            Status::Unknown(_) => None,
+        };
+
+        if let Some(status) = status {
+            write_line(f, format_args!("STATUS:{status}"))?;
+        }
+
+        if let Some(creator) = &event.creator {
+            let name = creator.global_name.as_deref().unwrap_or(&creator.name);
+
+            write_line(
+                f,
+                format_args!(
+                    "ORGANIZER;CN=\"{}\":https://discord.com/users/{}",
+                    ParamDisplay(name),
+                    creator.id
+                ),
+            )?;
+        } else if let Some(creator_id) = event.creator_id {
+            write_line(
+                f,
+                format_args!("ORGANIZER:https://discord.com/users/{creator_id}"),
+            )?;
+        }
+
+        write_line(f, format_args!("END:VEVENT"))
+    }
+}
+
+/// Display implementation to format scheduled events as an iCalendar
+/// `VCALENDAR` object.
+///
+/// # Examples
+///
+/// Export the scheduled events of a guild as a calendar:
+///
+/// ```
+/// use twilight_model::guild::scheduled_event::{GuildScheduledEvent, ICalendarDisplay};
+///
+/// # let events: Vec<GuildScheduledEvent> = Vec::new();
+/// let calendar = ICalendarDisplay::new(&events)
+///     .name("Twilight Events")
+///     .to_string();
+///
+/// assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ICalendarDisplay<'a> {
+    /// Events to format.
+    events: &'a [GuildScheduledEvent],
+    /// Name of the calendar.
+    name: Option<&'a str>,
+}
+
+impl<'a> ICalendarDisplay<'a> {
+    /// Create a new iCalendar display formatter for a list of events.
+    pub const fn new(events: &'a [GuildScheduledEvent]) -> Self {
+        Self { events, name: None }
+    }
+
+    /// Get the inner events.
+    pub const fn get(self) -> &'a [GuildScheduledEvent] {
+        self.events
+    }
+
+    /// Set the name calendar applications display for the calendar, such as
+    /// the name of the guild.
+    ///
+    /// The name is formatted as the widely supported, non-standard
+    /// `X-WR-CALNAME` property.
+    #[must_use = "setting the name has no effect if the display is left unused"]
+    pub const fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+
+        self
+    }
+}
+
+impl Display for ICalendarDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_line(f, format_args!("BEGIN:VCALENDAR"))?;
+        write_line(f, format_args!("VERSION:2.0"))?;
+        write_line(f, format_args!("PRODID:{PRODUCT_ID}"))?;
+        write_line(f, format_args!("CALSCALE:GREGORIAN"))?;
+
+        if let Some(name) = self.name {
+            write_line(f, format_args!("X-WR-CALNAME:{}", TextDisplay(name)))?;
+        }
+
+        for event in self.events {
+            Display::fmt(&event.icalendar(), f)?;
+        }
+
+        write_line(f, format_args!("END:VCALENDAR"))
+    }
+}
+
+/// Write a content line, folding it into multiple lines of at most
+/// [`LINE_LENGTH_MAX`] octets.
+fn write_line(f: &mut Formatter<'_>, line: impl Display) -> FmtResult {
+    let line = line.to_string();
+    let mut length = 0;
+
+    for character in line.chars() {
+        // Lines may only be folded between characters, not within the
+        // octets of a multi-octet character.
+        if length + character.len_utf8() > LINE_LENGTH_MAX {
+            f.write_str("\r\n ")?;
+            length = 1;
+        }
+
+        f.write_char(character)?;
+        length += character.len_utf8();
+    }
+
+    f.write_str("\r\n")
+}
+
+/// Unix timestamp in seconds of the earliest date-time value, at the start of
+/// the year 0000.
+const DATE_TIME_MIN: i64 = -62_167_219_200;
+
+/// Unix timestamp in seconds of the latest date-time value, at the end of the
+/// year 9999.
+const DATE_TIME_MAX: i64 = 253_402_300_799;
+
+/// Display a Unix timestamp in seconds as a UTC date-time value.
+///
+/// Timestamps outside of the years representable by a date-time value are
+/// clamped to the earliest or latest date-time value.
+struct DateTimeDisplay(i64);
+
+impl Display for DateTimeDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let datetime =
+            OffsetDateTime::from_unix_timestamp(self.0.clamp(DATE_TIME_MIN, DATE_TIME_MAX))
+                .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+        write!(
+            f,
+            "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+            datetime.year(),
+            u8::from(datetime.month()),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+        )
+    }
+}
+
+/// Display a parameter value, removing the characters it may not contain.
+struct ParamDisplay<'a>(&'a str);
+
+impl Display for ParamDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for character in self.0.chars() {
+            if character != '"' && !character.is_control() {
+                f.write_char(character)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Display a recurrence rule as a recurrence rule value.
+struct RecurrenceRuleDisplay<'a>(&'a RecurrenceRule);
+
+impl Display for RecurrenceRuleDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let rule = self.0;
+
+        f.write_str("FREQ=")?;
+        f.write_str(match rule.frequency {
+            RecurrenceRuleFrequency::Yearly => "YEARLY",
+            RecurrenceRuleFrequency::Monthly => "MONTHLY",
+            RecurrenceRuleFrequency::Weekly => "WEEKLY",
+            _ => "DAILY",
+        })?;
+
+        if rule.interval > 1 {
+            write!(f, ";INTERVAL={}", rule.interval)?;
+        }
+
+        if let Some(count) = rule.count {
+            write!(f, ";COUNT={count}")?;
+        } else if let Some(end) = rule.end {
+            write!(f, ";UNTIL={}", DateTimeDisplay(end.as_secs()))?;
+        }
+
+        let weekdays =
+            rule.by_weekday
+                .iter()
+                .flatten()
+                .filter_map(|day| Some((None, weekday_code(*day)?)))
+                .chain(rule.by_n_weekday.iter().flatten().filter_map(|n_weekday| {
+                    Some((Some(n_weekday.n), weekday_code(n_weekday.day)?))
+                }));
+
+        for (index, (n, code)) in weekdays.enumerate() {
+            f.write_str(if index == 0 { ";BYDAY=" } else { "," })?;
+
+            if let Some(n) = n {
+                Display::fmt(&n, f)?;
+            }
+
+            f.write_str(code)?;
+        }
+
+        let months = rule.by_month.iter().flatten().map(|month| u8::from(*month));
+        write_list(
+            f,
+            "BYMONTH",
+            months.filter(|month| (1..=12).contains(month)),
+        )?;
+        write_list(f, "BYMONTHDAY", rule.by_month_day.iter().flatten())?;
+
+        write_list(f, "BYYEARDAY", rule.by_year_day.iter().flatten())
+    }
+}
+
+/// Write a comma separated list rule part if the list is not empty.
+fn write_list(
+    f: &mut Formatter<'_>,
+    name: &str,
+    values: impl Iterator<Item = impl Display>,
+) -> FmtResult {
+    for (index, value) in values.enumerate() {
+        if index == 0 {
+            f.write_str(";")?;
+            f.write_str(name)?;
+            f.write_str("=")?;
+        } else {
+            f.write_str(",")?;
+        }
+
+        Display::fmt(&value, f)?;
+    }
+
+    Ok(())
+}
+
+/// Display a text value, escaping the characters with special meaning.
+struct TextDisplay<'a>(&'a str);
+
+impl Display for TextDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for character in self.0.chars() {
+            match character {
+                '\\' => f.write_str("\\\\")?,
+                ';' => f.write_str("\\;")?,
+                ',' => f.write_str("\\,")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => {}
+                character => f.write_char(character)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Weekday code of a weekday, or [`None`] if the weekday is unknown.
+const fn weekday_code(weekday: RecurrenceRuleWeekday) -> Option<&'static str> {
+    Some(match weekday {
+        RecurrenceRuleWeekday::Monday => "MO",
+        RecurrenceRuleWeekday::Tuesday => "TU",
+        RecurrenceRuleWeekday::Wednesday => "WE",
+        RecurrenceRuleWeekday::Thursday => "TH",
+        RecurrenceRuleWeekday::Friday => "FR",
+        RecurrenceRuleWeekday::Saturday => "SA",
+        RecurrenceRuleWeekday::Sunday => "SU",
+        RecurrenceRuleWeekday::Unknown(_) => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateTimeDisplay, ICalendarDisplay, TextDisplay};
+    use crate::{
+        guild::scheduled_event::{
+            EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule,
+            RecurrenceRuleFrequency, RecurrenceRuleNWeekday, RecurrenceRuleWeekday, Status,
+        },
+        id::Id,
+        util::Timestamp,
+    };
+
+    fn timestamp(datetime: &str) -> Timestamp {
+        Timestamp::parse(datetime).unwrap()
+    }
+
+    fn event() -> GuildScheduledEvent {
+        GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: Some(Id::new(5)),
+            description: Some("Bring snacks; lasagna, preferably.\nNo mondays.".to_owned()),
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("Garfield's house".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: Id::new(1),
+            id: Id::new(1_026_474_962_788_696_064),
+            image: None,
+            name: "Garfield watch party".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: Some(timestamp("2024-06-19T20:00:00+00:00")),
+            scheduled_start_time: timestamp("2024-06-19T18:00:00+00:00"),
+            status: Status::Cancelled,
+            user_count: None,
+        }
+    }
+
+    #[test]
+    fn event_component() {
+        assert_eq!(
+            concat!(
+                "BEGIN:VEVENT\r\n",
+                "UID:1026474962788696064@discord.com\r\n",
+                "DTSTAMP:20221003T124502Z\r\n",
+                "DTSTART:20240619T180000Z\r\n",
+                "DTEND:20240619T200000Z\r\n",
+                "SUMMARY:Garfield watch party\r\n",
+                "DESCRIPTION:Bring snacks\\; lasagna\\, preferably.\\nNo mondays.\r\n",
+                "LOCATION:Garfield's house\r\n",
+                "URL:https://discord.com/events/1/1026474962788696064\r\n",
+                "STATUS:CANCELLED\r\n",
+                "ORGANIZER:https://discord.com/users/5\r\n",
+                "END:VEVENT\r\n",
+            ),
+            event().icalendar().to_string(),
+        );
+    }
+
+    #[test]
+    fn recurring_event() {
+        let mut value = event();
+        value.status = Status::Completed;
+        value.recurrence_rule = Some(RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: Some(Vec::from([RecurrenceRuleNWeekday {
+                day: RecurrenceRuleWeekday::Wednesday,
+                n: 3,
+            }])),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Monthly,
+            interval: 1,
+            start: timestamp("2024-01-17T18:00:00+00:00"),
+        });
+
+        let component = value.icalendar().to_string();
+
+        assert!(component.contains("\r\nDTSTART:20240117T180000Z\r\n"));
+        assert!(component.contains("\r\nDTEND:20240117T200000Z\r\n"));
+        assert!(component.contains("\r\nRRULE:FREQ=MONTHLY;BYDAY=3WE\r\n"));
+        assert!(component.contains("\r\nSTATUS:CONFIRMED\r\n"));
+    }
+
+    #[test]
+    fn date_time_out_of_range() {
+        assert_eq!("00000101T000000Z", DateTimeDisplay(i64::MIN).to_string());
+        assert_eq!("99991231T235959Z", DateTimeDisplay(i64::MAX).to_string());
+
+        let mut value = event();
+        value.scheduled_start_time = timestamp("2024-06-19T18:00:00+00:00");
+        value.scheduled_end_time = Some(timestamp("9999-06-19T18:00:00+00:00"));
+        value.recurrence_rule = Some(RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Yearly,
+            interval: 1,
+            start: timestamp("9000-06-19T18:00:00+00:00"),
+        });
+
+        let component = value.icalendar().to_string();
+
+        assert!(component.contains("\r\nDTSTART:90000619T180000Z\r\n"));
+        assert!(component.contains("\r\nDTEND:99991231T235959Z\r\n"));
+    }
+
+    #[test]
+    fn calendar() {
+        let events = [event(), event()];
+        let calendar = ICalendarDisplay::new(&events)
+            .name("Lasagna, Inc.")
+            .to_string();
+
+        assert!(calendar.starts_with(concat!(
+            "BEGIN:VCALENDAR\r\n",
+            "VERSION:2.0\r\n",
+            "PRODID:-//twilight-rs//twilight-model//EN\r\n",
+            "CALSCALE:GREGORIAN\r\n",
+            "X-WR-CALNAME:Lasagna\\, Inc.\r\n",
+            "BEGIN:VEVENT\r\n",
+        )));
+        assert!(calendar.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert_eq!(2, calendar.matches("BEGIN:VEVENT").count());
+    }
+
+    #[test]
+    fn folding() {
+        let mut value = event();
+        value.name = "é".repeat(60);
+
+        let component = value.icalendar().to_string();
+        let summary = component
+            .split("\r\n")
+            .skip_while(|line| !line.starts_with("SUMMARY:"))
+            .take_while(|line| line.starts_with("SUMMARY:") || line.starts_with(' '))
+            .collect::<Vec<_>>();
+
+        assert_eq!(2, summary.len());
+        assert!(summary.iter().all(|line| line.len() <= 75));
+        assert_eq!(
+            value.name,
+            format!("{}{}", &summary[0]["SUMMARY:".len()..], &summary[1][1..])
+        );
+    }
+
+    #[test]
+    fn text_escaping() {
+        assert_eq!(
+            "a\\\\b\\;c\\,d\\ne",
+            TextDisplay("a\\b;c,d\r\ne").to_string()
+        );
+    }
+}
@@ -1,8 +1,18 @@
 //! Types for interacting with scheduled events.
 
+mod icalendar;
+mod recurrence;
 mod user;
 
-pub use self::user::GuildScheduledEventUser;
+pub use self::{
+    icalendar::{ICalendarDisplay, ICalendarEventDisplay},
+    recurrence::{
+        RecurrenceRule, RecurrenceRuleError, RecurrenceRuleErrorType, RecurrenceRuleFrequency,
+        RecurrenceRuleMonth, RecurrenceRuleNWeekday, RecurrenceRuleOccurrences,
+        RecurrenceRuleWeekday,
+    },
+    user::GuildScheduledEventUser,
+};
 
 use crate::{
     id::{
@@ -25,6 +35,7 @@ use serde::{Deserialize, Serialize};
 /// [`creator`]: Self::creator
 /// [`creator_id`]: Self::creator_id
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildScheduledEvent {
     /// ID of the stage or voice channel if there is one.
     ///
@@ -66,6 +77,9 @@ pub struct GuildScheduledEvent {
     pub name: String,
     /// Privacy level of the event.
     pub privacy_level: PrivacyLevel,
+    /// Rule defining how often the event recurs, if it does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence_rule: Option<RecurrenceRule>,
     /// Scheduled end time of the event.
     ///
     /// Required on events of type [`EntityType::External`]. It also may be
@@ -81,8 +95,22 @@ pub struct GuildScheduledEvent {
     pub user_count: Option<u64>,
 }
 
+impl GuildScheduledEvent {
+    /// Create a [`Display`] formatter for the event as an iCalendar
+    /// ([RFC 5545]) `VEVENT` component.
+    ///
+    /// Use [`ICalendarDisplay`] to format a calendar of events.
+    ///
+    /// [`Display`]: core::fmt::Display
+    /// [RFC 5545]: https://datatracker.ietf.org/doc/html/rfc5545
+    pub const fn icalendar(&self) -> ICalendarEventDisplay<'_> {
+        ICalendarEventDisplay::new(self)
+    }
+}
+
 /// Metadata associated with an event.
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EntityMetadata {
     /// Physical location of an event with type [`EntityType::External`].
     pub location: Option<String>,
@@ -90,6 +118,7 @@ pub struct EntityMetadata {
 
 /// Type of event.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum EntityType {
@@ -127,6 +156,7 @@ impl From<EntityType> for u8 {
 
 /// Privacy level of an event.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum PrivacyLevel {
@@ -156,6 +186,7 @@ impl From<PrivacyLevel> for u8 {
 
 /// Status of an event.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum Status {
@@ -223,6 +254,7 @@ mod tests {
             image: Some(COVER),
             name: "garfield dance party".into(),
             privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
             scheduled_end_time: None,
             scheduled_start_time,
             status: Status::Completed,
@@ -238,22 +270,30 @@ mod tests {
                 },
                 Token::Str("channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("description"),
                 Token::Some,
                 Token::Str("this is a dance party for garfield lovers"),
                 Token::Str("entity_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("entity_type"),
                 Token::U8(1),
                 Token::Str("guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("image"),
                 Token::Some,
@@ -0,0 +1,1138 @@
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use time::{Date, Month, Weekday};
+
+/// Number of microseconds in a day.
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+/// Julian day of the Unix epoch.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+/// Number of consecutive periods without an occurrence after which
+/// [`RecurrenceRuleOccurrences`] assumes the rule never recurs again.
+const PERIOD_ATTEMPTS: u32 = 100;
+
+/// Sets of weekdays supported by [`RecurrenceRuleFrequency::Daily`] rules, as
+/// bitmasks of [`RecurrenceRuleWeekday`] values.
+const DAILY_WEEKDAY_SETS: [u8; 6] = [
+    0b001_1111, // Monday to Friday.
+    0b011_1110, // Tuesday to Saturday.
+    0b100_1111, // Sunday to Thursday.
+    0b011_0000, // Friday and Saturday.
+    0b110_0000, // Saturday and Sunday.
+    0b100_0001, // Sunday and Monday.
+];
+
+/// Error emitted when a recurrence rule is not supported by Discord.
+#[derive(Debug)]
+pub struct RecurrenceRuleError {
+    kind: RecurrenceRuleErrorType,
+}
+
+impl RecurrenceRuleError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RecurrenceRuleErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        RecurrenceRuleErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for RecurrenceRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RecurrenceRuleErrorType::ByMonth => {
+                f.write_str("yearly rules must recur on exactly one month")
+            }
+            RecurrenceRuleErrorType::ByMonthDay => {
+                f.write_str("yearly rules must recur on exactly one day of the month")
+            }
+            RecurrenceRuleErrorType::ByNWeekday => f.write_str(
+                "monthly rules must recur on exactly one weekday in the first to fifth week",
+            ),
+            RecurrenceRuleErrorType::ByWeekday => f.write_str(
+                "daily rules must recur on a supported set of weekdays and weekly rules on \
+                 exactly one weekday",
+            ),
+            RecurrenceRuleErrorType::Field { field } => {
+                f.write_str("field `")?;
+                f.write_str(field)?;
+
+                f.write_str("` is not supported by the rule's frequency")
+            }
+            RecurrenceRuleErrorType::Frequency => f.write_str("frequency is unknown"),
+            RecurrenceRuleErrorType::Interval { interval } => {
+                f.write_str("interval ")?;
+                Display::fmt(interval, f)?;
+
+                f.write_str(" is not supported by the rule's frequency")
+            }
+        }
+    }
+}
+
+impl Error for RecurrenceRuleError {}
+
+/// Type of [`RecurrenceRuleError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RecurrenceRuleErrorType {
+    /// [`Yearly`] rule does not have exactly one known month.
+    ///
+    /// [`Yearly`]: RecurrenceRuleFrequency::Yearly
+    ByMonth,
+    /// [`Yearly`] rule does not have exactly one day of the month, or the day
+    /// does not exist in the month.
+    ///
+    /// [`Yearly`]: RecurrenceRuleFrequency::Yearly
+    ByMonthDay,
+    /// [`Monthly`] rule does not have exactly one weekday, or its week is not
+    /// between 1 and 5.
+    ///
+    /// [`Monthly`]: RecurrenceRuleFrequency::Monthly
+    ByNWeekday,
+    /// [`Daily`] rule has an unsupported set of weekdays, or [`Weekly`] rule
+    /// does not have exactly one weekday.
+    ///
+    /// [`Daily`]: RecurrenceRuleFrequency::Daily
+    /// [`Weekly`]: RecurrenceRuleFrequency::Weekly
+    ByWeekday,
+    /// Field is set which is not supported by the rule's frequency.
+    Field {
+        /// Name of the unsupported field.
+        field: &'static str,
+    },
+    /// Frequency is unknown to the library.
+    Frequency,
+    /// Interval is not supported by the rule's frequency.
+    ///
+    /// Only [`Weekly`] rules may have an interval of 2, all other rules must
+    /// have an interval of 1.
+    ///
+    /// [`Weekly`]: RecurrenceRuleFrequency::Weekly
+    Interval {
+        /// Provided interval.
+        interval: u16,
+    },
+}
+
+/// Rule defining how often a scheduled event recurs.
+///
+/// See [Discord Docs/Guild Scheduled Event Recurrence Rule Object].
+///
+/// [Discord Docs/Guild Scheduled Event Recurrence Rule Object]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RecurrenceRule {
+    /// Months to recur on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_month: Option<Vec<RecurrenceRuleMonth>>,
+    /// Days of the month to recur on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_month_day: Option<Vec<u8>>,
+    /// Weekdays within a specific week of the month to recur on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_n_weekday: Option<Vec<RecurrenceRuleNWeekday>>,
+    /// Weekdays to recur on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_weekday: Option<Vec<RecurrenceRuleWeekday>>,
+    /// Days of the year to recur on.
+    ///
+    /// Set by Discord and can't be configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_year_day: Option<Vec<u16>>,
+    /// Total number of times the event is allowed to recur before stopping.
+    ///
+    /// Set by Discord and can't be configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    /// End of the recurrence interval.
+    ///
+    /// Set by Discord and can't be configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<Timestamp>,
+    /// How often the event occurs.
+    pub frequency: RecurrenceRuleFrequency,
+    /// Spacing between occurrences, in units of the frequency.
+    ///
+    /// For example, a [`Weekly`] rule with an interval of 2 recurs every other
+    /// week.
+    ///
+    /// [`Weekly`]: RecurrenceRuleFrequency::Weekly
+    pub interval: u16,
+    /// Start of the recurrence interval.
+    pub start: Timestamp,
+}
+
+impl RecurrenceRule {
+    /// Iterator over the occurrences of the rule starting at or after a
+    /// timestamp.
+    ///
+    /// Every occurrence takes place at the time of day of [`start`], in UTC.
+    /// Pass [`start`] to iterate from the first occurrence. The iterator ends
+    /// once [`count`] occurrences have taken place, [`end`] has passed or no
+    /// occurrence has been found in 100 consecutive recurrence periods, such
+    /// as when the rule recurs on a day that doesn't exist, and is otherwise
+    /// unbounded.
+    ///
+    /// # Examples
+    ///
+    /// Get the next three occurrences of an event recurring every other
+    /// Friday:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     guild::scheduled_event::{RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleWeekday},
+    ///     util::Timestamp,
+    /// };
+    ///
+    /// let rule = RecurrenceRule {
+    ///     by_month: None,
+    ///     by_month_day: None,
+    ///     by_n_weekday: None,
+    ///     by_weekday: Some(Vec::from([RecurrenceRuleWeekday::Friday])),
+    ///     by_year_day: None,
+    ///     count: None,
+    ///     end: None,
+    ///     frequency: RecurrenceRuleFrequency::Weekly,
+    ///     interval: 2,
+    ///     start: Timestamp::parse("2024-03-01T18:00:00+00:00")?,
+    /// };
+    ///
+    /// let now = Timestamp::parse("2024-03-10T00:00:00+00:00")?;
+    /// let occurrences = rule
+    ///     .occurrences(now)
+    ///     .take(3)
+    ///     .map(|timestamp| timestamp.iso_8601().to_string())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     [
+    ///         "2024-03-15T18:00:00.000000+00:00",
+    ///         "2024-03-29T18:00:00.000000+00:00",
+    ///         "2024-04-12T18:00:00.000000+00:00",
+    ///     ],
+    ///     occurrences.as_slice(),
+    /// );
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`count`]: Self::count
+    /// [`end`]: Self::end
+    /// [`start`]: Self::start
+    pub const fn occurrences(&self, from: Timestamp) -> RecurrenceRuleOccurrences<'_> {
+        let start = self.start.as_micros();
+        let start_day = start.div_euclid(MICROSECONDS_PER_DAY);
+
+        RecurrenceRuleOccurrences {
+            day: start_day,
+            emitted: 0,
+            from: from.as_micros(),
+            misses: 0,
+            period: None,
+            period_end: start_day - 1,
+            rule: self,
+            start_day,
+            time_of_day: start.rem_euclid(MICROSECONDS_PER_DAY),
+        }
+    }
+
+    /// Validate the rule against the combinations of fields supported by
+    /// Discord.
+    ///
+    /// Fields set by Discord, such as [`count`] and [`end`], are not
+    /// validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Frequency`] if the frequency is unknown.
+    ///
+    /// Returns an error of type [`Field`] if a field is set which is not
+    /// supported by the frequency.
+    ///
+    /// Returns an error of type [`Interval`] if the interval is not supported
+    /// by the frequency.
+    ///
+    /// Returns an error of type [`ByWeekday`], [`ByNWeekday`], [`ByMonth`] or
+    /// [`ByMonthDay`] if the corresponding field has an unsupported value.
+    ///
+    /// [`ByMonth`]: RecurrenceRuleErrorType::ByMonth
+    /// [`ByMonthDay`]: RecurrenceRuleErrorType::ByMonthDay
+    /// [`ByNWeekday`]: RecurrenceRuleErrorType::ByNWeekday
+    /// [`ByWeekday`]: RecurrenceRuleErrorType::ByWeekday
+    /// [`count`]: Self::count
+    /// [`end`]: Self::end
+    /// [`Field`]: RecurrenceRuleErrorType::Field
+    /// [`Frequency`]: RecurrenceRuleErrorType::Frequency
+    /// [`Interval`]: RecurrenceRuleErrorType::Interval
+    pub fn validate(&self) -> Result<(), RecurrenceRuleError> {
+        let (weekdays, n_weekdays, months, max_interval) = match self.frequency {
+            RecurrenceRuleFrequency::Daily | RecurrenceRuleFrequency::Weekly => {
+                let max_interval = if self.frequency == RecurrenceRuleFrequency::Weekly {
+                    2
+                } else {
+                    1
+                };
+
+                (true, false, false, max_interval)
+            }
+            RecurrenceRuleFrequency::Monthly => (false, true, false, 1),
+            RecurrenceRuleFrequency::Yearly => (false, false, true, 1),
+            RecurrenceRuleFrequency::Unknown(_) => {
+                return Err(RecurrenceRuleError {
+                    kind: RecurrenceRuleErrorType::Frequency,
+                })
+            }
+        };
+
+        let unsupported = [
+            ("by_weekday", !weekdays && self.by_weekday.is_some()),
+            ("by_n_weekday", !n_weekdays && self.by_n_weekday.is_some()),
+            ("by_month", !months && self.by_month.is_some()),
+            ("by_month_day", !months && self.by_month_day.is_some()),
+        ];
+
+        if let Some((field, _)) = unsupported
+            .into_iter()
+            .find(|(_, unsupported)| *unsupported)
+        {
+            return Err(RecurrenceRuleError {
+                kind: RecurrenceRuleErrorType::Field { field },
+            });
+        }
+
+        if self.interval == 0 || self.interval > max_interval {
+            return Err(RecurrenceRuleError {
+                kind: RecurrenceRuleErrorType::Interval {
+                    interval: self.interval,
+                },
+            });
+        }
+
+        match self.frequency {
+            RecurrenceRuleFrequency::Daily => self.validate_daily(),
+            RecurrenceRuleFrequency::Weekly => {
+                if !matches!(self.by_weekday.as_deref(), Some([day]) if day.to_time().is_some()) {
+                    return Err(RecurrenceRuleError {
+                        kind: RecurrenceRuleErrorType::ByWeekday,
+                    });
+                }
+
+                Ok(())
+            }
+            RecurrenceRuleFrequency::Monthly => {
+                if !matches!(
+                    self.by_n_weekday.as_deref(),
+                    Some([n_weekday]) if (1..=5).contains(&n_weekday.n)
+                        && n_weekday.day.to_time().is_some()
+                ) {
+                    return Err(RecurrenceRuleError {
+                        kind: RecurrenceRuleErrorType::ByNWeekday,
+                    });
+                }
+
+                Ok(())
+            }
+            _ => self.validate_yearly(),
+        }
+    }
+
+    /// Validate the weekdays of a [`RecurrenceRuleFrequency::Daily`] rule.
+    fn validate_daily(&self) -> Result<(), RecurrenceRuleError> {
+        let Some(weekdays) = &self.by_weekday else {
+            return Ok(());
+        };
+
+        let mut mask = 0;
+
+        for weekday in weekdays {
+            if weekday.to_time().is_none() {
+                mask = 0;
+
+                break;
+            }
+
+            mask |= 1 << u8::from(*weekday);
+        }
+
+        if !DAILY_WEEKDAY_SETS.contains(&mask) {
+            return Err(RecurrenceRuleError {
+                kind: RecurrenceRuleErrorType::ByWeekday,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validate the date of a [`RecurrenceRuleFrequency::Yearly`] rule.
+    fn validate_yearly(&self) -> Result<(), RecurrenceRuleError> {
+        let Some(month) = (match self.by_month.as_deref() {
+            Some([month]) => month.to_time(),
+            _ => None,
+        }) else {
+            return Err(RecurrenceRuleError {
+                kind: RecurrenceRuleErrorType::ByMonth,
+            });
+        };
+
+        // Use a leap year so that rules recurring on February 29th are valid.
+        if !matches!(
+            self.by_month_day.as_deref(),
+            Some([day]) if Date::from_calendar_date(2024, month, *day).is_ok()
+        ) {
+            return Err(RecurrenceRuleError {
+                kind: RecurrenceRuleErrorType::ByMonthDay,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Number of days since the Unix epoch of the first day of a period,
+    /// counted in periods since the period of the rule's start.
+    fn period_start(&self, start: Date, period: i64) -> Option<i64> {
+        let julian_day = match self.frequency {
+            RecurrenceRuleFrequency::Daily => i64::from(start.to_julian_day()) + period,
+            RecurrenceRuleFrequency::Weekly => week_start(start) + period * 7,
+            RecurrenceRuleFrequency::Monthly => {
+                let month =
+                    i64::from(start.year()) * 12 + i64::from(u8::from(start.month()) - 1) + period;
+                let year = i32::try_from(month.div_euclid(12)).ok()?;
+                let month = Month::try_from(u8::try_from(month.rem_euclid(12) + 1).ok()?).ok()?;
+
+                i64::from(
+                    Date::from_calendar_date(year, month, 1)
+                        .ok()?
+                        .to_julian_day(),
+                )
+            }
+            RecurrenceRuleFrequency::Yearly => {
+                let year = i32::try_from(i64::from(start.year()) + period).ok()?;
+
+                i64::from(
+                    Date::from_calendar_date(year, Month::January, 1)
+                        .ok()?
+                        .to_julian_day(),
+                )
+            }
+            RecurrenceRuleFrequency::Unknown(_) => return None,
+        };
+
+        Some(julian_day - UNIX_EPOCH_JULIAN_DAY)
+    }
+
+    /// Whether the rule recurs on a day.
+    fn recurs_on(&self, date: Date, start: Date) -> bool {
+        let Some(elapsed) = elapsed_periods(self.frequency, date, start) else {
+            return date == start;
+        };
+
+        if elapsed % i64::from(self.interval.max(1)) != 0 {
+            return false;
+        }
+
+        let weekday = RecurrenceRuleWeekday::from_time(date.weekday());
+
+        let weekday_matches = match (&self.by_weekday, self.frequency) {
+            (Some(weekdays), _) => weekdays.contains(&weekday),
+            (None, RecurrenceRuleFrequency::Weekly) => date.weekday() == start.weekday(),
+            (None, _) => true,
+        };
+
+        let month_matches = match (&self.by_month, self.frequency) {
+            (Some(months), _) => months
+                .iter()
+                .any(|month| month.to_time() == Some(date.month())),
+            (None, RecurrenceRuleFrequency::Yearly) => date.month() == start.month(),
+            (None, _) => true,
+        };
+
+        let day_matches = match (&self.by_n_weekday, &self.by_month_day, self.frequency) {
+            (Some(n_weekdays), _, _) => n_weekdays.iter().any(|n_weekday| {
+                n_weekday.day == weekday && n_weekday.n == (date.day() - 1) / 7 + 1
+            }),
+            (None, Some(days), _) => days.contains(&date.day()),
+            (None, None, RecurrenceRuleFrequency::Monthly | RecurrenceRuleFrequency::Yearly) => {
+                date.day() == start.day()
+            }
+            (None, None, _) => true,
+        };
+
+        weekday_matches && month_matches && day_matches
+    }
+}
+
+/// Iterator over the occurrences of a [`RecurrenceRule`].
+///
+/// Created via [`RecurrenceRule::occurrences`].
+#[derive(Clone, Debug)]
+pub struct RecurrenceRuleOccurrences<'a> {
+    /// Number of days since the Unix epoch of the next day to check.
+    day: i64,
+    /// Number of occurrences which have taken place, including those skipped
+    /// for being before [`from`].
+    ///
+    /// [`from`]: Self::from
+    emitted: u32,
+    /// Unix timestamp in microseconds before which occurrences are skipped.
+    from: i64,
+    /// Number of consecutive periods checked without an occurrence.
+    misses: u32,
+    /// Number of periods since the rule's start of the period being checked,
+    /// if any.
+    period: Option<i64>,
+    /// Number of days since the Unix epoch of the last day of the period
+    /// being checked.
+    period_end: i64,
+    /// Rule to expand.
+    rule: &'a RecurrenceRule,
+    /// Number of days since the Unix epoch of the rule's start.
+    start_day: i64,
+    /// Number of microseconds since midnight of each occurrence.
+    time_of_day: i64,
+}
+
+impl Iterator for RecurrenceRuleOccurrences<'_> {
+    type Item = Timestamp;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = date(self.start_day)?;
+
+        loop {
+            if matches!(self.rule.count, Some(count) if self.emitted >= count) {
+                return None;
+            }
+
+            if self.day > self.period_end {
+                self.next_period(start)?;
+
+                continue;
+            }
+
+            let day = self.day;
+            let date = date(day)?;
+            self.day += 1;
+
+            if !self.rule.recurs_on(date, start) {
+                continue;
+            }
+
+            let micros = day * MICROSECONDS_PER_DAY + self.time_of_day;
+
+            if matches!(self.rule.end, Some(end) if micros > end.as_micros()) {
+                return None;
+            }
+
+            self.emitted += 1;
+            self.misses = 0;
+
+            if micros >= self.from {
+                return Timestamp::from_micros(micros).ok();
+            }
+        }
+    }
+}
+
+impl RecurrenceRuleOccurrences<'_> {
+    /// Move on to the next period the rule may recur in.
+    ///
+    /// The first period is that of the rule's start, or that of [`from`] if
+    /// occurrences before it don't need to be counted. Returns [`None`] if
+    /// there are no more periods to check.
+    ///
+    /// [`from`]: Self::from
+    fn next_period(&mut self, start: Date) -> Option<()> {
+        if matches!(self.rule.frequency, RecurrenceRuleFrequency::Unknown(_)) {
+            if self.period.is_some() {
+                return None;
+            }
+
+            self.period = Some(0);
+            self.period_end = self.start_day;
+
+            return Some(());
+        }
+
+        if self.misses >= PERIOD_ATTEMPTS {
+            return None;
+        }
+
+        let interval = i64::from(self.rule.interval.max(1));
+        let period = match self.period {
+            Some(period) => period + interval,
+            None if self.rule.count.is_none() => {
+                let from = date(self.from.div_euclid(MICROSECONDS_PER_DAY))?;
+                let elapsed = elapsed_periods(self.rule.frequency, from, start)?.max(0);
+
+                elapsed - elapsed % interval
+            }
+            None => 0,
+        };
+
+        let first_day = self.rule.period_start(start, period)?;
+
+        self.day = first_day.max(self.start_day);
+        self.misses += 1;
+        self.period = Some(period);
+        self.period_end = self.rule.period_start(start, period + 1)? - 1;
+
+        Some(())
+    }
+}
+
+/// Number of periods of a frequency between the periods of two dates.
+///
+/// Returns [`None`] if the frequency is unknown.
+fn elapsed_periods(frequency: RecurrenceRuleFrequency, date: Date, start: Date) -> Option<i64> {
+    Some(match frequency {
+        RecurrenceRuleFrequency::Daily => {
+            i64::from(date.to_julian_day()) - i64::from(start.to_julian_day())
+        }
+        RecurrenceRuleFrequency::Weekly => (week_start(date) - week_start(start)).div_euclid(7),
+        RecurrenceRuleFrequency::Monthly => {
+            i64::from(date.year() - start.year()) * 12 + i64::from(u8::from(date.month()))
+                - i64::from(u8::from(start.month()))
+        }
+        RecurrenceRuleFrequency::Yearly => i64::from(date.year() - start.year()),
+        RecurrenceRuleFrequency::Unknown(_) => return None,
+    })
+}
+
+/// Date of a number of days since the Unix epoch.
+fn date(day: i64) -> Option<Date> {
+    let julian_day = i32::try_from(day + UNIX_EPOCH_JULIAN_DAY).ok()?;
+
+    Date::from_julian_day(julian_day).ok()
+}
+
+/// Julian day of the Monday of a date's week.
+fn week_start(date: Date) -> i64 {
+    i64::from(date.to_julian_day()) - i64::from(date.weekday().number_days_from_monday())
+}
+
+/// How often a scheduled event recurs.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleFrequency {
+    /// Event recurs every year.
+    Yearly,
+    /// Event recurs every month.
+    Monthly,
+    /// Event recurs every week.
+    Weekly,
+    /// Event recurs every day.
+    Daily,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RecurrenceRuleFrequency {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => RecurrenceRuleFrequency::Yearly,
+            1 => RecurrenceRuleFrequency::Monthly,
+            2 => RecurrenceRuleFrequency::Weekly,
+            3 => RecurrenceRuleFrequency::Daily,
+            unknown => RecurrenceRuleFrequency::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleFrequency> for u8 {
+    fn from(value: RecurrenceRuleFrequency) -> Self {
+        match value {
+            RecurrenceRuleFrequency::Yearly => 0,
+            RecurrenceRuleFrequency::Monthly => 1,
+            RecurrenceRuleFrequency::Weekly => 2,
+            RecurrenceRuleFrequency::Daily => 3,
+            RecurrenceRuleFrequency::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Month a scheduled event recurs on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleMonth {
+    /// January.
+    January,
+    /// February.
+    February,
+    /// March.
+    March,
+    /// April.
+    April,
+    /// May.
+    May,
+    /// June.
+    June,
+    /// July.
+    July,
+    /// August.
+    August,
+    /// September.
+    September,
+    /// October.
+    October,
+    /// November.
+    November,
+    /// December.
+    December,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl RecurrenceRuleMonth {
+    /// Month of the [`time`] crate, or [`None`] if the month is unknown.
+    fn to_time(self) -> Option<Month> {
+        Month::try_from(u8::from(self)).ok()
+    }
+}
+
+impl From<u8> for RecurrenceRuleMonth {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => RecurrenceRuleMonth::January,
+            2 => RecurrenceRuleMonth::February,
+            3 => RecurrenceRuleMonth::March,
+            4 => RecurrenceRuleMonth::April,
+            5 => RecurrenceRuleMonth::May,
+            6 => RecurrenceRuleMonth::June,
+            7 => RecurrenceRuleMonth::July,
+            8 => RecurrenceRuleMonth::August,
+            9 => RecurrenceRuleMonth::September,
+            10 => RecurrenceRuleMonth::October,
+            11 => RecurrenceRuleMonth::November,
+            12 => RecurrenceRuleMonth::December,
+            unknown => RecurrenceRuleMonth::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleMonth> for u8 {
+    fn from(value: RecurrenceRuleMonth) -> Self {
+        match value {
+            RecurrenceRuleMonth::January => 1,
+            RecurrenceRuleMonth::February => 2,
+            RecurrenceRuleMonth::March => 3,
+            RecurrenceRuleMonth::April => 4,
+            RecurrenceRuleMonth::May => 5,
+            RecurrenceRuleMonth::June => 6,
+            RecurrenceRuleMonth::July => 7,
+            RecurrenceRuleMonth::August => 8,
+            RecurrenceRuleMonth::September => 9,
+            RecurrenceRuleMonth::October => 10,
+            RecurrenceRuleMonth::November => 11,
+            RecurrenceRuleMonth::December => 12,
+            RecurrenceRuleMonth::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Weekday within a specific week of the month a scheduled event recurs on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RecurrenceRuleNWeekday {
+    /// Weekday to recur on.
+    pub day: RecurrenceRuleWeekday,
+    /// Week of the month to recur on, between 1 and 5.
+    pub n: u8,
+}
+
+/// Weekday a scheduled event recurs on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleWeekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl RecurrenceRuleWeekday {
+    /// Weekday of a weekday of the [`time`] crate.
+    const fn from_time(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Monday => RecurrenceRuleWeekday::Monday,
+            Weekday::Tuesday => RecurrenceRuleWeekday::Tuesday,
+            Weekday::Wednesday => RecurrenceRuleWeekday::Wednesday,
+            Weekday::Thursday => RecurrenceRuleWeekday::Thursday,
+            Weekday::Friday => RecurrenceRuleWeekday::Friday,
+            Weekday::Saturday => RecurrenceRuleWeekday::Saturday,
+            Weekday::Sunday => RecurrenceRuleWeekday::Sunday,
+        }
+    }
+
+    /// Weekday of the [`time`] crate, or [`None`] if the weekday is unknown.
+    const fn to_time(self) -> Option<Weekday> {
+        Some(match self {
+            RecurrenceRuleWeekday::Monday => Weekday::Monday,
+            RecurrenceRuleWeekday::Tuesday => Weekday::Tuesday,
+            RecurrenceRuleWeekday::Wednesday => Weekday::Wednesday,
+            RecurrenceRuleWeekday::Thursday => Weekday::Thursday,
+            RecurrenceRuleWeekday::Friday => Weekday::Friday,
+            RecurrenceRuleWeekday::Saturday => Weekday::Saturday,
+            RecurrenceRuleWeekday::Sunday => Weekday::Sunday,
+            RecurrenceRuleWeekday::Unknown(_) => return None,
+        })
+    }
+}
+
+impl From<u8> for RecurrenceRuleWeekday {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => RecurrenceRuleWeekday::Monday,
+            1 => RecurrenceRuleWeekday::Tuesday,
+            2 => RecurrenceRuleWeekday::Wednesday,
+            3 => RecurrenceRuleWeekday::Thursday,
+            4 => RecurrenceRuleWeekday::Friday,
+            5 => RecurrenceRuleWeekday::Saturday,
+            6 => RecurrenceRuleWeekday::Sunday,
+            unknown => RecurrenceRuleWeekday::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleWeekday> for u8 {
+    fn from(value: RecurrenceRuleWeekday) -> Self {
+        match value {
+            RecurrenceRuleWeekday::Monday => 0,
+            RecurrenceRuleWeekday::Tuesday => 1,
+            RecurrenceRuleWeekday::Wednesday => 2,
+            RecurrenceRuleWeekday::Thursday => 3,
+            RecurrenceRuleWeekday::Friday => 4,
+            RecurrenceRuleWeekday::Saturday => 5,
+            RecurrenceRuleWeekday::Sunday => 6,
+            RecurrenceRuleWeekday::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RecurrenceRule, RecurrenceRuleErrorType, RecurrenceRuleFrequency, RecurrenceRuleMonth,
+        RecurrenceRuleNWeekday, RecurrenceRuleWeekday,
+    };
+    use crate::util::Timestamp;
+    use serde_test::Token;
+
+    fn rule(frequency: RecurrenceRuleFrequency, start: &str) -> RecurrenceRule {
+        RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency,
+            interval: 1,
+            start: Timestamp::parse(start).unwrap(),
+        }
+    }
+
+    fn occurrences(rule: &RecurrenceRule, from: &str, count: usize) -> Vec<String> {
+        rule.occurrences(Timestamp::parse(from).unwrap())
+            .take(count)
+            .map(|timestamp| timestamp.iso_8601().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn recurrence_rule() {
+        let mut value = rule(
+            RecurrenceRuleFrequency::Monthly,
+            "2024-01-09T17:00:00+00:00",
+        );
+        value.by_n_weekday = Some(Vec::from([RecurrenceRuleNWeekday {
+            day: RecurrenceRuleWeekday::Tuesday,
+            n: 2,
+        }]));
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RecurrenceRule",
+                    len: 4,
+                },
+                Token::Str("by_n_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "RecurrenceRuleNWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(1),
+                Token::Str("n"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("frequency"),
+                Token::U8(1),
+                Token::Str("interval"),
+                Token::U16(1),
+                Token::Str("start"),
+                Token::Str("2024-01-09T17:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert!(value.validate().is_ok());
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(0, u8::from(RecurrenceRuleFrequency::Yearly));
+        assert_eq!(1, u8::from(RecurrenceRuleFrequency::Monthly));
+        assert_eq!(2, u8::from(RecurrenceRuleFrequency::Weekly));
+        assert_eq!(3, u8::from(RecurrenceRuleFrequency::Daily));
+        assert_eq!(1, u8::from(RecurrenceRuleMonth::January));
+        assert_eq!(12, u8::from(RecurrenceRuleMonth::December));
+        assert_eq!(0, u8::from(RecurrenceRuleWeekday::Monday));
+        assert_eq!(6, u8::from(RecurrenceRuleWeekday::Sunday));
+        assert_eq!(250, u8::from(RecurrenceRuleWeekday::Unknown(250)));
+    }
+
+    #[test]
+    fn validate() {
+        let mut daily = rule(RecurrenceRuleFrequency::Daily, "2024-01-01T00:00:00+00:00");
+        assert!(daily.validate().is_ok());
+
+        daily.by_weekday = Some(Vec::from([
+            RecurrenceRuleWeekday::Sunday,
+            RecurrenceRuleWeekday::Saturday,
+        ]));
+        assert!(daily.validate().is_ok());
+
+        daily.by_weekday = Some(Vec::from([RecurrenceRuleWeekday::Monday]));
+        assert!(matches!(
+            daily.validate().unwrap_err().kind(),
+            RecurrenceRuleErrorType::ByWeekday
+        ));
+
+        daily.by_weekday = None;
+        daily.interval = 2;
+        assert!(matches!(
+            daily.validate().unwrap_err().kind(),
+            RecurrenceRuleErrorType::Interval { interval: 2 }
+        ));
+
+        let mut weekly = rule(RecurrenceRuleFrequency::Weekly, "2024-01-01T00:00:00+00:00");
+        weekly.interval = 2;
+        assert!(matches!(
+            weekly.validate().unwrap_err().kind(),
+            RecurrenceRuleErrorType::ByWeekday
+        ));
+
+        weekly.by_weekday = Some(Vec::from([RecurrenceRuleWeekday::Monday]));
+        assert!(weekly.validate().is_ok());
+
+        weekly.by_month_day = Some(Vec::from([1]));
+        assert!(matches!(
+            weekly.validate().unwrap_err().kind(),
+            RecurrenceRuleErrorType::Field {
+                field: "by_month_day"
+            }
+        ));
+
+        let mut monthly = rule(
+            RecurrenceRuleFrequency::Monthly,
+            "2024-01-01T00:00:00+00:00",
+        );
+        monthly.by_n_weekday = Some(Vec::from([RecurrenceRuleNWeekday {
+            day: RecurrenceRuleWeekday::Monday,
+            n: 6,
+        }]));
+        assert!(matches!(
+            monthly.validate().unwrap_err().kind(),
+            RecurrenceRuleErrorType::ByNWeekday
+        ));
+
+        let mut yearly = rule(RecurrenceRuleFrequency::Yearly, "2024-01-01T00:00:00+00:00");
+        yearly.by_month = Some(Vec::from([RecurrenceRuleMonth::February]));
+        yearly.by_month_day = Some(Vec::from([29]));
+        assert!(yearly.validate().is_ok());
+
+        yearly.by_month_day = Some(Vec::from([30]));
+        assert!(matches!(
+            yearly.validate().unwrap_err().kind(),
+            RecurrenceRuleErrorType::ByMonthDay
+        ));
+
+        yearly.by_month = None;
+        assert!(matches!(
+            yearly.validate().unwrap_err().kind(),
+            RecurrenceRuleErrorType::ByMonth
+        ));
+    }
+
+    #[test]
+    fn occurrences_daily() {
+        let mut value = rule(RecurrenceRuleFrequency::Daily, "2024-01-05T09:30:00+00:00");
+        value.by_weekday = Some(Vec::from([
+            RecurrenceRuleWeekday::Monday,
+            RecurrenceRuleWeekday::Tuesday,
+            RecurrenceRuleWeekday::Wednesday,
+            RecurrenceRuleWeekday::Thursday,
+            RecurrenceRuleWeekday::Friday,
+        ]));
+
+        assert_eq!(
+            [
+                "2024-01-05T09:30:00.000000+00:00",
+                "2024-01-08T09:30:00.000000+00:00",
+                "2024-01-09T09:30:00.000000+00:00",
+            ],
+            occurrences(&value, "2024-01-01T00:00:00+00:00", 3).as_slice(),
+        );
+    }
+
+    #[test]
+    fn occurrences_monthly() {
+        let mut value = rule(
+            RecurrenceRuleFrequency::Monthly,
+            "2024-01-31T20:00:00+00:00",
+        );
+        value.by_n_weekday = Some(Vec::from([RecurrenceRuleNWeekday {
+            day: RecurrenceRuleWeekday::Wednesday,
+            n: 5,
+        }]));
+
+        assert_eq!(
+            [
+                "2024-01-31T20:00:00.000000+00:00",
+                "2024-05-29T20:00:00.000000+00:00",
+                "2024-07-31T20:00:00.000000+00:00",
+            ],
+            occurrences(&value, "2024-01-31T20:00:00+00:00", 3).as_slice(),
+        );
+    }
+
+    #[test]
+    fn occurrences_yearly() {
+        let mut value = rule(RecurrenceRuleFrequency::Yearly, "2024-02-29T12:00:00+00:00");
+        value.by_month = Some(Vec::from([RecurrenceRuleMonth::February]));
+        value.by_month_day = Some(Vec::from([29]));
+
+        assert_eq!(
+            [
+                "2028-02-29T12:00:00.000000+00:00",
+                "2032-02-29T12:00:00.000000+00:00",
+            ],
+            occurrences(&value, "2024-03-01T00:00:00+00:00", 2).as_slice(),
+        );
+    }
+
+    #[test]
+    fn occurrences_bounded() {
+        let mut value = rule(RecurrenceRuleFrequency::Weekly, "2024-01-01T00:00:00+00:00");
+        value.count = Some(3);
+
+        assert_eq!(
+            [
+                "2024-01-08T00:00:00.000000+00:00",
+                "2024-01-15T00:00:00.000000+00:00",
+            ],
+            occurrences(&value, "2024-01-02T00:00:00+00:00", 10).as_slice(),
+        );
+
+        value.count = None;
+        value.end = Some(Timestamp::parse("2024-01-08T00:00:00+00:00").unwrap());
+
+        assert_eq!(
+            [
+                "2024-01-01T00:00:00.000000+00:00",
+                "2024-01-08T00:00:00.000000+00:00",
+            ],
+            occurrences(&value, "2024-01-01T00:00:00+00:00", 10).as_slice(),
+        );
+
+        value.end = None;
+        value.frequency = RecurrenceRuleFrequency::Unknown(10);
+
+        assert_eq!(
+            ["2024-01-01T00:00:00.000000+00:00"],
+            occurrences(&value, "2024-01-01T00:00:00+00:00", 10).as_slice(),
+        );
+    }
+
+    #[test]
+    fn occurrences_from() {
+        let mut value = rule(RecurrenceRuleFrequency::Weekly, "2024-01-05T18:00:00+00:00");
+        value.by_weekday = Some(Vec::from([RecurrenceRuleWeekday::Friday]));
+        value.interval = 2;
+
+        assert_eq!(
+            [
+                "3024-01-09T18:00:00.000000+00:00",
+                "3024-01-23T18:00:00.000000+00:00",
+            ],
+            occurrences(&value, "3024-01-06T00:00:00+00:00", 2).as_slice(),
+        );
+
+        let value = rule(RecurrenceRuleFrequency::Daily, "2024-01-05T18:00:00+00:00");
+
+        assert_eq!(
+            ["2024-01-05T18:00:00.000000+00:00"],
+            occurrences(&value, "2000-01-01T00:00:00+00:00", 1).as_slice(),
+        );
+    }
+
+    #[test]
+    fn occurrences_never() {
+        let mut value = rule(RecurrenceRuleFrequency::Yearly, "2024-01-01T00:00:00+00:00");
+        value.by_month = Some(Vec::from([RecurrenceRuleMonth::February]));
+        value.by_month_day = Some(Vec::from([30]));
+
+        assert!(occurrences(&value, "2024-01-01T00:00:00+00:00", 1).is_empty());
+
+        let mut value = rule(RecurrenceRuleFrequency::Daily, "2024-01-01T00:00:00+00:00");
+        value.by_weekday = Some(Vec::new());
+        value.count = Some(1);
+
+        assert!(occurrences(&value, "2024-01-01T00:00:00+00:00", 1).is_empty());
+    }
+}
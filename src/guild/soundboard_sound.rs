@@ -0,0 +1,207 @@
+use crate::{
+    id::{
+        marker::{EmojiMarker, GuildMarker, SoundboardSoundMarker},
+        Id,
+    },
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+/// Sound that can be played in voice channels through the soundboard.
+///
+/// See [Discord Docs/Soundboard Sound Object].
+///
+/// [Discord Docs/Soundboard Sound Object]: https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SoundboardSound {
+    /// Whether the sound can be used.
+    ///
+    /// May be `false` when the guild loses boosts.
+    pub available: bool,
+    /// ID of the sound's custom emoji.
+    pub emoji_id: Option<Id<EmojiMarker>>,
+    /// Name of the sound's unicode emoji.
+    pub emoji_name: Option<String>,
+    /// ID of the guild the sound is in.
+    ///
+    /// Not present for default sounds available in every guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// Name of the sound.
+    pub name: String,
+    /// ID of the sound.
+    pub sound_id: Id<SoundboardSoundMarker>,
+    /// User that created the sound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Volume of the sound.
+    pub volume: SoundboardSoundVolume,
+}
+
+impl SoundboardSound {
+    /// Whether the sound is a default sound, available in every guild.
+    pub const fn is_default(&self) -> bool {
+        self.guild_id.is_none()
+    }
+}
+
+/// Volume of a soundboard sound, from 0 to 1.
+///
+/// Volumes are compared and hashed by their bit representation, so models
+/// containing them may implement [`Eq`] and [`Hash`].
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct SoundboardSoundVolume(f64);
+
+impl SoundboardSoundVolume {
+    /// Create a new volume.
+    pub const fn new(volume: f64) -> Self {
+        Self(volume)
+    }
+
+    /// Retrieve the inner volume.
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<f64> for SoundboardSoundVolume {
+    fn from(volume: f64) -> Self {
+        Self(volume)
+    }
+}
+
+impl From<SoundboardSoundVolume> for f64 {
+    fn from(volume: SoundboardSoundVolume) -> Self {
+        volume.0
+    }
+}
+
+impl Eq for SoundboardSoundVolume {}
+
+impl Hash for SoundboardSoundVolume {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl PartialEq for SoundboardSoundVolume {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SoundboardSound, SoundboardSoundVolume};
+    use crate::id::Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        SoundboardSound: available,
+        emoji_id,
+        emoji_name,
+        guild_id,
+        name,
+        sound_id,
+        user,
+        volume
+    );
+    assert_impl_all!(
+        SoundboardSound: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+    assert_impl_all!(
+        SoundboardSoundVolume: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        From<f64>,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn soundboard_sound() {
+        let value = SoundboardSound {
+            available: true,
+            emoji_id: None,
+            emoji_name: Some("🦆".to_owned()),
+            guild_id: Some(Id::new(1)),
+            name: "quack".to_owned(),
+            sound_id: Id::new(2),
+            user: None,
+            volume: SoundboardSoundVolume::new(0.5),
+        };
+
+        assert!(!value.is_default());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "SoundboardSound",
+                    len: 7,
+                },
+                Token::Str("available"),
+                Token::Bool(true),
+                Token::Str("emoji_id"),
+                Token::None,
+                Token::Str("emoji_name"),
+                Token::Some,
+                Token::Str("🦆"),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("quack"),
+                Token::Str("sound_id"),
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("2"),
+                Token::Str("volume"),
+                Token::F64(0.5),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn volume() {
+        let volume = SoundboardSoundVolume::from(0.5);
+
+        assert_eq!(0.5_f64.to_bits(), f64::from(volume).to_bits());
+        assert_eq!(SoundboardSoundVolume::new(0.5), volume);
+        assert_ne!(
+            SoundboardSoundVolume::new(0.0),
+            SoundboardSoundVolume::new(-0.0)
+        );
+        assert_eq!(
+            SoundboardSoundVolume::new(f64::NAN),
+            SoundboardSoundVolume::new(f64::NAN)
+        );
+
+        serde_test::assert_tokens(&volume, &[Token::F64(0.5)]);
+    }
+}
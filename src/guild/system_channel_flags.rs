@@ -36,6 +36,17 @@ impl Serialize for SystemChannelFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SystemChannelFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "SystemChannelFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SystemChannelFlags;
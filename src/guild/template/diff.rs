@@ -0,0 +1,473 @@
+use super::{TemplateGuild, TemplateRole};
+use crate::{
+    channel::{
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        Channel,
+    },
+    guild::{Guild, Role},
+    id::{
+        marker::{ChannelMarker, GenericMarker, RoleMarker},
+        Id,
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+/// Difference between a [`Guild`] and the [`TemplateGuild`] it is compared
+/// against.
+///
+/// Created via [`TemplateGuild::diff`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TemplateDrift {
+    /// Channel differs from its template channel.
+    ChannelChanged {
+        /// ID of the guild's channel.
+        channel_id: Id<ChannelMarker>,
+        /// Names of the fields which differ.
+        fields: Vec<&'static str>,
+        /// ID of the template channel.
+        template_channel_id: Id<ChannelMarker>,
+    },
+    /// Channel of the guild is not part of the template.
+    ChannelExtra {
+        /// ID of the guild's channel.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Channel of the template is missing from the guild.
+    ChannelMissing {
+        /// ID of the template channel.
+        template_channel_id: Id<ChannelMarker>,
+    },
+    /// Guild setting differs from the template.
+    GuildChanged {
+        /// Names of the fields which differ.
+        fields: Vec<&'static str>,
+    },
+    /// Role differs from its template role.
+    RoleChanged {
+        /// Names of the fields which differ.
+        fields: Vec<&'static str>,
+        /// ID of the guild's role.
+        role_id: Id<RoleMarker>,
+        /// ID of the template role.
+        template_role_id: Id<RoleMarker>,
+    },
+    /// Role of the guild is not part of the template.
+    RoleExtra {
+        /// ID of the guild's role.
+        role_id: Id<RoleMarker>,
+    },
+    /// Role of the template is missing from the guild.
+    RoleMissing {
+        /// ID of the template role.
+        template_role_id: Id<RoleMarker>,
+    },
+}
+
+impl TemplateGuild {
+    /// Compare a guild against the template, reporting how the guild has
+    /// drifted from it.
+    ///
+    /// The first role of the template is matched with the guild's `@everyone`
+    /// role. Other roles are matched by name and channels by name and type;
+    /// managed roles of the guild, such as bot roles, are ignored. Permission
+    /// overwrites, parents and the AFK and system channels are compared
+    /// through the matched roles and channels. The name and icon of the guild
+    /// are not compared, and neither are the positions of roles and channels.
+    ///
+    /// Returns an empty list if the guild matches the template.
+    pub fn diff(&self, guild: &Guild) -> Vec<TemplateDrift> {
+        let mut drift = Vec::new();
+
+        let roles = self.match_roles(guild, &mut drift);
+        let channels = match_channels(&self.channels, &guild.channels, &mut drift);
+
+        let overwrite_ids: HashMap<Id<GenericMarker>, Id<GenericMarker>> = roles
+            .iter()
+            .map(|(template_role, role)| (template_role.id.cast(), role.id.cast()))
+            .collect();
+        let channel_ids: HashMap<Id<ChannelMarker>, Id<ChannelMarker>> = channels
+            .iter()
+            .map(|(template_channel, channel)| (template_channel.id, channel.id))
+            .collect();
+
+        let mut fields = Vec::new();
+        let remap_channel =
+            |id: Option<Id<ChannelMarker>>| id.map(|id| channel_ids.get(&id).copied());
+
+        if remap_channel(self.afk_channel_id) != guild.afk_channel_id.map(Some) {
+            fields.push("afk_channel_id");
+        }
+
+        if self.afk_timeout != guild.afk_timeout {
+            fields.push("afk_timeout");
+        }
+
+        if self.default_message_notifications != guild.default_message_notifications {
+            fields.push("default_message_notifications");
+        }
+
+        if self.description != guild.description {
+            fields.push("description");
+        }
+
+        if self.explicit_content_filter != guild.explicit_content_filter {
+            fields.push("explicit_content_filter");
+        }
+
+        if self.preferred_locale != guild.preferred_locale {
+            fields.push("preferred_locale");
+        }
+
+        if self.system_channel_flags != guild.system_channel_flags {
+            fields.push("system_channel_flags");
+        }
+
+        if remap_channel(self.system_channel_id) != guild.system_channel_id.map(Some) {
+            fields.push("system_channel_id");
+        }
+
+        if self.verification_level != guild.verification_level {
+            fields.push("verification_level");
+        }
+
+        if !fields.is_empty() {
+            drift.push(TemplateDrift::GuildChanged { fields });
+        }
+
+        for (template_channel, channel) in channels {
+            let fields = channel_fields(template_channel, channel, &channel_ids, &overwrite_ids);
+
+            if !fields.is_empty() {
+                drift.push(TemplateDrift::ChannelChanged {
+                    channel_id: channel.id,
+                    fields,
+                    template_channel_id: template_channel.id,
+                });
+            }
+        }
+
+        drift
+    }
+
+    /// Match the template's roles with the guild's roles, recording unmatched
+    /// and changed roles.
+    fn match_roles<'a>(
+        &'a self,
+        guild: &'a Guild,
+        drift: &mut Vec<TemplateDrift>,
+    ) -> Vec<(&'a TemplateRole, &'a Role)> {
+        let everyone_id = guild.id.cast::<RoleMarker>();
+        let mut unmatched: Vec<&Role> = guild
+            .roles
+            .iter()
+            .filter(|role| role.id != everyone_id && !role.managed)
+            .collect();
+        let mut matches = Vec::with_capacity(self.roles.len());
+
+        for (index, template_role) in self.roles.iter().enumerate() {
+            let role = if index == 0 {
+                guild.roles.iter().find(|role| role.id == everyone_id)
+            } else {
+                unmatched
+                    .iter()
+                    .position(|role| role.name == template_role.name)
+                    .map(|position| unmatched.remove(position))
+            };
+
+            let Some(role) = role else {
+                drift.push(TemplateDrift::RoleMissing {
+                    template_role_id: template_role.id,
+                });
+
+                continue;
+            };
+
+            let mut fields = Vec::new();
+
+            if template_role.color != role.color {
+                fields.push("color");
+            }
+
+            if template_role.hoist != role.hoist {
+                fields.push("hoist");
+            }
+
+            if template_role.mentionable != role.mentionable {
+                fields.push("mentionable");
+            }
+
+            if template_role.permissions != role.permissions {
+                fields.push("permissions");
+            }
+
+            if !fields.is_empty() {
+                drift.push(TemplateDrift::RoleChanged {
+                    fields,
+                    role_id: role.id,
+                    template_role_id: template_role.id,
+                });
+            }
+
+            matches.push((template_role, role));
+        }
+
+        drift.extend(
+            unmatched
+                .into_iter()
+                .map(|role| TemplateDrift::RoleExtra { role_id: role.id }),
+        );
+
+        matches
+    }
+}
+
+/// Match template channels with guild channels by name and type, recording
+/// unmatched channels.
+fn match_channels<'a>(
+    template_channels: &'a [Channel],
+    channels: &'a [Channel],
+    drift: &mut Vec<TemplateDrift>,
+) -> Vec<(&'a Channel, &'a Channel)> {
+    let mut unmatched: Vec<&Channel> = channels.iter().collect();
+    let mut matches = Vec::with_capacity(template_channels.len());
+
+    for template_channel in template_channels {
+        let position = unmatched.iter().position(|channel| {
+            channel.kind == template_channel.kind && channel.name == template_channel.name
+        });
+
+        if let Some(position) = position {
+            matches.push((template_channel, unmatched.remove(position)));
+        } else {
+            drift.push(TemplateDrift::ChannelMissing {
+                template_channel_id: template_channel.id,
+            });
+        }
+    }
+
+    drift.extend(
+        unmatched
+            .into_iter()
+            .map(|channel| TemplateDrift::ChannelExtra {
+                channel_id: channel.id,
+            }),
+    );
+
+    matches
+}
+
+/// Names of the fields in which a channel differs from its template channel.
+fn channel_fields(
+    template_channel: &Channel,
+    channel: &Channel,
+    channel_ids: &HashMap<Id<ChannelMarker>, Id<ChannelMarker>>,
+    overwrite_ids: &HashMap<Id<GenericMarker>, Id<GenericMarker>>,
+) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+
+    if template_channel.bitrate != channel.bitrate {
+        fields.push("bitrate");
+    }
+
+    if template_channel.nsfw.unwrap_or_default() != channel.nsfw.unwrap_or_default() {
+        fields.push("nsfw");
+    }
+
+    let parent_id = template_channel
+        .parent_id
+        .map(|parent_id| channel_ids.get(&parent_id).copied());
+
+    if parent_id != channel.parent_id.map(Some) {
+        fields.push("parent_id");
+    }
+
+    // Role overwrites of the template are only comparable once remapped to
+    // the matched roles; unmatched roles never compare equal.
+    let overwrites = |channel: &Channel, remap: bool| -> HashSet<_> {
+        channel
+            .permission_overwrites
+            .iter()
+            .flatten()
+            .map(|overwrite: &PermissionOverwrite| {
+                let id = if remap && overwrite.kind == PermissionOverwriteType::Role {
+                    overwrite_ids.get(&overwrite.id).copied()
+                } else {
+                    Some(overwrite.id)
+                };
+
+                (id, overwrite.kind, overwrite.allow, overwrite.deny)
+            })
+            .collect()
+    };
+
+    if overwrites(template_channel, true) != overwrites(channel, false) {
+        fields.push("permission_overwrites");
+    }
+
+    if template_channel.rate_limit_per_user.unwrap_or_default()
+        != channel.rate_limit_per_user.unwrap_or_default()
+    {
+        fields.push("rate_limit_per_user");
+    }
+
+    if template_channel.topic != channel.topic {
+        fields.push("topic");
+    }
+
+    if template_channel.user_limit.unwrap_or_default() != channel.user_limit.unwrap_or_default() {
+        fields.push("user_limit");
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemplateDrift;
+    use crate::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        guild::{template::TemplateGuild, Guild, Permissions},
+        id::Id,
+        test::{
+            self,
+            guild::{channel, role},
+        },
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(TemplateDrift: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn template() -> TemplateGuild {
+        let mut template: TemplateGuild = serde_json::from_value(serde_json::json!({
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "channels": [
+                    {
+                        "id": "10",
+                        "name": "text",
+                        "permission_overwrites": [],
+                        "position": 0,
+                        "type": 4,
+                    },
+                    {
+                        "id": "11",
+                        "name": "general",
+                        "parent_id": "10",
+                        "permission_overwrites": [],
+                        "position": 0,
+                        "type": 0,
+                    },
+                ],
+                "default_message_notifications": 0,
+                "description": null,
+                "explicit_content_filter": 0,
+                "icon_hash": null,
+                "name": "template",
+                "preferred_locale": "en-US",
+                "roles": [
+                    {
+                        "color": 0,
+                        "hoist": false,
+                        "id": "1",
+                        "mentionable": false,
+                        "name": "@everyone",
+                        "permissions": "1024",
+                    },
+                    {
+                        "color": 1,
+                        "hoist": true,
+                        "id": "2",
+                        "mentionable": true,
+                        "name": "moderator",
+                        "permissions": "8",
+                    },
+                ],
+                "system_channel_flags": 0,
+                "system_channel_id": "11",
+                "verification_level": 1,
+        }))
+        .unwrap();
+
+        template.channels[1].permission_overwrites = Some(Vec::from([role_overwrite(2)]));
+
+        template
+    }
+
+    fn role_overwrite(id: u64) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow: Permissions::VIEW_CHANNEL,
+            deny: Permissions::empty(),
+            id: Id::new(id),
+            kind: PermissionOverwriteType::Role,
+        }
+    }
+
+    fn guild() -> Guild {
+        let mut moderator = role(201, "moderator", "8", 1);
+        moderator["color"] = 1.into();
+        moderator["hoist"] = true.into();
+        moderator["mentionable"] = true.into();
+        let mut bot = role(202, "bot", "0", 2);
+        bot["managed"] = true.into();
+
+        let mut guild = test::guild::guild(
+            1000,
+            &[role(1000, "@everyone", "1024", 0), moderator, bot],
+            &[channel(101, "general", 0), channel(100, "text", 4)],
+        );
+        guild.name = "clone".to_owned();
+        guild.system_channel_id = Some(Id::new(101));
+        guild.channels[0].parent_id = Some(Id::new(100));
+        guild.channels[0].permission_overwrites = Some(Vec::from([role_overwrite(201)]));
+
+        guild
+    }
+
+    #[test]
+    fn no_drift() {
+        assert!(template().diff(&guild()).is_empty());
+    }
+
+    #[test]
+    fn drift() {
+        let template = template();
+        let mut guild = guild();
+        guild.roles[1].name = "mod".to_owned();
+        guild.roles[0].permissions = Permissions::empty();
+        guild.channels[1].name = Some("texts".to_owned());
+        guild.verification_level = crate::guild::VerificationLevel::High;
+
+        assert_eq!(
+            [
+                TemplateDrift::RoleChanged {
+                    fields: Vec::from(["permissions"]),
+                    role_id: Id::new(1000),
+                    template_role_id: Id::new(1),
+                },
+                TemplateDrift::RoleMissing {
+                    template_role_id: Id::new(2),
+                },
+                TemplateDrift::RoleExtra {
+                    role_id: Id::new(201),
+                },
+                TemplateDrift::ChannelMissing {
+                    template_channel_id: Id::new(10),
+                },
+                TemplateDrift::ChannelExtra {
+                    channel_id: Id::new(100),
+                },
+                TemplateDrift::GuildChanged {
+                    fields: Vec::from(["verification_level"]),
+                },
+                TemplateDrift::ChannelChanged {
+                    channel_id: Id::new(101),
+                    fields: Vec::from(["parent_id", "permission_overwrites"]),
+                    template_channel_id: Id::new(11),
+                },
+            ],
+            template.diff(&guild).as_slice(),
+        );
+    }
+}
@@ -6,11 +6,12 @@ use crate::{
         VerificationLevel,
     },
     id::{marker::ChannelMarker, Id},
-    util::image_hash::ImageHash,
+    util::{image_hash::ImageHash, Locale},
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TemplateGuild {
     pub afk_channel_id: Option<Id<ChannelMarker>>,
     pub afk_timeout: AfkTimeout,
@@ -20,7 +21,7 @@ pub struct TemplateGuild {
     pub explicit_content_filter: ExplicitContentFilter,
     pub icon_hash: Option<ImageHash>,
     pub name: String,
-    pub preferred_locale: String,
+    pub preferred_locale: Locale,
     pub roles: Vec<TemplateRole>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<Id<ChannelMarker>>,
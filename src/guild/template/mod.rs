@@ -1,9 +1,17 @@
+mod diff;
 mod guild;
+mod plan;
 mod role;
 
+pub use self::diff::TemplateDrift;
 pub use self::guild::TemplateGuild;
+pub use self::plan::{
+    TemplateIdMap, TemplatePlan, TemplatePlanError, TemplatePlanErrorType, TemplatePlanStep,
+};
 pub use self::role::TemplateRole;
 
+pub(crate) use self::plan::order_channels;
+
 use crate::{
     id::{
         marker::{GuildMarker, UserMarker},
@@ -15,6 +23,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Template {
     pub code: String,
     pub created_at: Timestamp,
@@ -467,7 +476,9 @@ mod tests {
                 Token::Some,
                 Token::Str("test"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::Str("username"),
                 Token::Str("username"),
@@ -476,7 +487,9 @@ mod tests {
                 Token::U64(0),
                 Token::StructEnd,
                 Token::Str("creator_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100"),
                 Token::Str("description"),
                 Token::Some,
@@ -502,7 +515,9 @@ mod tests {
                     len: 6,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(4),
@@ -525,7 +540,9 @@ mod tests {
                     len: 8,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -537,7 +554,9 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("parent_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("permission_overwrites"),
                 Token::Some,
@@ -551,7 +570,9 @@ mod tests {
                 Token::Str("deny"),
                 Token::Str("2048"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -565,7 +586,9 @@ mod tests {
                 Token::Str("deny"),
                 Token::Str("0"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("type"),
                 Token::U8(0),
@@ -583,7 +606,9 @@ mod tests {
                     len: 6,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("type"),
                 Token::U8(4),
@@ -609,7 +634,9 @@ mod tests {
                 Token::Some,
                 Token::U32(64000),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("4"),
                 Token::Str("type"),
                 Token::U8(2),
@@ -621,7 +648,9 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("parent_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("3"),
                 Token::Str("permission_overwrites"),
                 Token::Some,
@@ -658,7 +687,9 @@ mod tests {
                 Token::Str("hoist"),
                 Token::Bool(false),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("200"),
                 Token::Str("mentionable"),
                 Token::Bool(false),
@@ -676,7 +707,9 @@ mod tests {
                 Token::Str("hoist"),
                 Token::Bool(false),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("mentionable"),
                 Token::Bool(false),
@@ -690,13 +723,17 @@ mod tests {
                 Token::U64(0),
                 Token::Str("system_channel_id"),
                 Token::Some,
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("verification_level"),
                 Token::U8(0),
                 Token::StructEnd,
                 Token::Str("source_guild_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("200"),
                 Token::Str("updated_at"),
                 Token::Str("2021-04-07T14:55:37.000000+00:00"),
@@ -0,0 +1,651 @@
+use super::{TemplateGuild, TemplateRole};
+use crate::{
+    channel::{permission_overwrite::PermissionOverwriteType, Channel, ChannelType},
+    id::{
+        marker::{ChannelMarker, RoleMarker},
+        Id,
+    },
+};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when a template can't be planned or a planned entity can't be
+/// remapped.
+#[derive(Debug)]
+pub struct TemplatePlanError {
+    kind: TemplatePlanErrorType,
+}
+
+impl TemplatePlanError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TemplatePlanErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (TemplatePlanErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for TemplatePlanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            TemplatePlanErrorType::ChannelParent {
+                channel_id,
+                parent_id,
+            } => {
+                f.write_str("parent ")?;
+                Display::fmt(parent_id, f)?;
+                f.write_str(" of channel ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" is not a category of the template")
+            }
+            TemplatePlanErrorType::DuplicateChannel { channel_id } => {
+                f.write_str("channel ID ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" is used by more than one channel")
+            }
+            TemplatePlanErrorType::DuplicateRole { role_id } => {
+                f.write_str("role ID ")?;
+                Display::fmt(role_id, f)?;
+
+                f.write_str(" is used by more than one role")
+            }
+            TemplatePlanErrorType::GuildChannel { channel_id, field } => {
+                f.write_str("guild field `")?;
+                f.write_str(field)?;
+                f.write_str("` references channel ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" which is not part of the template")
+            }
+            TemplatePlanErrorType::OverwriteRole {
+                channel_id,
+                role_id,
+            } => {
+                f.write_str("permission overwrite of channel ")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str(" references role ")?;
+                Display::fmt(role_id, f)?;
+
+                f.write_str(" which is not part of the template")
+            }
+            TemplatePlanErrorType::UnmappedChannel { channel_id } => {
+                f.write_str("template channel ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" has not been mapped to a created channel")
+            }
+            TemplatePlanErrorType::UnmappedRole { role_id } => {
+                f.write_str("template role ")?;
+                Display::fmt(role_id, f)?;
+
+                f.write_str(" has not been mapped to a created role")
+            }
+        }
+    }
+}
+
+impl Error for TemplatePlanError {}
+
+/// Type of [`TemplatePlanError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TemplatePlanErrorType {
+    /// Parent of a channel is not a category of the template.
+    ChannelParent {
+        /// Template ID of the channel.
+        channel_id: Id<ChannelMarker>,
+        /// Template ID of the parent.
+        parent_id: Id<ChannelMarker>,
+    },
+    /// Template channel ID is used by more than one channel.
+    DuplicateChannel {
+        /// Duplicated template ID.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Template role ID is used by more than one role.
+    DuplicateRole {
+        /// Duplicated template ID.
+        role_id: Id<RoleMarker>,
+    },
+    /// Guild setting references a channel which is not part of the template.
+    GuildChannel {
+        /// Template ID of the channel.
+        channel_id: Id<ChannelMarker>,
+        /// Name of the guild field.
+        field: &'static str,
+    },
+    /// Permission overwrite references a role which is not part of the
+    /// template.
+    OverwriteRole {
+        /// Template ID of the channel.
+        channel_id: Id<ChannelMarker>,
+        /// Template ID of the role.
+        role_id: Id<RoleMarker>,
+    },
+    /// Template channel has not been mapped to a created channel.
+    UnmappedChannel {
+        /// Template ID of the channel.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Template role has not been mapped to a created role.
+    UnmappedRole {
+        /// Template ID of the role.
+        role_id: Id<RoleMarker>,
+    },
+}
+
+/// Ordered steps to create a guild from a [`TemplateGuild`].
+///
+/// Roles are planned first, followed by categories and then all other
+/// channels, so that every role and category is created before it is
+/// referenced. Steps reference the template's entities, which use
+/// template-local IDs; record the ID of every created entity in a
+/// [`TemplateIdMap`] and use it to remap later steps before creating them.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{
+///     guild::template::{TemplateGuild, TemplateIdMap, TemplatePlan, TemplatePlanStep},
+///     id::Id,
+/// };
+///
+/// # fn create(template: &TemplateGuild) -> Result<(), Box<dyn std::error::Error>> {
+/// let plan = TemplatePlan::new(template)?;
+/// let mut ids = TemplateIdMap::new();
+/// # let mut next_id = 1;
+///
+/// for step in plan.steps() {
+///     match step {
+///         TemplatePlanStep::EveryoneRole(role) => {
+///             // Update the permissions of the `@everyone` role, whose ID is the
+///             // guild ID.
+///             # let guild_id = Id::new(1);
+///             ids.insert_role(role.id, guild_id);
+///         }
+///         TemplatePlanStep::Role(role) => {
+///             // Create the role and record its ID.
+///             # next_id += 1;
+///             # let created_id = Id::new(next_id);
+///             ids.insert_role(role.id, created_id);
+///         }
+///         TemplatePlanStep::Category(channel) | TemplatePlanStep::Channel(channel) => {
+///             let channel = ids.remap_channel(channel)?;
+///
+///             // Create the channel, whose parent and permission overwrites now
+///             // reference created entities, and record its ID.
+///             # next_id += 1;
+///             # let created_id = Id::new(next_id);
+///             ids.insert_channel(channel.id, created_id);
+///         }
+///     }
+/// }
+///
+/// // Remap the AFK and system channels to update the guild's settings.
+/// let afk_channel_id = ids.remap_channel_id(template.afk_channel_id)?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TemplatePlan<'a> {
+    /// Template the plan was created from.
+    guild: &'a TemplateGuild,
+    /// Ordered steps of the plan.
+    steps: Vec<TemplatePlanStep<'a>>,
+}
+
+impl<'a> TemplatePlan<'a> {
+    /// Plan the creation of a template's roles and channels.
+    ///
+    /// The first role of the template is its `@everyone` role.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`DuplicateRole`] or [`DuplicateChannel`] if
+    /// a template ID is used more than once.
+    ///
+    /// Returns an error of type [`ChannelParent`] if the parent of a channel
+    /// is not a category of the template.
+    ///
+    /// Returns an error of type [`OverwriteRole`] if a role permission
+    /// overwrite references a role which is not part of the template.
+    ///
+    /// Returns an error of type [`GuildChannel`] if the template's AFK or
+    /// system channel is not part of the template.
+    ///
+    /// [`ChannelParent`]: TemplatePlanErrorType::ChannelParent
+    /// [`DuplicateChannel`]: TemplatePlanErrorType::DuplicateChannel
+    /// [`DuplicateRole`]: TemplatePlanErrorType::DuplicateRole
+    /// [`GuildChannel`]: TemplatePlanErrorType::GuildChannel
+    /// [`OverwriteRole`]: TemplatePlanErrorType::OverwriteRole
+    pub fn new(guild: &'a TemplateGuild) -> Result<Self, TemplatePlanError> {
+        let mut role_ids = HashSet::with_capacity(guild.roles.len());
+
+        for role in &guild.roles {
+            if !role_ids.insert(role.id) {
+                return Err(TemplatePlanError {
+                    kind: TemplatePlanErrorType::DuplicateRole { role_id: role.id },
+                });
+            }
+        }
+
+        let mut channel_ids = HashSet::with_capacity(guild.channels.len());
+
+        for channel in &guild.channels {
+            if !channel_ids.insert(channel.id) {
+                return Err(TemplatePlanError {
+                    kind: TemplatePlanErrorType::DuplicateChannel {
+                        channel_id: channel.id,
+                    },
+                });
+            }
+        }
+
+        let (categories, channels) = order_channels(&guild.channels);
+
+        for channel in &guild.channels {
+            validate_channel(channel, &categories, &role_ids)?;
+        }
+
+        for (field, channel_id) in [
+            ("afk_channel_id", guild.afk_channel_id),
+            ("system_channel_id", guild.system_channel_id),
+        ] {
+            if let Some(channel_id) = channel_id {
+                if !channel_ids.contains(&channel_id) {
+                    return Err(TemplatePlanError {
+                        kind: TemplatePlanErrorType::GuildChannel { channel_id, field },
+                    });
+                }
+            }
+        }
+
+        let roles = guild.roles.iter().enumerate().map(|(index, role)| {
+            if index == 0 {
+                TemplatePlanStep::EveryoneRole(role)
+            } else {
+                TemplatePlanStep::Role(role)
+            }
+        });
+
+        let steps = roles
+            .chain(categories.into_iter().map(TemplatePlanStep::Category))
+            .chain(channels.into_iter().map(TemplatePlanStep::Channel))
+            .collect();
+
+        Ok(Self { guild, steps })
+    }
+
+    /// Template the plan was created from.
+    pub const fn guild(&self) -> &'a TemplateGuild {
+        self.guild
+    }
+
+    /// Ordered steps of the plan.
+    pub fn steps(&self) -> &[TemplatePlanStep<'a>] {
+        &self.steps
+    }
+}
+
+/// Split channels into categories and other channels, each ordered by
+/// position.
+///
+/// Categories are created before the channels within them, so plans create
+/// the returned categories first.
+pub(crate) fn order_channels(channels: &[Channel]) -> (Vec<&Channel>, Vec<&Channel>) {
+    let (mut categories, mut channels): (Vec<&Channel>, Vec<&Channel>) = channels
+        .iter()
+        .partition(|channel| channel.kind == ChannelType::GuildCategory);
+
+    // Sorting is stable, so channels without a position keep their order.
+    categories.sort_by_key(|channel| channel.position.unwrap_or_default());
+    channels.sort_by_key(|channel| channel.position.unwrap_or_default());
+
+    (categories, channels)
+}
+
+/// Validate the parent and permission overwrites of a template channel.
+fn validate_channel(
+    channel: &Channel,
+    categories: &[&Channel],
+    role_ids: &HashSet<Id<RoleMarker>>,
+) -> Result<(), TemplatePlanError> {
+    if let Some(parent_id) = channel.parent_id {
+        let is_category = channel.kind != ChannelType::GuildCategory
+            && categories.iter().any(|category| category.id == parent_id);
+
+        if !is_category {
+            return Err(TemplatePlanError {
+                kind: TemplatePlanErrorType::ChannelParent {
+                    channel_id: channel.id,
+                    parent_id,
+                },
+            });
+        }
+    }
+
+    for overwrite in channel.permission_overwrites.iter().flatten() {
+        let role_id = overwrite.id.cast();
+
+        if overwrite.kind == PermissionOverwriteType::Role && !role_ids.contains(&role_id) {
+            return Err(TemplatePlanError {
+                kind: TemplatePlanErrorType::OverwriteRole {
+                    channel_id: channel.id,
+                    role_id,
+                },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Step of a [`TemplatePlan`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TemplatePlanStep<'a> {
+    /// Update the guild's `@everyone` role, whose ID is the guild's ID.
+    EveryoneRole(&'a TemplateRole),
+    /// Create a role.
+    Role(&'a TemplateRole),
+    /// Create a category channel.
+    Category(&'a Channel),
+    /// Create a non-category channel.
+    Channel(&'a Channel),
+}
+
+/// Mapping of template-local IDs to the IDs of created entities.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TemplateIdMap {
+    /// Created channel IDs by template channel ID.
+    channels: HashMap<Id<ChannelMarker>, Id<ChannelMarker>>,
+    /// Created role IDs by template role ID.
+    roles: HashMap<Id<RoleMarker>, Id<RoleMarker>>,
+}
+
+impl TemplateIdMap {
+    /// Create an empty mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Created ID of a template channel.
+    pub fn channel(&self, template_id: Id<ChannelMarker>) -> Option<Id<ChannelMarker>> {
+        self.channels.get(&template_id).copied()
+    }
+
+    /// Created channel IDs by template channel ID.
+    pub const fn channels(&self) -> &HashMap<Id<ChannelMarker>, Id<ChannelMarker>> {
+        &self.channels
+    }
+
+    /// Record the created ID of a template channel, returning the previously
+    /// recorded ID if there is one.
+    pub fn insert_channel(
+        &mut self,
+        template_id: Id<ChannelMarker>,
+        id: Id<ChannelMarker>,
+    ) -> Option<Id<ChannelMarker>> {
+        self.channels.insert(template_id, id)
+    }
+
+    /// Record the created ID of a template role, returning the previously
+    /// recorded ID if there is one.
+    pub fn insert_role(
+        &mut self,
+        template_id: Id<RoleMarker>,
+        id: Id<RoleMarker>,
+    ) -> Option<Id<RoleMarker>> {
+        self.roles.insert(template_id, id)
+    }
+
+    /// Clone a template channel, remapping its parent and role permission
+    /// overwrites to created IDs.
+    ///
+    /// The ID of the returned channel remains its template ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`] if the parent has not been
+    /// mapped, and [`UnmappedRole`] if a role of a permission overwrite has not
+    /// been mapped.
+    ///
+    /// [`UnmappedChannel`]: TemplatePlanErrorType::UnmappedChannel
+    /// [`UnmappedRole`]: TemplatePlanErrorType::UnmappedRole
+    pub fn remap_channel(&self, channel: &Channel) -> Result<Channel, TemplatePlanError> {
+        let mut channel = channel.clone();
+        channel.parent_id = self.remap_channel_id(channel.parent_id)?;
+
+        for overwrite in channel.permission_overwrites.iter_mut().flatten() {
+            if overwrite.kind == PermissionOverwriteType::Role {
+                let role_id = overwrite.id.cast();
+                let id = self.role(role_id).ok_or(TemplatePlanError {
+                    kind: TemplatePlanErrorType::UnmappedRole { role_id },
+                })?;
+
+                overwrite.id = id.cast();
+            }
+        }
+
+        Ok(channel)
+    }
+
+    /// Remap an optional template channel ID, such as the AFK channel of a
+    /// template, to its created ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnmappedChannel`] if the channel has not
+    /// been mapped.
+    ///
+    /// [`UnmappedChannel`]: TemplatePlanErrorType::UnmappedChannel
+    pub fn remap_channel_id(
+        &self,
+        template_id: Option<Id<ChannelMarker>>,
+    ) -> Result<Option<Id<ChannelMarker>>, TemplatePlanError> {
+        template_id
+            .map(|channel_id| {
+                self.channel(channel_id).ok_or(TemplatePlanError {
+                    kind: TemplatePlanErrorType::UnmappedChannel { channel_id },
+                })
+            })
+            .transpose()
+    }
+
+    /// Created ID of a template role.
+    pub fn role(&self, template_id: Id<RoleMarker>) -> Option<Id<RoleMarker>> {
+        self.roles.get(&template_id).copied()
+    }
+
+    /// Created role IDs by template role ID.
+    pub const fn roles(&self) -> &HashMap<Id<RoleMarker>, Id<RoleMarker>> {
+        &self.roles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        TemplateIdMap, TemplatePlan, TemplatePlanError, TemplatePlanErrorType, TemplatePlanStep,
+    };
+    use crate::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        guild::{template::TemplateGuild, Permissions},
+        id::Id,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(TemplateIdMap: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(TemplatePlan<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(TemplatePlanError: Debug, Error, Send, Sync);
+    assert_impl_all!(TemplatePlanStep<'_>: Clone, Copy, Debug, Send, Sync);
+
+    fn template() -> TemplateGuild {
+        let mut template: TemplateGuild = serde_json::from_value(serde_json::json!({
+                "afk_channel_id": "12",
+                "afk_timeout": 300,
+                "channels": [
+                    {
+                        "id": "12",
+                        "name": "voice",
+                        "parent_id": "10",
+                        "permission_overwrites": [],
+                        "position": 0,
+                        "type": 2,
+                    },
+                    {
+                        "id": "11",
+                        "name": "general",
+                        "parent_id": "10",
+                        "permission_overwrites": [],
+                        "position": 1,
+                        "type": 0,
+                    },
+                    {
+                        "id": "10",
+                        "name": "text",
+                        "permission_overwrites": [],
+                        "position": 0,
+                        "type": 4,
+                    },
+                ],
+                "default_message_notifications": 0,
+                "description": null,
+                "explicit_content_filter": 0,
+                "icon_hash": null,
+                "name": "template",
+                "preferred_locale": "en-US",
+                "roles": [
+                    {
+                        "color": 0,
+                        "hoist": false,
+                        "id": "1",
+                        "mentionable": false,
+                        "name": "@everyone",
+                        "permissions": "104320577",
+                    },
+                    {
+                        "color": 15_277_667,
+                        "hoist": true,
+                        "id": "2",
+                        "mentionable": true,
+                        "name": "moderator",
+                        "permissions": "1099511627775",
+                    },
+                ],
+                "system_channel_flags": 0,
+                "system_channel_id": "11",
+                "verification_level": 1,
+        }))
+        .unwrap();
+
+        template.channels[1].permission_overwrites = Some(Vec::from([role_overwrite(2)]));
+
+        template
+    }
+
+    fn role_overwrite(id: u64) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow: Permissions::VIEW_CHANNEL,
+            deny: Permissions::empty(),
+            id: Id::new(id),
+            kind: PermissionOverwriteType::Role,
+        }
+    }
+
+    #[test]
+    fn plan_order() -> Result<(), TemplatePlanError> {
+        let template = template();
+        let plan = TemplatePlan::new(&template)?;
+
+        assert_eq!(
+            [
+                TemplatePlanStep::EveryoneRole(&template.roles[0]),
+                TemplatePlanStep::Role(&template.roles[1]),
+                TemplatePlanStep::Category(&template.channels[2]),
+                TemplatePlanStep::Channel(&template.channels[0]),
+                TemplatePlanStep::Channel(&template.channels[1]),
+            ],
+            plan.steps(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn plan_errors() {
+        let mut template = template();
+        template.channels[0].parent_id = Some(Id::new(11));
+
+        assert!(matches!(
+            TemplatePlan::new(&template).unwrap_err().kind(),
+            TemplatePlanErrorType::ChannelParent {
+                channel_id,
+                parent_id,
+            } if channel_id.get() == 12 && parent_id.get() == 11
+        ));
+
+        let mut template = self::template();
+        template.roles.remove(1);
+
+        assert!(matches!(
+            TemplatePlan::new(&template).unwrap_err().kind(),
+            TemplatePlanErrorType::OverwriteRole { role_id, .. } if role_id.get() == 2
+        ));
+
+        let mut template = self::template();
+        template.afk_channel_id = Some(Id::new(20));
+
+        assert!(matches!(
+            TemplatePlan::new(&template).unwrap_err().kind(),
+            TemplatePlanErrorType::GuildChannel {
+                field: "afk_channel_id",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn remap() -> Result<(), TemplatePlanError> {
+        let template = template();
+        let mut ids = TemplateIdMap::new();
+
+        assert!(matches!(
+            ids.remap_channel(&template.channels[1]).unwrap_err().kind(),
+            TemplatePlanErrorType::UnmappedChannel { channel_id } if channel_id.get() == 10
+        ));
+
+        ids.insert_channel(Id::new(10), Id::new(100));
+
+        assert!(matches!(
+            ids.remap_channel(&template.channels[1]).unwrap_err().kind(),
+            TemplatePlanErrorType::UnmappedRole { role_id } if role_id.get() == 2
+        ));
+
+        ids.insert_role(Id::new(2), Id::new(200));
+        let channel = ids.remap_channel(&template.channels[1])?;
+
+        assert_eq!(Some(Id::new(100)), channel.parent_id);
+        assert_eq!(Id::new(200), channel.permission_overwrites.unwrap()[0].id);
+        assert_eq!(Id::new(11), channel.id);
+        assert_eq!(Ok(None), ids.remap_channel_id(None).map_err(|_| ()));
+
+        Ok(())
+    }
+}
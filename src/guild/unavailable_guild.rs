@@ -2,6 +2,7 @@ use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UnavailableGuild {
     pub id: Id<GuildMarker>,
 }
@@ -14,9 +15,7 @@ mod tests {
 
     #[test]
     fn unavailable_guild() {
-        let value = UnavailableGuild {
-            id: Id::new(1),
-        };
+        let value = UnavailableGuild { id: Id::new(1) };
 
         serde_test::assert_tokens(
             &value,
@@ -26,7 +25,9 @@ mod tests {
                     len: 1,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::StructEnd,
             ],
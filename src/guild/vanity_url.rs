@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// Information about a guild's vanity URL setting.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VanityUrl {
     /// Code of the vanity URL.
     ///
@@ -14,6 +14,7 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildWidget {
     pub channels: Vec<GuildWidgetChannel>,
     pub id: Id<GuildMarker>,
@@ -24,6 +25,7 @@ pub struct GuildWidget {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildWidgetChannel {
     pub id: Id<ChannelMarker>,
     pub name: String,
@@ -31,10 +33,12 @@ pub struct GuildWidgetChannel {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildWidgetMember {
     pub avatar: Option<ImageHash>,
     pub avatar_url: Option<String>,
     #[serde(with = "discriminator")]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "discriminator::schema"))]
     pub discriminator: u16,
     /// Member's ID.
     pub id: AnonymizableId<UserMarker>,
@@ -148,7 +152,9 @@ mod tests {
                     len: 3,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("2"),
                 Token::Str("name"),
                 Token::Str("General"),
@@ -157,7 +163,9 @@ mod tests {
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("instant_invite"),
                 Token::Some,
@@ -177,7 +185,7 @@ mod tests {
                 Token::Str("0001"),
                 Token::Str("id"),
                 Token::NewtypeStruct {
-                    name: "AnonymizableId",
+                    name: "$twilight_model::AnonymizableId",
                 },
                 Token::Str("0"),
                 Token::Str("username"),
@@ -2,6 +2,7 @@ use crate::id::{marker::ChannelMarker, Id};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuildWidgetSettings {
     pub channel_id: Id<ChannelMarker>,
     pub enabled: bool,
@@ -44,7 +45,9 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("channel_id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("111111111111111111"),
                 Token::Str("enabled"),
                 Token::Bool(true),
@@ -25,6 +25,7 @@ use serde::{Deserialize, Serialize};
 /// attachment.description("Raw data about Twilight Sparkle".to_owned());
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Attachment {
     /// Description of the attachment, useful for screen readers and users
     /// requiring alt text.
@@ -1,6 +1,6 @@
 //! Models used when responding to interactions over HTTP.
 
-use super::attachment::Attachment;
+use super::{attachment::Attachment, poll::PollCreateRequest};
 use crate::{
     application::command::CommandOptionChoice,
     channel::message::{AllowedMentions, Component, Embed, MessageFlags},
@@ -14,6 +14,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 ///
 /// [Discord Docs/Interaction Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-structure
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InteractionResponse {
     /// Type of the response.
     #[serde(rename = "type")]
@@ -36,6 +37,7 @@ pub struct InteractionResponse {
 
 /// Data included in an interaction response.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InteractionResponseData {
     /// Allowed mentions of the response.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -67,6 +69,9 @@ pub struct InteractionResponseData {
     /// [`MessageFlags::EPHEMERAL`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<MessageFlags>,
+    /// Poll of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollCreateRequest>,
     /// For [`InteractionResponseType::Modal`], title of the modal.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -77,6 +82,7 @@ pub struct InteractionResponseData {
 
 /// Type of interaction response.
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum InteractionResponseType {
@@ -100,6 +106,11 @@ pub enum InteractionResponseType {
     ApplicationCommandAutocompleteResult = 8,
     /// Respond to an interaction with a popup modal.
     Modal = 9,
+    /// Respond to an interaction with an upgrade button, prompting the user
+    /// to purchase a premium SKU of the application.
+    ///
+    /// This is not valid for autocomplete and modal submit interactions.
+    PremiumRequired = 10,
 }
 
 #[cfg(test)]
@@ -123,6 +134,7 @@ mod tests {
         content,
         embeds,
         flags,
+        poll,
         tts
     );
     assert_impl_all!(
@@ -148,6 +160,7 @@ mod tests {
                 custom_id: None,
                 embeds: None,
                 flags: Some(MessageFlags::EPHEMERAL),
+                poll: None,
                 title: None,
                 tts: None,
             }),
@@ -3,3 +3,4 @@
 pub mod attachment;
 pub mod interaction;
 pub mod permission_overwrite;
+pub mod poll;
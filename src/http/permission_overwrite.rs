@@ -9,6 +9,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// Permission overwrite data for a role or member.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PermissionOverwrite {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allow: Option<Permissions>,
@@ -21,6 +22,7 @@ pub struct PermissionOverwrite {
 
 /// Type of a permission overwrite target.
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u8)]
 #[serde(rename_all = "snake_case")]
@@ -88,7 +90,9 @@ mod tests {
                 Token::Some,
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("12345678"),
                 Token::Str("type"),
                 Token::U8(PermissionOverwriteType::Member as u8),
@@ -132,7 +136,9 @@ mod tests {
                 Token::Some,
                 Token::Str("2"),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("1"),
                 Token::Str("type"),
                 Token::U8(1),
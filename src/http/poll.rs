@@ -0,0 +1,426 @@
+//! Models used when creating polls.
+
+use crate::poll::{PollLayoutType, PollMedia};
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error emitted when the poll can not be created as configured.
+#[derive(Debug)]
+pub struct PollCreateRequestError {
+    kind: PollCreateRequestErrorType,
+}
+
+impl PollCreateRequestError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PollCreateRequestErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        PollCreateRequestErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for PollCreateRequestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PollCreateRequestErrorType::AnswerCount { count } => {
+                Display::fmt(count, f)?;
+                f.write_str(" answers were provided when between ")?;
+                Display::fmt(&PollCreateRequest::ANSWERS_MIN, f)?;
+                f.write_str(" and ")?;
+                Display::fmt(&PollCreateRequest::ANSWERS_MAX, f)?;
+
+                f.write_str(" are allowed")
+            }
+            PollCreateRequestErrorType::AnswerText { index } => {
+                f.write_str("answer at index ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" must have between 1 and ")?;
+                Display::fmt(&PollCreateRequest::ANSWER_TEXT_LENGTH_MAX, f)?;
+
+                f.write_str(" characters of text")
+            }
+            PollCreateRequestErrorType::Duration { hours } => {
+                f.write_str("duration of ")?;
+                Display::fmt(hours, f)?;
+                f.write_str(" hours is not between ")?;
+                Display::fmt(&PollCreateRequest::DURATION_MIN, f)?;
+                f.write_str(" and ")?;
+                Display::fmt(&PollCreateRequest::DURATION_MAX, f)?;
+
+                f.write_str(" hours")
+            }
+            PollCreateRequestErrorType::QuestionText => {
+                f.write_str("question must have between 1 and ")?;
+                Display::fmt(&PollCreateRequest::QUESTION_TEXT_LENGTH_MAX, f)?;
+
+                f.write_str(" characters of text")
+            }
+        }
+    }
+}
+
+impl Error for PollCreateRequestError {}
+
+/// Type of [`PollCreateRequestError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PollCreateRequestErrorType {
+    /// Too few or too many answers were provided.
+    AnswerCount {
+        /// Number of provided answers.
+        count: usize,
+    },
+    /// Answer has no text or too much text.
+    AnswerText {
+        /// Index of the answer.
+        index: usize,
+    },
+    /// Duration is too short or too long.
+    Duration {
+        /// Provided duration in hours.
+        hours: u16,
+    },
+    /// Question has no text or too much text.
+    QuestionText,
+}
+
+/// Poll to create alongside a message.
+///
+/// See [Discord Docs/Poll Create Request Object].
+///
+/// [Discord Docs/Poll Create Request Object]: https://discord.com/developers/docs/resources/poll#poll-create-request-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PollCreateRequest {
+    /// Whether users can vote for more than one answer.
+    pub allow_multiselect: bool,
+    /// Answers users can vote for.
+    pub answers: Vec<PollCreateRequestAnswer>,
+    /// Number of hours the poll is open for.
+    pub duration: u16,
+    /// Layout of the poll.
+    ///
+    /// Defaults to [`PollLayoutType::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout_type: Option<PollLayoutType>,
+    /// Question of the poll.
+    pub question: PollMedia,
+}
+
+impl PollCreateRequest {
+    /// Maximum number of characters of an answer's text.
+    pub const ANSWER_TEXT_LENGTH_MAX: usize = 55;
+
+    /// Maximum number of answers of a poll.
+    pub const ANSWERS_MAX: usize = 10;
+
+    /// Minimum number of answers of a poll.
+    pub const ANSWERS_MIN: usize = 1;
+
+    /// Maximum duration of a poll in hours, equal to 32 days.
+    pub const DURATION_MAX: u16 = 768;
+
+    /// Minimum duration of a poll in hours.
+    pub const DURATION_MIN: u16 = 1;
+
+    /// Maximum number of characters of the question's text.
+    pub const QUESTION_TEXT_LENGTH_MAX: usize = 300;
+
+    /// Create a validated poll.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`QuestionText`] if the question has no text
+    /// or more than [`QUESTION_TEXT_LENGTH_MAX`] characters.
+    ///
+    /// Returns an error of type [`AnswerCount`] if fewer than
+    /// [`ANSWERS_MIN`] or more than [`ANSWERS_MAX`] answers are provided.
+    ///
+    /// Returns an error of type [`AnswerText`] if an answer has no text or
+    /// more than [`ANSWER_TEXT_LENGTH_MAX`] characters.
+    ///
+    /// Returns an error of type [`Duration`] if the duration is shorter than
+    /// [`DURATION_MIN`] or longer than [`DURATION_MAX`] hours.
+    ///
+    /// [`ANSWER_TEXT_LENGTH_MAX`]: Self::ANSWER_TEXT_LENGTH_MAX
+    /// [`ANSWERS_MAX`]: Self::ANSWERS_MAX
+    /// [`ANSWERS_MIN`]: Self::ANSWERS_MIN
+    /// [`AnswerCount`]: PollCreateRequestErrorType::AnswerCount
+    /// [`AnswerText`]: PollCreateRequestErrorType::AnswerText
+    /// [`DURATION_MAX`]: Self::DURATION_MAX
+    /// [`DURATION_MIN`]: Self::DURATION_MIN
+    /// [`Duration`]: PollCreateRequestErrorType::Duration
+    /// [`QUESTION_TEXT_LENGTH_MAX`]: Self::QUESTION_TEXT_LENGTH_MAX
+    /// [`QuestionText`]: PollCreateRequestErrorType::QuestionText
+    pub fn new(
+        question: impl Into<String>,
+        answers: impl Into<Vec<PollMedia>>,
+        duration: u16,
+        allow_multiselect: bool,
+    ) -> Result<Self, PollCreateRequestError> {
+        Self::_new(question.into(), answers.into(), duration, allow_multiselect)
+    }
+
+    fn _new(
+        question: String,
+        answers: Vec<PollMedia>,
+        duration: u16,
+        allow_multiselect: bool,
+    ) -> Result<Self, PollCreateRequestError> {
+        if !text_valid(Some(&question), Self::QUESTION_TEXT_LENGTH_MAX) {
+            return Err(PollCreateRequestError {
+                kind: PollCreateRequestErrorType::QuestionText,
+            });
+        }
+
+        if !(Self::ANSWERS_MIN..=Self::ANSWERS_MAX).contains(&answers.len()) {
+            return Err(PollCreateRequestError {
+                kind: PollCreateRequestErrorType::AnswerCount {
+                    count: answers.len(),
+                },
+            });
+        }
+
+        if let Some(index) = answers
+            .iter()
+            .position(|answer| !text_valid(answer.text.as_deref(), Self::ANSWER_TEXT_LENGTH_MAX))
+        {
+            return Err(PollCreateRequestError {
+                kind: PollCreateRequestErrorType::AnswerText { index },
+            });
+        }
+
+        if !(Self::DURATION_MIN..=Self::DURATION_MAX).contains(&duration) {
+            return Err(PollCreateRequestError {
+                kind: PollCreateRequestErrorType::Duration { hours: duration },
+            });
+        }
+
+        Ok(Self {
+            allow_multiselect,
+            answers: answers
+                .into_iter()
+                .map(|poll_media| PollCreateRequestAnswer { poll_media })
+                .collect(),
+            duration,
+            layout_type: None,
+            question: PollMedia::text(question),
+        })
+    }
+}
+
+/// Answer of a [`PollCreateRequest`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PollCreateRequestAnswer {
+    /// Content of the answer.
+    pub poll_media: PollMedia,
+}
+
+/// Whether text is present and at most a number of characters long.
+fn text_valid(text: Option<&str>, max: usize) -> bool {
+    matches!(text, Some(text) if !text.is_empty() && text.chars().count() <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PollCreateRequest, PollCreateRequestErrorType};
+    use crate::poll::{PollMedia, PollMediaEmoji};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        PollCreateRequest: allow_multiselect,
+        answers,
+        duration,
+        layout_type,
+        question
+    );
+    assert_impl_all!(
+        PollCreateRequest: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn poll_create_request() -> Result<(), Box<dyn Error>> {
+        let value = PollCreateRequest::new(
+            "Tabs or spaces?",
+            [
+                PollMedia::text("Tabs"),
+                PollMedia {
+                    emoji: Some(PollMediaEmoji {
+                        id: None,
+                        name: Some("🚀".to_owned()),
+                    }),
+                    text: Some("Spaces".to_owned()),
+                },
+            ],
+            24,
+            false,
+        )?;
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PollCreateRequest",
+                    len: 4,
+                },
+                Token::Str("allow_multiselect"),
+                Token::Bool(false),
+                Token::Str("answers"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "PollCreateRequestAnswer",
+                    len: 1,
+                },
+                Token::Str("poll_media"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("Tabs"),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::Struct {
+                    name: "PollCreateRequestAnswer",
+                    len: 1,
+                },
+                Token::Str("poll_media"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 2,
+                },
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "PollMediaEmoji",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("🚀"),
+                Token::StructEnd,
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("Spaces"),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("duration"),
+                Token::U16(24),
+                Token::Str("question"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("Tabs or spaces?"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let answers = || Vec::from([PollMedia::text("Yes"), PollMedia::text("No")]);
+
+        assert!(matches!(
+            PollCreateRequest::new("", answers(), 24, false)
+                .unwrap_err()
+                .kind(),
+            PollCreateRequestErrorType::QuestionText
+        ));
+        assert!(matches!(
+            PollCreateRequest::new("a".repeat(301), answers(), 24, false)
+                .unwrap_err()
+                .kind(),
+            PollCreateRequestErrorType::QuestionText
+        ));
+        assert!(PollCreateRequest::new("a".repeat(300), answers(), 24, false).is_ok());
+
+        assert!(matches!(
+            PollCreateRequest::new("Question?", Vec::new(), 24, false)
+                .unwrap_err()
+                .kind(),
+            PollCreateRequestErrorType::AnswerCount { count: 0 }
+        ));
+        assert!(matches!(
+            PollCreateRequest::new("Question?", vec![PollMedia::text("Yes"); 11], 24, false)
+                .unwrap_err()
+                .kind(),
+            PollCreateRequestErrorType::AnswerCount { count: 11 }
+        ));
+        assert!(
+            PollCreateRequest::new("Question?", vec![PollMedia::text("Yes"); 10], 24, false)
+                .is_ok()
+        );
+
+        assert!(matches!(
+            PollCreateRequest::new(
+                "Question?",
+                [PollMedia::text("Yes"), PollMedia::default()],
+                24,
+                false
+            )
+            .unwrap_err()
+            .kind(),
+            PollCreateRequestErrorType::AnswerText { index: 1 }
+        ));
+        assert!(matches!(
+            PollCreateRequest::new("Question?", [PollMedia::text("a".repeat(56))], 24, false)
+                .unwrap_err()
+                .kind(),
+            PollCreateRequestErrorType::AnswerText { index: 0 }
+        ));
+
+        assert!(matches!(
+            PollCreateRequest::new("Question?", answers(), 0, false)
+                .unwrap_err()
+                .kind(),
+            PollCreateRequestErrorType::Duration { hours: 0 }
+        ));
+        assert!(matches!(
+            PollCreateRequest::new("Question?", answers(), 769, false)
+                .unwrap_err()
+                .kind(),
+            PollCreateRequestErrorType::Duration { hours: 769 }
+        ));
+        assert!(PollCreateRequest::new("Question?", answers(), 768, true).is_ok());
+    }
+}
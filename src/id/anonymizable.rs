@@ -1,6 +1,6 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use super::Id;
+use super::{Id, ANONYMIZABLE_NEWTYPE_NAME};
 use std::hash::{Hash, Hasher};
 
 #[derive(Debug)]
@@ -50,8 +50,29 @@ impl<T> Hash for AnonymizableId<T> {
 impl<T> Serialize for AnonymizableId<T> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
-            Self::Anonymized => serializer.serialize_newtype_struct("AnonymizableId", "0"),
-            Self::Id(id) => serializer.serialize_newtype_struct("AnonymizableId", &id.to_string()),
+            Self::Anonymized => serializer.serialize_newtype_struct(ANONYMIZABLE_NEWTYPE_NAME, "0"),
+            Self::Id(id) => {
+                serializer.serialize_newtype_struct(ANONYMIZABLE_NEWTYPE_NAME, &id.to_string())
+            }
         }
     }
 }
+
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for AnonymizableId<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "AnonymizableId".into()
+    }
+
+    // Anonymized IDs are zero.
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[0-9]+$",
+        })
+    }
+}
@@ -85,6 +85,15 @@ pub struct CommandVersionMarker;
 #[non_exhaustive]
 pub struct EmojiMarker;
 
+/// Marker for entitlement IDs.
+///
+/// Types such as [`Entitlement`] use this ID marker.
+///
+/// [`Entitlement`]: crate::application::monetization::Entitlement
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct EntitlementMarker;
+
 /// Marker for generic IDs.
 ///
 /// Types such as [`AuditLogChange::Id`] or [`CommandOptionValue`] use this
@@ -202,6 +211,25 @@ pub struct ScheduledEventMarker;
 #[non_exhaustive]
 pub struct ScheduledEventEntityMarker;
 
+/// Marker for SKU IDs.
+///
+/// Types such as [`Entitlement`] or [`Sku`] use this ID marker.
+///
+/// [`Entitlement`]: crate::application::monetization::Entitlement
+/// [`Sku`]: crate::application::monetization::Sku
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SkuMarker;
+
+/// Marker for soundboard sound IDs.
+///
+/// Types such as [`SoundboardSound`] use this ID marker.
+///
+/// [`SoundboardSound`]: crate::guild::SoundboardSound
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SoundboardSoundMarker;
+
 /// Marker for stage IDs.
 ///
 /// Types such as [`StageInstance`] use this ID marker.
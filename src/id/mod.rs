@@ -47,6 +47,7 @@ mod anonymizable;
 
 pub use self::anonymizable::AnonymizableId;
 
+use self::marker::GuildMarker;
 use serde::{
     de::{Deserialize, Deserializer, Error as DeError, Unexpected, Visitor},
     ser::{Serialize, Serializer},
@@ -62,6 +63,20 @@ use std::{
     str::FromStr,
 };
 
+/// Unix timestamp of the Discord epoch in milliseconds.
+const DISCORD_EPOCH_MILLISECONDS: i64 = 1_420_070_400_000;
+
+/// Name of the newtype struct [`Id`]s serialize as.
+///
+/// Unique to the crate so that the [storage] serializer, which stores IDs as
+/// integers, doesn't mistake other newtypes for IDs.
+///
+/// [storage]: crate::util::storage
+pub(crate) const NEWTYPE_NAME: &str = "$twilight_model::Id";
+
+/// Name of the newtype struct [`AnonymizableId`]s serialize as.
+pub(crate) const ANONYMIZABLE_NEWTYPE_NAME: &str = "$twilight_model::AnonymizableId";
+
 /// ID of a resource, such as the ID of a [channel] or [user].
 ///
 /// Markers themselves perform no logical action, and are only used to ensure
@@ -209,6 +224,58 @@ impl<T> Id<T> {
     pub const fn cast<New>(self) -> Id<New> {
         Id::from_nonzero(self.value)
     }
+
+    /// Unix timestamp of when the ID was created, in milliseconds.
+    ///
+    /// IDs are [snowflakes], whose upper 42 bits are the number of
+    /// milliseconds since the Discord epoch, the first second of 2015.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::id::{marker::UserMarker, Id};
+    ///
+    /// let user_id = Id::<UserMarker>::new(175_928_847_299_117_063);
+    ///
+    /// // 2016-04-30T11:18:25.796Z
+    /// assert_eq!(1_462_015_105_796, user_id.timestamp());
+    /// ```
+    ///
+    /// [snowflakes]: https://discord.com/developers/docs/reference#snowflakes
+    pub const fn timestamp(self) -> i64 {
+        // Shifting leaves at most 42 bits, which always fit.
+        #[allow(clippy::cast_possible_wrap)]
+        let elapsed = (self.get() >> 22) as i64;
+
+        elapsed + DISCORD_EPOCH_MILLISECONDS
+    }
+}
+
+impl Id<GuildMarker> {
+    /// Number of the shard that receives events for this guild.
+    ///
+    /// Calculated as `(guild_id >> 22) % total`, which is the formula Discord
+    /// uses to route guild events to shards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::id::{marker::GuildMarker, Id};
+    ///
+    /// let guild_id = Id::<GuildMarker>::new(41_771_983_423_143_937);
+    ///
+    /// assert_eq!(6, guild_id.shard_number(8));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of shards is zero.
+    #[track_caller]
+    pub const fn shard_number(self, total: u64) -> u64 {
+        assert!(total != 0, "total must be at least 1");
+
+        (self.get() >> 22) % total
+    }
 }
 
 impl<T> Clone for Id<T> {
@@ -389,7 +456,25 @@ impl<T> PartialOrd for Id<T> {
 
 impl<T> Serialize for Id<T> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_newtype_struct("Id", &self.to_string())
+        serializer.serialize_newtype_struct(NEWTYPE_NAME, &self.to_string())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for Id<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Id".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[1-9][0-9]*$",
+        })
     }
 }
 
@@ -419,9 +504,10 @@ mod tests {
     use super::{
         marker::{
             ApplicationMarker, AttachmentMarker, AuditLogEntryMarker, ChannelMarker, CommandMarker,
-            CommandVersionMarker, EmojiMarker, GenericMarker, GuildMarker, IntegrationMarker,
-            InteractionMarker, MessageMarker, RoleMarker, RoleSubscriptionSkuMarker, StageMarker,
-            UserMarker, WebhookMarker,
+            CommandVersionMarker, EmojiMarker, EntitlementMarker, GenericMarker, GuildMarker,
+            IntegrationMarker, InteractionMarker, MessageMarker, RoleMarker,
+            RoleSubscriptionSkuMarker, SkuMarker, SoundboardSoundMarker, StageMarker, UserMarker,
+            WebhookMarker,
         },
         Id,
     };
@@ -445,6 +531,7 @@ mod tests {
     assert_impl_all!(CommandMarker: Debug, Send, Sync);
     assert_impl_all!(CommandVersionMarker: Debug, Send, Sync);
     assert_impl_all!(EmojiMarker: Debug, Send, Sync);
+    assert_impl_all!(EntitlementMarker: Debug, Send, Sync);
     assert_impl_all!(GenericMarker: Debug, Send, Sync);
     assert_impl_all!(GuildMarker: Debug, Send, Sync);
     assert_impl_all!(IntegrationMarker: Debug, Send, Sync);
@@ -452,6 +539,8 @@ mod tests {
     assert_impl_all!(MessageMarker: Debug, Send, Sync);
     assert_impl_all!(RoleMarker: Debug, Send, Sync);
     assert_impl_all!(RoleSubscriptionSkuMarker: Debug, Send, Sync);
+    assert_impl_all!(SkuMarker: Debug, Send, Sync);
+    assert_impl_all!(SoundboardSoundMarker: Debug, Send, Sync);
     assert_impl_all!(StageMarker: Debug, Send, Sync);
     assert_impl_all!(UserMarker: Debug, Send, Sync);
     assert_impl_all!(WebhookMarker: Debug, Send, Sync);
@@ -568,238 +657,306 @@ mod tests {
         serde_test::assert_tokens(
             &Id::<ApplicationMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<ApplicationMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<AttachmentMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<AttachmentMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<AuditLogEntryMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<AuditLogEntryMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<ChannelMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<ChannelMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<CommandMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<CommandMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<CommandVersionMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<CommandVersionMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<EmojiMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<EmojiMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<GenericMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<GenericMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<GuildMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<GuildMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<IntegrationMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<IntegrationMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<InteractionMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<InteractionMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<MessageMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<MessageMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<RoleMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<RoleMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<RoleSubscriptionSkuMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_tokens(
             &Id::<StageMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<StageMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<UserMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<UserMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_tokens(
             &Id::<WebhookMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("114941315417899012"),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<WebhookMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::U64(114_941_315_417_899_012),
             ],
         );
         serde_test::assert_de_tokens(
             &Id::<WebhookMarker>::new(114_941_315_417_899_012),
             &[
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::I64(114_941_315_417_899_012),
             ],
         );
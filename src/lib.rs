@@ -13,6 +13,9 @@ pub mod guild;
 pub mod http;
 pub mod id;
 pub mod oauth;
+pub mod poll;
+#[cfg(feature = "schemars")]
+pub mod schema;
 pub mod user;
 pub mod util;
 pub mod voice;
@@ -63,6 +63,17 @@ impl Serialize for ApplicationFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ApplicationFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ApplicationFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ApplicationFlags;
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [1]: https://discord.com/developers/docs/topics/oauth2#get-current-authorization-information
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CurrentAuthorizationInformation {
     /// Current application.
     pub application: Application,
@@ -125,7 +126,9 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::ICON_INPUT),
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("100000000000000000"),
                 Token::Str("name"),
                 Token::Str(NAME),
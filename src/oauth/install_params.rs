@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Install Params Object]: https://discord.com/developers/docs/resources/application#install-params-object
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InstallParams {
     /// Permissions to request for the bot role.
     pub permissions: Permissions,
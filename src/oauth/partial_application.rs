@@ -3,6 +3,7 @@ use crate::id::{marker::ApplicationMarker, Id};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PartialApplication {
     pub flags: ApplicationFlags,
     pub id: Id<ApplicationMarker>,
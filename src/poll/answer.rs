@@ -0,0 +1,17 @@
+use super::PollMedia;
+use serde::{Deserialize, Serialize};
+
+/// Answer of a [`Poll`].
+///
+/// See [Discord Docs/Poll Answer Object].
+///
+/// [`Poll`]: super::Poll
+/// [Discord Docs/Poll Answer Object]: https://discord.com/developers/docs/resources/poll#poll-answer-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PollAnswer {
+    /// ID of the answer, unique within its poll.
+    pub answer_id: u8,
+    /// Content of the answer.
+    pub poll_media: PollMedia,
+}
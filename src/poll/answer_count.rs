@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of votes for a [`PollAnswer`].
+///
+/// See [Discord Docs/Poll Answer Count Object].
+///
+/// [`PollAnswer`]: super::PollAnswer
+/// [Discord Docs/Poll Answer Count Object]: https://discord.com/developers/docs/resources/poll#poll-results-object-poll-answer-count-object-structure
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PollAnswerCount {
+    /// Number of votes for the answer.
+    pub count: u64,
+    /// ID of the answer.
+    pub id: u8,
+    /// Whether the current user voted for the answer.
+    pub me_voted: bool,
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// Layout of a [`Poll`].
+///
+/// See [Discord Docs/Layout Type].
+///
+/// [`Poll`]: super::Poll
+/// [Discord Docs/Layout Type]: https://discord.com/developers/docs/resources/poll#layout-type
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum PollLayoutType {
+    /// Default layout.
+    #[default]
+    Default,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for PollLayoutType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Default,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<PollLayoutType> for u8 {
+    fn from(value: PollLayoutType) -> Self {
+        match value {
+            PollLayoutType::Default => 1,
+            PollLayoutType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PollLayoutType;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&PollLayoutType::Default, &[Token::U8(1)]);
+        serde_test::assert_tokens(&PollLayoutType::Unknown(99), &[Token::U8(99)]);
+    }
+}
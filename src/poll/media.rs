@@ -0,0 +1,107 @@
+use crate::id::{marker::EmojiMarker, Id};
+use serde::{Deserialize, Serialize};
+
+/// Content of a poll's question or of one of its answers.
+///
+/// See [Discord Docs/Poll Media Object].
+///
+/// [Discord Docs/Poll Media Object]: https://discord.com/developers/docs/resources/poll#poll-media-object
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PollMedia {
+    /// Emoji displayed alongside an answer.
+    ///
+    /// Questions can't have an emoji.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<PollMediaEmoji>,
+    /// Text of the question or answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl PollMedia {
+    /// Create media with only text.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            emoji: None,
+            text: Some(text.into()),
+        }
+    }
+}
+
+/// Emoji of a [`PollMedia`].
+///
+/// Custom emojis are identified by their ID and unicode emojis by their name.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PollMediaEmoji {
+    /// ID of the custom emoji.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id<EmojiMarker>>,
+    /// Name of the emoji, or the unicode emoji itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PollMedia, PollMediaEmoji};
+    use crate::id::Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(PollMedia: emoji, text);
+    assert_fields!(PollMediaEmoji: id, name);
+    assert_impl_all!(
+        PollMedia: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn poll_media() {
+        let value = PollMedia {
+            emoji: Some(PollMediaEmoji {
+                id: Some(Id::new(1)),
+                name: None,
+            }),
+            text: Some("Tabs".to_owned()),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 2,
+                },
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "PollMediaEmoji",
+                    len: 1,
+                },
+                Token::Str("id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
+                Token::Str("1"),
+                Token::StructEnd,
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("Tabs"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Polls attached to messages, where users vote for one or more answers to a
+//! question.
+//!
+//! See [Discord Docs/Poll].
+//!
+//! [Discord Docs/Poll]: https://discord.com/developers/docs/resources/poll
+
+mod answer;
+mod answer_count;
+mod layout_type;
+mod media;
+mod results;
+
+pub use self::{
+    answer::PollAnswer,
+    answer_count::PollAnswerCount,
+    layout_type::PollLayoutType,
+    media::{PollMedia, PollMediaEmoji},
+    results::PollResults,
+};
+
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Poll attached to a [`Message`].
+///
+/// See [Discord Docs/Poll Object].
+///
+/// [`Message`]: crate::channel::Message
+/// [Discord Docs/Poll Object]: https://discord.com/developers/docs/resources/poll#poll-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Poll {
+    /// Whether users can vote for more than one answer.
+    pub allow_multiselect: bool,
+    /// Answers users can vote for.
+    pub answers: Vec<PollAnswer>,
+    /// When the poll ends.
+    ///
+    /// Polls without an expiry never end.
+    pub expiry: Option<Timestamp>,
+    /// Layout of the poll.
+    pub layout_type: PollLayoutType,
+    /// Question of the poll.
+    ///
+    /// Only the text is supported.
+    pub question: PollMedia,
+    /// Vote counts of the poll.
+    ///
+    /// Discord may not send results, in which case they are unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<PollResults>,
+}
+
+impl Poll {
+    /// Answer of the poll with an ID.
+    pub fn answer(&self, answer_id: u8) -> Option<&PollAnswer> {
+        self.answers
+            .iter()
+            .find(|answer| answer.answer_id == answer_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Poll, PollAnswer, PollAnswerCount, PollLayoutType, PollMedia, PollResults};
+    use crate::util::datetime::{Timestamp, TimestampParseError};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash, str::FromStr};
+
+    assert_fields!(
+        Poll: allow_multiselect,
+        answers,
+        expiry,
+        layout_type,
+        question,
+        results
+    );
+    assert_fields!(PollAnswer: answer_id, poll_media);
+    assert_fields!(PollAnswerCount: count, id, me_voted);
+    assert_fields!(PollResults: answer_counts, is_finalized);
+    assert_impl_all!(
+        Poll: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn poll() -> Result<(), TimestampParseError> {
+        let expiry = Timestamp::from_str("2024-01-02T00:00:00.000000+00:00")?;
+
+        let value = Poll {
+            allow_multiselect: false,
+            answers: Vec::from([
+                PollAnswer {
+                    answer_id: 1,
+                    poll_media: PollMedia::text("Tabs"),
+                },
+                PollAnswer {
+                    answer_id: 2,
+                    poll_media: PollMedia::text("Spaces"),
+                },
+            ]),
+            expiry: Some(expiry),
+            layout_type: PollLayoutType::Default,
+            question: PollMedia::text("Tabs or spaces?"),
+            results: Some(PollResults {
+                answer_counts: Vec::from([PollAnswerCount {
+                    count: 3,
+                    id: 2,
+                    me_voted: true,
+                }]),
+                is_finalized: false,
+            }),
+        };
+
+        assert_eq!(
+            Some("Spaces"),
+            value
+                .answer(2)
+                .and_then(|answer| answer.poll_media.text.as_deref())
+        );
+        assert!(value.answer(3).is_none());
+        let results = value.results.as_ref().expect("results present");
+        assert_eq!(0, results.count(1));
+        assert_eq!(3, results.count(2));
+        assert_eq!(3, results.total());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Poll",
+                    len: 6,
+                },
+                Token::Str("allow_multiselect"),
+                Token::Bool(false),
+                Token::Str("answers"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "PollAnswer",
+                    len: 2,
+                },
+                Token::Str("answer_id"),
+                Token::U8(1),
+                Token::Str("poll_media"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("Tabs"),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::Struct {
+                    name: "PollAnswer",
+                    len: 2,
+                },
+                Token::Str("answer_id"),
+                Token::U8(2),
+                Token::Str("poll_media"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("Spaces"),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("expiry"),
+                Token::Some,
+                Token::Str("2024-01-02T00:00:00.000000+00:00"),
+                Token::Str("layout_type"),
+                Token::U8(1),
+                Token::Str("question"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("Tabs or spaces?"),
+                Token::StructEnd,
+                Token::Str("results"),
+                Token::Some,
+                Token::Struct {
+                    name: "PollResults",
+                    len: 2,
+                },
+                Token::Str("answer_counts"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "PollAnswerCount",
+                    len: 3,
+                },
+                Token::Str("count"),
+                Token::U64(3),
+                Token::Str("id"),
+                Token::U8(2),
+                Token::Str("me_voted"),
+                Token::Bool(true),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("is_finalized"),
+                Token::Bool(false),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+}
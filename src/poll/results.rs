@@ -0,0 +1,46 @@
+use super::PollAnswerCount;
+use serde::{Deserialize, Serialize};
+
+/// Results of a [`Poll`].
+///
+/// See [Discord Docs/Poll Results Object].
+///
+/// [`Poll`]: super::Poll
+/// [Discord Docs/Poll Results Object]: https://discord.com/developers/docs/resources/poll#poll-results-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PollResults {
+    /// Vote counts of the poll's answers.
+    ///
+    /// Answers without votes may be absent.
+    pub answer_counts: Vec<PollAnswerCount>,
+    /// Whether the votes have been precisely counted.
+    ///
+    /// Results of ongoing polls are approximate.
+    pub is_finalized: bool,
+}
+
+impl PollResults {
+    /// Number of votes for an answer.
+    ///
+    /// Answers without an [answer count] have no votes.
+    ///
+    /// [answer count]: Self::answer_counts
+    pub fn count(&self, answer_id: u8) -> u64 {
+        self.answer_counts
+            .iter()
+            .find(|answer_count| answer_count.id == answer_id)
+            .map_or(0, |answer_count| answer_count.count)
+    }
+
+    /// Total number of votes across all answers.
+    ///
+    /// A user voting for multiple answers of a poll allowing multiselect is
+    /// counted once per answer.
+    pub fn total(&self) -> u64 {
+        self.answer_counts
+            .iter()
+            .map(|answer_count| answer_count.count)
+            .sum()
+    }
+}
@@ -0,0 +1,280 @@
+//! [JSON Schema] generation for models.
+//!
+//! Enabled with the `schemars` feature, every model implements
+//! [`JsonSchema`] describing its JSON representation, including that of
+//! models with custom (de)serialization implementations: IDs are strings,
+//! image hashes are hex strings, enums with an `Unknown` variant are their
+//! integer or string value, [`Permissions`] are strings and other bitflags,
+//! such as [`Intents`], are integers.
+//!
+//! [`generate`] creates a single document containing the schema of every
+//! model, which may be diffed across versions of this crate.
+//!
+//! The `schemars` feature requires Rust 1.74 or later.
+//!
+//! # Examples
+//!
+//! Generate the schema document of every model for use in an [OpenAPI 3.0]
+//! specification:
+//!
+//! ```
+//! use schemars::generate::SchemaSettings;
+//! use twilight_model::schema;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let document = schema::generate(SchemaSettings::openapi3());
+//! let json = serde_json::to_string_pretty(&document)?;
+//!
+//! assert!(document.get("components").is_some());
+//! # Ok(()) }
+//! ```
+//!
+//! Generate the schema of a single model:
+//!
+//! ```
+//! use twilight_model::channel::Message;
+//!
+//! let schema = schemars::schema_for!(Message);
+//! ```
+//!
+//! [JSON Schema]: https://json-schema.org
+//! [OpenAPI 3.0]: https://spec.openapis.org/oas/v3.0.3
+//! [`Intents`]: crate::gateway::Intents
+//! [`Permissions`]: crate::guild::Permissions
+
+use crate::{
+    application::{
+        command::{permissions::GuildCommandPermissions, Command},
+        interaction::Interaction,
+        monetization::{Entitlement, Sku},
+        role_connection::{ApplicationRoleConnection, ApplicationRoleConnectionMetadata},
+    },
+    channel::{
+        message::{sticker::StickerPack, Sticker},
+        Channel, FollowedChannel, Message, StageInstance, Webhook,
+    },
+    gateway::{
+        connection_info::{BotConnectionInfo, ConnectionInfo},
+        event::GatewayEvent,
+        payload::outgoing::{
+            Heartbeat, Identify, RequestGuildMembers, RequestSoundboardSounds, Resume,
+            UpdatePresence, UpdateVoiceState,
+        },
+        presence::Presence,
+    },
+    guild::{
+        audit_log::AuditLog, auto_moderation::AutoModerationRule, backup::GuildBackup,
+        invite::Invite, onboarding::Onboarding, scheduled_event::GuildScheduledEvent,
+        template::Template, Ban, Emoji, Guild, GuildIntegration, GuildPreview, GuildPrune,
+        GuildWidget, Member, Role, SoundboardSound, VanityUrl,
+    },
+    http::{interaction::InteractionResponse, poll::PollCreateRequest},
+    oauth::{Application, CurrentAuthorizationInformation},
+    poll::Poll,
+    user::{Connection, CurrentUser, CurrentUserGuild, User},
+    voice::{VoiceRegion, VoiceState},
+};
+use schemars::{
+    generate::{SchemaGenerator, SchemaSettings},
+    json_schema, JsonSchema, Schema,
+};
+use std::borrow::Cow;
+
+/// Generate a schema document containing the schema of every model.
+///
+/// The document is valid against any of the top-level models, such as
+/// [`GatewayEvent`], [`Guild`] and [`Message`], with the schemas of all
+/// models located at the definitions path of the provided settings.
+///
+/// Refer to the [module-level documentation] for an example.
+///
+/// [module-level documentation]: self
+pub fn generate(settings: SchemaSettings) -> Schema {
+    settings.into_generator().into_root_schema_for::<Models>()
+}
+
+/// Schema of bitflags (de)serialized as their integer value.
+pub(crate) fn bitflags() -> Schema {
+    json_schema!({
+        "type": "integer",
+        "format": "uint64",
+        "minimum": 0,
+    })
+}
+
+/// Schema of the top-level models, being any one of them.
+struct Models;
+
+impl JsonSchema for Models {
+    fn schema_name() -> Cow<'static, str> {
+        "twilight-model".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let models = vec![
+            // Application.
+            generator.subschema_for::<ApplicationRoleConnection>(),
+            generator.subschema_for::<ApplicationRoleConnectionMetadata>(),
+            generator.subschema_for::<Command>(),
+            generator.subschema_for::<Entitlement>(),
+            generator.subschema_for::<GuildCommandPermissions>(),
+            generator.subschema_for::<Interaction>(),
+            generator.subschema_for::<InteractionResponse>(),
+            generator.subschema_for::<Sku>(),
+            // Channel.
+            generator.subschema_for::<Channel>(),
+            generator.subschema_for::<FollowedChannel>(),
+            generator.subschema_for::<Message>(),
+            generator.subschema_for::<StageInstance>(),
+            generator.subschema_for::<Sticker>(),
+            generator.subschema_for::<StickerPack>(),
+            generator.subschema_for::<Webhook>(),
+            // Gateway.
+            generator.subschema_for::<BotConnectionInfo>(),
+            generator.subschema_for::<ConnectionInfo>(),
+            generator.subschema_for::<GatewayEvent>(),
+            generator.subschema_for::<Heartbeat>(),
+            generator.subschema_for::<Identify>(),
+            generator.subschema_for::<Presence>(),
+            generator.subschema_for::<RequestGuildMembers>(),
+            generator.subschema_for::<RequestSoundboardSounds>(),
+            generator.subschema_for::<Resume>(),
+            generator.subschema_for::<UpdatePresence>(),
+            generator.subschema_for::<UpdateVoiceState>(),
+            // Guild.
+            generator.subschema_for::<AuditLog>(),
+            generator.subschema_for::<AutoModerationRule>(),
+            generator.subschema_for::<Ban>(),
+            generator.subschema_for::<Emoji>(),
+            generator.subschema_for::<Guild>(),
+            generator.subschema_for::<GuildBackup>(),
+            generator.subschema_for::<GuildIntegration>(),
+            generator.subschema_for::<GuildPreview>(),
+            generator.subschema_for::<GuildPrune>(),
+            generator.subschema_for::<GuildScheduledEvent>(),
+            generator.subschema_for::<GuildWidget>(),
+            generator.subschema_for::<Invite>(),
+            generator.subschema_for::<Member>(),
+            generator.subschema_for::<Onboarding>(),
+            generator.subschema_for::<Role>(),
+            generator.subschema_for::<SoundboardSound>(),
+            generator.subschema_for::<Template>(),
+            generator.subschema_for::<VanityUrl>(),
+            // HTTP.
+            generator.subschema_for::<PollCreateRequest>(),
+            // OAuth.
+            generator.subschema_for::<Application>(),
+            generator.subschema_for::<CurrentAuthorizationInformation>(),
+            // Poll.
+            generator.subschema_for::<Poll>(),
+            // User.
+            generator.subschema_for::<Connection>(),
+            generator.subschema_for::<CurrentUser>(),
+            generator.subschema_for::<CurrentUserGuild>(),
+            generator.subschema_for::<User>(),
+            // Voice.
+            generator.subschema_for::<VoiceRegion>(),
+            generator.subschema_for::<VoiceState>(),
+        ];
+
+        json_schema!({
+            "description": "Discord API models defined by twilight-model.",
+            "anyOf": models,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::{
+        channel::{message::Component, ChannelType},
+        gateway::Intents,
+        guild::Permissions,
+        id::{marker::UserMarker, Id},
+        user::User,
+        util::{ImageHash, Timestamp},
+    };
+    use schemars::{generate::SchemaSettings, schema_for};
+    use serde_json::{json, Value};
+
+    fn definition<'a>(document: &'a Value, name: &str) -> &'a Value {
+        &document["$defs"][name]
+    }
+
+    #[test]
+    fn leaf_types() {
+        assert_eq!(
+            json!("string"),
+            schema_for!(Id<UserMarker>).as_value()["type"]
+        );
+        assert_eq!(
+            json!("^(a_)?[0-9a-f]{32}$"),
+            schema_for!(ImageHash).as_value()["pattern"]
+        );
+        assert_eq!(
+            json!("date-time"),
+            schema_for!(Timestamp).as_value()["format"]
+        );
+        assert_eq!(json!("string"), schema_for!(Permissions).as_value()["type"]);
+        assert_eq!(json!("integer"), schema_for!(Intents).as_value()["type"]);
+        assert_eq!(
+            json!("integer"),
+            schema_for!(ChannelType).as_value()["type"]
+        );
+    }
+
+    #[test]
+    fn user() {
+        let schema = schema_for!(User);
+        let properties = &schema.as_value()["properties"];
+
+        assert_eq!(json!("string"), properties["id"]["type"]);
+        assert_eq!(json!("^[0-9]{4}$"), properties["discriminator"]["pattern"]);
+        assert!(schema.as_value()["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("username")));
+    }
+
+    #[test]
+    fn component() {
+        let schema = schema_for!(Component);
+        let variants = schema.as_value()["oneOf"].as_array().unwrap();
+
+        assert_eq!(4, variants.len());
+        assert_eq!(json!(1), variants[0]["properties"]["type"]["const"]);
+    }
+
+    #[test]
+    fn document() {
+        let document = generate(SchemaSettings::draft2020_12()).to_value();
+
+        assert_eq!(json!("twilight-model"), document["title"]);
+        assert!(definition(&document, "GatewayEvent").is_object());
+        assert!(definition(&document, "Guild").is_object());
+        assert!(definition(&document, "Message").is_object());
+
+        for name in [
+            "ApplicationRoleConnection",
+            "ApplicationRoleConnectionMetadata",
+            "Entitlement",
+            "GuildBackup",
+            "Poll",
+            "PollCreateRequest",
+            "RequestSoundboardSounds",
+            "Sku",
+            "SoundboardSound",
+        ] {
+            assert!(definition(&document, name).is_object(), "{name}");
+        }
+        assert_eq!(
+            json!("integer"),
+            definition(&document, "ChannelType")["type"]
+        );
+        assert_eq!(
+            json!("integer"),
+            definition(&document, "MessageFlags")["type"]
+        );
+    }
+}
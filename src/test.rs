@@ -1,5 +1,216 @@
 //! Scaffolding for unit tests.
 
+pub mod auto_moderation {
+    //! Auto moderation rules for use in tests.
+
+    use crate::{
+        guild::auto_moderation::{
+            AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType,
+            AutoModerationEventType, AutoModerationRule, AutoModerationTriggerMetadata,
+            AutoModerationTriggerType,
+        },
+        id::Id,
+    };
+
+    /// Action of a type with optional metadata.
+    pub const fn action(
+        kind: AutoModerationActionType,
+        metadata: Option<AutoModerationActionMetadata>,
+    ) -> AutoModerationAction {
+        AutoModerationAction { kind, metadata }
+    }
+
+    /// Enabled rule of guild 1 with an ID and trigger type, without trigger
+    /// metadata or exemptions.
+    ///
+    /// The rule blocks the content it triggers on, and its event type is the
+    /// one required by the trigger type.
+    pub fn rule(id: u64, trigger_type: AutoModerationTriggerType) -> AutoModerationRule {
+        let (event_type, action_type) = if trigger_type == AutoModerationTriggerType::MemberProfile
+        {
+            (
+                AutoModerationEventType::MemberUpdate,
+                AutoModerationActionType::BlockMemberInteraction,
+            )
+        } else {
+            (
+                AutoModerationEventType::MessageSend,
+                AutoModerationActionType::BlockMessage,
+            )
+        };
+
+        AutoModerationRule {
+            actions: Vec::from([action(action_type, None)]),
+            creator_id: Id::new(1),
+            enabled: true,
+            event_type,
+            exempt_channels: Vec::new(),
+            exempt_roles: Vec::new(),
+            guild_id: Id::new(1),
+            id: Id::new(id),
+            name: format!("rule {id}"),
+            trigger_metadata: AutoModerationTriggerMetadata {
+                allow_list: None,
+                keyword_filter: None,
+                presets: None,
+                mention_raid_protection_enabled: None,
+                mention_total_limit: None,
+                regex_patterns: None,
+            },
+            trigger_type,
+        }
+    }
+}
+
+pub mod guild {
+    //! Minimal guilds for use in tests.
+
+    use crate::guild::Guild;
+    use serde_json::{json, Value};
+
+    /// Guild with an ID, roles and channels, and default values for the
+    /// other required fields.
+    pub fn guild(id: u64, roles: &[Value], channels: &[Value]) -> Guild {
+        serde_json::from_value(json!({
+            "afk_timeout": 300,
+            "channels": channels,
+            "default_message_notifications": 0,
+            "explicit_content_filter": 0,
+            "features": [],
+            "id": id.to_string(),
+            "mfa_level": 0,
+            "name": "guild",
+            "nsfw_level": 0,
+            "owner_id": "5",
+            "preferred_locale": "en-US",
+            "premium_progress_bar_enabled": false,
+            "premium_tier": 0,
+            "roles": roles,
+            "system_channel_flags": 0,
+            "verification_level": 1,
+        }))
+        .unwrap()
+    }
+
+    /// Channel with an ID, name and type, without permission overwrites.
+    pub fn channel(id: u64, name: &str, kind: u8) -> Value {
+        json!({
+            "id": id.to_string(),
+            "name": name,
+            "permission_overwrites": [],
+            "position": 0,
+            "type": kind,
+        })
+    }
+
+    /// Role with an ID, name, permissions and position, which isn't managed.
+    pub fn role(id: u64, name: &str, permissions: &str, position: i64) -> Value {
+        json!({
+            "color": 0,
+            "flags": 0,
+            "hoist": false,
+            "id": id.to_string(),
+            "managed": false,
+            "mentionable": false,
+            "name": name,
+            "permissions": permissions,
+            "position": position,
+        })
+    }
+}
+
+pub mod onboarding {
+    //! Onboarding configurations and their guilds for use in tests.
+
+    use super::guild::{channel, role};
+    use crate::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        guild::{onboarding::Onboarding, Guild, Permissions},
+        id::Id,
+    };
+    use serde_json::{json, Value};
+
+    /// Guild with ID 1, the text channels 10 to 17 and the `@everyone` and
+    /// `member` roles with IDs 1 and 2, followed by additional roles.
+    pub fn guild(roles: &[Value]) -> Guild {
+        let channels: Vec<_> = (10..=17)
+            .map(|id| channel(id, &format!("channel-{id}"), 0))
+            .collect();
+        let roles: Vec<_> = [role(1, "@everyone", "3072", 0), role(2, "member", "0", 1)]
+            .into_iter()
+            .chain(roles.iter().cloned())
+            .collect();
+
+        super::guild::guild(1, &roles, &channels)
+    }
+
+    /// Enabled onboarding of guild 1 with the channels 10 to 16 as default
+    /// channels and a list of prompts.
+    pub fn onboarding(prompts: &[Value]) -> Onboarding {
+        serde_json::from_value(json!({
+            "default_channel_ids": ["10", "11", "12", "13", "14", "15", "16"],
+            "enabled": true,
+            "guild_id": "1",
+            "mode": 0,
+            "prompts": prompts,
+        }))
+        .unwrap()
+    }
+
+    /// Multiple choice prompt with an ID, title and options.
+    pub fn prompt(
+        id: u64,
+        title: &str,
+        required: bool,
+        single_select: bool,
+        options: &[Value],
+    ) -> Value {
+        json!({
+            "id": id.to_string(),
+            "in_onboarding": true,
+            "options": options,
+            "required": required,
+            "single_select": single_select,
+            "title": title,
+            "type": 1,
+        })
+    }
+
+    /// Prompt option with an ID, title, channels and roles, without a
+    /// description or emoji.
+    pub fn option(id: u64, title: &str, channel_ids: &[u64], role_ids: &[u64]) -> Value {
+        let ids = |ids: &[u64]| ids.iter().map(u64::to_string).collect::<Vec<_>>();
+
+        json!({
+            "channel_ids": ids(channel_ids),
+            "description": null,
+            "emoji": {"id": null, "name": null},
+            "id": id.to_string(),
+            "role_ids": ids(role_ids),
+            "title": title,
+        })
+    }
+
+    /// Permission overwrite denying permissions to the `@everyone` role.
+    pub const fn deny_everyone(permissions: Permissions) -> PermissionOverwrite {
+        role_overwrite(1, Permissions::empty(), permissions)
+    }
+
+    /// Permission overwrite of a role.
+    pub const fn role_overwrite(
+        id: u64,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            id: Id::new(id),
+            kind: PermissionOverwriteType::Role,
+        }
+    }
+}
+
 pub mod image_hash {
     //! Static hashes for use in tests.
 
@@ -1,15 +1,20 @@
-use crate::{guild::GuildIntegration, user::ConnectionVisibility};
+use crate::{
+    guild::GuildIntegration,
+    user::{ConnectionType, ConnectionVisibility},
+};
 use serde::{Deserialize, Serialize};
+use std::fmt::Write;
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Connection {
     pub friend_sync: bool,
     pub id: String,
     #[serde(default)]
     pub integrations: Vec<GuildIntegration>,
     #[serde(rename = "type")]
-    pub kind: String,
+    pub kind: ConnectionType,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub revoked: Option<bool>,
@@ -20,9 +25,64 @@ pub struct Connection {
     pub visibility: ConnectionVisibility,
 }
 
+impl Connection {
+    /// URL of the connected account's profile on the external service.
+    ///
+    /// The URL is built from the service's [profile URL template] and the
+    /// connection's ID or name, which is percent-encoded. Returns `None` if the
+    /// service has no template.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::user::{Connection, ConnectionType, ConnectionVisibility};
+    ///
+    /// let connection = Connection {
+    ///     friend_sync: false,
+    ///     id: "76561197960287930".to_owned(),
+    ///     integrations: Vec::new(),
+    ///     kind: ConnectionType::Steam,
+    ///     name: "gabelogannewell".to_owned(),
+    ///     revoked: None,
+    ///     show_activity: true,
+    ///     two_way_link: false,
+    ///     verified: true,
+    ///     visibility: ConnectionVisibility::Everyone,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Some("https://steamcommunity.com/profiles/76561197960287930"),
+    ///     connection.profile_url().as_deref(),
+    /// );
+    /// ```
+    ///
+    /// [profile URL template]: ConnectionType::profile_url_template
+    pub fn profile_url(&self) -> Option<String> {
+        let template = self.kind.profile_url_template()?;
+
+        let (placeholder, value) = if template.contains("{id}") {
+            ("{id}", &self.id)
+        } else {
+            ("{name}", &self.name)
+        };
+
+        let mut encoded = String::with_capacity(value.len());
+
+        for byte in value.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                encoded.push(char::from(byte));
+            } else {
+                let _ = write!(encoded, "%{byte:02X}");
+            }
+        }
+
+        Some(template.replace(placeholder, &encoded))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Connection, ConnectionVisibility};
+    use super::{Connection, ConnectionType, ConnectionVisibility};
     use serde_test::Token;
 
     #[test]
@@ -31,7 +91,7 @@ mod tests {
             friend_sync: true,
             id: "connection id".to_owned(),
             integrations: Vec::new(),
-            kind: "integration type".to_owned(),
+            kind: ConnectionType::Unknown("integration type".to_owned()),
             name: "integration name".to_owned(),
             revoked: Some(false),
             show_activity: true,
@@ -73,4 +133,40 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn profile_url() {
+        let mut connection = Connection {
+            friend_sync: false,
+            id: "1234".to_owned(),
+            integrations: Vec::new(),
+            kind: ConnectionType::Domain,
+            name: "example.com".to_owned(),
+            revoked: None,
+            show_activity: false,
+            two_way_link: false,
+            verified: true,
+            visibility: ConnectionVisibility::None,
+        };
+        assert_eq!(
+            Some("https://example.com"),
+            connection.profile_url().as_deref()
+        );
+
+        connection.kind = ConnectionType::Reddit;
+        connection.name = "some user/../".to_owned();
+        assert_eq!(
+            Some("https://www.reddit.com/user/some%20user%2F..%2F"),
+            connection.profile_url().as_deref()
+        );
+
+        connection.kind = ConnectionType::YouTube;
+        assert_eq!(
+            Some("https://www.youtube.com/channel/1234"),
+            connection.profile_url().as_deref()
+        );
+
+        connection.kind = ConnectionType::Unknown("myspace".to_owned());
+        assert!(connection.profile_url().is_none());
+    }
 }
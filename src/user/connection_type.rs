@@ -0,0 +1,301 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// External service of a user's [`Connection`].
+///
+/// See [Discord Docs/Services].
+///
+/// [`Connection`]: super::Connection
+/// [Discord Docs/Services]: https://discord.com/developers/docs/resources/user#connection-object-services
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "String", into = "Cow<'static, str>")]
+pub enum ConnectionType {
+    /// Amazon Music.
+    AmazonMusic,
+    /// Battle.net.
+    BattleNet,
+    /// Bungie.net.
+    Bungie,
+    /// Domain name.
+    Domain,
+    /// eBay.
+    Ebay,
+    /// Epic Games.
+    EpicGames,
+    /// Facebook.
+    Facebook,
+    /// `GitHub`.
+    GitHub,
+    /// Instagram.
+    Instagram,
+    /// League of Legends.
+    LeagueOfLegends,
+    /// `PayPal`.
+    PayPal,
+    /// `PlayStation` Network.
+    PlayStation,
+    /// Reddit.
+    Reddit,
+    /// Riot Games.
+    RiotGames,
+    /// Roblox.
+    Roblox,
+    /// Skype.
+    Skype,
+    /// Spotify.
+    Spotify,
+    /// Steam.
+    Steam,
+    /// `TikTok`.
+    TikTok,
+    /// Twitch.
+    Twitch,
+    /// X, formerly Twitter.
+    Twitter,
+    /// Xbox.
+    Xbox,
+    /// `YouTube`.
+    YouTube,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// Name of the service as shown to users, such as "Epic Games".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::user::ConnectionType;
+    ///
+    /// assert_eq!(Some("Battle.net"), ConnectionType::BattleNet.display_name());
+    /// assert!(ConnectionType::Unknown("myspace".to_owned())
+    ///     .display_name()
+    ///     .is_none());
+    /// ```
+    pub const fn display_name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::AmazonMusic => "Amazon Music",
+            Self::BattleNet => "Battle.net",
+            Self::Bungie => "Bungie.net",
+            Self::Domain => "Domain",
+            Self::Ebay => "eBay",
+            Self::EpicGames => "Epic Games",
+            Self::Facebook => "Facebook",
+            Self::GitHub => "GitHub",
+            Self::Instagram => "Instagram",
+            Self::LeagueOfLegends => "League of Legends",
+            Self::PayPal => "PayPal",
+            Self::PlayStation => "PlayStation Network",
+            Self::Reddit => "Reddit",
+            Self::RiotGames => "Riot Games",
+            Self::Roblox => "Roblox",
+            Self::Skype => "Skype",
+            Self::Spotify => "Spotify",
+            Self::Steam => "Steam",
+            Self::TikTok => "TikTok",
+            Self::Twitch => "Twitch",
+            Self::Twitter => "X",
+            Self::Xbox => "Xbox",
+            Self::YouTube => "YouTube",
+            Self::Unknown(_) => return None,
+        })
+    }
+
+    /// Name of the service used by Discord's API, such as `playstation`.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Unknown(unknown) => unknown,
+            known => known.static_name().unwrap_or_default(),
+        }
+    }
+
+    /// Template of the URL of a connected account's profile on the service.
+    ///
+    /// Templates contain an `{id}` or `{name}` placeholder for the
+    /// connection's [`id`] or [`name`]. Use [`Connection::profile_url`] to
+    /// build the URL of a connection's profile.
+    ///
+    /// Services whose profiles can't be linked to from a connection's ID or
+    /// name have no template.
+    ///
+    /// [`Connection::profile_url`]: super::Connection::profile_url
+    /// [`id`]: super::Connection::id
+    /// [`name`]: super::Connection::name
+    pub const fn profile_url_template(&self) -> Option<&'static str> {
+        match self {
+            Self::Domain => Some("https://{name}"),
+            Self::Ebay => Some("https://www.ebay.com/usr/{name}"),
+            Self::GitHub => Some("https://github.com/{name}"),
+            Self::Instagram => Some("https://www.instagram.com/{name}"),
+            Self::Reddit => Some("https://www.reddit.com/user/{name}"),
+            Self::Roblox => Some("https://www.roblox.com/users/{id}/profile"),
+            Self::Spotify => Some("https://open.spotify.com/user/{id}"),
+            Self::Steam => Some("https://steamcommunity.com/profiles/{id}"),
+            Self::TikTok => Some("https://www.tiktok.com/@{name}"),
+            Self::Twitch => Some("https://www.twitch.tv/{name}"),
+            Self::Twitter => Some("https://x.com/{name}"),
+            Self::YouTube => Some("https://www.youtube.com/channel/{id}"),
+            _ => None,
+        }
+    }
+
+    /// Name of the service if it is known.
+    const fn static_name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::AmazonMusic => "amazon-music",
+            Self::BattleNet => "battlenet",
+            Self::Bungie => "bungie",
+            Self::Domain => "domain",
+            Self::Ebay => "ebay",
+            Self::EpicGames => "epicgames",
+            Self::Facebook => "facebook",
+            Self::GitHub => "github",
+            Self::Instagram => "instagram",
+            Self::LeagueOfLegends => "leagueoflegends",
+            Self::PayPal => "paypal",
+            Self::PlayStation => "playstation",
+            Self::Reddit => "reddit",
+            Self::RiotGames => "riotgames",
+            Self::Roblox => "roblox",
+            Self::Skype => "skype",
+            Self::Spotify => "spotify",
+            Self::Steam => "steam",
+            Self::TikTok => "tiktok",
+            Self::Twitch => "twitch",
+            Self::Twitter => "twitter",
+            Self::Xbox => "xbox",
+            Self::YouTube => "youtube",
+            Self::Unknown(_) => return None,
+        })
+    }
+
+    /// Whether Discord verifies that users own their accounts on the service.
+    ///
+    /// Unknown services are assumed to not support verification.
+    pub const fn supports_verification(&self) -> bool {
+        !matches!(
+            self,
+            Self::AmazonMusic
+                | Self::Instagram
+                | Self::LeagueOfLegends
+                | Self::Skype
+                | Self::Unknown(_)
+        )
+    }
+}
+
+impl From<ConnectionType> for Cow<'static, str> {
+    fn from(value: ConnectionType) -> Self {
+        match value {
+            ConnectionType::Unknown(unknown) => unknown.into(),
+            known => known.static_name().unwrap_or_default().into(),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "amazon-music" => Self::AmazonMusic,
+            "battlenet" => Self::BattleNet,
+            "bungie" => Self::Bungie,
+            "domain" => Self::Domain,
+            "ebay" => Self::Ebay,
+            "epicgames" => Self::EpicGames,
+            "facebook" => Self::Facebook,
+            "github" => Self::GitHub,
+            "instagram" => Self::Instagram,
+            "leagueoflegends" => Self::LeagueOfLegends,
+            "paypal" => Self::PayPal,
+            "playstation" => Self::PlayStation,
+            "reddit" => Self::Reddit,
+            "riotgames" => Self::RiotGames,
+            "roblox" => Self::Roblox,
+            "skype" => Self::Skype,
+            "spotify" => Self::Spotify,
+            "steam" => Self::Steam,
+            "tiktok" => Self::TikTok,
+            "twitch" => Self::Twitch,
+            "twitter" => Self::Twitter,
+            "xbox" => Self::Xbox,
+            "youtube" => Self::YouTube,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConnectionType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        ConnectionType: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    const KNOWN: &[(ConnectionType, &str)] = &[
+        (ConnectionType::AmazonMusic, "amazon-music"),
+        (ConnectionType::BattleNet, "battlenet"),
+        (ConnectionType::Bungie, "bungie"),
+        (ConnectionType::Domain, "domain"),
+        (ConnectionType::Ebay, "ebay"),
+        (ConnectionType::EpicGames, "epicgames"),
+        (ConnectionType::Facebook, "facebook"),
+        (ConnectionType::GitHub, "github"),
+        (ConnectionType::Instagram, "instagram"),
+        (ConnectionType::LeagueOfLegends, "leagueoflegends"),
+        (ConnectionType::PayPal, "paypal"),
+        (ConnectionType::PlayStation, "playstation"),
+        (ConnectionType::Reddit, "reddit"),
+        (ConnectionType::RiotGames, "riotgames"),
+        (ConnectionType::Roblox, "roblox"),
+        (ConnectionType::Skype, "skype"),
+        (ConnectionType::Spotify, "spotify"),
+        (ConnectionType::Steam, "steam"),
+        (ConnectionType::TikTok, "tiktok"),
+        (ConnectionType::Twitch, "twitch"),
+        (ConnectionType::Twitter, "twitter"),
+        (ConnectionType::Xbox, "xbox"),
+        (ConnectionType::YouTube, "youtube"),
+    ];
+
+    #[test]
+    fn variants() {
+        for (kind, name) in KNOWN {
+            serde_test::assert_tokens(kind, &[Token::Str(name)]);
+            assert_eq!(*name, kind.name());
+            assert!(kind.display_name().is_some());
+        }
+
+        serde_test::assert_tokens(
+            &ConnectionType::Unknown("myspace".to_owned()),
+            &[Token::Str("myspace")],
+        );
+    }
+
+    #[test]
+    fn metadata() {
+        assert!(ConnectionType::Steam.supports_verification());
+        assert!(!ConnectionType::Skype.supports_verification());
+        assert!(!ConnectionType::Unknown("myspace".to_owned()).supports_verification());
+        assert_eq!(
+            Some("https://github.com/{name}"),
+            ConnectionType::GitHub.profile_url_template()
+        );
+        assert!(ConnectionType::Xbox.profile_url_template().is_none());
+    }
+}
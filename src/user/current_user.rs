@@ -1,11 +1,12 @@
 use super::{DiscriminatorDisplay, PremiumType, UserFlags};
 use crate::{
     id::{marker::UserMarker, Id},
-    util::image_hash::ImageHash,
+    util::{image_hash::ImageHash, Locale},
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CurrentUser {
     /// Accent color of the user's banner.
     ///
@@ -39,6 +40,10 @@ pub struct CurrentUser {
     ///
     /// [`discriminator`]: Self::discriminator
     #[serde(with = "super::discriminator")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "super::discriminator::schema")
+    )]
     pub discriminator: u16,
     /// User's email address associated to the account.
     ///
@@ -54,7 +59,7 @@ pub struct CurrentUser {
     pub id: Id<UserMarker>,
     /// User's chosen language option.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// Whether the user has two factor enabled on their account.
     pub mfa_enabled: bool,
     /// User's username, not unique across the platform.
@@ -110,7 +115,9 @@ mod tests {
             Token::Str("discriminator"),
             discriminator_token,
             Token::Str("id"),
-            Token::NewtypeStruct { name: "Id" },
+            Token::NewtypeStruct {
+                name: "$twilight_model::Id",
+            },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
@@ -157,7 +164,9 @@ mod tests {
             Token::Some,
             Token::U64(1),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "Id" },
+            Token::NewtypeStruct {
+                name: "$twilight_model::Id",
+            },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
@@ -195,7 +204,7 @@ mod tests {
             premium_type: Some(PremiumType::NitroClassic),
             public_flags: Some(UserFlags::STAFF),
             flags: None,
-            locale: Some("test locale".to_owned()),
+            locale: Some("test locale".into()),
         };
 
         // Deserializing a current user with a string discriminator (which
@@ -224,7 +233,7 @@ mod tests {
             premium_type: Some(PremiumType::NitroClassic),
             public_flags: Some(UserFlags::STAFF),
             flags: Some(UserFlags::STAFF),
-            locale: Some("test locale".to_owned()),
+            locale: Some("test locale".into()),
         };
 
         // Deserializing a current user with a string discriminator (which
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Discord Docs/Get Current User Guilds]: https://discord.com/developers/docs/resources/user#get-current-user-guilds-example-partial-guild
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CurrentUserGuild {
     /// Unique ID.
     pub id: Id<GuildMarker>,
@@ -61,7 +62,9 @@ mod tests {
                     len: 6,
                 },
                 Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
+                Token::NewtypeStruct {
+                    name: "$twilight_model::Id",
+                },
                 Token::Str("80351110224678912"),
                 Token::Str("name"),
                 Token::Str("abcd"),
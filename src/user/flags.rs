@@ -60,6 +60,17 @@ impl Serialize for UserFlags {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for UserFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "UserFlags".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::bitflags()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(deprecated)]
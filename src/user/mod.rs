@@ -1,4 +1,5 @@
 mod connection;
+mod connection_type;
 mod connection_visibility;
 mod current_user;
 mod current_user_guild;
@@ -7,14 +8,15 @@ mod premium_type;
 mod profile;
 
 pub use self::{
-    connection::Connection, connection_visibility::ConnectionVisibility, current_user::CurrentUser,
+    connection::Connection, connection_type::ConnectionType,
+    connection_visibility::ConnectionVisibility, current_user::CurrentUser,
     current_user_guild::CurrentUserGuild, flags::UserFlags, premium_type::PremiumType,
     profile::UserProfile,
 };
 
 use crate::{
     id::{marker::UserMarker, Id},
-    util::image_hash::ImageHash,
+    util::{image_hash::ImageHash, Locale},
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
@@ -57,6 +59,14 @@ pub(crate) mod discriminator {
     pub fn serialize<S: Serializer>(value: &u16, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.collect_str(&DiscriminatorDisplay(*value))
     }
+
+    #[cfg(feature = "schemars")]
+    pub fn schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[0-9]{4}$",
+        })
+    }
 }
 
 /// Display formatter for a user discriminator.
@@ -122,6 +132,7 @@ impl Display for DiscriminatorDisplay {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct User {
     /// Accent color of the user's banner.
     ///
@@ -154,6 +165,7 @@ pub struct User {
     ///
     /// [`discriminator`]: Self::discriminator
     #[serde(with = "discriminator")]
+    #[cfg_attr(feature = "schemars", schemars(schema_with = "discriminator::schema"))]
     pub discriminator: u16,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
@@ -164,7 +176,7 @@ pub struct User {
     pub global_name: Option<String>,
     pub id: Id<UserMarker>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mfa_enabled: Option<bool>,
     #[serde(rename = "username")]
@@ -239,7 +251,9 @@ mod tests {
             Token::Some,
             Token::Str("test"),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "Id" },
+            Token::NewtypeStruct {
+                name: "$twilight_model::Id",
+            },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
@@ -293,7 +307,9 @@ mod tests {
             Token::Some,
             Token::Str("test"),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "Id" },
+            Token::NewtypeStruct {
+                name: "$twilight_model::Id",
+            },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
@@ -342,7 +358,7 @@ mod tests {
             flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
             global_name: Some("test".to_owned()),
             id: Id::new(1),
-            locale: Some("en-us".to_owned()),
+            locale: Some("en-us".into()),
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
@@ -374,7 +390,7 @@ mod tests {
             flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
             global_name: Some("test".to_owned()),
             id: Id::new(1),
-            locale: Some("en-us".to_owned()),
+            locale: Some("en-us".into()),
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
@@ -403,7 +419,7 @@ mod tests {
             flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
             global_name: Some("test".to_owned()),
             id: Id::new(1),
-            locale: Some("en-us".to_owned()),
+            locale: Some("en-us".into()),
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
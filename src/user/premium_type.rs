@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`User`]: super::User
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum PremiumType {
@@ -1,11 +1,12 @@
 use super::{DiscriminatorDisplay, PremiumType, UserFlags};
 use crate::{
     id::{marker::UserMarker, Id},
-    util::image_hash::ImageHash,
+    util::{image_hash::ImageHash, Locale},
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UserProfile {
     /// Accent color of the user's banner.
     ///
@@ -33,6 +34,10 @@ pub struct UserProfile {
     ///
     /// [`discriminator`]: Self::discriminator
     #[serde(with = "super::discriminator")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "super::discriminator::schema")
+    )]
     pub discriminator: u16,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
@@ -40,7 +45,7 @@ pub struct UserProfile {
     pub flags: Option<UserFlags>,
     pub id: Id<UserMarker>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mfa_enabled: Option<bool>,
     #[serde(rename = "username")]
@@ -92,7 +97,9 @@ mod tests {
             Token::Some,
             Token::U64(131_072),
             Token::Str("id"),
-            Token::NewtypeStruct { name: "Id" },
+            Token::NewtypeStruct {
+                name: "$twilight_model::Id",
+            },
             Token::Str("1"),
             Token::Str("locale"),
             Token::Some,
@@ -123,7 +130,7 @@ mod tests {
             email: Some("email@example.com".to_owned()),
             flags: Some(UserFlags::VERIFIED_DEVELOPER),
             id: Id::new(1),
-            locale: Some("en-us".to_owned()),
+            locale: Some("en-us".into()),
             mfa_enabled: Some(true),
             name: "user name".to_owned(),
             premium_type: Some(PremiumType::Nitro),
@@ -268,6 +268,20 @@ impl Serialize for Timestamp {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Timestamp {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Timestamp".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "format": "date-time",
+        })
+    }
+}
+
 impl TryFrom<&'_ str> for Timestamp {
     type Error = TimestampParseError;
 
@@ -389,4 +403,11 @@ mod tests {
 
         Ok(())
     }
+
+    /// Test that timestamps are only deserialized from strings.
+    #[test]
+    fn deserialize_integer() {
+        assert!(serde_json::from_str::<Timestamp>("0").is_err());
+        assert!(serde_json::from_str::<Timestamp>("1628594243123456").is_err());
+    }
 }
@@ -472,6 +472,20 @@ impl Serialize for ImageHash {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ImageHash {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ImageHash".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^(a_)?[0-9a-f]{32}$",
+        })
+    }
+}
+
 impl TryFrom<&[u8]> for ImageHash {
     type Error = ImageHashParseError;
 
@@ -826,4 +840,12 @@ mod tests {
 
         Ok(())
     }
+
+    /// Test that image hashes are only deserialized from strings.
+    #[test]
+    fn deserialize_bytes() {
+        assert!(
+            serde_json::from_str::<ImageHash>("[[0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],true]").is_err()
+        );
+    }
 }
@@ -0,0 +1,507 @@
+//! Locales supported by Discord and resolution of localized values.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+};
+
+/// Locale supported by Discord.
+///
+/// Locales are (de)serialized as their [code], such as `en-US`. Codes of
+/// unsupported locales are preserved as [`Unknown`].
+///
+/// See [Discord Docs/Locales].
+///
+/// [code]: Self::code
+/// [`Unknown`]: Self::Unknown
+/// [Discord Docs/Locales]: https://discord.com/developers/docs/reference#locales
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "String", into = "Cow<'static, str>")]
+pub enum Locale {
+    /// Bulgarian.
+    Bulgarian,
+    /// Chinese, as used in China.
+    ChineseChina,
+    /// Chinese, as used in Taiwan.
+    ChineseTaiwan,
+    /// Croatian.
+    Croatian,
+    /// Czech.
+    Czech,
+    /// Danish.
+    Danish,
+    /// Dutch.
+    Dutch,
+    /// English, as used in the United Kingdom.
+    EnglishUk,
+    /// English, as used in the United States.
+    ///
+    /// This is Discord's default locale.
+    #[default]
+    EnglishUs,
+    /// Finnish.
+    Finnish,
+    /// French.
+    French,
+    /// German.
+    German,
+    /// Greek.
+    Greek,
+    /// Hindi.
+    Hindi,
+    /// Hungarian.
+    Hungarian,
+    /// Indonesian.
+    Indonesian,
+    /// Italian.
+    Italian,
+    /// Japanese.
+    Japanese,
+    /// Korean.
+    Korean,
+    /// Lithuanian.
+    Lithuanian,
+    /// Norwegian.
+    Norwegian,
+    /// Polish.
+    Polish,
+    /// Portuguese, as used in Brazil.
+    PortugueseBrazil,
+    /// Romanian.
+    Romanian,
+    /// Russian.
+    Russian,
+    /// Spanish, as used in Latin America.
+    SpanishLatinAmerica,
+    /// Spanish, as used in Spain.
+    SpanishSpain,
+    /// Swedish.
+    Swedish,
+    /// Thai.
+    Thai,
+    /// Turkish.
+    Turkish,
+    /// Ukrainian.
+    Ukrainian,
+    /// Vietnamese.
+    Vietnamese,
+    /// Locale code unknown to the library.
+    Unknown(String),
+}
+
+impl Locale {
+    /// All locales supported by Discord.
+    pub const ALL: &'static [Self] = &[
+        Self::Bulgarian,
+        Self::ChineseChina,
+        Self::ChineseTaiwan,
+        Self::Croatian,
+        Self::Czech,
+        Self::Danish,
+        Self::Dutch,
+        Self::EnglishUk,
+        Self::EnglishUs,
+        Self::Finnish,
+        Self::French,
+        Self::German,
+        Self::Greek,
+        Self::Hindi,
+        Self::Hungarian,
+        Self::Indonesian,
+        Self::Italian,
+        Self::Japanese,
+        Self::Korean,
+        Self::Lithuanian,
+        Self::Norwegian,
+        Self::Polish,
+        Self::PortugueseBrazil,
+        Self::Romanian,
+        Self::Russian,
+        Self::SpanishLatinAmerica,
+        Self::SpanishSpain,
+        Self::Swedish,
+        Self::Thai,
+        Self::Turkish,
+        Self::Ukrainian,
+        Self::Vietnamese,
+    ];
+
+    /// Code of the locale, such as `en-US`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::util::Locale;
+    ///
+    /// assert_eq!("pt-BR", Locale::PortugueseBrazil.code());
+    /// assert_eq!("tlh", Locale::Unknown("tlh".to_owned()).code());
+    /// ```
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Unknown(code) => code,
+            known => known.static_code().unwrap_or_default(),
+        }
+    }
+
+    /// Next locale to use when a value isn't localized for this locale.
+    ///
+    /// Spanish as used in Latin America falls back to Spanish as used in
+    /// Spain, and all other supported locales fall back to English as used in
+    /// the United States, which has no fallback.
+    ///
+    /// [`Unknown`] locales fall back to the supported locale whose code only
+    /// differs in case, such as `en-us`, then to the primary supported locale
+    /// of their language, such as `pt-BR` for `pt-PT`, and otherwise to
+    /// English as used in the United States.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::util::Locale;
+    ///
+    /// assert_eq!(Some(Locale::EnglishUs), Locale::PortugueseBrazil.fallback());
+    /// assert_eq!(
+    ///     Some(Locale::PortugueseBrazil),
+    ///     Locale::Unknown("pt-PT".to_owned()).fallback(),
+    /// );
+    /// assert!(Locale::EnglishUs.fallback().is_none());
+    /// ```
+    pub fn fallback(&self) -> Option<Self> {
+        match self {
+            Self::EnglishUs => None,
+            Self::SpanishLatinAmerica => Some(Self::SpanishSpain),
+            Self::Unknown(code) => Some(
+                Self::ALL
+                    .iter()
+                    .find(|locale| locale.code().eq_ignore_ascii_case(code))
+                    .cloned()
+                    .or_else(|| Self::primary(self.language()))
+                    .unwrap_or(Self::EnglishUs),
+            ),
+            _ => Some(Self::EnglishUs),
+        }
+    }
+
+    /// Iterator over the locale followed by its chain of [fallbacks].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::util::Locale;
+    ///
+    /// let chain = Locale::SpanishLatinAmerica.fallbacks().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     [
+    ///         Locale::SpanishLatinAmerica,
+    ///         Locale::SpanishSpain,
+    ///         Locale::EnglishUs,
+    ///     ],
+    ///     chain.as_slice(),
+    /// );
+    /// ```
+    ///
+    /// [fallbacks]: Self::fallback
+    pub fn fallbacks(&self) -> LocaleFallbacks {
+        LocaleFallbacks {
+            next: Some(self.clone()),
+        }
+    }
+
+    /// Whether the locale is supported by Discord, meaning it isn't
+    /// [`Unknown`].
+    ///
+    /// [`Unknown`]: Self::Unknown
+    pub const fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown(_))
+    }
+
+    /// English name of the locale, if it is supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::util::Locale;
+    ///
+    /// assert_eq!(Some("Spanish, LATAM"), Locale::SpanishLatinAmerica.name());
+    /// ```
+    pub const fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Bulgarian => "Bulgarian",
+            Self::ChineseChina => "Chinese, China",
+            Self::ChineseTaiwan => "Chinese, Taiwan",
+            Self::Croatian => "Croatian",
+            Self::Czech => "Czech",
+            Self::Danish => "Danish",
+            Self::Dutch => "Dutch",
+            Self::EnglishUk => "English, UK",
+            Self::EnglishUs => "English, US",
+            Self::Finnish => "Finnish",
+            Self::French => "French",
+            Self::German => "German",
+            Self::Greek => "Greek",
+            Self::Hindi => "Hindi",
+            Self::Hungarian => "Hungarian",
+            Self::Indonesian => "Indonesian",
+            Self::Italian => "Italian",
+            Self::Japanese => "Japanese",
+            Self::Korean => "Korean",
+            Self::Lithuanian => "Lithuanian",
+            Self::Norwegian => "Norwegian",
+            Self::Polish => "Polish",
+            Self::PortugueseBrazil => "Portuguese, Brazilian",
+            Self::Romanian => "Romanian, Romania",
+            Self::Russian => "Russian",
+            Self::SpanishLatinAmerica => "Spanish, LATAM",
+            Self::SpanishSpain => "Spanish",
+            Self::Swedish => "Swedish",
+            Self::Thai => "Thai",
+            Self::Turkish => "Turkish",
+            Self::Ukrainian => "Ukrainian",
+            Self::Vietnamese => "Vietnamese",
+            Self::Unknown(_) => return None,
+        })
+    }
+
+    /// Resolve the localized value of the first locale in the locale's chain
+    /// of [fallbacks] that has one.
+    ///
+    /// # Examples
+    ///
+    /// Pick the name of a command for a user whose locale is `pt-BR`, with the
+    /// command only being localized in English:
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use twilight_model::util::Locale;
+    ///
+    /// let localizations = HashMap::from([
+    ///     (Locale::EnglishUs, "ping"),
+    ///     (Locale::German, "klingeln"),
+    /// ]);
+    ///
+    /// assert_eq!(Some(&"ping"), Locale::PortugueseBrazil.resolve(&localizations));
+    /// assert_eq!(Some(&"klingeln"), Locale::German.resolve(&localizations));
+    /// ```
+    ///
+    /// [fallbacks]: Self::fallback
+    pub fn resolve<'a, T>(&self, localizations: &'a HashMap<Self, T>) -> Option<&'a T> {
+        self.fallbacks()
+            .find_map(|locale| localizations.get(&locale))
+    }
+
+    /// Language subtag of the locale's code, such as `pt` for `pt-BR`.
+    fn language(&self) -> &str {
+        let code = self.code();
+
+        code.split(['-', '_']).next().unwrap_or(code)
+    }
+
+    /// Primary supported locale of a language, being the one that the
+    /// language's other locales fall back to.
+    fn primary(language: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .filter(|locale| locale.language().eq_ignore_ascii_case(language))
+            .find(|locale| {
+                !matches!(
+                    locale.fallback(),
+                    Some(fallback) if fallback.language() == locale.language()
+                )
+            })
+            .cloned()
+    }
+
+    /// Code of the locale if it is supported.
+    const fn static_code(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Bulgarian => "bg",
+            Self::ChineseChina => "zh-CN",
+            Self::ChineseTaiwan => "zh-TW",
+            Self::Croatian => "hr",
+            Self::Czech => "cs",
+            Self::Danish => "da",
+            Self::Dutch => "nl",
+            Self::EnglishUk => "en-GB",
+            Self::EnglishUs => "en-US",
+            Self::Finnish => "fi",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Greek => "el",
+            Self::Hindi => "hi",
+            Self::Hungarian => "hu",
+            Self::Indonesian => "id",
+            Self::Italian => "it",
+            Self::Japanese => "ja",
+            Self::Korean => "ko",
+            Self::Lithuanian => "lt",
+            Self::Norwegian => "no",
+            Self::Polish => "pl",
+            Self::PortugueseBrazil => "pt-BR",
+            Self::Romanian => "ro",
+            Self::Russian => "ru",
+            Self::SpanishLatinAmerica => "es-419",
+            Self::SpanishSpain => "es-ES",
+            Self::Swedish => "sv-SE",
+            Self::Thai => "th",
+            Self::Turkish => "tr",
+            Self::Ukrainian => "uk",
+            Self::Vietnamese => "vi",
+            Self::Unknown(_) => return None,
+        })
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.code())
+    }
+}
+
+impl From<&str> for Locale {
+    fn from(value: &str) -> Self {
+        Self::ALL
+            .iter()
+            .find(|locale| locale.code() == value)
+            .cloned()
+            .unwrap_or_else(|| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl From<Locale> for Cow<'static, str> {
+    fn from(value: Locale) -> Self {
+        match value {
+            Locale::Unknown(code) => code.into(),
+            known => known.static_code().unwrap_or_default().into(),
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Unknown(_) => Self::Unknown(value),
+            known => known,
+        }
+    }
+}
+
+/// Iterator over a [`Locale`] and its chain of fallbacks.
+///
+/// Created by [`Locale::fallbacks`].
+#[derive(Clone, Debug)]
+pub struct LocaleFallbacks {
+    next: Option<Locale>,
+}
+
+impl Iterator for LocaleFallbacks {
+    type Item = Locale;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let locale = self.next.take()?;
+        self.next = locale.fallback();
+
+        Some(locale)
+    }
+}
+
+impl FusedIterator for LocaleFallbacks {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Locale, LocaleFallbacks};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Locale: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+    assert_impl_all!(LocaleFallbacks: Clone, Debug, Iterator, Send, Sync);
+
+    #[test]
+    fn codes() {
+        for locale in Locale::ALL {
+            assert_eq!(*locale, Locale::from(locale.code()));
+            assert!(locale.is_known());
+            assert!(locale.name().is_some());
+        }
+
+        assert_eq!(32, Locale::ALL.len());
+    }
+
+    #[test]
+    fn serde() {
+        serde_test::assert_tokens(&Locale::EnglishUs, &[Token::Str("en-US")]);
+        serde_test::assert_tokens(&Locale::SpanishLatinAmerica, &[Token::Str("es-419")]);
+        serde_test::assert_tokens(&Locale::Unknown("en-us".to_owned()), &[Token::Str("en-us")]);
+    }
+
+    #[test]
+    fn fallbacks() {
+        assert_eq!(
+            [Locale::PortugueseBrazil, Locale::EnglishUs],
+            Locale::PortugueseBrazil
+                .fallbacks()
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+        assert_eq!(
+            [Locale::Unknown("en-us".to_owned()), Locale::EnglishUs],
+            Locale::Unknown("en-us".to_owned())
+                .fallbacks()
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+        assert_eq!(
+            Some(Locale::SpanishSpain),
+            Locale::Unknown("es-MX".to_owned()).fallback()
+        );
+        assert_eq!(
+            Some(Locale::ChineseChina),
+            Locale::Unknown("zh-HK".to_owned()).fallback()
+        );
+        assert_eq!(
+            Some(Locale::EnglishUs),
+            Locale::Unknown("en-AU".to_owned()).fallback()
+        );
+        assert_eq!(
+            Some(Locale::EnglishUs),
+            Locale::Unknown("tlh".to_owned()).fallback()
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let localizations = HashMap::from([
+            (Locale::EnglishUs, "color"),
+            (Locale::EnglishUk, "colour"),
+            (Locale::SpanishSpain, "color (es)"),
+        ]);
+
+        assert_eq!(Some(&"colour"), Locale::EnglishUk.resolve(&localizations));
+        assert_eq!(
+            Some(&"color (es)"),
+            Locale::SpanishLatinAmerica.resolve(&localizations)
+        );
+        assert_eq!(Some(&"color"), Locale::Japanese.resolve(&localizations));
+        assert!(Locale::Japanese
+            .resolve(&HashMap::<_, &str>::new())
+            .is_none());
+    }
+}
@@ -2,10 +2,16 @@
 
 pub mod datetime;
 pub mod image_hash;
+pub mod locale;
+pub mod storage;
 
-pub use self::{datetime::Timestamp, image_hash::ImageHash};
+pub use self::{datetime::Timestamp, image_hash::ImageHash, locale::Locale};
 
 #[allow(clippy::trivially_copy_pass_by_ref)]
 pub(crate) fn is_false(value: &bool) -> bool {
     !value
 }
+
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
@@ -0,0 +1,1382 @@
+//! Compact binary storage of models.
+//!
+//! Models (de)serialize into the JSON representation used by Discord, where
+//! IDs and permissions are strings, image hashes are hex strings and
+//! timestamps are ISO 8601 strings. Many models also omit absent fields or are
+//! deserialized based on their contents, which formats that aren't
+//! self-describing - such as `bincode` and `postcard` - can't represent.
+//!
+//! The storage format is an [ETF] term in which IDs are integers, timestamps
+//! are tuples of their Unix timestamp in microseconds, image hashes are tuples
+//! of their 16 bytes and whether they are animated, and strings shorter than
+//! 256 bytes are atoms. Only the storage deserializer reads tuples back as
+//! timestamps and image hashes, so they keep rejecting these forms in other
+//! formats.
+//! Models serialize as they do with JSON, the storage serializer compacting
+//! their IDs, timestamps and image hashes, so the storage format doesn't
+//! affect other formats. Being self-describing, every model round-trips
+//! through it.
+//!
+//! [`Stored`] wraps a model so that it is (de)serialized as the bytes of its
+//! storage representation, allowing it to be used with any `serde` format.
+//!
+//! # Examples
+//!
+//! Round-trip a timestamp through its storage representation:
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_model::util::{storage, Timestamp};
+//!
+//! let timestamp = Timestamp::parse("2021-08-10T12:41:51.602000+00:00")?;
+//! let bytes = storage::to_vec(&timestamp)?;
+//!
+//! assert_eq!(timestamp, storage::from_slice(&bytes)?);
+//! # Ok(()) }
+//! ```
+//!
+//! Define a cache entry containing a message, which may be encoded with any
+//! `serde` format:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use twilight_model::{channel::Message, util::storage::Stored};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct CacheEntry {
+//!     hits: u64,
+//!     message: Stored<Message>,
+//! }
+//! ```
+//!
+//! [ETF]: crate::gateway::etf
+
+use crate::gateway::etf::{self, EtfError};
+use serde::{
+    de::{DeserializeOwned, Deserializer, Error as DeError, SeqAccess, Visitor},
+    ser::{Error as SerError, Serializer},
+    Deserialize, Serialize,
+};
+use std::{
+    fmt::{Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+
+/// Maximum number of bytes allocated ahead of deserializing a sequence of
+/// bytes.
+const PREALLOCATION_MAX: usize = 1024 * 1024;
+
+/// Serialize a model into its storage representation.
+///
+/// # Errors
+///
+/// Returns an error if the model's serialization implementation fails. Refer
+/// to [`etf::to_vec`] for more information.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EtfError> {
+    let mut serializer = etf::Serializer::compact();
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.into_inner())
+}
+
+/// Deserialize a model from its storage representation.
+///
+/// # Errors
+///
+/// Returns an error if the input isn't a valid storage representation of the
+/// model. Refer to [`etf::from_slice`] for more information.
+pub fn from_slice<'a, T: Deserialize<'a>>(input: &'a [u8]) -> Result<T, EtfError> {
+    let mut deserializer = etf::Deserializer::storage(input)?;
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+/// Model (de)serialized as the bytes of its storage representation.
+///
+/// Refer to the [module-level documentation] for more information.
+///
+/// [module-level documentation]: self
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Stored<T>(pub T);
+
+impl<T> Stored<T> {
+    /// Consume the wrapper, returning the model.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Stored<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Stored<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StoredVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: DeserializeOwned> Visitor<'de> for StoredVisitor<T> {
+            type Value = Stored<T>;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("bytes of a stored model")
+            }
+
+            fn visit_bytes<E: DeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+                from_slice(v).map(Stored).map_err(DeError::custom)
+            }
+
+            // Human readable formats may represent bytes as a sequence.
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                // The size hint is read from the input, so don't trust it
+                // with more than a bounded allocation.
+                let capacity = seq.size_hint().unwrap_or_default().min(PREALLOCATION_MAX);
+                let mut bytes = Vec::with_capacity(capacity);
+
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+
+                self.visit_bytes(&bytes)
+            }
+        }
+
+        deserializer.deserialize_bytes(StoredVisitor(PhantomData))
+    }
+}
+
+impl<T: Serialize> Serialize for Stored<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = to_vec(&self.0).map_err(SerError::custom)?;
+
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_slice, to_vec, Stored};
+    use crate::{
+        application::{
+            command::{permissions::GuildCommandPermissions, Command},
+            interaction::Interaction,
+            monetization::{Entitlement, Sku},
+            role_connection::{ApplicationRoleConnection, ApplicationRoleConnectionMetadata},
+        },
+        channel::{
+            message::{sticker::StickerPack, Component, Message, ReactionType},
+            Channel, FollowedChannel, StageInstance, Webhook,
+        },
+        gateway::{
+            connection_info::{BotConnectionInfo, ConnectionInfo},
+            etf,
+            event::{DispatchEvent, DispatchEventWithTypeDeserializer},
+            payload::{
+                incoming::MemberAdd,
+                outgoing::{
+                    Identify, RequestGuildMembers, RequestSoundboardSounds, Resume, UpdatePresence,
+                    UpdateVoiceState,
+                },
+            },
+            presence::Presence,
+        },
+        guild::{
+            audit_log::AuditLog, auto_moderation::AutoModerationRule, backup::GuildBackup,
+            invite::Invite, onboarding::Onboarding, scheduled_event::GuildScheduledEvent,
+            template::Template, Ban, Guild, GuildIntegration, GuildPreview, GuildPrune,
+            GuildWidget, Member, Permissions, SoundboardSound, VanityUrl,
+        },
+        http::{interaction::InteractionResponse, poll::PollCreateRequest},
+        id::{marker::UserMarker, AnonymizableId, Id},
+        oauth::{Application, CurrentAuthorizationInformation},
+        poll::Poll,
+        user::{Connection, CurrentUser, CurrentUserGuild, User},
+        util::{ImageHash, Timestamp},
+        voice::{VoiceRegion, VoiceState},
+    };
+    use serde::{
+        de::{DeserializeOwned, DeserializeSeed},
+        Deserialize, Serialize,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Stored<Id<UserMarker>>: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    /// Assert that a model round-trips through storage, wrapped in `bincode`
+    /// and that the storage representation is smaller than JSON.
+    fn assert_round_trip<T>(json: &str) -> T
+    where
+        T: Debug + DeserializeOwned + PartialEq + Serialize,
+    {
+        let value = assert_stored::<T>(json);
+        let bytes = to_vec(&value).unwrap();
+        assert!(bytes.len() < serde_json::to_vec(&value).unwrap().len());
+
+        value
+    }
+
+    /// Assert that a model round-trips through storage and wrapped in
+    /// `bincode`.
+    fn assert_stored<T>(json: &str) -> T
+    where
+        T: Debug + DeserializeOwned + PartialEq + Serialize,
+    {
+        let value = serde_json::from_str::<T>(json).unwrap();
+
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(value, from_slice::<T>(&bytes).unwrap());
+
+        let encoded = bincode::serialize(&Stored(&value)).unwrap();
+        let decoded: Stored<T> = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(value, decoded.0);
+
+        value
+    }
+
+    #[test]
+    fn leaf_types() {
+        // Image hashes are stored as their bytes and whether they are
+        // animated.
+        let hash = ImageHash::parse(b"a_b0e09d6697b11e9c79a89e5e3756ddee").unwrap();
+        let bytes = to_vec(&hash).unwrap();
+        assert_eq!(hash, from_slice::<ImageHash>(&bytes).unwrap());
+        assert_eq!(
+            (hash.bytes().as_slice(), true),
+            etf::from_slice::<(&[u8], bool)>(&bytes).unwrap()
+        );
+        assert_eq!(
+            ImageHash::CLYDE,
+            from_slice::<ImageHash>(&to_vec(&ImageHash::CLYDE).unwrap()).unwrap()
+        );
+
+        // Timestamps are stored as Unix timestamps in microseconds.
+        let timestamp = Timestamp::from_micros(1_628_594_243_123_456).unwrap();
+        let bytes = to_vec(&timestamp).unwrap();
+        assert_eq!(timestamp, from_slice::<Timestamp>(&bytes).unwrap());
+        assert_eq!(
+            (1_628_594_243_123_456_i64,),
+            etf::from_slice::<(i64,)>(&bytes).unwrap()
+        );
+
+        // Compacted values are only visited as their strings when
+        // deserializing storage.
+        assert!(etf::from_slice::<Timestamp>(&bytes).is_err());
+        assert!(
+            from_slice::<Timestamp>(&etf::to_vec(&1_628_594_243_123_456_i64).unwrap()).is_err()
+        );
+
+        // Only timestamps are compacted, not strings which look like one.
+        assert_eq!(
+            "2021-08-10T11:17:23.123456+00:00",
+            from_slice::<String>(&to_vec("2021-08-10T11:17:23.123456+00:00").unwrap()).unwrap()
+        );
+
+        // Other formats keep the string representations.
+        assert_eq!(
+            r#""2021-08-10T11:17:23.123456+00:00""#,
+            serde_json::to_string(&timestamp).unwrap()
+        );
+        assert_eq!(
+            r#""a_b0e09d6697b11e9c79a89e5e3756ddee""#,
+            serde_json::to_string(&hash).unwrap()
+        );
+
+        let permissions = Permissions::ADMINISTRATOR | Permissions::SEND_MESSAGES;
+        assert_eq!(
+            permissions,
+            from_slice::<Permissions>(&to_vec(&permissions).unwrap()).unwrap()
+        );
+
+        for id in [
+            AnonymizableId::<UserMarker>::Anonymized,
+            AnonymizableId::Id(Id::new(7)),
+        ] {
+            assert_eq!(id, from_slice(&to_vec(&id).unwrap()).unwrap());
+        }
+
+        // Models outside of the storage format keep their JSON representation.
+        assert_eq!(
+            r#""100000000000000001""#,
+            serde_json::to_string(&Id::<UserMarker>::new(100_000_000_000_000_001)).unwrap()
+        );
+
+        // IDs are stored as integers, while strings of digits aren't.
+        let id = Id::<UserMarker>::new(100_000_000_000_000_001);
+        assert_eq!(
+            etf::to_vec(&100_000_000_000_000_001_u64).unwrap(),
+            to_vec(&id).unwrap()
+        );
+        assert_eq!(
+            "100000000000000001",
+            from_slice::<String>(&to_vec("100000000000000001").unwrap()).unwrap()
+        );
+    }
+
+    /// Only the crate's IDs are compacted, not other newtypes named `Id`.
+    #[test]
+    fn foreign_ids() {
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Id(String);
+
+        let id = Id("100000000000000001".to_owned());
+        assert_eq!(id, from_slice(&to_vec(&id).unwrap()).unwrap());
+    }
+
+    /// Strings of 256 bytes or more are too long to be atoms and are stored as
+    /// binaries instead.
+    #[test]
+    fn long_strings() {
+        for len in [0, 1, 254, 255, 256, 257, 1024, 70_000] {
+            let text = "a".repeat(len);
+            let bytes = to_vec(&text).unwrap();
+
+            assert_eq!(text, from_slice::<String>(&bytes).unwrap());
+            assert_eq!(text, from_slice::<&str>(&bytes).unwrap());
+
+            let content = serde_json::to_string(&text).unwrap();
+            let message = assert_stored::<Message>(&MESSAGE.replacen(
+                r#""hello there, this is a message stored in a compact format""#,
+                &content,
+                1,
+            ));
+            assert_eq!(text, message.content);
+
+            let channel = assert_stored::<Channel>(&CHANNEL.replacen(r#""chat""#, &content, 1));
+            assert_eq!(Some(text.as_str()), channel.topic.as_deref());
+        }
+
+        // Multi-byte characters crossing the boundary.
+        for len in [85, 86] {
+            let text = "\u{2603}".repeat(len);
+
+            assert_eq!(text, from_slice::<String>(&to_vec(&text).unwrap()).unwrap());
+        }
+
+        // Strings read back as units or booleans when they are atoms.
+        for text in ["nil", "true", "false"] {
+            assert_eq!(text, from_slice::<String>(&to_vec(text).unwrap()).unwrap());
+        }
+    }
+
+    #[test]
+    fn message() {
+        let message = assert_round_trip::<Message>(MESSAGE);
+
+        assert_eq!(2, message.components.len());
+        assert!(message.edited_timestamp.is_some());
+    }
+
+    #[test]
+    fn user() {
+        assert_round_trip::<User>(USER);
+    }
+
+    #[test]
+    fn member() {
+        assert_round_trip::<Member>(MEMBER);
+        assert_round_trip::<MemberAdd>(&MEMBER.replacen('{', r#"{"guild_id":"1","#, 1));
+    }
+
+    #[test]
+    fn guild() {
+        assert_round_trip::<Guild>(GUILD);
+    }
+
+    #[test]
+    fn channel() {
+        assert_round_trip::<Channel>(CHANNEL);
+    }
+
+    #[test]
+    fn components() {
+        assert_round_trip::<Vec<Component>>(COMPONENTS);
+    }
+
+    #[test]
+    fn reaction_types() {
+        assert_round_trip::<Vec<ReactionType>>(
+            r#"[{"id":"123456789012345678","name":"rust","animated":true},{"name":"🦀"}]"#,
+        );
+    }
+
+    #[test]
+    fn interaction() {
+        assert_round_trip::<Interaction>(INTERACTION);
+    }
+
+    #[test]
+    fn presence() {
+        assert_round_trip::<Presence>(PRESENCE);
+    }
+
+    #[test]
+    fn audit_log() {
+        assert_round_trip::<AuditLog>(AUDIT_LOG);
+    }
+
+    #[test]
+    fn command() {
+        assert_stored::<Command>(COMMAND);
+        assert_stored::<GuildCommandPermissions>(
+            r#"{
+                "application_id": "100000000000000008",
+                "guild_id": "100000000000000005",
+                "id": "100000000000000012",
+                "permissions": [
+                    { "id": "100000000000000002", "type": 1, "permission": true },
+                    { "id": "100000000000000001", "type": 2, "permission": false },
+                    { "id": "100000000000000004", "type": 3, "permission": true }
+                ]
+            }"#,
+        );
+    }
+
+    #[test]
+    fn interaction_response() {
+        assert_stored::<InteractionResponse>(
+            r#"{
+                "type": 4,
+                "data": {
+                    "content": "response",
+                    "flags": 64,
+                    "allowed_mentions": { "parse": ["users"], "replied_user": false },
+                    "embeds": [{ "title": "title", "type": "rich" }],
+                    "tts": false
+                }
+            }"#,
+        );
+    }
+
+    #[test]
+    fn channel_models() {
+        assert_stored::<FollowedChannel>(
+            r#"{ "channel_id": "100000000000000004", "webhook_id": "100000000000000013" }"#,
+        );
+        assert_stored::<StageInstance>(
+            r#"{
+                "channel_id": "100000000000000004",
+                "guild_id": "100000000000000005",
+                "guild_scheduled_event_id": null,
+                "id": "100000000000000014",
+                "privacy_level": 2,
+                "topic": "stage"
+            }"#,
+        );
+        assert_stored::<StickerPack>(STICKER_PACK);
+        assert_stored::<Webhook>(
+            r#"{
+                "application_id": null,
+                "avatar": "c273213790e64f8230f7ea035817cbbf",
+                "channel_id": "100000000000000004",
+                "guild_id": "100000000000000005",
+                "id": "100000000000000013",
+                "type": 1,
+                "name": "webhook",
+                "token": "webhook token",
+                "user": { "id": "100000000000000001", "username": "twilight", "discriminator": "0001", "avatar": null }
+            }"#,
+        );
+        assert_round_trip::<Channel>(THREAD);
+    }
+
+    #[test]
+    fn gateway_models() {
+        assert_stored::<BotConnectionInfo>(
+            r#"{
+                "session_start_limit": { "max_concurrency": 16, "remaining": 998, "reset_after": 60000, "total": 1000 },
+                "shards": 40,
+                "url": "wss://gateway.discord.gg"
+            }"#,
+        );
+        assert_stored::<ConnectionInfo>(r#"{ "url": "wss://gateway.discord.gg" }"#);
+        assert_stored::<Identify>(
+            r#"{
+                "d": {
+                    "compress": false,
+                    "intents": 513,
+                    "large_threshold": 250,
+                    "presence": { "activities": [], "afk": false, "since": null, "status": "idle" },
+                    "properties": { "browser": "twilight", "device": "twilight", "os": "linux" },
+                    "shard": [1, 2],
+                    "token": "token"
+                },
+                "op": 2
+            }"#,
+        );
+        assert_stored::<Resume>(
+            r#"{ "d": { "seq": 5, "session_id": "session", "token": "token" }, "op": 6 }"#,
+        );
+        assert_stored::<UpdatePresence>(
+            r#"{
+                "d": {
+                    "activities": [{ "name": "twilight", "type": 0 }],
+                    "afk": true,
+                    "since": 1571048061237,
+                    "status": "dnd"
+                },
+                "op": 3
+            }"#,
+        );
+        assert_stored::<UpdateVoiceState>(
+            r#"{
+                "d": { "channel_id": null, "guild_id": "100000000000000005", "self_deaf": true, "self_mute": false },
+                "op": 4
+            }"#,
+        );
+        assert_stored::<RequestGuildMembers>(
+            r#"{
+                "d": { "guild_id": "100000000000000005", "limit": 0, "presences": true, "query": "twi" },
+                "op": 8
+            }"#,
+        );
+        assert_stored::<RequestSoundboardSounds>(
+            r#"{ "d": { "guild_ids": ["100000000000000005"] }, "op": 31 }"#,
+        );
+    }
+
+    #[test]
+    fn guild_models() {
+        assert_stored::<AutoModerationRule>(AUTO_MODERATION_RULE);
+        assert_stored::<Ban>(&format!(r#"{{ "reason": "spam", "user": {USER} }}"#));
+        assert_stored::<GuildIntegration>(
+            r#"{
+                "account": { "id": "account", "name": "twilight" },
+                "enabled": true,
+                "expire_behavior": 1,
+                "expire_grace_period": 3,
+                "id": "100000000000000015",
+                "type": "twitch",
+                "name": "integration",
+                "role_id": "100000000000000002",
+                "synced_at": "2021-08-10T12:41:51.602000+00:00",
+                "syncing": false
+            }"#,
+        );
+        assert_stored::<GuildPreview>(
+            r#"{
+                "approximate_member_count": 12000,
+                "approximate_presence_count": 3000,
+                "description": null,
+                "discovery_splash": null,
+                "emojis": [],
+                "features": ["COMMUNITY"],
+                "id": "100000000000000005",
+                "name": "twilight",
+                "icon": "a_b0e09d6697b11e9c79a89e5e3756ddee",
+                "splash": null,
+                "stickers": []
+            }"#,
+        );
+        assert_stored::<GuildPrune>(r#"{ "pruned": 5 }"#);
+        assert_stored::<GuildScheduledEvent>(SCHEDULED_EVENT);
+        assert_stored::<GuildWidget>(
+            r#"{
+                "channels": [{ "id": "100000000000000004", "name": "general", "position": 0 }],
+                "id": "100000000000000005",
+                "instant_invite": null,
+                "members": [
+                    {
+                        "avatar": null,
+                        "avatar_url": "https://cdn.discordapp.com/widget-avatars/avatar",
+                        "discriminator": "0000",
+                        "id": "100000000000000001",
+                        "status": "online",
+                        "username": "twilight"
+                    }
+                ],
+                "name": "twilight",
+                "presence_count": 1
+            }"#,
+        );
+        assert_stored::<Invite>(
+            r#"{
+                "approximate_member_count": 12000,
+                "channel": { "id": "100000000000000004", "name": "general", "type": 0 },
+                "code": "twilight",
+                "expires_at": "2021-08-10T12:41:51.602000+00:00",
+                "guild": {
+                    "banner": null,
+                    "description": null,
+                    "features": ["COMMUNITY", "SOME_NEW_FEATURE"],
+                    "icon": null,
+                    "id": "100000000000000005",
+                    "name": "twilight",
+                    "premium_subscription_count": 14,
+                    "splash": null,
+                    "vanity_url_code": "twilight",
+                    "verification_level": 2,
+                    "welcome_screen": null
+                },
+                "max_age": 86400,
+                "target_type": 1,
+                "uses": 3
+            }"#,
+        );
+        assert_stored::<Onboarding>(ONBOARDING);
+        assert_stored::<Template>(TEMPLATE);
+        assert_stored::<VanityUrl>(r#"{ "code": "twilight" }"#);
+        assert_stored::<GuildBackup>(GUILD_BACKUP);
+        assert_stored::<SoundboardSound>(
+            r#"{
+                "available": true,
+                "emoji_id": null,
+                "emoji_name": "🦀",
+                "guild_id": "100000000000000005",
+                "name": "crab",
+                "sound_id": "100000000000000016",
+                "volume": 0.5
+            }"#,
+        );
+    }
+
+    #[test]
+    fn application_models() {
+        assert_stored::<Application>(APPLICATION);
+        assert_stored::<CurrentAuthorizationInformation>(&format!(
+            r#"{{
+                "application": {APPLICATION},
+                "expires": "2021-08-10T12:41:51.602000+00:00",
+                "scopes": ["bot", "applications.commands"],
+                "user": {USER}
+            }}"#
+        ));
+        assert_stored::<Entitlement>(
+            r#"{
+                "application_id": "100000000000000008",
+                "consumed": false,
+                "deleted": false,
+                "ends_at": "2021-09-10T12:41:51.602000+00:00",
+                "guild_id": "100000000000000005",
+                "id": "100000000000000017",
+                "type": 8,
+                "sku_id": "100000000000000018",
+                "starts_at": "2021-08-10T12:41:51.602000+00:00"
+            }"#,
+        );
+        assert_stored::<Sku>(
+            r#"{
+                "application_id": "100000000000000008",
+                "flags": 128,
+                "id": "100000000000000018",
+                "type": 5,
+                "name": "premium",
+                "slug": "premium"
+            }"#,
+        );
+        assert_stored::<ApplicationRoleConnection>(
+            r#"{
+                "metadata": { "level": "5", "verified": "1" },
+                "platform_name": "twilight",
+                "platform_username": null
+            }"#,
+        );
+        assert_stored::<ApplicationRoleConnectionMetadata>(
+            r#"{
+                "description": "level",
+                "description_localizations": { "fr": "niveau" },
+                "key": "level",
+                "type": 2,
+                "name": "Level"
+            }"#,
+        );
+    }
+
+    #[test]
+    fn poll() {
+        assert_stored::<Poll>(
+            r#"{
+                "allow_multiselect": false,
+                "answers": [
+                    { "answer_id": 1, "poll_media": { "text": "yes", "emoji": { "name": "🦀" } } },
+                    { "answer_id": 2, "poll_media": { "text": "no" } }
+                ],
+                "expiry": "2021-08-11T12:41:51.602000+00:00",
+                "layout_type": 1,
+                "question": { "text": "question" },
+                "results": {
+                    "answer_counts": [{ "count": 3, "id": 1, "me_voted": true }],
+                    "is_finalized": false
+                }
+            }"#,
+        );
+        assert_stored::<PollCreateRequest>(
+            r#"{
+                "allow_multiselect": true,
+                "answers": [{ "poll_media": { "text": "yes" } }, { "poll_media": { "text": "no" } }],
+                "duration": 24,
+                "question": { "text": "question" }
+            }"#,
+        );
+    }
+
+    #[test]
+    fn user_models() {
+        assert_stored::<Connection>(
+            r#"{
+                "friend_sync": false,
+                "id": "connection",
+                "type": "github",
+                "name": "twilight-rs",
+                "show_activity": true,
+                "two_way_link": false,
+                "verified": true,
+                "visibility": 1
+            }"#,
+        );
+        assert_stored::<CurrentUser>(
+            r#"{
+                "accent_color": null,
+                "avatar": "a_b0e09d6697b11e9c79a89e5e3756ddee",
+                "banner": null,
+                "bot": true,
+                "discriminator": "0001",
+                "id": "100000000000000001",
+                "locale": "en-US",
+                "mfa_enabled": true,
+                "username": "twilight",
+                "public_flags": 64,
+                "verified": true
+            }"#,
+        );
+        assert_stored::<CurrentUserGuild>(
+            r#"{
+                "id": "100000000000000005",
+                "name": "twilight",
+                "icon": null,
+                "owner": false,
+                "permissions": "104193601",
+                "features": ["COMMUNITY"]
+            }"#,
+        );
+    }
+
+    #[test]
+    fn voice_models() {
+        assert_stored::<VoiceRegion>(
+            r#"{ "custom": false, "deprecated": false, "id": "rotterdam", "name": "Rotterdam", "optimal": true }"#,
+        );
+        assert_stored::<VoiceState>(&format!(
+            r#"{{
+                "channel_id": "100000000000000019",
+                "deaf": false,
+                "guild_id": "100000000000000005",
+                "member": {MEMBER},
+                "mute": false,
+                "self_deaf": false,
+                "self_mute": true,
+                "self_stream": false,
+                "self_video": false,
+                "session_id": "session",
+                "suppress": false,
+                "user_id": "100000000000000001",
+                "request_to_speak_timestamp": null
+            }}"#
+        ));
+    }
+
+    #[test]
+    fn dispatch_event() {
+        let event = DispatchEvent::GuildMemberAdd(Box::new(
+            serde_json::from_str(&MEMBER.replacen('{', r#"{"guild_id":"1","#, 1)).unwrap(),
+        ));
+        let bytes = to_vec(&event).unwrap();
+
+        let mut deserializer = etf::Deserializer::storage(&bytes).unwrap();
+        let value = DispatchEventWithTypeDeserializer::new("GUILD_MEMBER_ADD")
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert!(matches!(value, DispatchEvent::GuildMemberAdd(_)));
+    }
+
+    const COMMAND: &str = r#"{
+        "application_id": "100000000000000008",
+        "default_member_permissions": "8",
+        "description": "vote for something",
+        "description_localizations": { "fr": "voter", "en-GB": "vote" },
+        "guild_id": "100000000000000005",
+        "id": "100000000000000012",
+        "type": 1,
+        "name": "vote",
+        "nsfw": false,
+        "options": [
+            {
+                "type": 3,
+                "name": "choice",
+                "description": "choice",
+                "required": true,
+                "choices": [{ "name": "yes", "value": "yes" }, { "name": "no", "value": "no" }]
+            },
+            {
+                "type": 4,
+                "name": "weight",
+                "description": "weight",
+                "min_value": 1,
+                "max_value": 10
+            },
+            { "type": 7, "name": "channel", "description": "channel", "channel_types": [0, 5] }
+        ],
+        "version": "100000000000000020"
+    }"#;
+
+    const STICKER_PACK: &str = r#"{
+        "banner_asset_id": "100000000000000021",
+        "cover_sticker_id": "100000000000000022",
+        "description": "stickers",
+        "id": "100000000000000023",
+        "name": "pack",
+        "sku_id": "100000000000000024",
+        "stickers": [
+            {
+                "available": true,
+                "description": "sticker",
+                "format_type": 1,
+                "id": "100000000000000022",
+                "type": 1,
+                "name": "sticker",
+                "pack_id": "100000000000000023",
+                "sort_value": 3,
+                "tags": "crab"
+            }
+        ]
+    }"#;
+
+    const THREAD: &str = r#"{
+        "id": "100000000000000025",
+        "guild_id": "100000000000000005",
+        "parent_id": "100000000000000004",
+        "owner_id": "100000000000000001",
+        "type": 11,
+        "name": "a thread with a somewhat longer name",
+        "last_message_id": "100000000000000006",
+        "message_count": 5,
+        "member_count": 2,
+        "rate_limit_per_user": 0,
+        "thread_metadata": {
+            "archived": false,
+            "auto_archive_duration": 1440,
+            "archive_timestamp": "2021-08-10T12:41:51.602000+00:00",
+            "locked": false,
+            "create_timestamp": "2021-08-10T12:41:51.602000+00:00"
+        },
+        "member": {
+            "id": "100000000000000025",
+            "user_id": "100000000000000001",
+            "join_timestamp": "2021-08-10T12:41:51.602000+00:00",
+            "flags": 0
+        },
+        "applied_tags": ["100000000000000026"]
+    }"#;
+
+    const AUTO_MODERATION_RULE: &str = r#"{
+        "actions": [
+            { "type": 1, "metadata": { "custom_message": "blocked" } },
+            { "type": 2, "metadata": { "channel_id": "100000000000000004" } },
+            { "type": 3, "metadata": { "duration_seconds": 60 } }
+        ],
+        "creator_id": "100000000000000001",
+        "enabled": true,
+        "event_type": 1,
+        "exempt_channels": ["100000000000000004"],
+        "exempt_roles": [],
+        "guild_id": "100000000000000005",
+        "id": "100000000000000027",
+        "name": "keywords",
+        "trigger_metadata": {
+            "allow_list": ["allowed"],
+            "keyword_filter": ["bad*", "*word"],
+            "regex_patterns": ["b[a4]d"]
+        },
+        "trigger_type": 1
+    }"#;
+
+    const SCHEDULED_EVENT: &str = r#"{
+        "channel_id": "100000000000000019",
+        "creator_id": "100000000000000001",
+        "description": "weekly meeting",
+        "entity_type": 2,
+        "guild_id": "100000000000000005",
+        "id": "100000000000000028",
+        "image": "c273213790e64f8230f7ea035817cbbf",
+        "name": "meeting",
+        "privacy_level": 2,
+        "recurrence_rule": {
+            "start": "2021-08-10T12:00:00.000000+00:00",
+            "end": null,
+            "frequency": 2,
+            "interval": 1,
+            "by_weekday": [1],
+            "by_n_weekday": null,
+            "by_month": null,
+            "by_month_day": null,
+            "by_year_day": null,
+            "count": null
+        },
+        "scheduled_start_time": "2021-08-10T12:00:00.000000+00:00",
+        "status": 1,
+        "user_count": 4
+    }"#;
+
+    const ONBOARDING: &str = r#"{
+        "default_channel_ids": ["100000000000000004"],
+        "enabled": true,
+        "guild_id": "100000000000000005",
+        "mode": 1,
+        "prompts": [
+            {
+                "id": "100000000000000029",
+                "in_onboarding": true,
+                "type": 0,
+                "options": [
+                    {
+                        "channel_ids": ["100000000000000004"],
+                        "description": null,
+                        "emoji": { "id": null, "name": "🦀", "animated": false },
+                        "id": "100000000000000030",
+                        "role_ids": ["100000000000000002"],
+                        "title": "crab"
+                    }
+                ],
+                "required": false,
+                "single_select": true,
+                "title": "pick"
+            }
+        ]
+    }"#;
+
+    const TEMPLATE: &str = r#"{
+        "code": "template",
+        "created_at": "2021-08-10T12:41:51.602000+00:00",
+        "creator": {
+            "id": "100000000000000001",
+            "username": "twilight",
+            "discriminator": "0001",
+            "avatar": null
+        },
+        "creator_id": "100000000000000001",
+        "description": null,
+        "is_dirty": null,
+        "name": "template",
+        "serialized_source_guild": {
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "channels": [
+                {
+                    "id": 2,
+                    "name": "general",
+                    "type": 0,
+                    "position": 0,
+                    "parent_id": null,
+                    "nsfw": false,
+                    "permission_overwrites": [],
+                    "rate_limit_per_user": 0,
+                    "topic": null
+                }
+            ],
+            "default_message_notifications": 0,
+            "description": null,
+            "explicit_content_filter": 0,
+            "icon_hash": null,
+            "name": "twilight",
+            "preferred_locale": "en-US",
+            "roles": [
+                {
+                    "color": 0,
+                    "hoist": false,
+                    "id": 1,
+                    "mentionable": false,
+                    "name": "@everyone",
+                    "permissions": "104193601"
+                }
+            ],
+            "system_channel_flags": 0,
+            "system_channel_id": 2,
+            "verification_level": 0
+        },
+        "source_guild_id": "100000000000000005",
+        "updated_at": "2021-08-10T12:41:51.602000+00:00",
+        "usage_count": 3
+    }"#;
+
+    const GUILD_BACKUP: &str = r#"{
+        "channels": [],
+        "emojis": [],
+        "guild_id": "100000000000000005",
+        "roles": [
+            {
+                "color": 0,
+                "hoist": false,
+                "id": "100000000000000005",
+                "managed": false,
+                "mentionable": false,
+                "name": "@everyone",
+                "permissions": "104193601",
+                "position": 0,
+                "flags": 0
+            }
+        ],
+        "settings": {
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "banner": null,
+            "default_message_notifications": 0,
+            "description": null,
+            "discovery_splash": null,
+            "explicit_content_filter": 0,
+            "icon": null,
+            "name": "twilight",
+            "preferred_locale": "en-US",
+            "premium_progress_bar_enabled": false,
+            "public_updates_channel_id": null,
+            "rules_channel_id": null,
+            "safety_alerts_channel_id": null,
+            "splash": null,
+            "system_channel_flags": 0,
+            "system_channel_id": null,
+            "verification_level": 0
+        },
+        "stickers": [],
+        "version": 1
+    }"#;
+
+    const APPLICATION: &str = r#"{
+        "bot_public": true,
+        "bot_require_code_grant": false,
+        "cover_image": null,
+        "description": "an application",
+        "guild_id": null,
+        "flags": 8388608,
+        "icon": "c273213790e64f8230f7ea035817cbbf",
+        "id": "100000000000000008",
+        "install_params": { "permissions": "8", "scopes": ["bot"] },
+        "name": "twilight",
+        "owner": null,
+        "primary_sku_id": null,
+        "rpc_origins": [],
+        "slug": null,
+        "tags": ["rust"],
+        "team": null,
+        "verify_key": "key"
+    }"#;
+
+    const USER: &str = r#"{
+        "id": "100000000000000001",
+        "username": "twilight",
+        "discriminator": "0001",
+        "avatar": "a_b0e09d6697b11e9c79a89e5e3756ddee",
+        "bot": false,
+        "public_flags": 64
+    }"#;
+
+    const MEMBER: &str = r#"{
+        "avatar": null,
+        "communication_disabled_until": null,
+        "deaf": false,
+        "flags": 0,
+        "joined_at": "2021-08-10T12:18:37.000000+00:00",
+        "mute": true,
+        "nick": "twi",
+        "pending": false,
+        "premium_since": null,
+        "roles": ["100000000000000002", "100000000000000003"],
+        "user": {
+            "id": "100000000000000001",
+            "username": "twilight",
+            "discriminator": "0001",
+            "avatar": "a_b0e09d6697b11e9c79a89e5e3756ddee",
+            "bot": false,
+            "public_flags": 64
+        }
+    }"#;
+
+    const COMPONENTS: &str = r#"[
+        {
+            "type": 1,
+            "components": [
+                {
+                    "type": 2,
+                    "style": 1,
+                    "label": "Vote",
+                    "custom_id": "vote",
+                    "emoji": { "id": "123456789012345678", "name": "rust", "animated": false }
+                },
+                { "type": 2, "style": 5, "label": "Docs", "url": "https://twilight.rs" }
+            ]
+        },
+        {
+            "type": 1,
+            "components": [
+                {
+                    "type": 3,
+                    "custom_id": "select",
+                    "options": [
+                        { "label": "A", "value": "a", "default": true },
+                        { "label": "B", "value": "b", "description": "second" }
+                    ],
+                    "placeholder": "pick",
+                    "min_values": 1,
+                    "max_values": 2
+                }
+            ]
+        }
+    ]"#;
+
+    const MESSAGE: &str = r#"{
+        "attachments": [],
+        "author": {
+            "id": "100000000000000001",
+            "username": "twilight",
+            "discriminator": "0001",
+            "avatar": "a_b0e09d6697b11e9c79a89e5e3756ddee",
+            "bot": false,
+            "public_flags": 64
+        },
+        "channel_id": "100000000000000004",
+        "components": [
+            {
+                "type": 1,
+                "components": [
+                    { "type": 2, "style": 1, "label": "Vote", "custom_id": "vote" }
+                ]
+            },
+            {
+                "type": 1,
+                "components": [
+                    {
+                        "type": 3,
+                        "custom_id": "select",
+                        "options": [{ "label": "A", "value": "a", "default": true }]
+                    }
+                ]
+            }
+        ],
+        "content": "hello there, this is a message stored in a compact format",
+        "edited_timestamp": "2021-08-10T12:41:51.602000+00:00",
+        "embeds": [
+            {
+                "color": 16711680,
+                "description": "embed description",
+                "fields": [{ "inline": true, "name": "name", "value": "value" }],
+                "timestamp": "2021-08-10T12:41:51.602000+00:00",
+                "title": "title",
+                "type": "rich"
+            }
+        ],
+        "flags": 0,
+        "guild_id": "100000000000000005",
+        "id": "100000000000000006",
+        "member": {
+            "deaf": false,
+            "flags": 0,
+            "joined_at": "2021-08-10T12:18:37.000000+00:00",
+            "mute": false,
+            "roles": []
+        },
+        "mention_everyone": false,
+        "mention_roles": ["100000000000000002"],
+        "mentions": [],
+        "pinned": false,
+        "reactions": [
+            {
+                "count": 3,
+                "emoji": { "id": "123456789012345678", "name": "rust" },
+                "me": true
+            },
+            { "count": 1, "emoji": { "name": "🦀" }, "me": false }
+        ],
+        "timestamp": "2021-08-10T12:41:51.602000+00:00",
+        "tts": false,
+        "type": 0
+    }"#;
+
+    const CHANNEL: &str = r#"{
+        "id": "100000000000000004",
+        "guild_id": "100000000000000005",
+        "type": 0,
+        "position": 3,
+        "name": "general",
+        "nsfw": false,
+        "topic": "chat",
+        "last_message_id": "100000000000000006",
+        "rate_limit_per_user": 5,
+        "parent_id": "100000000000000007",
+        "last_pin_timestamp": "2021-08-10T12:41:51.602000+00:00"
+    }"#;
+
+    const GUILD: &str = r#"{
+        "afk_channel_id": null,
+        "afk_timeout": 300,
+        "application_id": null,
+        "banner": null,
+        "channels": [],
+        "default_message_notifications": 0,
+        "description": "a guild",
+        "discovery_splash": null,
+        "emojis": [
+            {
+                "animated": false,
+                "available": true,
+                "id": "123456789012345678",
+                "managed": false,
+                "name": "rust",
+                "require_colons": true,
+                "roles": []
+            }
+        ],
+        "explicit_content_filter": 2,
+        "features": ["COMMUNITY", "NEWS", "SOME_NEW_FEATURE"],
+        "icon": "c273213790e64f8230f7ea035817cbbf",
+        "id": "100000000000000005",
+        "joined_at": "2021-08-10T12:18:37.000000+00:00",
+        "large": false,
+        "max_members": 250000,
+        "max_video_channel_users": 25,
+        "member_count": 12000,
+        "members": [],
+        "mfa_level": 1,
+        "name": "twilight",
+        "nsfw_level": 0,
+        "owner_id": "100000000000000001",
+        "preferred_locale": "en-US",
+        "premium_progress_bar_enabled": true,
+        "premium_subscription_count": 14,
+        "premium_tier": 3,
+        "presences": [],
+        "public_updates_channel_id": "100000000000000004",
+        "roles": [
+            {
+                "color": 0,
+                "hoist": false,
+                "id": "100000000000000005",
+                "managed": false,
+                "mentionable": false,
+                "name": "@everyone",
+                "permissions": "104193601",
+                "position": 0,
+                "flags": 0
+            },
+            {
+                "color": 16711680,
+                "hoist": true,
+                "icon": "c273213790e64f8230f7ea035817cbbf",
+                "id": "100000000000000002",
+                "managed": true,
+                "mentionable": true,
+                "name": "bot",
+                "permissions": "8",
+                "position": 1,
+                "tags": { "bot_id": "100000000000000001", "premium_subscriber": null },
+                "flags": 0
+            }
+        ],
+        "rules_channel_id": "100000000000000004",
+        "splash": null,
+        "system_channel_flags": 0,
+        "system_channel_id": null,
+        "threads": [],
+        "unavailable": false,
+        "vanity_url_code": "twilight",
+        "verification_level": 2,
+        "voice_states": [],
+        "widget_channel_id": null,
+        "widget_enabled": false
+    }"#;
+
+    const INTERACTION: &str = r#"{
+        "app_permissions": "8",
+        "application_id": "100000000000000008",
+        "channel_id": "100000000000000004",
+        "data": {
+            "id": "100000000000000009",
+            "name": "vote",
+            "type": 1,
+            "options": [
+                { "name": "choice", "type": 3, "value": "yes" },
+                { "name": "weight", "type": 4, "value": 3 },
+                { "name": "ratio", "type": 10, "value": 0.5 }
+            ]
+        },
+        "guild_id": "100000000000000005",
+        "guild_locale": "en-US",
+        "id": "100000000000000010",
+        "locale": "en-GB",
+        "member": {
+            "avatar": null,
+            "deaf": false,
+            "flags": 0,
+            "joined_at": "2021-08-10T12:18:37.000000+00:00",
+            "mute": false,
+            "nick": null,
+            "pending": false,
+            "permissions": "8",
+            "roles": [],
+            "user": {
+                "id": "100000000000000001",
+                "username": "twilight",
+                "discriminator": "0001",
+                "avatar": null,
+                "public_flags": 0
+            }
+        },
+        "token": "interaction token",
+        "type": 2,
+        "version": 1
+    }"#;
+
+    const PRESENCE: &str = r#"{
+        "activities": [
+            {
+                "application_id": "100000000000000008",
+                "created_at": 1571048061237,
+                "name": "twilight",
+                "state": "building",
+                "timestamps": { "start": 1571048061237 },
+                "type": 0
+            },
+            { "created_at": 1571048061237, "name": "Custom Status", "type": 4, "state": "hi" }
+        ],
+        "client_status": { "desktop": "online", "mobile": "idle" },
+        "guild_id": "100000000000000005",
+        "status": "online",
+        "user": { "id": "100000000000000001" }
+    }"#;
+
+    const AUDIT_LOG: &str = r#"{
+        "application_commands": [],
+        "audit_log_entries": [
+            {
+                "action_type": 24,
+                "changes": [
+                    { "key": "nick", "new_value": "new", "old_value": "old" },
+                    { "key": "mute", "new_value": true, "old_value": false },
+                    { "key": "$add", "new_value": [{ "id": "100000000000000002", "name": "bot" }] }
+                ],
+                "id": "100000000000000011",
+                "target_id": "100000000000000001",
+                "user_id": "100000000000000001"
+            }
+        ],
+        "auto_moderation_rules": [],
+        "guild_scheduled_events": [],
+        "integrations": [],
+        "threads": [],
+        "users": [],
+        "webhooks": []
+    }"#;
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// Animation of an emoji sent as a voice channel effect.
+///
+/// See [Discord Docs/Animation Types].
+///
+/// [Discord Docs/Animation Types]: https://discord.com/developers/docs/topics/gateway-events#voice-channel-effect-send-animation-types
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum VoiceChannelEffectAnimationType {
+    /// Fun animation, sent by Nitro subscribers.
+    Premium,
+    /// Standard animation.
+    Basic,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for VoiceChannelEffectAnimationType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Premium,
+            1 => Self::Basic,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<VoiceChannelEffectAnimationType> for u8 {
+    fn from(value: VoiceChannelEffectAnimationType) -> Self {
+        match value {
+            VoiceChannelEffectAnimationType::Premium => 0,
+            VoiceChannelEffectAnimationType::Basic => 1,
+            VoiceChannelEffectAnimationType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VoiceChannelEffectAnimationType;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&VoiceChannelEffectAnimationType::Premium, &[Token::U8(0)]);
+        serde_test::assert_tokens(&VoiceChannelEffectAnimationType::Basic, &[Token::U8(1)]);
+        serde_test::assert_tokens(
+            &VoiceChannelEffectAnimationType::Unknown(99),
+            &[Token::U8(99)],
+        );
+    }
+}
@@ -1,7 +1,10 @@
+use crate::gateway::CloseCodeConversionError;
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::convert::TryFrom;
 
 /// Voice gateway close event codes.
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[non_exhaustive]
 #[repr(u16)]
 pub enum CloseCode {
@@ -31,10 +34,85 @@ pub enum CloseCode {
     UnknownEncryptionMode = 4016,
 }
 
+impl CloseCode {
+    /// Whether the close code is one that allows reconnection.
+    ///
+    /// [`Disconnected`] is not reconnectable since it is sent when the client
+    /// was kicked from or the voice channel was deleted, while the remaining
+    /// non-reconnectable close codes are caused by an invalid configuration.
+    ///
+    /// [`Disconnected`]: Self::Disconnected
+    pub const fn can_reconnect(self) -> bool {
+        matches!(
+            self,
+            Self::UnknownOpcode
+                | Self::DecodeError
+                | Self::NotAuthenticated
+                | Self::AlreadyAuthenticated
+                | Self::SessionNoLongerValid
+                | Self::SessionTimedOut
+                | Self::VoiceServerCrashed
+        )
+    }
+
+    /// Whether the session may be resumed after receiving the close code.
+    pub const fn can_resume(self) -> bool {
+        matches!(
+            self,
+            Self::UnknownOpcode
+                | Self::DecodeError
+                | Self::AlreadyAuthenticated
+                | Self::VoiceServerCrashed
+        )
+    }
+
+    /// Description of the configuration error causing a fatal close code.
+    ///
+    /// Returns [`None`] if the close code [allows reconnecting] or, as with
+    /// [`Disconnected`], isn't caused by an invalid configuration.
+    ///
+    /// [`Disconnected`]: Self::Disconnected
+    /// [allows reconnecting]: Self::can_reconnect
+    pub const fn fatal_reason(self) -> Option<&'static str> {
+        Some(match self {
+            Self::AuthenticationFailed => "the voice session token is invalid",
+            Self::ServerNotFound => "the voice server could not be found",
+            Self::UnknownProtocol => "the selected transport protocol is not supported",
+            Self::UnknownEncryptionMode => "the selected encryption mode is not supported",
+            _ => return None,
+        })
+    }
+}
+
+impl TryFrom<u16> for CloseCode {
+    type Error = CloseCodeConversionError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let close_code = match value {
+            4001 => CloseCode::UnknownOpcode,
+            4002 => CloseCode::DecodeError,
+            4003 => CloseCode::NotAuthenticated,
+            4004 => CloseCode::AuthenticationFailed,
+            4005 => CloseCode::AlreadyAuthenticated,
+            4006 => CloseCode::SessionNoLongerValid,
+            4009 => CloseCode::SessionTimedOut,
+            4011 => CloseCode::ServerNotFound,
+            4012 => CloseCode::UnknownProtocol,
+            4014 => CloseCode::Disconnected,
+            4015 => CloseCode::VoiceServerCrashed,
+            4016 => CloseCode::UnknownEncryptionMode,
+            _ => return Err(CloseCodeConversionError::new(value)),
+        };
+
+        Ok(close_code)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::CloseCode;
     use serde_test::Token;
+    use std::convert::TryFrom;
 
     #[test]
     fn variants() {
@@ -50,4 +128,23 @@ mod tests {
         serde_test::assert_tokens(&CloseCode::VoiceServerCrashed, &[Token::U16(4015)]);
         serde_test::assert_tokens(&CloseCode::UnknownEncryptionMode, &[Token::U16(4016)]);
     }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            CloseCode::SessionNoLongerValid,
+            CloseCode::try_from(4006).unwrap()
+        );
+        assert_eq!(4007, CloseCode::try_from(4007).unwrap_err().code());
+    }
+
+    #[test]
+    fn reconnect() {
+        assert!(CloseCode::VoiceServerCrashed.can_resume());
+        assert!(CloseCode::SessionNoLongerValid.can_reconnect());
+        assert!(!CloseCode::SessionNoLongerValid.can_resume());
+        assert!(!CloseCode::Disconnected.can_reconnect());
+        assert!(CloseCode::Disconnected.fatal_reason().is_none());
+        assert!(CloseCode::UnknownEncryptionMode.fatal_reason().is_some());
+    }
 }
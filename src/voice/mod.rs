@@ -1,11 +1,13 @@
 //! Voice connection and gateway definitions.
 #![warn(missing_docs)]
 
+mod channel_effect_animation_type;
 mod close_code;
 mod opcode;
 mod voice_region;
 mod voice_state;
 
 pub use self::{
-    close_code::CloseCode, opcode::OpCode, voice_region::VoiceRegion, voice_state::VoiceState,
+    channel_effect_animation_type::VoiceChannelEffectAnimationType, close_code::CloseCode,
+    opcode::OpCode, voice_region::VoiceRegion, voice_state::VoiceState,
 };
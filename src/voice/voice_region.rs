@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// Geographically based collection of voice servers.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VoiceRegion {
     /// Whether this is a custom voice region, used for e.g. events.
     pub custom: bool,